@@ -1,12 +1,145 @@
 //! Authentication configuration module
 //!
 //! Handles loading and saving API credentials from ~/.config/polymarket-tui/auth.json
+//!
+//! The file holds either a single set of credentials or several named profiles
+//! (`{"active": "main", "profiles": {"main": {...}, "alt": {...}}}`); a single set is
+//! treated as one profile named [`DEFAULT_PROFILE`].
+//!
+//! Without an auth.json (or an active profile in it), credentials come from the
+//! `api_key`, `secret`, `passphrase` and `address` environment variables, the same
+//! ones `ClobClient::from_env` reads. The file always wins when both are set.
 
 use {
+    polymarket_api::signing::SignatureType,
     serde::{Deserialize, Serialize},
-    std::path::PathBuf,
+    std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+    },
 };
 
+/// Profile name used for auth.json files without named profiles
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Whether `address` looks like a wallet address (0x + 40 characters)
+pub fn is_valid_address(address: &str) -> bool {
+    address.starts_with("0x") && address.len() == 42
+}
+
+/// Named credential profiles stored in auth.json
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AuthProfiles {
+    /// Profile loaded on startup
+    #[serde(default)]
+    pub active: Option<String>,
+    pub profiles: HashMap<String, AuthConfig>,
+}
+
+/// Either layout of auth.json
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredAuth {
+    Profiles(AuthProfiles),
+    Single(Box<AuthConfig>),
+}
+
+impl AuthProfiles {
+    /// Parse auth.json content in either layout
+    pub fn from_json(content: &str) -> Result<Self, String> {
+        match serde_json::from_str(content) {
+            Ok(StoredAuth::Profiles(profiles)) => Ok(profiles),
+            Ok(StoredAuth::Single(config)) => Ok(Self {
+                active: Some(DEFAULT_PROFILE.to_string()),
+                profiles: HashMap::from([(DEFAULT_PROFILE.to_string(), *config)]),
+            }),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Credentials of the active profile
+    pub fn active_config(&self) -> Option<&AuthConfig> {
+        self.profiles.get(self.active.as_deref()?)
+    }
+
+    /// Profile names in alphabetical order
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.profiles.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Saved profiles, or None without an auth.json (errors are printed)
+    fn read() -> Option<Self> {
+        Self::try_read().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            None
+        })
+    }
+
+    /// Saved profiles, or None without an auth.json
+    ///
+    /// Errors when the file can't be read or parsed, so callers that write it back
+    /// don't replace the other saved profiles with an empty set.
+    fn try_read() -> Result<Option<Self>, String> {
+        Self::read_from(&AuthConfig::config_path())
+    }
+
+    fn read_from(path: &Path) -> Result<Option<Self>, String> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read auth config: {}", e))?;
+        Self::from_json(&content)
+            .map(Some)
+            .map_err(|e| format!("Failed to parse auth config {}: {}", path.display(), e))
+    }
+
+    fn write(&self) -> Result<(), String> {
+        let dir = AuthConfig::config_dir();
+        if !dir.exists() {
+            std::fs::create_dir_all(&dir)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize auth config: {}", e))?;
+
+        write_private(&AuthConfig::config_path(), &content)
+            .map_err(|e| format!("Failed to write auth config: {}", e))
+    }
+}
+
+/// Order signing key from the `private_key` variable or its `POLY_PRIVATE_KEY` alias
+fn private_key_var(var: impl Fn(&str) -> Option<String>) -> Option<String> {
+    var("private_key").or_else(|| var("POLY_PRIVATE_KEY"))
+}
+
+/// Write `content` to `path`, readable only by the owner on unix
+///
+/// auth.json holds API secrets and possibly the wallet's private key.
+fn write_private(path: &Path, content: &str) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::{
+            io::Write,
+            os::unix::fs::{OpenOptionsExt, PermissionsExt},
+        };
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        // `mode` only applies when the file is created, so tighten existing files too
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        file.write_all(content.as_bytes())
+    }
+    #[cfg(not(unix))]
+    std::fs::write(path, content)
+}
+
 /// Authentication credentials for Polymarket API
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +168,15 @@ pub struct AuthConfig {
     /// Look for the "polymarketauthtype" cookie value
     #[serde(default)]
     pub session_auth_type: Option<String>,
+    /// Optional hex private key of the wallet that signs orders (required to trade)
+    #[serde(default)]
+    pub private_key: Option<String>,
+    /// Wallet kind of `address`: "eoa", "poly_proxy" (email accounts) or
+    /// "poly_gnosis_safe" (browser wallet accounts)
+    ///
+    /// Inferred from `private_key` when unset: eoa if it owns `address`, else poly_proxy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_type: Option<SignatureType>,
 }
 
 #[allow(dead_code)]
@@ -51,54 +193,105 @@ impl AuthConfig {
         Self::config_dir().join("auth.json")
     }
 
-    /// Load auth config from file
+    /// Load the active profile's credentials from file, falling back to the environment
     pub fn load() -> Option<Self> {
-        let path = Self::config_path();
-        if !path.exists() {
-            return None;
-        }
+        Self::load_saved().or_else(Self::from_env)
+    }
 
-        match std::fs::read_to_string(&path) {
-            Ok(content) => match serde_json::from_str(&content) {
-                Ok(config) => Some(config),
-                Err(e) => {
-                    eprintln!("Failed to parse auth config: {}", e);
-                    None
-                },
-            },
-            Err(e) => {
-                eprintln!("Failed to read auth config: {}", e);
-                None
-            },
-        }
+    /// Load the active profile's credentials from file only (never the environment)
+    pub fn load_saved() -> Option<Self> {
+        AuthProfiles::read()?.active_config().cloned()
     }
 
-    /// Save auth config to file
-    pub fn save(&self) -> Result<(), String> {
-        let dir = Self::config_dir();
-        if !dir.exists() {
-            std::fs::create_dir_all(&dir)
-                .map_err(|e| format!("Failed to create config directory: {}", e))?;
-        }
+    /// Credentials from the `api_key`, `secret`, `passphrase` and `address`
+    /// environment variables (`poly_address`/`POLY_ADDRESS` also work for the address)
+    pub fn from_env() -> Option<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
 
-        let path = Self::config_path();
-        let content = serde_json::to_string_pretty(self)
-            .map_err(|e| format!("Failed to serialize auth config: {}", e))?;
+    /// Credentials assembled from variables looked up with `var`, if all four are set
+    pub fn from_vars(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let address = var("address")
+            .or_else(|| var("poly_address"))
+            .or_else(|| var("POLY_ADDRESS"))?;
+        Some(Self {
+            api_key: var("api_key")?,
+            secret: var("secret")?,
+            passphrase: var("passphrase")?,
+            address,
+            username: None,
+            session_cookie: None,
+            session_nonce: None,
+            session_auth_type: None,
+            private_key: private_key_var(&var),
+            signature_type: None,
+        })
+    }
 
-        std::fs::write(&path, content)
-            .map_err(|e| format!("Failed to write auth config: {}", e))?;
+    /// Load a named profile's credentials from file
+    pub fn load_profile(name: &str) -> Option<Self> {
+        AuthProfiles::read()?.profiles.remove(name)
+    }
 
-        Ok(())
+    /// Names of the saved profiles in alphabetical order
+    pub fn list_profiles() -> Vec<String> {
+        AuthProfiles::read()
+            .map(|profiles| profiles.names())
+            .unwrap_or_default()
+    }
+
+    /// Name of the profile loaded on startup
+    pub fn active_profile() -> Option<String> {
+        AuthProfiles::read()?.active
+    }
+
+    /// Make a saved profile the active one
+    pub fn set_active_profile(name: &str) -> Result<(), String> {
+        let mut profiles = AuthProfiles::try_read()?.unwrap_or_default();
+        if !profiles.profiles.contains_key(name) {
+            return Err(format!("No profile named '{}'", name));
+        }
+        profiles.active = Some(name.to_string());
+        profiles.write()
     }
 
-    /// Delete auth config file (logout)
+    /// Save as the active profile (or [`DEFAULT_PROFILE`] if none is active)
+    pub fn save(&self) -> Result<(), String> {
+        let name = AuthProfiles::try_read()?
+            .and_then(|profiles| profiles.active)
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+        self.save_profile(&name)
+    }
+
+    /// Save as a named profile and make it the active one
+    ///
+    /// Refuses to write when auth.json exists but can't be parsed, which would
+    /// otherwise drop every other saved profile.
+    pub fn save_profile(&self, name: &str) -> Result<(), String> {
+        let mut profiles = AuthProfiles::try_read()?.unwrap_or_default();
+        profiles.profiles.insert(name.to_string(), self.clone());
+        profiles.active = Some(name.to_string());
+        profiles.write()
+    }
+
+    /// Delete the active profile (logout), removing the file when none are left
     pub fn delete() -> Result<(), String> {
         let path = Self::config_path();
-        if path.exists() {
-            std::fs::remove_file(&path)
-                .map_err(|e| format!("Failed to delete auth config: {}", e))?;
+        let Some(mut profiles) = AuthProfiles::try_read()? else {
+            if path.exists() {
+                std::fs::remove_file(&path)
+                    .map_err(|e| format!("Failed to delete auth config: {}", e))?;
+            }
+            return Ok(());
+        };
+        if let Some(name) = profiles.active.take() {
+            profiles.profiles.remove(&name);
+        }
+        if profiles.profiles.is_empty() {
+            std::fs::remove_file(&path).map_err(|e| format!("Failed to delete auth config: {}", e))
+        } else {
+            profiles.write()
         }
-        Ok(())
     }
 
     /// Validate the credentials format
@@ -125,14 +318,52 @@ impl AuthConfig {
         }
 
         // Check address is valid format (0x + 40 hex chars)
-        let address = self.address.trim();
-        if !address.starts_with("0x") || address.len() != 42 {
+        if !is_valid_address(self.address.trim()) {
             return Err("Address should be 0x followed by 40 hex characters".to_string());
         }
 
         Ok(())
     }
 
+    /// CLOB client authenticated with these credentials
+    ///
+    /// The order signing key comes from `private_key`, or the `private_key`
+    /// (or `POLY_PRIVATE_KEY`) environment variable.
+    pub fn clob_client(&self) -> Result<polymarket_api::ClobClient, String> {
+        let mut client = polymarket_api::ClobClient::with_auth(
+            self.api_key.clone(),
+            self.secret.clone(),
+            self.passphrase.clone(),
+            self.address.clone(),
+        );
+        if let Some(private_key) = self
+            .private_key
+            .clone()
+            .or_else(|| private_key_var(|name| std::env::var(name).ok()))
+        {
+            client
+                .set_private_key(&private_key)
+                .map_err(|e| e.to_string())?;
+        }
+        if let Some(signature_type) = self.signature_type {
+            client.set_signature_type(signature_type);
+        }
+        Ok(client)
+    }
+
+    /// Gamma client sending these credentials and session cookies (for favorites)
+    pub fn gamma_client(&self) -> polymarket_api::GammaClient {
+        polymarket_api::GammaClient::with_auth(polymarket_api::GammaAuth {
+            api_key: self.api_key.clone(),
+            api_secret: self.secret.clone(),
+            passphrase: self.passphrase.clone(),
+            address: self.address.clone(),
+            session_cookie: self.session_cookie.clone(),
+            session_nonce: self.session_nonce.clone(),
+            session_auth_type: self.session_auth_type.clone(),
+        })
+    }
+
     /// Get a shortened display version of the address
     pub fn short_address(&self) -> String {
         if self.address.len() >= 10 {
@@ -153,3 +384,125 @@ impl AuthConfig {
             .unwrap_or_else(|| self.short_address())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_profiles_layouts() {
+        let creds = |address: &str| {
+            serde_json::json!({
+                "api_key": "key",
+                "secret": "secret",
+                "passphrase": "passphrase",
+                "address": address
+            })
+        };
+
+        // A single set of credentials is the default profile
+        let single = AuthProfiles::from_json(
+            &creds("0x0000000000000000000000000000000000000001").to_string(),
+        )
+        .expect("Should parse single credentials");
+        assert_eq!(single.names(), vec![DEFAULT_PROFILE]);
+        assert_eq!(single.active.as_deref(), Some(DEFAULT_PROFILE));
+
+        let named = serde_json::json!({
+            "active": "trading",
+            "profiles": {
+                "trading": creds("0x0000000000000000000000000000000000000002"),
+                "main": creds("0x0000000000000000000000000000000000000001")
+            }
+        });
+        let named = AuthProfiles::from_json(&named.to_string()).expect("Should parse profiles");
+        assert_eq!(named.names(), vec!["main", "trading"]);
+        assert_eq!(
+            named.active_config().map(|c| c.address.as_str()),
+            Some("0x0000000000000000000000000000000000000002")
+        );
+    }
+
+    #[test]
+    fn test_auth_config_from_env_vars() {
+        use std::collections::HashMap;
+
+        let mut vars = HashMap::from([
+            ("api_key", "key"),
+            ("secret", "secret"),
+            ("passphrase", "passphrase"),
+            ("POLY_ADDRESS", "0x0000000000000000000000000000000000000001"),
+        ]);
+        let lookup = |vars: &HashMap<&str, &str>| {
+            AuthConfig::from_vars(|name| vars.get(name).map(|v| v.to_string()))
+        };
+
+        let config = lookup(&vars).expect("Should assemble credentials from the environment");
+        assert_eq!(config.api_key, "key");
+        assert_eq!(config.address, "0x0000000000000000000000000000000000000001");
+        assert!(config.username.is_none() && config.private_key.is_none());
+
+        // `address` takes precedence over the POLY_ADDRESS alias
+        vars.insert("address", "0x0000000000000000000000000000000000000002");
+        let config = lookup(&vars).expect("Should assemble credentials from the environment");
+        assert_eq!(config.address, "0x0000000000000000000000000000000000000002");
+
+        // The signing key can come from POLY_PRIVATE_KEY, with `private_key` preferred
+        vars.insert("POLY_PRIVATE_KEY", "0xabc");
+        let config = lookup(&vars).expect("Should assemble credentials from the environment");
+        assert_eq!(config.private_key.as_deref(), Some("0xabc"));
+        vars.insert("private_key", "0xdef");
+        let config = lookup(&vars).expect("Should assemble credentials from the environment");
+        assert_eq!(config.private_key.as_deref(), Some("0xdef"));
+
+        // All four values are required
+        vars.remove("passphrase");
+        assert!(lookup(&vars).is_none());
+    }
+
+    #[test]
+    fn test_read_from_reports_unparseable_file() {
+        let dir = std::env::temp_dir().join(format!("polymarket-auth-read-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("Should create dir");
+        let path = dir.join("auth.json");
+        assert_eq!(
+            AuthProfiles::read_from(&path).map(|p| p.is_some()),
+            Ok(false)
+        );
+
+        // One bad field fails both layouts, which must not read as "no profiles"
+        std::fs::write(&path, r#"{"profiles": {"main": {"api_key": 1}}}"#)
+            .expect("Should write file");
+        let err = AuthProfiles::read_from(&path).expect_err("Should not parse");
+        assert!(err.contains("Failed to parse auth config"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_private_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("polymarket-auth-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("Should create dir");
+        let path = dir.join("auth.json");
+        // An existing world-readable file is tightened on the next write
+        std::fs::write(&path, "{}").expect("Should write file");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644))
+            .expect("Should set permissions");
+
+        write_private(&path, "{\"profiles\": {}}").expect("Should write auth file");
+        let mode = std::fs::metadata(&path)
+            .expect("Should stat file")
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("Should read file"),
+            "{\"profiles\": {}}"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
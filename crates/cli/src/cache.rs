@@ -0,0 +1,38 @@
+//! On-disk API cache shared by the CLI commands and the TUI
+
+use {
+    polymarket_api::{GammaClient, default_cache_dir},
+    std::{
+        path::PathBuf,
+        sync::atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// Cleared by `--no-cache`
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Bypass the cache for the rest of the run
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Cache directory (configurable via the POLYMARKET_CACHE_DIR env var)
+pub fn cache_dir() -> PathBuf {
+    std::env::var("POLYMARKET_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| default_cache_dir())
+}
+
+/// Gamma client backed by the file cache, or uncached with `--no-cache`
+///
+/// Falls back to an uncached client if the cache directory can't be created.
+pub fn gamma_client() -> GammaClient {
+    if !is_enabled() {
+        return GammaClient::new();
+    }
+    GammaClient::with_cache(cache_dir()).unwrap_or_else(|_| GammaClient::new())
+}
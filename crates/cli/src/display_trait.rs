@@ -1,4 +1,105 @@
-use polymarket_api::rtds::RTDSMessage;
+use {
+    polymarket_api::rtds::RTDSMessage,
+    std::sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Cleared by `--no-color` or a non-empty NO_COLOR environment variable
+static COLOR: AtomicBool = AtomicBool::new(true);
+
+/// Turn off colors and emoji for the rest of the run
+pub fn disable_color() {
+    COLOR.store(false, Ordering::Relaxed);
+    colored::control::set_override(false);
+}
+
+pub fn color_enabled() -> bool {
+    COLOR.load(Ordering::Relaxed)
+}
+
+/// Whether NO_COLOR asks for plain output (set and not empty, per no-color.org)
+pub fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Trade side with a colored-dot emoji, or an ASCII marker without color
+pub fn side_marker(side: &str) -> &'static str {
+    match (side == "BUY", color_enabled()) {
+        (true, true) => "🟢 BUY",
+        (false, true) => "🔴 SELL",
+        (true, false) => "[BUY]",
+        (false, false) => "[SELL]",
+    }
+}
+
+/// Shown instead of a number that is NaN or infinite (malformed API data)
+#[cfg(feature = "tui")]
+pub const MISSING_VALUE: &str = "—";
+
+/// How prices (0-1 per share) are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum PriceFormat {
+    /// Cents like the Polymarket website (e.g. 95¢)
+    #[default]
+    Cents,
+    /// Dollars per share (e.g. $0.950)
+    Dollars,
+}
+
+#[cfg(feature = "tui")]
+impl PriceFormat {
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Cents => Self::Dollars,
+            Self::Dollars => Self::Cents,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Cents => "cents",
+            Self::Dollars => "dollars",
+        }
+    }
+}
+
+/// Format a price (0.0-1.0) in cents or dollars
+///
+/// Cents use 1 decimal place for sub-10¢ and 99-100¢ prices to match the website
+/// rounding: 0.01 -> "1¢", 0.89 -> "89¢", 0.003 -> "0.3¢", 0.998 -> "99.8¢".
+/// Dollars keep three decimals: 0.95 -> "$0.950".
+#[cfg(feature = "tui")]
+pub fn format_price(price: f64, format: PriceFormat) -> String {
+    if !price.is_finite() {
+        return MISSING_VALUE.to_string();
+    }
+    if format == PriceFormat::Dollars {
+        return format!("${:.3}", price);
+    }
+    let cents = price * 100.0;
+    if cents < 0.1 {
+        // Very small prices, show with 2 decimal places
+        format!("{:.2}¢", cents)
+    } else if cents < 10.0 || (cents > 99.0 && cents < 100.0) {
+        format!("{:.1}¢", cents)
+    } else {
+        format!("{:.0}¢", cents)
+    }
+}
+
+/// Format a price to a tenth of a cent (or four decimals in dollars)
+///
+/// For order prices and fill estimates, where [`format_price`] rounding hides
+/// slippage: 0.636 -> "63.6¢" or "$0.6360".
+#[cfg(feature = "tui")]
+pub fn format_price_exact(price: f64, format: PriceFormat) -> String {
+    if !price.is_finite() {
+        return MISSING_VALUE.to_string();
+    }
+    match format {
+        PriceFormat::Cents => format!("{:.1}¢", price * 100.0),
+        PriceFormat::Dollars => format!("${:.4}", price),
+    }
+}
 
 /// Trait for displaying RTDS trade messages
 pub trait TradeDisplay: Send + Sync {
@@ -7,14 +108,34 @@ pub trait TradeDisplay: Send + Sync {
 }
 
 /// Simple println-based display (default)
-pub struct SimpleDisplay;
+#[derive(Default)]
+pub struct SimpleDisplay {
+    /// Print each trader's wallet address under their pseudonym
+    pub show_address: bool,
+}
 
 impl TradeDisplay for SimpleDisplay {
     fn display_trade(&mut self, msg: &RTDSMessage) -> anyhow::Result<()> {
         use polymarket_api::RTDSFormatter;
 
-        let formatted = RTDSFormatter::format_message(msg);
+        let formatted = RTDSFormatter::default()
+            .with_address(self.show_address)
+            .format(msg);
         print!("{}", formatted);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_side_marker_without_color() {
+        assert!(color_enabled());
+        assert_eq!(side_marker("BUY"), "🟢 BUY");
+        disable_color();
+        assert_eq!(side_marker("BUY"), "[BUY]");
+        assert_eq!(side_marker("SELL"), "[SELL]");
+    }
+}
@@ -1,5 +1,15 @@
 mod auth;
+mod cache;
 mod display_trait;
+mod metrics;
+mod price_alert;
+mod trade_export;
+
+#[cfg(feature = "tui")]
+mod paper;
+
+#[cfg(feature = "tui")]
+mod settings;
 
 #[cfg(feature = "tui")]
 mod tui;
@@ -10,21 +20,33 @@ mod trending_tui;
 #[cfg(all(feature = "tui", feature = "tracing"))]
 mod tui_log_layer;
 
+#[cfg(feature = "tui")]
+mod working_set;
+
 use {
     anyhow::{Context, Result},
     clap::{Parser, Subcommand},
     colored::Colorize,
-    display_trait::TradeDisplay,
+    display_trait::{PriceFormat, TradeDisplay},
     polymarket_api::{
         ClobClient, DataClient, GammaClient, MarketUpdateFormatter, PolymarketWebSocket,
-        RTDSClient, default_cache_dir, lock_mutex,
+        RTDSClient,
+        clob::{Orderbook, OrderbookSummary, Side},
+        gamma::{CacheConfig, OutcomeNameStyle},
+        lock_mutex,
+        rtds::RTDSMessage,
+        websocket::{DEFAULT_SUBSCRIPTION_CHUNK_SIZE, MessageKind},
+        yields::find_yield_opportunities,
     },
     std::{
-        collections::HashMap,
+        collections::{HashMap, HashSet},
         env,
+        net::SocketAddr,
         path::PathBuf,
         sync::{Arc, Mutex},
+        time::Duration,
     },
+    trade_export::{OutputFormat, TradeExport},
 };
 
 /// Macro to log info messages only when tracing feature is enabled
@@ -44,6 +66,19 @@ macro_rules! log_info {
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    /// Serve API responses from a fixtures directory instead of the network (offline mode).
+    /// Can also be set with the POLYMARKET_FIXTURES environment variable.
+    #[arg(long, global = true, value_name = "DIR")]
+    fixtures: Option<PathBuf>,
+    /// Print orderbook, price, token, trades, event, markets, market and status results as JSON on stdout (logs go to stderr)
+    #[arg(long, global = true)]
+    json: bool,
+    /// Skip the on-disk cache (market info, public profiles) and always hit the API
+    #[arg(long, global = true)]
+    no_cache: bool,
+    /// Print without colors or emoji (also set by the NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    no_color: bool,
 }
 
 #[derive(Subcommand)]
@@ -57,6 +92,30 @@ enum Commands {
         /// Event slug to filter RTDS activity (only used with --rtds)
         #[arg(long)]
         event: Option<String>,
+        /// Ring the bell when an asset's price moves more than CENTS between
+        /// updates (repeatable, not used with --rtds)
+        #[arg(long = "alert", value_name = "ASSET_ID:CENTS")]
+        alerts: Vec<price_alert::PriceAlert>,
+        /// Only print these message types, e.g. `trade,price` (default: all, not
+        /// used with --rtds)
+        #[arg(long, value_name = "TYPES", value_delimiter = ',')]
+        only: Vec<MessageKind>,
+        /// Serve Prometheus metrics on this address (e.g. 127.0.0.1:9100, not
+        /// used with --rtds)
+        #[arg(long, value_name = "ADDR")]
+        metrics_addr: Option<SocketAddr>,
+        /// Subscribe at most N asset IDs per WebSocket connection, opening more
+        /// connections for the rest (not used with --rtds)
+        #[arg(long, value_name = "N", default_value_t = DEFAULT_SUBSCRIPTION_CHUNK_SIZE)]
+        chunk_size: usize,
+        /// Print each RTDS message as one JSON line instead of text (logs go to
+        /// stderr)
+        #[arg(long, requires = "rtds")]
+        ndjson: bool,
+        /// Show each trader's wallet address (short and full) under their
+        /// pseudonym (only used with --rtds)
+        #[arg(long, requires = "rtds")]
+        show_address: bool,
     },
     /// Watch trade activity for a specific event using RTDS
     WatchEvent {
@@ -66,6 +125,13 @@ enum Commands {
         /// Use TUI mode (requires --features tui)
         #[arg(long)]
         tui: bool,
+        /// Skip trades worth less than this many dollars
+        #[arg(long, value_name = "USD", default_value = "0")]
+        min_size: f64,
+        /// Show each trader's wallet address (short and full) under their
+        /// pseudonym (not used with --tui)
+        #[arg(long)]
+        show_address: bool,
     },
     /// Get orderbook for a market
     Orderbook {
@@ -75,6 +141,33 @@ enum Commands {
         /// Use asset ID instead of condition ID
         #[arg(long)]
         asset: bool,
+        /// Number of price levels to show and count per side (default: all)
+        #[arg(long, value_name = "N")]
+        depth: Option<usize>,
+        /// Price band around the mid for depth totals (0.05 = 5¢)
+        #[arg(long, default_value = "0.05")]
+        band: f64,
+        /// Keep re-fetching and redrawing the book until Ctrl+C
+        #[arg(long)]
+        watch: bool,
+        /// Seconds between polls with --watch
+        #[arg(long, value_name = "SECS", default_value = "2", requires = "watch")]
+        interval: u64,
+    },
+    /// Get the best price for a token on one side of the book
+    Price {
+        /// Asset (token) ID
+        #[arg(value_name = "ASSET_ID")]
+        market: String,
+        /// `sell` for the best ask, `buy` for the best bid
+        #[arg(long, default_value = "sell")]
+        side: Side,
+    },
+    /// Show which market and outcome an asset (token) ID belongs to
+    Token {
+        /// Asset (token) ID, e.g. from a trade feed
+        #[arg(value_name = "ASSET_ID")]
+        asset_id: String,
     },
     /// Get recent trades for a market
     Trades {
@@ -84,6 +177,9 @@ enum Commands {
         /// Limit number of trades
         #[arg(long, default_value = "10")]
         limit: usize,
+        /// Skip this many of the most recent trades (for paging through history)
+        #[arg(long, default_value = "0")]
+        offset: usize,
         /// Use asset ID instead of condition ID
         #[arg(long)]
         asset: bool,
@@ -93,6 +189,16 @@ enum Commands {
         /// Use event slug
         #[arg(long)]
         event_slug: bool,
+        /// Treat MARKET as a wallet address and list that user's trades
+        #[arg(long, conflicts_with_all = ["asset", "event_id", "event_slug"])]
+        user: bool,
+        /// Only trades newer than this, e.g. `30m`, `2h` or `1d` (applied before
+        /// --limit)
+        #[arg(long, value_name = "DURATION")]
+        since: Option<String>,
+        /// Output format (csv and json write to stdout)
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        format: OutputFormat,
     },
     /// Get event information
     Event {
@@ -102,6 +208,21 @@ enum Commands {
         /// Use event ID instead of slug
         #[arg(long)]
         id: bool,
+        /// Print only the markets' CLOB token IDs, one per line
+        #[arg(long, conflicts_with = "condition_ids_only")]
+        token_ids_only: bool,
+        /// Print only the markets' condition IDs, one per line
+        #[arg(long)]
+        condition_ids_only: bool,
+    },
+    /// List an event's markets with their condition and token IDs, prices and volume
+    Markets {
+        /// Event ID, slug or URL (all-digit values are treated as IDs)
+        #[arg(value_name = "EVENT")]
+        event: String,
+        /// Treat EVENT as a slug even if it is all digits
+        #[arg(long)]
+        slug: bool,
     },
     /// Get market information
     Market {
@@ -111,10 +232,19 @@ enum Commands {
         /// Use market ID instead of slug
         #[arg(long)]
         id: bool,
+        /// Use the CTF condition ID instead of slug
+        #[arg(long, conflicts_with = "id")]
+        condition: bool,
+        /// Print only the CLOB token IDs, one per line
+        #[arg(long, conflicts_with = "condition_ids_only")]
+        token_ids_only: bool,
+        /// Print only the condition IDs, one per line
+        #[arg(long)]
+        condition_ids_only: bool,
     },
     /// Browse trending events in TUI (requires --features tui)
     Trending {
-        /// Order by field (e.g., volume24hr, volume7d, volume30d)
+        /// Order by field (e.g., volume24hr, volume7d, volume30d, liquidity, competitive, startDate)
         #[arg(long, default_value = "volume24hr")]
         order_by: String,
         /// Sort ascending instead of descending
@@ -123,6 +253,32 @@ enum Commands {
         /// Limit number of events
         #[arg(long, default_value = "50")]
         limit: usize,
+        /// How to abbreviate outcome names: full, acronym, group, truncate or truncate:N
+        #[arg(long, default_value = "full")]
+        outcome_names: OutcomeNameStyle,
+        /// Show prices in cents or dollars (switch with `C` in the TUI)
+        #[arg(long, value_enum, default_value_t = PriceFormat::Cents)]
+        price_format: PriceFormat,
+        /// Minimum probability for yield opportunities (Yield tab and `$` list icons)
+        /// [default: last saved value, or 0.95]
+        #[arg(long)]
+        min_prob: Option<f64>,
+        /// Hide Yield tab markets resolving more than this many days out
+        /// [default: last saved value]
+        #[arg(long, value_name = "DAYS")]
+        max_days: Option<u32>,
+        /// Hide Yield tab markets with less liquidity than this many dollars
+        /// [default: last saved value, or 0]
+        #[arg(long, value_name = "USD")]
+        min_liquidity: Option<f64>,
+        /// Refresh the selected event's market prices every SECS seconds (0 = off)
+        /// [default: last saved value, or 10]
+        #[arg(long, value_name = "SECS")]
+        price_refresh: Option<u64>,
+        /// Paper trading: simulate Trade popup orders against the orderbook instead of
+        /// posting them (switch with `T` in the TUI)
+        #[arg(long)]
+        paper: bool,
     },
     /// Find high-probability markets for yield opportunities
     Yield {
@@ -138,60 +294,147 @@ enum Commands {
         /// Only show events expiring within this duration (e.g., "24h", "7d", "30d")
         #[arg(long)]
         expires_in: Option<String>,
+        /// How to abbreviate outcome names: full, acronym, group, truncate or truncate:N
+        #[arg(long, default_value = "full")]
+        outcome_names: OutcomeNameStyle,
+    },
+    /// Show a wallet's positions with their value and P&L
+    Positions {
+        /// Wallet address (defaults to the address in auth.json)
+        #[arg(value_name = "ADDRESS")]
+        address: Option<String>,
+        /// Include fully-exited positions
+        #[arg(long)]
+        closed: bool,
+    },
+    /// Check the credentials in auth.json (exits non-zero if none are configured)
+    Whoami,
+    /// Ping the Gamma, Data and CLOB APIs and RTDS, with round-trip times
+    /// (exits non-zero if any API is down)
+    Status,
+    /// Back up or restore your Polymarket favorites (needs the session cookie in auth.json)
+    Favorites {
+        /// Write the favorited events' slugs and titles as JSON to FILE (stdout without one)
+        #[arg(
+            long,
+            value_name = "FILE",
+            num_args = 0..=1,
+            conflicts_with = "import",
+            required_unless_present = "import"
+        )]
+        export: Option<Option<PathBuf>>,
+        /// Favorite every event in a file written by --export (already favorited ones
+        /// are skipped)
+        #[arg(long, value_name = "FILE")]
+        import: Option<PathBuf>,
+    },
+    /// Export watchlist, favorites and settings to a portable JSON file (no credentials)
+    ExportState {
+        /// Output file
+        #[arg(value_name = "FILE", default_value = "polymarket-state.json")]
+        file: PathBuf,
+    },
+    /// Import a file written by export-state, merging it into the local settings
+    ImportState {
+        /// File written by export-state
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+    },
+    /// Show how much the on-disk API cache holds, or purge it with --clear
+    Cache {
+        /// Remove cached entries
+        #[arg(long)]
+        clear: bool,
+        /// Only remove entries past their TTL (with --clear)
+        #[arg(long, requires = "clear")]
+        expired_only: bool,
     },
 }
 
+/// Canonical event slug from a slug or an event URL
+///
+/// For `polymarket.com/event/SLUG/...` URLs (with or without a scheme), everything
+/// after the slug segment (further path parts, `?query`, `#fragment`) is dropped.
 fn extract_event_slug(event_input: &str) -> String {
-    // If it's a URL, extract the slug
-    if event_input.starts_with("http://") || event_input.starts_with("https://") {
-        // Extract slug from URL pattern: https://polymarket.com/event/SLUG
-        if let Some(slug_part) = event_input.split("/event/").nth(1) {
-            // Remove query params and trailing slash if present
-            slug_part
-                .split('?')
-                .next()
-                .unwrap_or(slug_part)
-                .trim_end_matches('/')
-                .to_string()
-        } else {
-            event_input.to_string()
-        }
-    } else {
+    let input = event_input.trim();
+    let slug = match input.split_once("/event/") {
+        Some((_, rest)) => rest.split(['/', '?', '#']).next().unwrap_or(rest),
         // Already a slug
-        event_input.to_string()
-    }
+        None => input,
+    };
+    slug.to_lowercase()
 }
 
-async fn run_monitor(use_rtds: bool, event_slug: Option<String>) -> Result<()> {
+async fn run_monitor(
+    use_rtds: bool,
+    event_slug: Option<String>,
+    alerts: Vec<price_alert::PriceAlert>,
+    only: Vec<MessageKind>,
+    metrics_addr: Option<SocketAddr>,
+    chunk_size: usize,
+    ndjson: bool,
+    show_address: bool,
+) -> Result<()> {
     if use_rtds {
-        return run_monitor_rtds(event_slug).await;
+        if !alerts.is_empty() {
+            anyhow::bail!("--alert needs the CLOB WebSocket (drop --rtds)");
+        }
+        if !only.is_empty() {
+            anyhow::bail!("--only filters CLOB WebSocket messages (drop --rtds)");
+        }
+        if metrics_addr.is_some() {
+            anyhow::bail!("--metrics-addr needs the CLOB WebSocket (drop --rtds)");
+        }
+        return run_monitor_rtds(event_slug, ndjson, show_address).await;
     }
     log_info!("🚀 Polymarket Real-Time Monitor");
-    log_info!("Connecting to Polymarket WebSocket...");
 
-    // Setup cache directory (configurable via POLYMARKET_CACHE_DIR env var)
-    let cache_dir = env::var("POLYMARKET_CACHE_DIR")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| default_cache_dir());
+    let metrics = match metrics_addr {
+        Some(addr) => {
+            let metrics = Arc::new(metrics::MonitorMetrics::default());
+            let server = metrics::serve(addr, Arc::clone(&metrics))
+                .with_context(|| format!("Failed to serve metrics on {}", addr))?;
+            tokio::spawn(async move {
+                if let Err(_e) = server.await {
+                    log_info!("Metrics server stopped: {}", _e);
+                }
+            });
+            log_info!("📈 Serving metrics on http://{}/metrics", addr);
+            Some(metrics)
+        },
+        None => None,
+    };
 
-    log_info!("Using cache directory: {}", cache_dir.display());
+    log_info!("Connecting to Polymarket WebSocket...");
 
-    // Create Gamma client with file-based caching
-    // Market info is cached for 24 hours (market data rarely changes)
-    let mut gamma_client =
-        GammaClient::with_cache(&cache_dir).context("Failed to create Gamma client with cache")?;
+    // Create Gamma client with file-based caching (unless --no-cache)
+    // Market info is cached for 24 hours, event listings (with prices) for 5 minutes
+    let mut gamma_client = if cache::is_enabled() {
+        let cache_dir = cache::cache_dir();
+        log_info!("Using cache directory: {}", cache_dir.display());
+        GammaClient::with_cache(&cache_dir).context("Failed to create Gamma client with cache")?
+    } else {
+        GammaClient::new()
+    };
 
-    // Set cache TTL to 24 hours
-    gamma_client
-        .set_cache_ttl(24 * 60 * 60)
-        .context("Failed to set cache TTL")?;
+    gamma_client.set_cache_config(CacheConfig {
+        market_info_ttl: 24 * 60 * 60,
+        event_ttl: 5 * 60,
+        ..CacheConfig::default()
+    });
 
     // Fetch active markets and get asset IDs
     log_info!("📡 Fetching active markets...");
-    let asset_ids = gamma_client
+    let mut asset_ids = gamma_client
         .get_all_active_asset_ids()
         .await
         .context("Failed to fetch active markets")?;
+    // Alerted assets are always subscribed
+    for alert in &alerts {
+        if !asset_ids.contains(&alert.asset_id) {
+            asset_ids.push(alert.asset_id.clone());
+        }
+    }
 
     log_info!("✓ Found {} active asset IDs", asset_ids.len());
 
@@ -219,7 +462,7 @@ async fn run_monitor(use_rtds: bool, event_slug: Option<String>) -> Result<()> {
     log_info!("✓ Cached {} market info entries", _cache_len);
 
     // Create WebSocket client
-    let mut ws_client = PolymarketWebSocket::new(asset_ids.clone());
+    let mut ws_client = PolymarketWebSocket::new(asset_ids.clone()).with_chunk_size(chunk_size);
 
     // Transfer cached info to WebSocket client
     {
@@ -230,54 +473,73 @@ async fn run_monitor(use_rtds: bool, event_slug: Option<String>) -> Result<()> {
     }
 
     log_info!("🔌 Connecting to WebSocket...");
-    log_info!("Monitoring {} assets", asset_ids.len());
+    log_info!(
+        "Monitoring {} assets over {} connection(s)",
+        asset_ids.len(),
+        ws_client.subscription_messages().len()
+    );
     log_info!("Press Ctrl+C to exit");
     log_info!("{}", "─".repeat(80));
 
     // Connect and listen
     let cache_clone = Arc::clone(&market_info_cache);
+    let mut price_alerts = price_alert::PriceAlerts::new(&alerts);
+    // Empty means print every message type
+    let only: HashSet<MessageKind> = only.into_iter().collect();
+
+    let callback_metrics = metrics.clone();
+    let mut on_message = move |msg: polymarket_api::websocket::WebSocketMessage| {
+        if let Some(metrics) = &callback_metrics {
+            metrics.record_message(msg.kind());
+        }
 
-    ws_client
-        .connect_and_listen(move |msg| {
-            // Get market info for this message from cache
-            let asset_id = match &msg {
-                polymarket_api::websocket::WebSocketMessage::Orderbook(update) => {
-                    Some(update.asset_id.clone())
-                },
-                polymarket_api::websocket::WebSocketMessage::Trade(update) => {
-                    Some(update.asset_id.clone())
-                },
-                polymarket_api::websocket::WebSocketMessage::Order(update) => {
-                    Some(update.asset_id.clone())
-                },
-                polymarket_api::websocket::WebSocketMessage::Price(update) => {
-                    Some(update.asset_id.clone())
-                },
-                _ => None,
-            };
-
-            let market_info = if let Some(asset_id) = asset_id {
-                // Get from cache (synchronous access)
-                // Use lock_mutex helper, but handle errors gracefully in callback
-                match lock_mutex(&cache_clone) {
-                    Ok(cache) => cache.get(&asset_id).cloned(),
-                    Err(_) => None, // If lock fails, just skip market info
-                }
-            } else {
-                None
-            };
+        // Get market info for this message from cache. With several subscription
+        // chunks the messages of all connections arrive here, keyed by their asset.
+        let market_info = msg.asset_id().and_then(|asset_id| {
+            // Use lock_mutex helper, but handle errors gracefully in callback
+            // (if the lock fails, just skip market info)
+            lock_mutex(&cache_clone)
+                .ok()
+                .and_then(|cache| cache.get(asset_id).cloned())
+        });
 
-            // Format and print
+        // Format and print (alerts below still fire for filtered-out prices)
+        if only.is_empty() || only.contains(&msg.kind()) {
             let formatted = MarketUpdateFormatter::format_message(&msg, market_info.as_ref());
             print!("{}", formatted);
-        })
-        .await
-        .context("WebSocket connection failed")?;
+        }
 
-    Ok(())
+        if let polymarket_api::websocket::WebSocketMessage::Price(update) = &msg
+            && let Ok(price) = update.price.parse::<f64>()
+            && let Some(change) = price_alerts.observe(&update.asset_id, price)
+        {
+            let name = market_info
+                .as_ref()
+                .map(|info| info.market_question.as_str())
+                .unwrap_or(&update.asset_id);
+            let line = format!("🔔 {} moved {:+.1}¢ to {:.1}¢", name, change, price * 100.0);
+            println!("\x07{}", line.black().on_yellow().bold());
+        }
+    };
+
+    loop {
+        // Reconnect whenever the stream drops, until Ctrl+C
+        match ws_client.connect_and_listen(&mut on_message).await {
+            Ok(()) => log_info!("WebSocket stream closed, reconnecting in 5s"),
+            Err(_e) => log_info!("WebSocket connection failed: {:#}, reconnecting in 5s", _e),
+        }
+        if let Some(metrics) = &metrics {
+            metrics.record_reconnect();
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+    }
 }
 
-async fn run_monitor_rtds(event_slug: Option<String>) -> Result<()> {
+async fn run_monitor_rtds(
+    event_slug: Option<String>,
+    ndjson: bool,
+    show_address: bool,
+) -> Result<()> {
     log_info!("🚀 Polymarket Real-Time Monitor (RTDS)");
     log_info!("Connecting to RTDS WebSocket...");
 
@@ -307,10 +569,17 @@ async fn run_monitor_rtds(event_slug: Option<String>) -> Result<()> {
         rtds_client = rtds_client.with_event_slug(slug);
     }
 
-    let mut display = display_trait::SimpleDisplay {};
+    let mut display = display_trait::SimpleDisplay { show_address };
     rtds_client
         .connect_and_listen(|msg| {
-            let _ = display.display_trade(&msg);
+            if ndjson {
+                match serde_json::to_string(&msg) {
+                    Ok(line) => println!("{}", line),
+                    Err(_e) => log_info!("Failed to serialize RTDS message: {}", _e),
+                }
+            } else {
+                let _ = display.display_trade(&msg);
+            }
         })
         .await
         .context("Failed to connect to RTDS WebSocket")?;
@@ -318,7 +587,12 @@ async fn run_monitor_rtds(event_slug: Option<String>) -> Result<()> {
     Ok(())
 }
 
-async fn run_watch_event(event: String, use_tui: bool) -> Result<()> {
+async fn run_watch_event(
+    event: String,
+    use_tui: bool,
+    min_size: f64,
+    show_address: bool,
+) -> Result<()> {
     let event_slug = extract_event_slug(&event);
     log_info!("🎯 Watching trade activity for event: {}", event_slug);
     log_info!("Connecting to RTDS WebSocket...");
@@ -337,7 +611,7 @@ async fn run_watch_event(event: String, use_tui: bool) -> Result<()> {
     if use_tui {
         #[cfg(feature = "tui")]
         {
-            run_watch_event_tui(event_slug).await
+            run_watch_event_tui(event_slug, min_size).await
         }
         #[cfg(not(feature = "tui"))]
         {
@@ -348,11 +622,13 @@ async fn run_watch_event(event: String, use_tui: bool) -> Result<()> {
         log_info!("{}", "─".repeat(80));
 
         let rtds_client = RTDSClient::new().with_event_slug(event_slug.clone());
-        let mut display = display_trait::SimpleDisplay {};
+        let mut display = display_trait::SimpleDisplay { show_address };
 
         rtds_client
             .connect_and_listen(|msg| {
-                let _ = display.display_trade(&msg);
+                if trade_value(&msg) >= min_size {
+                    let _ = display.display_trade(&msg);
+                }
             })
             .await
             .context("Failed to connect to RTDS WebSocket")?;
@@ -361,8 +637,13 @@ async fn run_watch_event(event: String, use_tui: bool) -> Result<()> {
     }
 }
 
+/// Dollar value of an RTDS trade (price × shares)
+fn trade_value(msg: &RTDSMessage) -> f64 {
+    msg.payload.price * msg.payload.size
+}
+
 #[cfg(feature = "tui")]
-async fn run_watch_event_tui(event_slug: String) -> Result<()> {
+async fn run_watch_event_tui(event_slug: String, min_size: f64) -> Result<()> {
     use {
         crossterm::{
             event::{DisableMouseCapture, EnableMouseCapture},
@@ -411,6 +692,9 @@ async fn run_watch_event_tui(event_slug: String) -> Result<()> {
     let ws_handle = tokio::spawn(async move {
         let _ = rtds_client
             .connect_and_listen(|msg| {
+                if trade_value(&msg) < min_size {
+                    return;
+                }
                 let app_state = Arc::clone(&app_state_ws);
                 tokio::spawn(async move {
                     let mut app = app_state.lock().await;
@@ -439,6 +723,16 @@ async fn run_watch_event_tui(event_slug: String) -> Result<()> {
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    if let Some(dir) = cli.fixtures.clone() {
+        polymarket_api::fixtures::set_fixtures_dir(dir);
+    }
+    if cli.no_cache {
+        cache::disable();
+    }
+    if cli.no_color || display_trait::no_color_env() {
+        display_trait::disable_color();
+    }
+
     // Check if we're running a TUI command (None = default TUI, or explicit Trending)
     let _is_tui_command = matches!(
         cli.command,
@@ -448,47 +742,200 @@ async fn main() -> Result<()> {
     // Initialize tracing subscriber conditionally
     #[cfg(feature = "tracing")]
     if !_is_tui_command {
+        use tracing_subscriber::fmt::writer::BoxMakeWriter;
+
         // For non-TUI commands, use the default fmt subscriber
+        // (on stderr in JSON and IDs-only modes so stdout can be piped)
+        let json_output = cli.json
+            || matches!(cli.command, Some(Commands::Monitor { ndjson: true, .. }))
+            || matches!(
+                cli.command,
+                Some(Commands::Favorites {
+                    export: Some(None),
+                    ..
+                })
+            )
+            || cli.command.as_ref().and_then(Commands::ids_only).is_some();
+        let writer = if json_output {
+            BoxMakeWriter::new(std::io::stderr)
+        } else {
+            BoxMakeWriter::new(std::io::stdout)
+        };
         tracing_subscriber::fmt()
             .with_env_filter(
                 tracing_subscriber::EnvFilter::try_from_default_env()
                     .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
             )
-            .with_ansi(true)
+            .with_writer(writer)
+            .with_ansi(!json_output && display_trait::color_enabled())
             .init();
     }
 
     match cli.command {
         // Default to TUI when no command is provided
-        None => run_trending("volume24hr".to_string(), false, 50).await,
-        Some(Commands::Monitor { rtds, event }) => run_monitor(rtds, event).await,
-        Some(Commands::WatchEvent { event, tui }) => run_watch_event(event, tui).await,
-        Some(Commands::Orderbook { market, asset }) => run_orderbook(market, asset).await,
+        None => {
+            run_trending(
+                "volume24hr".to_string(),
+                false,
+                50,
+                OutcomeNameStyle::default(),
+                PriceFormat::default(),
+                None,
+                None,
+                None,
+                None,
+                false,
+            )
+            .await
+        },
+        Some(Commands::Monitor {
+            rtds,
+            event,
+            alerts,
+            only,
+            metrics_addr,
+            chunk_size,
+            ndjson,
+            show_address,
+        }) => {
+            run_monitor(
+                rtds,
+                event,
+                alerts,
+                only,
+                metrics_addr,
+                chunk_size,
+                ndjson,
+                show_address,
+            )
+            .await
+        },
+        Some(Commands::WatchEvent {
+            event,
+            tui,
+            min_size,
+            show_address,
+        }) => run_watch_event(event, tui, min_size, show_address).await,
+        Some(Commands::Orderbook {
+            market,
+            asset,
+            depth,
+            band,
+            watch,
+            interval,
+        }) => {
+            if watch && cli.json {
+                anyhow::bail!("--watch can't be combined with --json");
+            }
+            if watch {
+                run_orderbook_watch(market, asset, depth, band, interval).await
+            } else {
+                run_orderbook(market, asset, depth, band, cli.json).await
+            }
+        },
+        Some(Commands::Price { market, side }) => run_price(market, side, cli.json).await,
+        Some(Commands::Token { asset_id }) => run_token(asset_id, cli.json).await,
         Some(Commands::Trades {
             market,
             limit,
+            offset,
             asset,
             event_id,
             event_slug,
-        }) => run_trades(market, limit, asset, event_id, event_slug).await,
-        Some(Commands::Event { event, id }) => run_event(event, id).await,
-        Some(Commands::Market { market, id }) => run_market(market, id).await,
+            user,
+            since,
+            format,
+        }) => {
+            let format = if cli.json {
+                OutputFormat::Json
+            } else {
+                format
+            };
+            run_trades(
+                market, limit, offset, asset, event_id, event_slug, user, since, format,
+            )
+            .await
+        },
+        Some(Commands::Event {
+            event,
+            id,
+            token_ids_only,
+            condition_ids_only,
+        }) => {
+            let ids = IdsOnly::from_flags(token_ids_only, condition_ids_only);
+            run_event(event, id, ids, cli.json).await
+        },
+        Some(Commands::Markets { event, slug }) => run_markets(event, slug, cli.json).await,
+        Some(Commands::Market {
+            market,
+            id,
+            condition,
+            token_ids_only,
+            condition_ids_only,
+        }) => {
+            let ids = IdsOnly::from_flags(token_ids_only, condition_ids_only);
+            run_market(market, id, condition, ids, cli.json).await
+        },
         Some(Commands::Trending {
             order_by,
             ascending,
             limit,
-        }) => run_trending(order_by, ascending, limit).await,
+            outcome_names,
+            price_format,
+            min_prob,
+            max_days,
+            min_liquidity,
+            price_refresh,
+            paper,
+        }) => {
+            run_trending(
+                order_by,
+                ascending,
+                limit,
+                outcome_names,
+                price_format,
+                min_prob,
+                max_days,
+                min_liquidity,
+                price_refresh,
+                paper,
+            )
+            .await
+        },
         Some(Commands::Yield {
             min_prob,
             limit,
             min_volume,
             expires_in,
-        }) => run_yield(min_prob, limit, min_volume, expires_in).await,
+            outcome_names,
+        }) => run_yield(min_prob, limit, min_volume, expires_in, outcome_names).await,
+        Some(Commands::Positions { address, closed }) => run_positions(address, closed).await,
+        Some(Commands::Whoami) => run_whoami().await,
+        Some(Commands::Status) => run_status(cli.json).await,
+        Some(Commands::Favorites { export, import }) => run_favorites(export, import).await,
+        Some(Commands::ExportState { file }) => run_export_state(file),
+        Some(Commands::ImportState { file }) => run_import_state(file),
+        Some(Commands::Cache {
+            clear,
+            expired_only,
+        }) => run_cache(clear, expired_only),
     }
 }
 
 #[cfg(feature = "tui")]
-async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+async fn run_trending(
+    order_by: String,
+    ascending: bool,
+    limit: usize,
+    outcome_names: OutcomeNameStyle,
+    price_format: PriceFormat,
+    min_prob: Option<f64>,
+    max_days: Option<u32>,
+    min_liquidity: Option<f64>,
+    price_refresh: Option<u64>,
+    paper: bool,
+) -> Result<()> {
     use {
         crossterm::{
             event::{DisableMouseCapture, EnableMouseCapture},
@@ -500,6 +947,7 @@ async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result
         ratatui::{Terminal, backend::CrosstermBackend},
         std::io,
         tokio::sync::Mutex as TokioMutex,
+        trending_tui::state::EventSortBy,
     };
 
     // Setup custom tracing layer to capture logs for TUI
@@ -524,13 +972,33 @@ async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result
             .init();
     }
 
+    if let Some(min_prob) = min_prob
+        && !(0.0..=1.0).contains(&min_prob)
+    {
+        anyhow::bail!("--min-prob must be between 0 and 1 (e.g., 0.95 for 95%)");
+    }
+
     log_info!("🔥 Fetching trending events...");
 
+    // A sortable --order-by other than the default picks the events sort, otherwise
+    // the one from the last session is fetched (and kept on `s` re-fetches)
+    let saved = settings::Settings::load();
+    let order_sort = EventSortBy::from_api_order_param(&order_by);
+    let event_sort = order_sort
+        .filter(|sort| *sort != EventSortBy::default())
+        .unwrap_or(saved.event_sort);
+    let (fetch_order_by, fetch_ascending) = match order_sort {
+        Some(sort) if sort != event_sort => {
+            (event_sort.api_order_param(), event_sort.is_ascending())
+        },
+        _ => (order_by.as_str(), ascending),
+    };
+
     let gamma_client = GammaClient::new();
     // For trending events, we want descending order by default (highest volume first)
     // The API's ascending=false means descending (highest first), which is what we want for trending
     let events = gamma_client
-        .get_trending_events(Some(&order_by), Some(ascending), Some(limit))
+        .get_trending_events(Some(fetch_order_by), Some(fetch_ascending), Some(limit))
         .await
         .context("Failed to fetch trending events")?;
 
@@ -540,6 +1008,22 @@ async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result
 
     log_info!("Found {} trending events", events.len());
 
+    // Load the paper ledger before the terminal is taken over. A ledger that
+    // fails to parse is kept in memory only, so the user's file isn't overwritten.
+    let paper_path = paper::PaperLedger::config_path();
+    let (paper_ledger, paper_ledger_path, paper_warning) =
+        match paper::PaperLedger::load(&paper_path) {
+            Ok(ledger) => (ledger, Some(paper_path), None),
+            Err(e) => {
+                log_info!("{}", e);
+                (
+                    paper::PaperLedger::default(),
+                    None,
+                    Some(format!("{} - paper fills won't be saved", e)),
+                )
+            },
+        };
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -554,12 +1038,41 @@ async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result
         log_info!("CLOB API authentication available - trade counts will be fetched from API");
     }
 
-    let app_state = Arc::new(TokioMutex::new(trending_tui::TrendingAppState::new(
-        events,
-        order_by.clone(),
-        ascending,
-        has_clob_auth,
-    )));
+    let mut trending_state =
+        trending_tui::TrendingAppState::new(events, order_by.clone(), ascending, has_clob_auth);
+    trending_state.outcome_style = outcome_names;
+    trending_state.price_format = price_format;
+    trending_state.yield_state.load_settings(&saved.yield_tab);
+    // Flags given on the command line win over the saved Yield filters
+    if let Some(min_prob) = min_prob {
+        trending_state.yield_state.min_prob = min_prob;
+    }
+    if max_days.is_some() {
+        trending_state.yield_state.max_days_to_resolution = max_days;
+    }
+    if let Some(min_liquidity) = min_liquidity {
+        trending_state.yield_state.min_liquidity = min_liquidity;
+    }
+    // Restore the events sort picked with `s` in the last session (or from --order-by)
+    if event_sort != trending_state.event_sort_by {
+        trending_state.event_sort_by = event_sort;
+        trending_state.sort_events();
+    }
+    trending_state.trade_settings = saved.trade;
+    if let Some(secs) = price_refresh.or(saved.price_refresh_secs) {
+        trending_state.price_refresh_interval = (secs > 0).then(|| Duration::from_secs(secs));
+    }
+    if let Some(max_watched_events) = saved.max_watched_events {
+        trending_state.trades.max_watched_events = max_watched_events;
+    }
+    trending_state.paper_ledger = paper_ledger;
+    trending_state.paper_ledger_path = paper_ledger_path;
+    trending_state.settings_path = Some(settings::Settings::config_path());
+    trending_state.paper_mode = paper;
+    if let Some(warning) = paper_warning {
+        trending_state.flash(warning);
+    }
+    let app_state = Arc::new(TokioMutex::new(trending_state));
 
     // Connect logs to app state (only when tracing is enabled)
     #[cfg(feature = "tracing")]
@@ -632,11 +1145,102 @@ async fn run_trending(order_by: String, ascending: bool, limit: usize) -> Result
 }
 
 #[cfg(not(feature = "tui"))]
-async fn run_trending(_order_by: String, _ascending: bool, _limit: usize) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+async fn run_trending(
+    _order_by: String,
+    _ascending: bool,
+    _limit: usize,
+    _outcome_names: OutcomeNameStyle,
+    _price_format: PriceFormat,
+    _min_prob: Option<f64>,
+    _max_days: Option<u32>,
+    _min_liquidity: Option<f64>,
+    _price_refresh: Option<u64>,
+    _paper: bool,
+) -> Result<()> {
     anyhow::bail!("Trending command requires building with --features tui flag");
 }
 
-async fn run_orderbook(market: String, use_asset: bool) -> Result<()> {
+#[cfg(feature = "tui")]
+fn run_export_state(file: PathBuf) -> Result<()> {
+    let working_set = working_set::WorkingSet::new(settings::Settings::load());
+    working_set
+        .save(&file)
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    log_info!(
+        "Exported {} watched and {} favorite events to {}",
+        working_set.settings.watchlist.len(),
+        working_set.settings.favorites.len(),
+        file.display()
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "tui"))]
+fn run_export_state(_file: PathBuf) -> Result<()> {
+    anyhow::bail!("export-state requires building with --features tui flag");
+}
+
+#[cfg(feature = "tui")]
+fn run_import_state(file: PathBuf) -> Result<()> {
+    let working_set = working_set::WorkingSet::load(&file).map_err(|e| anyhow::anyhow!("{}", e))?;
+    let mut settings = settings::Settings::try_load().map_err(|e| anyhow::anyhow!("{}", e))?;
+    working_set.merge_into(&mut settings);
+    settings.save().map_err(|e| anyhow::anyhow!("{}", e))?;
+    log_info!(
+        "Imported {}: {} watched and {} favorite events",
+        file.display(),
+        settings.watchlist.len(),
+        settings.favorites.len()
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "tui"))]
+fn run_import_state(_file: PathBuf) -> Result<()> {
+    anyhow::bail!("import-state requires building with --features tui flag");
+}
+
+fn run_cache(clear: bool, expired_only: bool) -> Result<()> {
+    let dir = cache::cache_dir();
+    let file_cache = polymarket_api::FileCache::new(&dir)?;
+    if !clear {
+        let usage = file_cache.size_on_disk()?;
+        println!(
+            "{}: {} entries ({} bytes)",
+            dir.display(),
+            usage.entries,
+            usage.bytes
+        );
+        return Ok(());
+    }
+
+    let removed = if expired_only {
+        file_cache.clear_expired()?
+    } else {
+        file_cache.clear()?
+    };
+    println!(
+        "Removed {} {}entries ({} bytes) from {}",
+        removed.entries,
+        if expired_only {
+            "expired "
+        } else {
+            ""
+        },
+        removed.bytes,
+        dir.display()
+    );
+    Ok(())
+}
+
+async fn run_orderbook(
+    market: String,
+    use_asset: bool,
+    depth: Option<usize>,
+    band: f64,
+    json: bool,
+) -> Result<()> {
     log_info!("📊 Fetching orderbook for: {}", market);
     let clob_client = ClobClient::new();
 
@@ -646,57 +1250,386 @@ async fn run_orderbook(market: String, use_asset: bool) -> Result<()> {
         clob_client.get_orderbook(&market).await?
     };
 
-    log_info!("Bids (buy orders):");
-    for _bid in orderbook.bids {
-        log_info!("  Price: {}, Size: {}", _bid.price, _bid.size);
+    let summary = orderbook.summary(band, depth);
+    if json {
+        return print_json(&serde_json::json!({
+            "orderbook": orderbook,
+            "summary": summary,
+        }));
+    }
+
+    print_orderbook(&orderbook, &summary, depth, band, None);
+    Ok(())
+}
+
+async fn run_price(token_id: String, side: Side, json: bool) -> Result<()> {
+    log_info!("💲 Fetching {:?} price for: {}", side, token_id);
+    let clob_client = ClobClient::new();
+    let price = clob_client.get_price(&token_id, side).await?;
+
+    if json {
+        return print_json(&serde_json::json!({
+            "token_id": token_id,
+            "side": side,
+            "price": price.price,
+        }));
+    }
+
+    println!("{}", price.price);
+    Ok(())
+}
+
+async fn run_token(asset_id: String, json: bool) -> Result<()> {
+    log_info!("🔎 Looking up token: {}", asset_id);
+    let gamma_client = GammaClient::new();
+    let Some(info) = gamma_client.get_market_info_by_asset_id(&asset_id).await? else {
+        anyhow::bail!("No active market found for token {}", asset_id);
+    };
+
+    // Live midpoint, falling back to Gamma's last price (e.g. no book any more)
+    let clob_client = ClobClient::new();
+    let price = match clob_client.get_midpoint(&asset_id).await {
+        Ok(midpoint) => midpoint.mid.parse::<f64>().ok().or(info.price()),
+        Err(_e) => {
+            log_info!("Midpoint unavailable for {}: {}", asset_id, _e);
+            info.price()
+        },
+    };
+
+    if json {
+        return print_json(&serde_json::json!({
+            "asset_id": info.asset_id,
+            "outcome": info.outcome(),
+            "outcome_index": info.outcome_index,
+            "price": price,
+            "market_question": info.market_question,
+            "market_id": info.market_id,
+            "event_title": info.event_title,
+            "event_slug": info.event_slug,
+        }));
     }
 
-    log_info!("Asks (sell orders):");
-    for _ask in orderbook.asks {
-        log_info!("  Price: {}, Size: {}", _ask.price, _ask.size);
+    println!("{}", info.market_question.bold());
+    println!("  Outcome: {}", info.outcome().unwrap_or("-"));
+    println!(
+        "  Price:   {}",
+        price
+            .map(|p| format!("{:.1}¢", p * 100.0))
+            .unwrap_or_else(|| "-".to_string())
+    );
+    println!("  Event:   {} ({})", info.event_title, info.event_slug);
+    println!("  Market:  {}", info.market_id);
+    Ok(())
+}
+
+/// `orderbook --watch`: redraw the book every `interval_secs` until Ctrl+C
+async fn run_orderbook_watch(
+    market: String,
+    use_asset: bool,
+    depth: Option<usize>,
+    band: f64,
+    interval_secs: u64,
+) -> Result<()> {
+    let clob_client = ClobClient::new();
+    let interval_secs = interval_secs.max(1);
+    let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+    let mut previous: Option<OrderbookSummary> = None;
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {},
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+
+        let result = if use_asset {
+            clob_client.get_orderbook_by_asset(&market).await
+        } else {
+            clob_client.get_orderbook(&market).await
+        };
+
+        clear_screen()?;
+        println!(
+            "Orderbook for {} at {} (every {}s, Ctrl+C to stop)",
+            market,
+            chrono::Local::now().format("%H:%M:%S"),
+            interval_secs
+        );
+        println!();
+        match result {
+            Ok(orderbook) => {
+                let summary = orderbook.summary(band, depth);
+                print_orderbook(&orderbook, &summary, depth, band, previous.as_ref());
+                previous = Some(summary);
+            },
+            // Keep polling through transient failures
+            Err(e) => println!("{} {}", "Failed to fetch orderbook:".red(), e),
+        }
     }
+}
 
+/// Clear the terminal between `--watch` frames
+fn clear_screen() -> Result<()> {
+    #[cfg(feature = "tui")]
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+        crossterm::cursor::MoveTo(0, 0)
+    )?;
+    #[cfg(not(feature = "tui"))]
+    print!("\x1b[2J\x1b[H");
     Ok(())
 }
 
+/// Print the book's levels and summary
+///
+/// With `previous` (the last `--watch` poll), the mid and spread show how much they moved.
+fn print_orderbook(
+    orderbook: &Orderbook,
+    summary: &OrderbookSummary,
+    depth: Option<usize>,
+    band: f64,
+    previous: Option<&OrderbookSummary>,
+) {
+    let limit = depth.unwrap_or(usize::MAX);
+    println!("{}", "Asks (sell orders):".red());
+    for (price, size) in orderbook
+        .levels_for(Side::Buy)
+        .into_iter()
+        .take(limit)
+        .rev()
+    {
+        println!("  {:>6.1}¢  {:>12.2}", price * 100.0, size);
+    }
+    println!("{}", "Bids (buy orders):".green());
+    for (price, size) in orderbook.levels_for(Side::Sell).into_iter().take(limit) {
+        println!("  {:>6.1}¢  {:>12.2}", price * 100.0, size);
+    }
+
+    let cents = |price: Option<f64>| {
+        price.map_or_else(|| "-".to_string(), |p| format!("{:.1}¢", p * 100.0))
+    };
+    println!();
+    println!("Best bid: {}", cents(summary.best_bid));
+    println!("Best ask: {}", cents(summary.best_ask));
+    let mid_delta = cents_delta(summary.mid_price, previous.and_then(|p| p.mid_price));
+    println!("Mid:      {}{}", cents(summary.mid_price), mid_delta);
+    match (summary.spread, summary.spread_bps) {
+        (Some(spread), Some(bps)) => {
+            let spread_delta = cents_delta(summary.spread, previous.and_then(|p| p.spread));
+            println!(
+                "Spread:   {:.1}¢ ({:.0} bps){}",
+                spread * 100.0,
+                bps,
+                spread_delta
+            )
+        },
+        _ => println!("Spread:   -"),
+    }
+    println!(
+        "Depth within {:.1}¢: bids ${:.2}, asks ${:.2}",
+        band * 100.0,
+        summary.bid_notional,
+        summary.ask_notional
+    );
+}
+
+/// " (+0.5¢)" change since the previous poll, empty without one
+fn cents_delta(current: Option<f64>, previous: Option<f64>) -> String {
+    let (Some(current), Some(previous)) = (current, previous) else {
+        return String::new();
+    };
+    let delta = format!("{:+.1}¢", (current - previous) * 100.0);
+    let delta = if current > previous {
+        delta.green()
+    } else if current < previous {
+        delta.red()
+    } else {
+        delta.normal()
+    };
+    format!(" ({})", delta)
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Trades fetched per request while paging back to a `--since` cutoff
+const SINCE_PAGE_SIZE: usize = 100;
+/// Most pages fetched for one `--since` query
+const SINCE_MAX_PAGES: usize = 20;
+
+/// Fetch up to `limit` trades starting at `offset`, keeping only those at or after
+/// `cutoff` (Unix seconds) when given
+///
+/// Trades come newest first, so with a cutoff pages are fetched until one reaches
+/// past it; the cutoff is applied first and `limit` caps what is left.
+async fn fetch_trades_since<T, F, Fut>(
+    limit: usize,
+    offset: usize,
+    cutoff: Option<i64>,
+    timestamp: fn(&T) -> i64,
+    mut fetch: F,
+) -> Result<Vec<T>>
+where
+    F: FnMut(usize, usize) -> Fut,
+    Fut: Future<Output = polymarket_api::Result<Vec<T>>>,
+{
+    let Some(cutoff) = cutoff else {
+        return Ok(fetch(limit, offset).await?);
+    };
+    let mut trades = Vec::new();
+    let mut page_offset = offset;
+    for _ in 0..SINCE_MAX_PAGES {
+        let page = fetch(SINCE_PAGE_SIZE, page_offset).await?;
+        let page_len = page.len();
+        let reached_cutoff = page.iter().any(|trade| timestamp(trade) < cutoff);
+        trades.extend(page.into_iter().filter(|trade| timestamp(trade) >= cutoff));
+        if reached_cutoff || page_len < SINCE_PAGE_SIZE || trades.len() >= limit {
+            break;
+        }
+        page_offset += page_len;
+    }
+    trades.truncate(limit);
+    Ok(trades)
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn run_trades(
     market: String,
     limit: usize,
+    offset: usize,
     use_asset: bool,
     use_event_id: bool,
     use_event_slug: bool,
+    use_user: bool,
+    since: Option<String>,
+    format: OutputFormat,
 ) -> Result<()> {
     log_info!("📈 Fetching trades for: {}", market);
 
-    if use_event_id {
+    let cutoff = match since.as_deref() {
+        Some(since) => match parse_duration(since) {
+            Some(secs) => Some(chrono::Utc::now().timestamp() - secs),
+            None => anyhow::bail!(
+                "Invalid --since '{}'. Use formats like '30m', '2h', '1d'",
+                since
+            ),
+        },
+        None => None,
+    };
+    let data_timestamp: fn(&polymarket_api::data::DataTrade) -> i64 = |trade| trade.timestamp;
+    let clob_timestamp: fn(&polymarket_api::clob::Trade) -> i64 = |trade| trade.timestamp;
+
+    if use_user {
+        let address = market.trim();
+        if !auth::is_valid_address(address) {
+            anyhow::bail!(
+                "Invalid address '{}' (expected 0x followed by 40 hex characters)",
+                address
+            );
+        }
+        let data_client = DataClient::new();
+        let trades = fetch_trades_since(limit, offset, cutoff, data_timestamp, |limit, offset| {
+            data_client.get_trades_by_user(address, Some(limit), Some(offset))
+        })
+        .await?;
+        output_trades(&trades, format, display_trades)?;
+    } else if use_event_id {
         let event_id: u64 = market.parse().context("Invalid event ID")?;
         let data_client = DataClient::new();
-        let trades = data_client
-            .get_trades_by_event(event_id, Some(limit), None, None, None)
-            .await?;
-        display_trades(&trades);
+        let trades = fetch_trades_since(limit, offset, cutoff, data_timestamp, |limit, offset| {
+            data_client.get_trades_by_event(event_id, Some(limit), Some(offset), None, None)
+        })
+        .await?;
+        output_trades(&trades, format, display_trades)?;
     } else if use_event_slug {
         let data_client = DataClient::new();
-        let trades = data_client
-            .get_trades_by_event_slug(&market, Some(limit), None)
-            .await?;
-        display_trades(&trades);
+        let trades = fetch_trades_since(limit, offset, cutoff, data_timestamp, |limit, offset| {
+            data_client.get_trades_by_event_slug(&market, Some(limit), Some(offset))
+        })
+        .await?;
+        output_trades(&trades, format, display_trades)?;
     } else if use_asset {
         let clob_client = ClobClient::new();
-        let trades = clob_client
-            .get_trades_by_asset(&market, Some(limit))
-            .await?;
-        display_clob_trades(&trades);
+        let trades = fetch_trades_since(limit, offset, cutoff, clob_timestamp, |limit, offset| {
+            clob_client.get_trades_by_asset(&market, Some(limit), Some(offset))
+        })
+        .await?;
+        output_trades(&trades, format, display_clob_trades)?;
     } else {
         let clob_client = ClobClient::new();
-        let trades = clob_client.get_trades(&market, Some(limit)).await?;
-        display_clob_trades(&trades);
+        let trades = fetch_trades_since(limit, offset, cutoff, clob_timestamp, |limit, offset| {
+            clob_client.get_trades(&market, Some(limit), Some(offset))
+        })
+        .await?;
+        output_trades(&trades, format, display_clob_trades)?;
     }
 
     Ok(())
 }
 
-async fn run_event(event: String, use_id: bool) -> Result<()> {
+impl Commands {
+    /// IDs-only output requested with `--token-ids-only`/`--condition-ids-only`
+    fn ids_only(&self) -> Option<IdsOnly> {
+        match self {
+            Self::Event {
+                token_ids_only,
+                condition_ids_only,
+                ..
+            }
+            | Self::Market {
+                token_ids_only,
+                condition_ids_only,
+                ..
+            } => IdsOnly::from_flags(*token_ids_only, *condition_ids_only),
+            _ => None,
+        }
+    }
+}
+
+/// Raw IDs printed instead of the details by `--token-ids-only`/`--condition-ids-only`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IdsOnly {
+    Token,
+    Condition,
+}
+
+impl IdsOnly {
+    fn from_flags(token_ids_only: bool, condition_ids_only: bool) -> Option<Self> {
+        if token_ids_only {
+            Some(Self::Token)
+        } else if condition_ids_only {
+            Some(Self::Condition)
+        } else {
+            None
+        }
+    }
+}
+
+/// Print the markets' token or condition IDs one per line, for piping into other commands
+fn print_ids(markets: &[polymarket_api::gamma::Market], ids: IdsOnly) -> Result<()> {
+    let values: Vec<&str> = markets
+        .iter()
+        .flat_map(|market| match ids {
+            IdsOnly::Token => market
+                .clob_token_ids
+                .iter()
+                .flatten()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            IdsOnly::Condition => market.condition_id.as_deref().into_iter().collect(),
+        })
+        .collect();
+    if values.is_empty() {
+        match ids {
+            IdsOnly::Token => anyhow::bail!("No token IDs found"),
+            IdsOnly::Condition => anyhow::bail!("No condition IDs found"),
+        }
+    }
+    for value in values {
+        println!("{}", value);
+    }
+    Ok(())
+}
+
+async fn run_event(event: String, use_id: bool, ids: Option<IdsOnly>, json: bool) -> Result<()> {
     log_info!("📅 Fetching event: {}", event);
     let gamma_client = GammaClient::new();
 
@@ -707,6 +1640,12 @@ async fn run_event(event: String, use_id: bool) -> Result<()> {
     };
 
     if let Some(event) = event_data {
+        if let Some(ids) = ids {
+            return print_ids(&event.markets, ids);
+        }
+        if json {
+            return print_json(&event);
+        }
         log_info!("Title: {}", event.title);
         log_info!("Slug: {}", event.slug);
         log_info!("Active: {}", event.active);
@@ -722,12 +1661,110 @@ async fn run_event(event: String, use_id: bool) -> Result<()> {
     Ok(())
 }
 
-async fn run_market(market: String, use_id: bool) -> Result<()> {
+async fn run_markets(event: String, force_slug: bool, json: bool) -> Result<()> {
+    let gamma_client = GammaClient::new();
+    let is_id = !force_slug && !event.is_empty() && event.chars().all(|c| c.is_ascii_digit());
+    let event_data = if is_id {
+        log_info!("📅 Fetching markets for event ID: {}", event);
+        gamma_client.get_event_by_id(&event).await?
+    } else {
+        let slug = extract_event_slug(&event);
+        log_info!("📅 Fetching markets for event: {}", slug);
+        gamma_client.get_event_by_slug(&slug).await?
+    };
+    let Some(event) = event_data else {
+        anyhow::bail!("Event not found");
+    };
+
+    if json {
+        let markets: Vec<_> = event
+            .markets
+            .iter()
+            .map(|market| {
+                serde_json::json!({
+                    "question": market.question,
+                    "condition_id": market.condition_id,
+                    "clob_token_ids": market.clob_token_ids,
+                    "outcomes": market.outcomes,
+                    "outcome_prices": market.outcome_prices,
+                    "volume_24hr": market.volume_24hr,
+                    "closed": market.closed,
+                })
+            })
+            .collect();
+        return print_json(&markets);
+    }
+
+    println!("{} ({} markets)", event.title.bold(), event.markets.len());
+    println!(
+        "{:<50} {:<24} {:>9} {:>6}",
+        "Market", "Prices", "24h Vol", "Status"
+    );
+    println!("{}", "─".repeat(92));
+    for market in &event.markets {
+        let question: String = if market.question.chars().count() > 48 {
+            format!("{}…", market.question.chars().take(47).collect::<String>())
+        } else {
+            market.question.clone()
+        };
+        let prices = market
+            .outcomes
+            .iter()
+            .zip(&market.outcome_prices)
+            .map(|(outcome, price)| match price.parse::<f64>() {
+                Ok(price) => format!("{} {:.1}¢", outcome, price * 100.0),
+                Err(_) => format!("{} -", outcome),
+            })
+            .collect::<Vec<_>>()
+            .join(" / ");
+        let volume = market
+            .volume_24hr
+            .map(|v| format!("${:.0}", v))
+            .unwrap_or_else(|| "-".to_string());
+        let status = if market.closed {
+            "closed".dimmed()
+        } else {
+            "open".green()
+        };
+        println!(
+            "{:<50} {:<24} {:>9} {:>6}",
+            question, prices, volume, status
+        );
+        println!(
+            "    condition {}",
+            market.condition_id.as_deref().unwrap_or("-").dimmed()
+        );
+        for (idx, token_id) in market.clob_token_ids.iter().flatten().enumerate() {
+            let outcome = market.outcomes.get(idx).map(String::as_str).unwrap_or("-");
+            println!("    {:<9} {}", outcome, token_id.dimmed());
+        }
+    }
+    Ok(())
+}
+
+async fn run_market(
+    market: String,
+    use_id: bool,
+    condition: bool,
+    ids: Option<IdsOnly>,
+    json: bool,
+) -> Result<()> {
     log_info!("📊 Fetching market: {}", market);
     let gamma_client = GammaClient::new();
 
-    if use_id {
-        if let Some(market_data) = gamma_client.get_market_by_id(&market).await? {
+    if use_id || condition {
+        let market_data = if condition {
+            gamma_client.get_market_by_condition_id(&market).await?
+        } else {
+            gamma_client.get_market_by_id(&market).await?
+        };
+        if let Some(market_data) = market_data {
+            if let Some(ids) = ids {
+                return print_ids(std::slice::from_ref(&market_data), ids);
+            }
+            if json {
+                return print_json(&market_data);
+            }
             log_info!("Question: {}", market_data.question);
             if let Some(_id) = &market_data.id {
                 log_info!("Market ID: {}", _id);
@@ -745,6 +1782,12 @@ async fn run_market(market: String, use_id: bool) -> Result<()> {
         if markets.is_empty() {
             anyhow::bail!("Market not found");
         }
+        if let Some(ids) = ids {
+            return print_ids(&markets, ids);
+        }
+        if json {
+            return print_json(&markets);
+        }
         for market_data in markets {
             log_info!("Question: {}", market_data.question);
             if let Some(_id) = &market_data.id {
@@ -761,10 +1804,393 @@ async fn run_market(market: String, use_id: bool) -> Result<()> {
     Ok(())
 }
 
-/// Parse duration string like "24h", "7d", "30d" into seconds
+async fn run_positions(address: Option<String>, include_closed: bool) -> Result<()> {
+    let address = match address.or_else(|| auth::AuthConfig::load().map(|c| c.address)) {
+        Some(address) => address,
+        None => anyhow::bail!("No address given and no auth config found (login in the TUI first)"),
+    };
+    log_info!("💼 Fetching positions for: {}", address);
+
+    let data_client = DataClient::new();
+    let mut positions = data_client
+        .get_positions(&address)
+        .await
+        .context("Failed to fetch positions")?;
+    if include_closed {
+        positions.extend(
+            data_client
+                .get_closed_positions(&address)
+                .await
+                .context("Failed to fetch closed positions")?,
+        );
+    }
+
+    if positions.is_empty() {
+        println!("No positions found for {}", address);
+        return Ok(());
+    }
+
+    display_positions(&positions);
+    Ok(())
+}
+
+/// An event in a file written by `favorites --export`
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct FavoriteEntry {
+    slug: String,
+    title: String,
+}
+
+async fn run_favorites(export: Option<Option<PathBuf>>, import: Option<PathBuf>) -> Result<()> {
+    let Some(config) = auth::AuthConfig::load() else {
+        anyhow::bail!(
+            "No auth config found at {}",
+            auth::AuthConfig::config_path().display()
+        );
+    };
+    if config.session_cookie.is_none() {
+        anyhow::bail!(
+            "Favorites need 'session_cookie' in {} (the polymarketsession cookie from polymarket.com)",
+            auth::AuthConfig::config_path().display()
+        );
+    }
+    let gamma_client = config.gamma_client();
+    let favorites = gamma_client
+        .get_favorite_events()
+        .await
+        .context("Failed to fetch favorites")?;
+
+    if let Some(file) = import {
+        let content = std::fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        let entries: Vec<FavoriteEntry> = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", file.display()))?;
+        // Favorites without an embedded event are only known by event ID
+        let favorited_slugs: HashSet<&str> = favorites
+            .iter()
+            .filter_map(|f| f.event.as_ref().map(|e| e.slug.as_str()))
+            .collect();
+        let favorited_ids: HashSet<&str> = favorites.iter().map(|f| f.event_id.as_str()).collect();
+
+        let (mut added, mut skipped, mut not_found) = (0, 0, 0);
+        for entry in &entries {
+            if favorited_slugs.contains(entry.slug.as_str()) {
+                skipped += 1;
+                continue;
+            }
+            let Some(event) = gamma_client
+                .get_event_by_slug(&entry.slug)
+                .await
+                .with_context(|| format!("Failed to look up {}", entry.slug))?
+            else {
+                println!("{} {} (event not found)", "✗".red(), entry.slug);
+                not_found += 1;
+                continue;
+            };
+            if favorited_ids.contains(event.id.as_str()) {
+                skipped += 1;
+                continue;
+            }
+            gamma_client
+                .add_favorite_event(&event.id)
+                .await
+                .with_context(|| format!("Failed to favorite {}", entry.slug))?;
+            println!("{} {}", "✓".green(), entry.slug);
+            added += 1;
+        }
+        println!(
+            "Added {}, skipped {} already favorited, {} not found",
+            added, skipped, not_found
+        );
+        return Ok(());
+    }
+
+    // Favorites may come without their event, fetch those by ID
+    let mut entries = Vec::new();
+    for favorite in favorites {
+        let event = match favorite.event {
+            Some(event) => Some(event),
+            None => gamma_client
+                .get_event_by_id(&favorite.event_id)
+                .await
+                .with_context(|| format!("Failed to fetch event {}", favorite.event_id))?,
+        };
+        if let Some(event) = event {
+            entries.push(FavoriteEntry {
+                slug: event.slug,
+                title: event.title,
+            });
+        }
+    }
+    let content = serde_json::to_string_pretty(&entries)?;
+    match export.flatten() {
+        Some(file) => {
+            std::fs::write(&file, content)
+                .with_context(|| format!("Failed to write {}", file.display()))?;
+            println!("Exported {} favorites to {}", entries.len(), file.display());
+        },
+        None => println!("{}", content),
+    }
+    Ok(())
+}
+
+async fn run_whoami() -> Result<()> {
+    let path = auth::AuthConfig::config_path();
+    // auth.json wins over the environment, like everywhere else
+    let saved = auth::AuthConfig::load_saved();
+    let from_file = saved.is_some();
+    let Some(config) = saved.or_else(auth::AuthConfig::from_env) else {
+        anyhow::bail!(
+            "No auth config found at {} and no api_key/secret/passphrase/address environment variables",
+            path.display()
+        );
+    };
+    let ok = "✓".green();
+    let missing = "✗".red();
+
+    if from_file {
+        println!("Config:          {}", path.display());
+    } else {
+        println!("Config:          environment variables");
+    }
+    if let Some(profile) = auth::AuthConfig::active_profile() {
+        println!("Profile:         {}", profile);
+    }
+    println!("Address:         {}", config.address);
+    if let Err(e) = config.validate() {
+        println!("                 {} {}", "⚠".yellow(), e);
+    }
+
+    // Any authenticated endpoint proves the API key works; the balance is the cheapest
+    let clob_ok = match config.clob_client() {
+        Ok(client) if client.has_auth() => match client
+            .get_balance_allowance(polymarket_api::clob::AssetType::Collateral)
+            .await
+        {
+            Ok(balance) => {
+                let cash = balance.balance.parse::<f64>().unwrap_or(0.0) / 1_000_000.0;
+                println!("CLOB auth:       {} (cash ${:.2})", ok, cash);
+                if client.can_trade() {
+                    println!("Order signing:   {}", ok);
+                } else {
+                    println!("Order signing:   {} (no private_key)", missing);
+                }
+                true
+            },
+            Err(e) => {
+                println!("CLOB auth:       {} {}", missing, e);
+                false
+            },
+        },
+        Ok(_) => {
+            println!("CLOB auth:       {} (incomplete credentials)", missing);
+            false
+        },
+        Err(e) => {
+            println!("CLOB auth:       {} {}", missing, e);
+            false
+        },
+    };
+    if from_file && ClobClient::from_env().has_auth() {
+        println!("                 (environment credentials are also set, auth.json wins)");
+    }
+
+    let session = config.session_cookie.is_some() && config.session_nonce.is_some();
+    println!(
+        "Session cookie:  {}",
+        if session {
+            ok.clone()
+        } else {
+            missing.clone()
+        }
+    );
+
+    match cache::gamma_client()
+        .get_public_profile(&config.address)
+        .await
+    {
+        Ok(Some(profile)) => println!(
+            "Username:        {}",
+            profile
+                .name
+                .or(profile.pseudonym)
+                .unwrap_or_else(|| "-".to_string())
+        ),
+        Ok(None) => println!("Username:        {} (no public profile)", missing),
+        Err(e) => println!("Username:        {} {}", missing, e),
+    }
+
+    if !clob_ok {
+        anyhow::bail!("CLOB authentication failed");
+    }
+    Ok(())
+}
+
+/// How long one `status` check may take before the endpoint counts as down
+const STATUS_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Run a health check, timing it and treating any answer but "OK" as down
+async fn timed_status(
+    check: impl Future<Output = polymarket_api::Result<String>>,
+) -> (Duration, std::result::Result<(), String>) {
+    let start = std::time::Instant::now();
+    let result = match tokio::time::timeout(STATUS_TIMEOUT, check).await {
+        Ok(Ok(status)) if status.trim().eq_ignore_ascii_case("ok") => Ok(()),
+        Ok(Ok(status)) => Err(format!("unexpected status {:?}", status)),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err(format!("no answer within {}s", STATUS_TIMEOUT.as_secs())),
+    };
+    (start.elapsed(), result)
+}
+
+async fn run_status(json: bool) -> Result<()> {
+    let gamma_client = GammaClient::new();
+    let data_client = DataClient::new();
+    let clob_client = ClobClient::new();
+    let (gamma, data, clob, rtds) = tokio::join!(
+        timed_status(gamma_client.get_status()),
+        timed_status(async { data_client.get_status().await.map(|status| status.data) }),
+        timed_status(clob_client.get_status()),
+        timed_status(async {
+            RTDSClient::check_connection()
+                .await
+                .map(|_| "OK".to_string())
+        }),
+    );
+    // (name, critical, latency, result); RTDS only feeds live trades, so it doesn't fail the check
+    let checks = [
+        ("Gamma", true, gamma),
+        ("Data", true, data),
+        ("CLOB", true, clob),
+        ("RTDS", false, rtds),
+    ];
+
+    if json {
+        let report: Vec<_> = checks
+            .iter()
+            .map(|(name, critical, (latency, result))| {
+                serde_json::json!({
+                    "api": name.to_lowercase(),
+                    "up": result.is_ok(),
+                    "critical": critical,
+                    "latency_ms": latency.as_millis() as u64,
+                    "error": result.as_ref().err(),
+                })
+            })
+            .collect();
+        print_json(&report)?;
+    } else {
+        for (name, _, (latency, result)) in &checks {
+            let state = match result {
+                Ok(()) => "✓ up  ".green(),
+                Err(_) => "✗ down".red(),
+            };
+            let error = result
+                .as_ref()
+                .err()
+                .map(|e| format!("  {}", e).dimmed().to_string())
+                .unwrap_or_default();
+            println!(
+                "{:<6} {} {:>6} ms{}",
+                name,
+                state,
+                latency.as_millis(),
+                error
+            );
+        }
+    }
+
+    let down: Vec<&str> = checks
+        .iter()
+        .filter(|(_, critical, (_, result))| *critical && result.is_err())
+        .map(|(name, ..)| *name)
+        .collect();
+    if !down.is_empty() {
+        anyhow::bail!("{} API down", down.join(", "));
+    }
+    Ok(())
+}
+
+/// Green for gains, red for losses
+fn colored_pnl(value: f64) -> colored::ColoredString {
+    let text = format!(
+        "{}${:.2}",
+        if value < 0.0 {
+            "-"
+        } else {
+            "+"
+        },
+        value.abs()
+    );
+    if value < 0.0 {
+        text.red()
+    } else {
+        text.green()
+    }
+}
+
+fn display_positions(positions: &[polymarket_api::data::Position]) {
+    println!(
+        "{:<40} {:<10} {:>10} {:>7} {:>10} {:>10} {:>10}",
+        "Market", "Outcome", "Size", "Avg", "Value", "P&L", "Realized"
+    );
+    println!("{}", "─".repeat(103));
+
+    let (mut total_value, mut total_pnl, mut total_realized) = (0.0, 0.0, 0.0);
+    for position in positions {
+        let title: String = if position.title.chars().count() > 38 {
+            format!("{}…", position.title.chars().take(37).collect::<String>())
+        } else {
+            position.title.clone()
+        };
+        let outcome: String = position.outcome.chars().take(10).collect();
+        // Fully-exited positions have no size
+        let size = position
+            .size
+            .map(|s| format!("{:.2}", s))
+            .unwrap_or_else(|| "closed".to_string());
+        let avg = position
+            .avg_price
+            .map(|p| format!("{:.1}¢", p * 100.0))
+            .unwrap_or_else(|| "-".to_string());
+        let value = position.current_value.unwrap_or(0.0);
+        let pnl = position.cash_pnl.unwrap_or(0.0);
+        let realized = position.realized_pnl.unwrap_or(0.0);
+        total_value += value;
+        total_pnl += pnl;
+        total_realized += realized;
+
+        println!(
+            "{:<40} {:<10} {:>10} {:>7} {:>10} {:>10} {:>10}",
+            title,
+            outcome,
+            size,
+            avg,
+            format!("${:.2}", value),
+            colored_pnl(pnl),
+            colored_pnl(realized)
+        );
+    }
+
+    println!("{}", "─".repeat(103));
+    println!(
+        "{:<40} {:<10} {:>10} {:>7} {:>10} {:>10} {:>10}",
+        format!("Total ({} positions)", positions.len()).bold(),
+        "",
+        "",
+        "",
+        format!("${:.2}", total_value),
+        colored_pnl(total_pnl),
+        colored_pnl(total_realized)
+    );
+}
+
+/// Parse duration string like "30m", "24h", "7d" into seconds
 fn parse_duration(s: &str) -> Option<i64> {
     let s = s.trim().to_lowercase();
-    if let Some(hours) = s.strip_suffix('h') {
+    if let Some(minutes) = s.strip_suffix('m') {
+        minutes.parse::<i64>().ok().map(|m| m * 60)
+    } else if let Some(hours) = s.strip_suffix('h') {
         hours.parse::<i64>().ok().map(|h| h * 3600)
     } else if let Some(days) = s.strip_suffix('d') {
         days.parse::<i64>().ok().map(|d| d * 86400)
@@ -778,8 +2204,9 @@ async fn run_yield(
     limit: usize,
     min_volume: f64,
     expires_in: Option<String>,
+    outcome_names: OutcomeNameStyle,
 ) -> Result<()> {
-    use chrono::{DateTime, Utc};
+    use chrono::Utc;
 
     // Parse expires_in duration if provided
     let max_end_time = expires_in
@@ -809,94 +2236,13 @@ async fn run_yield(
 
     log_info!("Fetched {} markets, filtering...", markets.len());
 
-    // Structure to hold yield opportunities
-    struct YieldOpportunity {
-        market_name: String,
-        market_status: &'static str,
-        outcome: String,
-        price: f64,
-        est_return: f64,
-        volume: f64,
-        event_slug: String,
-        event_title: String,
-        event_status: &'static str,
-        end_date: Option<DateTime<Utc>>,
-    }
-
-    let mut opportunities: Vec<YieldOpportunity> = Vec::new();
-
-    for market in &markets {
-        // Skip closed markets
-        if market.closed {
-            continue;
-        }
-
-        // Skip markets without event info
-        let event = match market.event() {
-            Some(e) => e,
-            None => continue,
-        };
+    let mut opportunities = find_yield_opportunities(&markets, min_prob, min_volume);
 
-        // Parse end date
-        let end_date = event
-            .end_date
-            .as_ref()
-            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-            .map(|dt| dt.with_timezone(&Utc));
-
-        // Filter by expiration if --expires-in is set
-        if let Some(max_time) = max_end_time {
-            match end_date {
-                Some(end) if end <= max_time => {}, // Keep it
-                _ => continue,                      // Skip if no end date or too far out
-            }
-        }
-
-        // Check volume threshold (use 24hr volume as it's more reliably populated)
-        let volume = market.volume_24hr.unwrap_or(0.0);
-        if volume < min_volume {
-            continue;
-        }
-
-        // Check each outcome price
-        for (i, price_str) in market.outcome_prices.iter().enumerate() {
-            if let Ok(price) = price_str.parse::<f64>()
-                && price >= min_prob
-            {
-                let outcome = market
-                    .outcomes
-                    .get(i)
-                    .cloned()
-                    .unwrap_or_else(|| format!("Outcome {}", i));
-                let est_return = (1.0 - price) * 100.0; // Return as percentage
-
-                // Use short name if available, otherwise market question
-                let market_name = market
-                    .group_item_title
-                    .as_ref()
-                    .filter(|s| !s.is_empty())
-                    .cloned()
-                    .unwrap_or_else(|| market.question.clone());
-
-                opportunities.push(YieldOpportunity {
-                    market_name,
-                    market_status: market.status(),
-                    outcome,
-                    price,
-                    est_return,
-                    volume,
-                    event_slug: event.slug.clone(),
-                    event_title: event.title.clone(),
-                    event_status: event.status(),
-                    end_date,
-                });
-            }
-        }
+    // Filter by expiration if --expires-in is set (skip if no end date or too far out)
+    if let Some(max_time) = max_end_time {
+        opportunities.retain(|opp| opp.end_date.is_some_and(|end| end <= max_time));
     }
 
-    // Sort by estimated return (highest first)
-    opportunities.sort_by(|a, b| b.est_return.partial_cmp(&a.est_return).unwrap());
-
     if opportunities.is_empty() {
         log_info!(
             "No markets found with outcomes >= {:.1}% and volume >= ${:.0}",
@@ -963,7 +2309,7 @@ async fn run_yield(
             "   {:<35} {:>6} {:>6} {:>7.1}¢ {:>8} {:>9.0}$",
             truncated_name,
             market_status_colored,
-            opp.outcome,
+            opp.display_outcome(outcome_names),
             opp.price * 100.0,
             return_str,
             opp.volume,
@@ -975,17 +2321,34 @@ async fn run_yield(
     Ok(())
 }
 
+/// Print a value as pretty JSON on stdout (for `--json`)
+fn print_json<T: serde::Serialize>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Print trades in the requested format, using `table` for human-readable output
+fn output_trades<T: TradeExport>(
+    trades: &[T],
+    format: OutputFormat,
+    table: fn(&[T]),
+) -> Result<()> {
+    let mut out = std::io::stdout().lock();
+    match format {
+        OutputFormat::Table => table(trades),
+        OutputFormat::Csv => trade_export::write_csv(&mut out, trades)?,
+        OutputFormat::Json => trade_export::write_json(&mut out, trades)?,
+    }
+    Ok(())
+}
+
 fn display_trades(trades: &[polymarket_api::data::DataTrade]) {
     use chrono::DateTime;
     for _trade in trades {
         let _time = DateTime::from_timestamp(_trade.timestamp, 0)
             .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
             .unwrap_or_else(|| "unknown".to_string());
-        let _side = if _trade.side == "BUY" {
-            "🟢 BUY"
-        } else {
-            "🔴 SELL"
-        };
+        let _side = display_trait::side_marker(&_trade.side);
         log_info!(
             "{} | {} | {} @ ${:.4} ({} shares) | {} | {}",
             _time,
@@ -1005,11 +2368,7 @@ fn display_clob_trades(trades: &[polymarket_api::clob::Trade]) {
         let _time = DateTime::from_timestamp(_trade.timestamp, 0)
             .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
             .unwrap_or_else(|| "unknown".to_string());
-        let _side = if _trade.side == "BUY" {
-            "🟢 BUY"
-        } else {
-            "🔴 SELL"
-        };
+        let _side = display_trait::side_marker(&_trade.side);
         log_info!(
             "{} | {} | @ ${} ({} shares)",
             _time,
@@ -1019,3 +2378,26 @@ fn display_clob_trades(trades: &[polymarket_api::clob::Trade]) {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_event_slug() {
+        for input in [
+            "will-it-rain",
+            "https://polymarket.com/event/will-it-rain",
+            "https://polymarket.com/event/will-it-rain/",
+            "https://polymarket.com/event/will-it-rain?tid=123",
+            "https://polymarket.com/event/will-it-rain/comments",
+            "https://polymarket.com/event/will-it-rain?tid=123#section",
+            "https://polymarket.com/event/will-it-rain#section",
+            "http://polymarket.com/event/Will-It-Rain/will-it-rain-tomorrow",
+            "polymarket.com/event/will-it-rain/comments",
+            "  WILL-IT-RAIN  ",
+        ] {
+            assert_eq!(extract_event_slug(input), "will-it-rain", "{}", input);
+        }
+    }
+}
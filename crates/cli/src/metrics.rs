@@ -0,0 +1,173 @@
+//! Prometheus metrics for the `monitor` command (`--metrics-addr`)
+
+use {
+    hyper::{
+        Body, Request, Response, Server, StatusCode,
+        header::{CONTENT_TYPE, HeaderValue},
+        service::{make_service_fn, service_fn},
+    },
+    polymarket_api::websocket::MessageKind,
+    std::{
+        convert::Infallible,
+        fmt::Write,
+        net::SocketAddr,
+        sync::{
+            Arc,
+            atomic::{AtomicU64, Ordering},
+        },
+        time::{SystemTime, UNIX_EPOCH},
+    },
+};
+
+/// Every message kind, in the order the counters are stored
+const KINDS: [MessageKind; 7] = [
+    MessageKind::Orderbook,
+    MessageKind::Trade,
+    MessageKind::Order,
+    MessageKind::Price,
+    MessageKind::Error,
+    MessageKind::Subscribed,
+    MessageKind::Unknown,
+];
+
+/// Counters updated from the WebSocket callback and read by the metrics server
+#[derive(Debug, Default)]
+pub struct MonitorMetrics {
+    messages: [AtomicU64; KINDS.len()],
+    reconnects: AtomicU64,
+    /// Unix time of the last message in milliseconds (0 before the first one)
+    last_message_ms: AtomicU64,
+}
+
+impl MonitorMetrics {
+    pub fn record_message(&self, kind: MessageKind) {
+        if let Some(idx) = KINDS.iter().position(|k| *k == kind) {
+            self.messages[idx].fetch_add(1, Ordering::Relaxed);
+        }
+        self.last_message_ms.store(now_ms(), Ordering::Relaxed);
+    }
+
+    pub fn record_reconnect(&self) {
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Metrics in the Prometheus text exposition format
+    pub fn render(&self, now_ms: u64) -> String {
+        let mut out = String::new();
+        out.push_str(
+            "# HELP polymarket_monitor_messages_total WebSocket messages received by type.\n",
+        );
+        out.push_str("# TYPE polymarket_monitor_messages_total counter\n");
+        for (kind, count) in KINDS.iter().zip(&self.messages) {
+            let _ = writeln!(
+                out,
+                "polymarket_monitor_messages_total{{type=\"{}\"}} {}",
+                kind_label(*kind),
+                count.load(Ordering::Relaxed)
+            );
+        }
+        out.push_str("# HELP polymarket_monitor_reconnects_total WebSocket reconnects after the stream dropped.\n");
+        out.push_str("# TYPE polymarket_monitor_reconnects_total counter\n");
+        let _ = writeln!(
+            out,
+            "polymarket_monitor_reconnects_total {}",
+            self.reconnects.load(Ordering::Relaxed)
+        );
+        // Not reported until the first message arrives
+        let last = self.last_message_ms.load(Ordering::Relaxed);
+        if last > 0 {
+            out.push_str("# HELP polymarket_monitor_last_message_age_seconds Seconds since the last WebSocket message.\n");
+            out.push_str("# TYPE polymarket_monitor_last_message_age_seconds gauge\n");
+            let _ = writeln!(
+                out,
+                "polymarket_monitor_last_message_age_seconds {:.3}",
+                now_ms.saturating_sub(last) as f64 / 1000.0
+            );
+        }
+        out
+    }
+}
+
+fn kind_label(kind: MessageKind) -> &'static str {
+    match kind {
+        MessageKind::Orderbook => "orderbook",
+        MessageKind::Trade => "trade",
+        MessageKind::Order => "order",
+        MessageKind::Price => "price",
+        MessageKind::Error => "error",
+        MessageKind::Subscribed => "subscribed",
+        MessageKind::Unknown => "unknown",
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Bind `addr` and return the server future answering `GET /metrics`
+///
+/// Binding happens up front so a taken port fails the command before it connects.
+pub fn serve(
+    addr: SocketAddr,
+    metrics: Arc<MonitorMetrics>,
+) -> hyper::Result<impl Future<Output = hyper::Result<()>>> {
+    let make_service = make_service_fn(move |_| {
+        let metrics = Arc::clone(&metrics);
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                let metrics = Arc::clone(&metrics);
+                async move { Ok::<_, Infallible>(respond(&req, &metrics)) }
+            }))
+        }
+    });
+    Ok(Server::try_bind(&addr)?.serve(make_service))
+}
+
+fn respond(req: &Request<Body>, metrics: &MonitorMetrics) -> Response<Body> {
+    if req.uri().path() != "/metrics" {
+        let mut not_found = Response::new(Body::from("Not found, try /metrics\n"));
+        *not_found.status_mut() = StatusCode::NOT_FOUND;
+        return not_found;
+    }
+    let mut response = Response::new(Body::from(metrics.render(now_ms())));
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("text/plain; version=0.0.4"),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, polymarket_api::websocket::MessageKind};
+
+    #[test]
+    fn test_monitor_metrics_render() {
+        let metrics = MonitorMetrics::default();
+        let text = metrics.render(0);
+        assert!(text.contains("polymarket_monitor_messages_total{type=\"trade\"} 0"));
+        assert!(!text.contains("last_message_age"));
+
+        metrics.record_message(MessageKind::Trade);
+        metrics.record_message(MessageKind::Trade);
+        metrics.record_message(MessageKind::Price);
+        metrics.record_reconnect();
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Should read clock")
+            .as_millis() as u64;
+        let text = metrics.render(now + 2_000);
+        assert!(text.contains("polymarket_monitor_messages_total{type=\"trade\"} 2"));
+        assert!(text.contains("polymarket_monitor_messages_total{type=\"price\"} 1"));
+        assert!(text.contains("polymarket_monitor_reconnects_total 1"));
+        let age: f64 = text
+            .lines()
+            .find_map(|line| line.strip_prefix("polymarket_monitor_last_message_age_seconds "))
+            .and_then(|age| age.parse().ok())
+            .expect("Should report message age");
+        assert!((2.0..3.0).contains(&age));
+    }
+}
@@ -0,0 +1,185 @@
+//! Paper trading module
+//!
+//! In paper mode (`trending --paper`, or `T` in the TUI) orders from the Trade popup
+//! are filled against the current orderbook and recorded in a local ledger instead
+//! of being posted to the CLOB. The ledger is kept in
+//! ~/.config/polymarket-tui/paper.json.
+
+use {
+    crate::auth::AuthConfig,
+    polymarket_api::clob::Side,
+    serde::{Deserialize, Serialize},
+    std::path::{Path, PathBuf},
+};
+
+/// Shares below this are treated as a closed position
+const MIN_SHARES: f64 = 1e-9;
+
+/// One simulated fill
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaperTrade {
+    /// Unix timestamp (seconds) of the fill
+    pub timestamp: i64,
+    pub token_id: String,
+    /// Market question the token belongs to
+    pub market: String,
+    pub outcome: String,
+    pub side: Side,
+    /// Fill price (0-1)
+    pub price: f64,
+    /// Shares filled
+    pub size: f64,
+}
+
+/// Open paper position in one outcome token
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaperPosition {
+    pub token_id: String,
+    pub market: String,
+    pub outcome: String,
+    pub shares: f64,
+    /// Average price paid for the shares still held
+    pub avg_price: f64,
+}
+
+impl PaperPosition {
+    /// Dollars paid for the shares still held
+    pub fn cost(&self) -> f64 {
+        self.shares * self.avg_price
+    }
+}
+
+/// Every simulated fill, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaperLedger {
+    pub trades: Vec<PaperTrade>,
+}
+
+impl PaperLedger {
+    /// Get the ledger file path (next to auth.json)
+    pub fn config_path() -> PathBuf {
+        AuthConfig::config_dir().join("paper.json")
+    }
+
+    /// Load the ledger, starting empty if the file is missing
+    ///
+    /// A file that exists but can't be read or parsed is an error, so callers
+    /// don't save a fresh ledger over the user's trades.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse {}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Failed to read {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Write the ledger to `path`, creating its directory if needed
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent()
+            && !dir.exists()
+        {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize paper ledger: {}", e))?;
+        std::fs::write(path, content)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Record a fill, rejecting sells of more shares than the ledger holds
+    pub fn record(&mut self, trade: PaperTrade) -> Result<(), String> {
+        if trade.side == Side::Sell {
+            let held = self.held_shares(&trade.token_id);
+            if trade.size > held + MIN_SHARES {
+                return Err(format!(
+                    "Only {:.2} paper shares of {} held",
+                    held, trade.outcome
+                ));
+            }
+        }
+        self.trades.push(trade);
+        Ok(())
+    }
+
+    /// Paper shares currently held of `token_id`
+    pub fn held_shares(&self, token_id: &str) -> f64 {
+        self.positions()
+            .iter()
+            .find(|p| p.token_id == token_id)
+            .map_or(0.0, |p| p.shares)
+    }
+
+    /// Open positions in the order they were first bought
+    ///
+    /// Sells keep the average price of the remaining shares.
+    pub fn positions(&self) -> Vec<PaperPosition> {
+        let (mut positions, _) = self.replay();
+        positions.retain(|p| p.shares > MIN_SHARES);
+        positions
+    }
+
+    /// Profit locked in by sells, measured against the average buy price
+    pub fn realized_pnl(&self) -> f64 {
+        self.replay().1
+    }
+
+    /// Apply every fill in order, returning each token's position and the realized P&L
+    fn replay(&self) -> (Vec<PaperPosition>, f64) {
+        let mut positions: Vec<PaperPosition> = Vec::new();
+        let mut realized = 0.0;
+        for trade in &self.trades {
+            let idx = match positions.iter().position(|p| p.token_id == trade.token_id) {
+                Some(idx) => idx,
+                None => {
+                    positions.push(PaperPosition {
+                        token_id: trade.token_id.clone(),
+                        market: trade.market.clone(),
+                        outcome: trade.outcome.clone(),
+                        shares: 0.0,
+                        avg_price: 0.0,
+                    });
+                    positions.len() - 1
+                },
+            };
+            let position = &mut positions[idx];
+            match trade.side {
+                Side::Buy => {
+                    let shares = position.shares + trade.size;
+                    if shares > MIN_SHARES {
+                        position.avg_price = (position.cost() + trade.size * trade.price) / shares;
+                    }
+                    position.shares = shares;
+                },
+                Side::Sell => {
+                    let sold = trade.size.min(position.shares);
+                    realized += sold * (trade.price - position.avg_price);
+                    position.shares -= sold;
+                },
+            }
+        }
+        (positions, realized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_reports_unparseable_ledger() {
+        let dir = std::env::temp_dir().join(format!("polymarket-paper-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("Should create dir");
+        let path = dir.join("paper.json");
+        assert_eq!(PaperLedger::load(&path), Ok(PaperLedger::default()));
+
+        // A corrupt ledger must not read as an empty one that the next fill saves over
+        std::fs::write(&path, r#"{"trades": [{"token_id": 1}]}"#).expect("Should write file");
+        let err = PaperLedger::load(&path).expect_err("Should not parse");
+        assert!(err.contains("Failed to parse"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
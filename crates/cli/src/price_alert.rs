@@ -0,0 +1,93 @@
+//! Price-change alerts for the `monitor` command
+
+use std::collections::HashMap;
+
+/// An `--alert <asset_id>:<threshold>` argument (threshold in cents)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceAlert {
+    pub asset_id: String,
+    pub threshold_cents: f64,
+}
+
+impl std::str::FromStr for PriceAlert {
+    type Err = String;
+
+    /// Parse "ASSET_ID:CENTS", e.g. "1234:2.5"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (asset_id, threshold) = s
+            .rsplit_once(':')
+            .ok_or_else(|| format!("invalid alert '{}' (expected ASSET_ID:CENTS)", s))?;
+        let threshold_cents = threshold
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|cents| cents.is_finite() && *cents > 0.0)
+            .ok_or_else(|| {
+                format!(
+                    "invalid alert threshold '{}' (expected cents > 0)",
+                    threshold
+                )
+            })?;
+        if asset_id.trim().is_empty() {
+            return Err(format!("invalid alert '{}' (missing asset ID)", s));
+        }
+        Ok(Self {
+            asset_id: asset_id.trim().to_string(),
+            threshold_cents,
+        })
+    }
+}
+
+/// Compares each price update with the last one seen for the same asset
+#[derive(Debug, Default)]
+pub struct PriceAlerts {
+    thresholds: HashMap<String, f64>,
+    last_prices: HashMap<String, f64>,
+}
+
+impl PriceAlerts {
+    pub fn new(alerts: &[PriceAlert]) -> Self {
+        Self {
+            thresholds: alerts
+                .iter()
+                .map(|alert| (alert.asset_id.clone(), alert.threshold_cents))
+                .collect(),
+            last_prices: HashMap::new(),
+        }
+    }
+
+    /// Record a price (0-1) and return the move in cents if it exceeds the threshold
+    ///
+    /// The first price seen for an asset only sets the baseline.
+    pub fn observe(&mut self, asset_id: &str, price: f64) -> Option<f64> {
+        let threshold = *self.thresholds.get(asset_id)?;
+        let previous = self.last_prices.insert(asset_id.to_string(), price)?;
+        let change_cents = (price - previous) * 100.0;
+        (change_cents.abs() > threshold).then_some(change_cents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_alerts() {
+        let alert: PriceAlert = "1002:2.5".parse().expect("Should parse alert");
+        assert_eq!(alert.asset_id, "1002");
+        assert_eq!(alert.threshold_cents, 2.5);
+        for invalid in ["1002", "1002:", "1002:-1", ":2", "1002:abc"] {
+            assert!(invalid.parse::<PriceAlert>().is_err(), "{}", invalid);
+        }
+
+        let mut alerts = PriceAlerts::new(&[alert]);
+        // First update only sets the baseline; unwatched assets never alert
+        assert_eq!(alerts.observe("1002", 0.60), None);
+        assert_eq!(alerts.observe("1003", 0.10), None);
+        assert_eq!(alerts.observe("1003", 0.90), None);
+        // Moves are measured from the last seen price
+        assert_eq!(alerts.observe("1002", 0.62), None);
+        let change = alerts.observe("1002", 0.59).expect("Should alert");
+        assert!((change + 3.0).abs() < 1e-9);
+    }
+}
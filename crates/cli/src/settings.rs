@@ -0,0 +1,169 @@
+//! User settings module
+//!
+//! Handles loading and saving preferences from ~/.config/polymarket-tui/settings.json
+
+use {
+    crate::{
+        auth::AuthConfig,
+        trending_tui::{
+            logging::log_warn,
+            state::{EventSortBy, YieldSortBy},
+        },
+    },
+    polymarket_api::yields::DEFAULT_MIN_PROB,
+    serde::{Deserialize, Serialize},
+    std::path::{Path, PathBuf},
+};
+
+/// User preferences (every field falls back to its default when missing)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Trade popup defaults
+    pub trade: TradeSettings,
+    /// Event slugs watched for live trades when the TUI starts
+    pub watchlist: Vec<String>,
+    /// Locally bookmarked event slugs, shown in the Favorites tab when no
+    /// session cookie is configured
+    pub favorites: Vec<String>,
+    /// Yield tab filters and sort order from the last session
+    #[serde(rename = "yield")]
+    pub yield_tab: YieldSettings,
+    /// Events list sort order from the last session (cycled with `s`)
+    pub event_sort: EventSortBy,
+    /// Seconds between price refreshes of the selected event's markets
+    /// (default 10, 0 turns them off)
+    pub price_refresh_secs: Option<u64>,
+    /// Most events watched for live trades at once (default 10); watching one
+    /// more stops the least recently active watch
+    pub max_watched_events: Option<usize>,
+}
+
+/// Trade popup defaults
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TradeSettings {
+    /// Dollar amount pre-filled when the Trade popup opens
+    pub default_amount: Option<f64>,
+    /// Quick-select dollar amounts, mapped to 1, 2, ... (or F1, F2, ...) in the Trade popup
+    /// (the key after the last preset selects the maximum)
+    pub quick_amounts: Vec<f64>,
+    /// Maximum slippage (in %) from the best price accepted for market orders
+    pub max_slippage_pct: f64,
+}
+
+impl Default for TradeSettings {
+    fn default() -> Self {
+        Self {
+            default_amount: None,
+            quick_amounts: vec![10.0, 50.0, 100.0, 500.0],
+            max_slippage_pct: 2.0,
+        }
+    }
+}
+
+/// Yield tab filters and sort order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct YieldSettings {
+    /// Minimum outcome probability (0-1)
+    pub min_prob: f64,
+    /// Minimum 24h volume in dollars
+    pub min_volume: f64,
+    /// Minimum market liquidity in dollars
+    pub min_liquidity: f64,
+    /// Hide markets resolving further out than this many days
+    pub max_days_to_resolution: Option<u32>,
+    pub sort_by: YieldSortBy,
+}
+
+impl Default for YieldSettings {
+    fn default() -> Self {
+        Self {
+            min_prob: DEFAULT_MIN_PROB,
+            min_volume: 0.0,
+            min_liquidity: 0.0,
+            max_days_to_resolution: None,
+            sort_by: YieldSortBy::Return,
+        }
+    }
+}
+
+impl Settings {
+    /// Get the settings file path (next to auth.json)
+    pub fn config_path() -> PathBuf {
+        AuthConfig::config_dir().join("settings.json")
+    }
+
+    /// Load settings from file, falling back to defaults if missing or invalid
+    ///
+    /// For read-only use: callers that save the settings back use `try_load`.
+    pub fn load() -> Self {
+        Self::try_load().unwrap_or_else(|e| {
+            log_warn!("{}", e);
+            Self::default()
+        })
+    }
+
+    /// Load settings from file, or defaults if there is none
+    ///
+    /// Errors when the file can't be read or parsed, so callers that save the
+    /// result don't replace the watchlist, favorites and trade defaults with defaults.
+    pub fn try_load() -> Result<Self, String> {
+        Self::read_from(&Self::config_path())
+    }
+
+    /// Load settings from `path`, or defaults if there is none (errors like `try_load`)
+    pub fn read_from(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse settings {}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Failed to read settings {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Save settings to file
+    pub fn save(&self) -> Result<(), String> {
+        self.save_to(&Self::config_path())
+    }
+
+    /// Save settings to `path`, creating its directory if needed
+    pub fn save_to(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent()
+            && !dir.exists()
+        {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+        std::fs::write(path, content).map_err(|e| format!("Failed to write settings: {}", e))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_from_reports_unparseable_file() {
+        let dir =
+            std::env::temp_dir().join(format!("polymarket-settings-read-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("Should create temp dir");
+        let path = dir.join("settings.json");
+
+        let missing = Settings::read_from(&path).expect("Missing file should be defaults");
+        assert!(missing.watchlist.is_empty());
+
+        // A file that fails to parse must not read as defaults that the next save writes
+        std::fs::write(&path, r#"{"watchlist": "not-a-list"}"#).expect("Should write file");
+        let err = Settings::read_from(&path).expect_err("Should not parse");
+        assert!(err.contains("Failed to parse settings"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,161 @@
+//! Machine-readable trade output for the `trades` command
+//!
+//! CSV rows share one column layout for CLOB and Data API trades so exports from
+//! either source can be concatenated. Missing fields (the CLOB API has no outcome or
+//! market title) are left empty.
+
+use {
+    chrono::DateTime,
+    polymarket_api::{clob, data},
+    serde::Serialize,
+    std::io::{self, Write},
+};
+
+/// Output format selected with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable lines
+    #[default]
+    Table,
+    Csv,
+    Json,
+}
+
+/// Columns written by [`write_csv`]
+pub const CSV_HEADER: [&str; 6] = ["timestamp", "side", "outcome", "price", "size", "market"];
+
+/// A trade that can be written as a CSV row
+pub trait TradeExport: Serialize {
+    /// Unix timestamp in seconds
+    fn timestamp(&self) -> i64;
+    fn side(&self) -> &str;
+    fn outcome(&self) -> &str;
+    fn price(&self) -> String;
+    fn size(&self) -> String;
+    /// Market title
+    fn market(&self) -> &str;
+
+    /// Fields in [`CSV_HEADER`] order, quoted where needed
+    fn csv_row(&self) -> String {
+        let timestamp = DateTime::from_timestamp(self.timestamp(), 0)
+            .map(|dt| dt.to_rfc3339())
+            .unwrap_or_default();
+        [
+            timestamp,
+            self.side().to_string(),
+            self.outcome().to_string(),
+            self.price(),
+            self.size(),
+            self.market().to_string(),
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+impl TradeExport for clob::Trade {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn side(&self) -> &str {
+        &self.side
+    }
+
+    fn outcome(&self) -> &str {
+        ""
+    }
+
+    fn price(&self) -> String {
+        self.price.clone()
+    }
+
+    fn size(&self) -> String {
+        self.size.clone()
+    }
+
+    fn market(&self) -> &str {
+        ""
+    }
+}
+
+impl TradeExport for data::DataTrade {
+    fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    fn side(&self) -> &str {
+        &self.side
+    }
+
+    fn outcome(&self) -> &str {
+        &self.outcome
+    }
+
+    fn price(&self) -> String {
+        self.price.to_string()
+    }
+
+    fn size(&self) -> String {
+        self.size.to_string()
+    }
+
+    fn market(&self) -> &str {
+        &self.title
+    }
+}
+
+/// Write a header line followed by one row per trade
+pub fn write_csv<T: TradeExport>(out: &mut impl Write, trades: &[T]) -> io::Result<()> {
+    writeln!(out, "{}", CSV_HEADER.join(","))?;
+    for trade in trades {
+        writeln!(out, "{}", trade.csv_row())?;
+    }
+    Ok(())
+}
+
+/// Write trades as a pretty-printed JSON array
+pub fn write_json<T: TradeExport>(out: &mut impl Write, trades: &[T]) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, trades)?;
+    writeln!(out)
+}
+
+/// Quote a field if it contains a comma, quote or line break (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        polymarket_api::{Fixtures, data::DataTrade},
+    };
+
+    #[test]
+    fn test_trade_csv_export() {
+        let mut trades: Vec<DataTrade> = Fixtures::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../polymarket-api/tests/fixtures"
+        ))
+        .load_required("data/trades")
+        .expect("Should load trades fixture");
+        trades.truncate(1);
+        trades[0].title = "Will A, B or \"C\" win?".to_string();
+
+        let mut out = Vec::new();
+        write_csv(&mut out, &trades).expect("Should write CSV");
+        let csv = String::from_utf8(out).expect("Should be UTF-8");
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines, vec![
+            "timestamp,side,outcome,price,size,market",
+            "2030-03-17T17:46:40+00:00,BUY,Yes,0.62,100,\"Will A, B or \"\"C\"\" win?\"",
+        ]);
+    }
+}
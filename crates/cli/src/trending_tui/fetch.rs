@@ -4,19 +4,76 @@ use {
     super::{
         logging::{log_debug, log_error, log_info, log_warn},
         state::{
-            self, EventFilter, OrderbookLevel, SearchMode, TrendingAppState, YieldOpportunity,
-            YieldSearchResult,
+            self, AppTab, EventFilter, EventSortBy, EventTrades, OrderRequest, RtdsStatus,
+            SearchMode, TrendingAppState, YieldOpportunity, YieldSearchResult,
         },
     },
-    chrono::{DateTime, Utc},
+    chrono::Utc,
     polymarket_api::{
-        GammaClient,
+        GammaClient, PolymarketError,
         clob::{BatchTokenRequest, ClobClient, Side},
+        fixtures::Fixtures,
+        gamma::Event,
+        websocket::{PolymarketWebSocket, WebSocketMessage},
+        yields::{best_event_yield, find_yield_opportunities},
     },
     std::{collections::HashMap, sync::Arc},
     tokio::sync::Mutex as TokioMutex,
 };
 
+/// Consecutive reconnect attempts before a watched event's live trades stop
+const RTDS_MAX_RECONNECTS: u32 = 8;
+/// Delay before the first reconnect attempt (doubled for each further attempt)
+const RTDS_RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+/// How often the header API status dots are refreshed
+const API_HEALTH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+/// How long a single API status ping may take before the API is shown as down
+const API_HEALTH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Switch to a header tab, starting any fetch the tab needs (releases the lock first)
+/// Returns false if the tab was already active.
+pub fn switch_tab(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: tokio::sync::MutexGuard<'_, TrendingAppState>,
+    tab: AppTab,
+) -> bool {
+    if app.current_tab() == tab {
+        return false;
+    }
+    app.main_tab = tab.main_tab();
+    log_info!("Switched to {} tab", tab.label());
+
+    match tab {
+        AppTab::Events | AppTab::Breaking => {
+            if let Some(filter) = tab.event_filter()
+                && let Some((filter, limit)) = switch_filter_tab(&mut app, filter)
+            {
+                let sort = app.event_sort_by;
+                drop(app);
+                spawn_filter_fetch(Arc::clone(app_state), filter, sort, limit);
+            }
+        },
+        AppTab::Favorites => {
+            // Fetch favorites if not already loaded
+            if app.favorites_state.events.is_empty()
+                && !app.favorites_state.is_loading
+                && app.auth_state.is_authenticated
+            {
+                drop(app);
+                spawn_fetch_favorites(Arc::clone(app_state));
+            }
+        },
+        AppTab::Yield => {
+            // Fetch yield data if not already loaded
+            if app.yield_state.opportunities.is_empty() && !app.yield_state.is_loading {
+                drop(app);
+                spawn_yield_fetch(Arc::clone(app_state));
+            }
+        },
+    }
+    true
+}
+
 /// Switch to a new filter tab, using cache if available.
 /// Returns `Some((order_by, limit))` if API fetch is needed, `None` if cache was used.
 pub fn switch_filter_tab(
@@ -64,12 +121,13 @@ pub fn switch_filter_tab(
 pub fn spawn_filter_fetch(
     app_state: Arc<TokioMutex<TrendingAppState>>,
     filter: EventFilter,
+    sort: EventSortBy,
     limit: usize,
 ) {
     let gamma_client = GammaClient::new();
 
     tokio::spawn(async move {
-        match fetch_events_for_filter(&gamma_client, filter, limit).await {
+        match fetch_events_for_filter(&gamma_client, filter, sort, limit).await {
             Ok(new_events) => {
                 log_info!(
                     "Fetched {} events for {:?} filter",
@@ -77,6 +135,11 @@ pub fn spawn_filter_fetch(
                     filter
                 );
                 let mut app = app_state.lock().await;
+                // The sort was changed again while this page was loading
+                if app.event_sort_by != sort {
+                    app.pagination.is_fetching_more = false;
+                    return;
+                }
                 // Cache events in global event cache
                 app.cache_events(&new_events);
                 app.events_cache.insert(filter, new_events.clone());
@@ -89,6 +152,7 @@ pub fn spawn_filter_fetch(
                 log_error!("Failed to fetch events: {}", e);
                 let mut app = app_state.lock().await;
                 app.pagination.is_fetching_more = false;
+                app.flash(fetch_error_message("Failed to load events", &e));
             },
         }
     });
@@ -98,72 +162,100 @@ pub fn spawn_filter_fetch(
 pub async fn fetch_events_for_filter(
     gamma_client: &GammaClient,
     filter: EventFilter,
+    sort: EventSortBy,
     limit: usize,
-) -> crate::Result<Vec<polymarket_api::gamma::Event>> {
+) -> polymarket_api::Result<Vec<Event>> {
     match filter {
         EventFilter::Breaking => {
             // Breaking = markets that moved the most in the last 24 hours
-            gamma_client
-                .get_breaking_events(Some(limit))
-                .await
-                .map_err(|e| anyhow::anyhow!("{}", e))
+            gamma_client.get_breaking_events(Some(limit)).await
         },
         _ => {
-            // Trending and New use the events endpoint with different ordering
+            // The Events tab is ordered by the API with the picked sort option
             gamma_client
-                .get_trending_events(Some(filter.order_by()), Some(false), Some(limit))
+                .get_trending_events(
+                    Some(sort.api_order_param()),
+                    Some(sort.is_ascending()),
+                    Some(limit),
+                )
                 .await
-                .map_err(|e| anyhow::anyhow!("{}", e))
         },
     }
 }
 
-/// Spawn async task to fetch API status and update app state
-pub fn spawn_fetch_api_status(app_state: Arc<TokioMutex<TrendingAppState>>) {
+/// Footer message for a request the user is waiting on, e.g. "Failed to load events: ..."
+///
+/// Rate limits and missing items get their own wording instead of the raw error.
+pub fn fetch_error_message(action: &str, e: &PolymarketError) -> String {
+    if e.is_rate_limited() {
+        format!("{}: rate limited, try again shortly", action)
+    } else if e.is_not_found() {
+        format!("{}: not found", action)
+    } else {
+        format!("{}: {}", action, e)
+    }
+}
+
+/// Spawn a background task that pings the Gamma and Data APIs every
+/// `API_HEALTH_INTERVAL` and updates the header status dots.
+/// Each ping gives up after `API_HEALTH_TIMEOUT` so a hung endpoint shows red promptly.
+pub fn spawn_health_check(app_state: Arc<TokioMutex<TrendingAppState>>) {
     use polymarket_api::DataClient;
 
     let gamma_client = GammaClient::new();
     let data_client = DataClient::new();
 
-    // Check Gamma API
-    let app_state_gamma = Arc::clone(&app_state);
     tokio::spawn(async move {
-        match gamma_client.get_status().await {
-            Ok(status) => {
-                let is_healthy = status == "OK" || status == "ok";
-                log_info!("Gamma API status: {} (healthy={})", status, is_healthy);
-                let mut app = app_state_gamma.lock().await;
-                app.gamma_api_status = Some(is_healthy);
-            },
-            Err(e) => {
-                log_error!("Gamma API status check failed: {}", e);
-                let mut app = app_state_gamma.lock().await;
-                app.gamma_api_status = Some(false);
-            },
-        }
-    });
+        let mut interval = tokio::time::interval(API_HEALTH_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            let (gamma, data) = tokio::join!(
+                tokio::time::timeout(API_HEALTH_TIMEOUT, gamma_client.get_status()),
+                tokio::time::timeout(API_HEALTH_TIMEOUT, data_client.get_status()),
+            );
 
-    // Check Data API
-    tokio::spawn(async move {
-        match data_client.get_status().await {
-            Ok(status) => {
-                let is_healthy = status.data == "OK" || status.data == "ok";
-                log_info!("Data API status: {} (healthy={})", status.data, is_healthy);
-                let mut app = app_state.lock().await;
-                app.data_api_status = Some(is_healthy);
-            },
-            Err(e) => {
-                log_error!("Data API status check failed: {}", e);
-                let mut app = app_state.lock().await;
-                app.data_api_status = Some(false);
-            },
+            let gamma_healthy = match gamma {
+                Ok(Ok(status)) => {
+                    let is_healthy = status == "OK" || status == "ok";
+                    log_debug!("Gamma API status: {} (healthy={})", status, is_healthy);
+                    is_healthy
+                },
+                Ok(Err(_e)) => {
+                    log_error!("Gamma API status check failed: {}", _e);
+                    false
+                },
+                Err(_) => {
+                    log_warn!("Gamma API status check timed out");
+                    false
+                },
+            };
+            let data_healthy = match data {
+                Ok(Ok(status)) => {
+                    let is_healthy = status.data == "OK" || status.data == "ok";
+                    log_debug!("Data API status: {} (healthy={})", status.data, is_healthy);
+                    is_healthy
+                },
+                Ok(Err(_e)) => {
+                    log_error!("Data API status check failed: {}", _e);
+                    false
+                },
+                Err(_) => {
+                    log_warn!("Data API status check timed out");
+                    false
+                },
+            };
+
+            let mut app = app_state.lock().await;
+            app.gamma_api_status = Some(gamma_healthy);
+            app.data_api_status = Some(data_healthy);
         }
     });
 }
 
 /// Spawn async task to fetch user profile by address and update auth state
 pub fn spawn_fetch_user_profile(app_state: Arc<TokioMutex<TrendingAppState>>, address: String) {
-    let gamma_client = GammaClient::new();
+    let gamma_client = crate::cache::gamma_client();
 
     tokio::spawn(async move {
         match gamma_client.get_public_profile(&address).await {
@@ -192,8 +284,9 @@ pub fn spawn_fetch_user_profile(app_state: Arc<TokioMutex<TrendingAppState>>, ad
                     profile_image: profile.profile_image,
                 });
 
-                // Also update saved auth config with username
-                if let Some(mut config) = crate::auth::AuthConfig::load() {
+                // Also update saved auth config with username (environment
+                // credentials are never written to disk)
+                if let Some(mut config) = crate::auth::AuthConfig::load_saved() {
                     config.username = username;
                     let _ = config.save();
                 }
@@ -208,36 +301,210 @@ pub fn spawn_fetch_user_profile(app_state: Arc<TokioMutex<TrendingAppState>>, ad
     });
 }
 
+/// CLOB client for the logged-in account (see [`TrendingAppState::clob_client`])
+async fn authenticated_clob_client(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+) -> Result<ClobClient, String> {
+    app_state.lock().await.clob_client()
+}
+
+/// Spawn async task to sign and post an order from the Trade popup
+///
+/// On success the popup closes and the order ID is logged; on failure the error is
+/// shown in the form.
+pub fn spawn_submit_order(app_state: Arc<TokioMutex<TrendingAppState>>, order: OrderRequest) {
+    tokio::spawn(async move {
+        let result = match authenticated_clob_client(&app_state).await {
+            Ok(client) if client.can_trade() => client
+                .submit_order(
+                    &order.token_id,
+                    order.side.api_side(),
+                    order.price,
+                    order.size,
+                    order.time_in_force,
+                )
+                .await
+                .map_err(|e| e.to_string()),
+            Ok(_) => Err("Add private_key to auth.json to trade".to_string()),
+            Err(e) => Err(e),
+        };
+
+        let mut app = app_state.lock().await;
+        match result {
+            Ok(response) => {
+                let order_id = response.order_id.unwrap_or_default();
+                log_info!(
+                    "{} placed: {} {:.2} {} at {:.1}¢ ({}) - ID {}",
+                    if order.away_from_market {
+                        "Limit order"
+                    } else {
+                        "Order"
+                    },
+                    order.side.label(),
+                    order.size,
+                    order.outcome,
+                    order.price * 100.0,
+                    order.time_in_force.as_str(),
+                    order_id
+                );
+                if let Some(form) = app.trade_form.as_mut() {
+                    form.is_submitting = false;
+                    form.error_message = Some(format!("Order placed: {}", order_id));
+                }
+                if app.popup == Some(state::PopupType::Trade) {
+                    app.close_popup();
+                }
+                // Refresh the balance and positions the order changed
+                if let Some(address) = app.auth_state.address.clone() {
+                    drop(app);
+                    spawn_fetch_portfolio(Arc::clone(&app_state), address);
+                }
+            },
+            Err(e) => {
+                log_error!("Order failed: {}", e);
+                if let Some(form) = app.trade_form.as_mut() {
+                    form.is_submitting = false;
+                    form.error_message = Some(e);
+                }
+            },
+        }
+    });
+}
+
+/// Spawn async task to load the user's resting orders into the "My Orders" popup
+pub fn spawn_fetch_open_orders(app_state: Arc<TokioMutex<TrendingAppState>>) {
+    tokio::spawn(async move {
+        app_state.lock().await.open_orders.is_loading = true;
+        let result = match authenticated_clob_client(&app_state).await {
+            Ok(client) if client.has_auth() => client
+                .get_open_orders(None)
+                .await
+                .map_err(|e| e.to_string()),
+            Ok(_) => Err("Login required to view orders".to_string()),
+            Err(e) => Err(e),
+        };
+
+        let mut app = app_state.lock().await;
+        match result {
+            Ok(orders) => {
+                log_info!("Fetched {} open orders", orders.len());
+                app.open_orders.set_orders(orders);
+            },
+            Err(e) => {
+                log_error!("Failed to fetch open orders: {}", e);
+                app.open_orders.is_loading = false;
+                app.open_orders.error_message = Some(e);
+            },
+        }
+    });
+}
+
+/// Spawn async task to load the top holders of a market into the "Top Holders" popup
+pub fn spawn_fetch_holders(app_state: Arc<TokioMutex<TrendingAppState>>, condition_id: String) {
+    use polymarket_api::DataClient;
+
+    tokio::spawn(async move {
+        let result = DataClient::new()
+            .get_holders(&condition_id, state::HOLDERS_LIMIT)
+            .await;
+
+        let mut app = app_state.lock().await;
+        // Ignore results for a market the popup no longer shows
+        if app.holders.condition_id.as_deref() != Some(condition_id.as_str()) {
+            return;
+        }
+        match result {
+            Ok(holders) => {
+                log_info!("Fetched {} holders for {}", holders.len(), condition_id);
+                app.holders.set_holders(holders);
+            },
+            Err(e) => {
+                log_error!("Failed to fetch holders for {}: {}", condition_id, e);
+                app.holders.is_loading = false;
+                app.holders.error_message = Some(e.to_string());
+            },
+        }
+    });
+}
+
+/// Spawn async task to cancel a resting order, then refresh the order list
+pub fn spawn_cancel_order(app_state: Arc<TokioMutex<TrendingAppState>>, order_id: String) {
+    tokio::spawn(async move {
+        let result = match authenticated_clob_client(&app_state).await {
+            Ok(client) => client
+                .cancel_order(&order_id)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e),
+        };
+
+        let mut app = app_state.lock().await;
+        app.open_orders.cancelling = None;
+        match result {
+            Ok(response) if response.canceled.contains(&order_id) => {
+                log_info!("Cancelled order {}", order_id);
+            },
+            Ok(response) => {
+                let reason = response
+                    .not_canceled
+                    .get(&order_id)
+                    .map(|reason| reason.to_string())
+                    .unwrap_or_else(|| "not cancelled".to_string());
+                log_warn!("Order {} was not cancelled: {}", order_id, reason);
+                app.open_orders.error_message = Some(format!("Not cancelled: {}", reason));
+            },
+            Err(e) => {
+                log_error!("Failed to cancel order {}: {}", order_id, e);
+                app.open_orders.error_message = Some(e);
+            },
+        }
+        drop(app);
+        spawn_fetch_open_orders(app_state);
+    });
+}
+
+/// USDC balance of the account `clob_client` is authenticated as
+pub async fn fetch_usdc_balance(clob_client: &ClobClient) -> Result<f64, PolymarketError> {
+    let balance_info = clob_client
+        .get_balance_allowance(polymarket_api::clob::AssetType::Collateral)
+        .await?;
+    // Balance is in smallest units (6 decimals for USDC)
+    Ok(balance_info
+        .balance
+        .parse()
+        .map(|b: f64| b / 1_000_000.0)
+        .unwrap_or(0.0))
+}
+
 /// Spawn async task to fetch user's portfolio data (balance, positions)
 pub fn spawn_fetch_portfolio(app_state: Arc<TokioMutex<TrendingAppState>>, address: String) {
-    use polymarket_api::{DataClient, clob::AssetType};
+    use polymarket_api::DataClient;
 
     tokio::spawn(async move {
-        let clob_client = ClobClient::from_env();
         let data_client = DataClient::new();
 
         // Fetch USDC balance
-        if clob_client.has_auth() {
-            match clob_client
-                .get_balance_allowance(AssetType::Collateral)
-                .await
-            {
-                Ok(balance_info) => {
-                    // Balance is in smallest units (6 decimals for USDC)
-                    let balance: f64 = balance_info
-                        .balance
-                        .parse()
-                        .map(|b: f64| b / 1_000_000.0)
-                        .unwrap_or(0.0);
-                    log_info!("Fetched balance: ${:.2} USDC", balance);
+        match authenticated_clob_client(&app_state).await {
+            Ok(clob_client) if clob_client.has_auth() => {
+                match fetch_usdc_balance(&clob_client).await {
+                    Ok(balance) => {
+                        log_info!("Fetched balance: ${:.2} USDC", balance);
 
-                    let mut app = app_state.lock().await;
-                    app.auth_state.balance = Some(balance);
-                },
-                Err(e) => {
-                    log_debug!("Failed to fetch balance: {}", e);
-                },
-            }
+                        let mut app = app_state.lock().await;
+                        // Ignore a balance for an account switched away from meanwhile
+                        if app.auth_state.address.as_deref() == Some(address.as_str()) {
+                            app.auth_state.balance = Some(balance);
+                        }
+                    },
+                    Err(e) => {
+                        log_debug!("Failed to fetch balance: {}", e);
+                    },
+                }
+            },
+            Ok(_) => {},
+            Err(e) => {
+                log_error!("Failed to fetch balance: {}", e);
+            },
         }
 
         // Fetch positions
@@ -253,6 +520,8 @@ pub fn spawn_fetch_portfolio(app_state: Arc<TokioMutex<TrendingAppState>>, addre
                 // Sum up realized P&L from all positions
                 let realized_pnl: f64 = positions.iter().filter_map(|p| p.realized_pnl).sum();
 
+                let cost_basis: f64 = positions.iter().filter_map(|p| p.initial_value).sum();
+
                 log_info!(
                     "Fetched portfolio: {} positions, ${:.2} value, unrealized P&L: ${:.2}, realized P&L: ${:.2}",
                     positions_count,
@@ -261,11 +530,19 @@ pub fn spawn_fetch_portfolio(app_state: Arc<TokioMutex<TrendingAppState>>, addre
                     realized_pnl
                 );
 
+                let position_sizes = positions
+                    .iter()
+                    .filter_map(|p| p.size.map(|size| (p.asset.clone(), size)))
+                    .collect();
+
                 let mut app = app_state.lock().await;
+                app.auth_state.position_sizes = position_sizes;
                 app.auth_state.portfolio_value = Some(total_value);
                 app.auth_state.positions_count = Some(positions_count);
                 app.auth_state.unrealized_pnl = Some(unrealized_pnl);
                 app.auth_state.realized_pnl = Some(realized_pnl);
+                app.auth_state.cost_basis = Some(cost_basis);
+                app.auth_state.positions = positions;
             },
             Err(e) => {
                 log_debug!("Failed to fetch positions: {}", e);
@@ -274,6 +551,154 @@ pub fn spawn_fetch_portfolio(app_state: Arc<TokioMutex<TrendingAppState>>, addre
     });
 }
 
+/// Start watching live trades for an event over RTDS
+pub fn spawn_watch_event(
+    app_state: Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+    event_slug: String,
+) {
+    use polymarket_api::{RTDSClient, rtds::RTDSEvent};
+
+    // Ensure the event_trades entry exists before starting websocket
+    app.trades
+        .event_trades
+        .entry(event_slug.clone())
+        .or_insert_with(EventTrades::new);
+
+    let event_slug_for_closure = event_slug.clone();
+
+    let rtds_client = RTDSClient::new()
+        .with_event_slug(event_slug.clone())
+        .with_reconnect(RTDS_MAX_RECONNECTS, RTDS_RECONNECT_DELAY);
+    let _event_slug_for_log = event_slug.clone();
+
+    log_info!("Starting RTDS WebSocket for event: {}", event_slug);
+
+    let ws_handle = tokio::spawn(async move {
+        let callback_state = Arc::clone(&app_state);
+        let result = rtds_client
+            .connect_and_listen_events(move |event| {
+                let app_state = Arc::clone(&callback_state);
+                let event_slug = event_slug_for_closure.clone();
+                let msg = match event {
+                    RTDSEvent::Message(msg) => *msg,
+                    RTDSEvent::Connected | RTDSEvent::Heartbeat => {
+                        tokio::spawn(async move {
+                            let mut app = app_state.lock().await;
+                            app.trades
+                                .record_rtds_message(&event_slug, std::time::Instant::now());
+                        });
+                        return;
+                    },
+                    RTDSEvent::Reconnecting { attempt, delay } => {
+                        log_warn!(
+                            "Live trades for {} disconnected, reconnecting in {:.1}s (attempt {}/{})",
+                            event_slug,
+                            delay.as_secs_f64(),
+                            attempt,
+                            RTDS_MAX_RECONNECTS
+                        );
+                        tokio::spawn(async move {
+                            let mut app = app_state.lock().await;
+                            app.trades
+                                .set_rtds_status(&event_slug, RtdsStatus::Connecting);
+                        });
+                        return;
+                    },
+                };
+
+                log_info!("Received RTDS trade for event: {}", event_slug);
+
+                tokio::spawn(async move {
+                    let mut app = app_state.lock().await;
+                    app.trades
+                        .record_rtds_message(&event_slug, std::time::Instant::now());
+                    if app.add_trade(&event_slug, &msg) {
+                        log_info!("Trade added to event_trades for: {}", event_slug);
+                    } else {
+                        log_warn!("No event_trades entry found for: {}", event_slug);
+                    }
+                });
+            })
+            .await;
+        match result {
+            Ok(()) => {
+                log_info!(
+                    "RTDS WebSocket connection closed for event: {}",
+                    _event_slug_for_log
+                );
+            },
+            Err(_e) => {
+                log_error!(
+                    "RTDS WebSocket error for event {}: {}",
+                    _event_slug_for_log,
+                    _e
+                );
+            },
+        }
+        // Still watching, but the retries ran out
+        let mut app = app_state.lock().await;
+        app.trades
+            .set_rtds_status(&_event_slug_for_log, RtdsStatus::Disconnected);
+    });
+
+    for _evicted in app.start_watching(event_slug, ws_handle) {
+        log_warn!(
+            "Stopped watching {} (least recently active, over the {} watched events limit)",
+            _evicted,
+            app.trades.max_watched_events
+        );
+    }
+}
+
+/// Spawn async task to check held positions in the given events for resolved markets
+///
+/// Announces the realized P&L of every position whose market resolved since the
+/// previous check.
+pub fn spawn_check_resolutions(
+    app_state: Arc<TokioMutex<TrendingAppState>>,
+    address: String,
+    event_slugs: Vec<String>,
+) {
+    use polymarket_api::DataClient;
+
+    tokio::spawn(async move {
+        let positions = match DataClient::new().get_positions(&address).await {
+            Ok(positions) => positions,
+            Err(e) => {
+                log_debug!("Failed to fetch positions for resolution check: {}", e);
+                return;
+            },
+        };
+        let held: Vec<_> = positions
+            .into_iter()
+            .filter(|p| event_slugs.contains(&p.event_slug))
+            .collect();
+        if held.is_empty() {
+            return;
+        }
+
+        let gamma_client = GammaClient::new();
+        let mut events = Vec::new();
+        for slug in held.iter().map(|p| &p.event_slug) {
+            if events.iter().any(|e: &Event| &e.slug == slug) {
+                continue;
+            }
+            match gamma_client.get_event_by_slug(slug).await {
+                Ok(Some(event)) => events.push(event),
+                Ok(None) => {},
+                Err(e) => log_debug!("Failed to refresh event {}: {}", slug, e),
+            }
+        }
+
+        let mut app = app_state.lock().await;
+        app.cache_events(&events);
+        for notice in app.resolutions.observe(&held, &events) {
+            log_info!("{}", notice.message());
+        }
+    });
+}
+
 /// Spawn async task to toggle favorite status for an event
 pub fn spawn_toggle_favorite(
     app_state: Arc<TokioMutex<TrendingAppState>>,
@@ -295,6 +720,12 @@ pub fn spawn_toggle_favorite(
 
         // Check if session cookie is available
         if auth_config.session_cookie.is_none() {
+            // Fall back to locally bookmarked events (e.g. from import-state)
+            let local_favorites = crate::settings::Settings::load().favorites;
+            if !local_favorites.is_empty() {
+                load_local_favorites(&app_state, local_favorites).await;
+                return;
+            }
             log_error!("Session cookie required for favorites");
             return;
         }
@@ -386,9 +817,14 @@ pub fn spawn_fetch_and_toggle_favorite(
             },
             Ok(None) => {
                 log_error!("Event not found: {}", event_slug);
+                app_state.lock().await.flash("Event not found");
             },
             Err(e) => {
                 log_error!("Failed to fetch event {}: {}", event_slug, e);
+                app_state
+                    .lock()
+                    .await
+                    .flash(fetch_error_message("Failed to bookmark", &e));
             },
         }
     });
@@ -425,6 +861,196 @@ pub fn spawn_fetch_event_for_cache(
     });
 }
 
+/// Spawn async task to list the events sharing a tag with `event` in the events list
+pub fn spawn_fetch_related_events(app_state: Arc<TokioMutex<TrendingAppState>>, event: Event) {
+    let gamma_client = GammaClient::new();
+
+    tokio::spawn(async move {
+        log_info!("Fetching events related to: {}", event.slug);
+
+        match gamma_client.get_related_events(&event, Some(50)).await {
+            Ok(related) if related.is_empty() => {
+                log_warn!("No related events found for: {}", event.slug);
+                app_state.lock().await.flash("No related events");
+            },
+            Ok(related) => {
+                log_info!("Showing {} events related to {}", related.len(), event.slug);
+                let mut app = app_state.lock().await;
+                app.set_related_events(related, &event.title);
+            },
+            Err(e) => {
+                log_error!("Failed to fetch events related to {}: {}", event.slug, e);
+                app_state
+                    .lock()
+                    .await
+                    .flash(fetch_error_message("Failed to load related events", &e));
+            },
+        }
+    });
+}
+
+/// Spawn async task to fetch the probability history of an event's leading outcome
+/// Failures are retried after `HISTORY_RETRY_AFTER` rather than on every frame
+pub fn spawn_fetch_probability_history(
+    app_state: Arc<TokioMutex<TrendingAppState>>,
+    slug: String,
+    token_id: String,
+    outcome: String,
+) {
+    tokio::spawn(async move {
+        let clob_client = app_state.lock().await.rate_limited(ClobClient::new());
+        let start_ts = chrono::Utc::now().timestamp() - state::HISTORY_DAYS * 24 * 60 * 60;
+        // 6-hour resolution: ~120 points over 30 days
+        let points: Vec<f64> = match clob_client
+            .get_prices_history(&token_id, Some(start_ts), None, None, Some(360))
+            .await
+        {
+            Ok(response) => response.history.into_iter().map(|p| p.p).collect(),
+            Err(e) => {
+                log_warn!("Failed to fetch price history for {}: {}", slug, e);
+                let mut app = app_state.lock().await;
+                app.history.record_failure(slug, std::time::Instant::now());
+                return;
+            },
+        };
+        log_debug!("Price history for {}: {} points", slug, points.len());
+
+        let mut app = app_state.lock().await;
+        app.history
+            .insert(slug, state::ProbabilityHistory { outcome, points });
+    });
+}
+
+/// Spawn async task to fetch the 24h high/low of each token, one after another
+/// Failures are cached as missing stats until the entry expires
+pub fn spawn_fetch_price_stats(
+    app_state: Arc<TokioMutex<TrendingAppState>>,
+    token_ids: Vec<String>,
+) {
+    tokio::spawn(async move {
+        let clob_client = app_state.lock().await.rate_limited(ClobClient::new());
+        for token_id in token_ids {
+            let stats = match clob_client.get_price_stats_24h(&token_id).await {
+                Ok(stats) => stats,
+                Err(e) => {
+                    log_warn!("Failed to fetch 24h price stats for {}: {}", token_id, e);
+                    None
+                },
+            };
+            let mut app = app_state.lock().await;
+            app.price_stats
+                .insert(token_id, stats, std::time::Instant::now());
+        }
+    });
+}
+
+/// Spawn async task to fetch the orderbook backing the Trade popup's fill estimates
+/// The book is only stored if the form still targets the same token when it arrives
+pub fn spawn_fetch_trade_orderbook(app_state: Arc<TokioMutex<TrendingAppState>>, token_id: String) {
+    tokio::spawn(async move {
+        let clob_client = app_state.lock().await.rate_limited(ClobClient::new());
+        match clob_client.get_orderbook_by_asset(&token_id).await {
+            Ok(orderbook) => {
+                let mut app = app_state.lock().await;
+                if let Some(ref mut form) = app.trade_form
+                    && form.token_id() == Some(token_id.as_str())
+                {
+                    log_debug!(
+                        "Trade orderbook for {}: {} bids, {} asks",
+                        token_id,
+                        orderbook.bids.len(),
+                        orderbook.asks.len()
+                    );
+                    form.orderbook = Some(orderbook);
+                }
+            },
+            Err(e) => {
+                log_warn!("Failed to fetch trade orderbook for {}: {}", token_id, e);
+            },
+        }
+    });
+}
+
+/// Spawn a batch price fetch, dropping the result if a newer request was started
+pub fn spawn_fetch_market_prices(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+    active_markets: Vec<Vec<String>>,
+) {
+    let generation = app.next_price_request();
+    let clob_client = app.price_client();
+    let app_state = Arc::clone(app_state);
+
+    tokio::spawn(async move {
+        let prices = fetch_market_prices_batch(&clob_client, active_markets).await;
+        let mut app = app_state.lock().await;
+        if app.apply_market_prices(generation, prices) {
+            log_debug!("Market prices updated (request {})", generation);
+        } else {
+            log_debug!("Discarded stale market prices (request {})", generation);
+        }
+    });
+}
+
+/// Fetch prices, trade count and orderbook for the selected event
+/// Called once the selection has settled (see `KeyState::selection_debounce`).
+pub fn spawn_fetch_selected_event(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+) {
+    let Some(event) = app.selected_event().cloned() else {
+        return;
+    };
+    // Nothing to price, count or show a book for
+    if !event.has_tradeable_markets() {
+        log_debug!("Event {} has no tradeable markets", event.slug);
+        return;
+    }
+
+    // Only fetch prices for active (non-closed) markets
+    let active_markets: Vec<_> = event
+        .markets
+        .iter()
+        .filter(|m| !m.closed)
+        .filter_map(|m| m.clob_token_ids.clone())
+        .filter(|ids| !ids.is_empty())
+        .collect();
+    if !active_markets.is_empty() {
+        spawn_fetch_market_prices(app_state, app, active_markets);
+    }
+
+    // Fetch trade counts if authenticated and not already fetched
+    if app.has_clob_auth && !app.event_trade_counts.contains_key(&event.slug) {
+        // Market ID is the condition_id used by CLOB API
+        let condition_ids: Vec<String> =
+            event.markets.iter().filter_map(|m| m.id.clone()).collect();
+        if !condition_ids.is_empty()
+            && let Ok(clob_client) = app.clob_client()
+        {
+            let app_state = Arc::clone(app_state);
+            let slug = event.slug.clone();
+
+            tokio::spawn(async move {
+                if let Some(count) = fetch_event_trade_count(&clob_client, condition_ids).await {
+                    let mut app = app_state.lock().await;
+                    app.event_trade_counts.insert(slug, count);
+                    log_info!("Fetched trade count: {} trades", count);
+                }
+            });
+        }
+    }
+
+    // Fetch orderbook for the first market's first outcome (Yes)
+    // Use sorted markets (non-closed first)
+    let mut sorted: Vec<_> = event.markets.iter().collect();
+    sorted.sort_by_key(|m| m.closed);
+    if let Some(market) = sorted.first()
+        && let Some(token_id) = market.clob_token_ids.as_ref().and_then(|ids| ids.first())
+    {
+        spawn_fetch_orderbook(Arc::clone(app_state), token_id.clone(), !market.closed);
+    }
+}
+
 /// Spawn async task to fetch orderbook data for a specific token ID
 /// Only fetches if market_is_active is true (closed markets don't need orderbook)
 pub fn spawn_fetch_orderbook(
@@ -437,16 +1063,15 @@ pub fn spawn_fetch_orderbook(
         return;
     }
 
-    let clob_client = ClobClient::new();
-
     tokio::spawn(async move {
         log_info!("Fetching orderbook for token: {}", token_id);
 
         // Set loading state
-        {
+        let clob_client = {
             let mut app = app_state.lock().await;
             app.orderbook_state.is_loading = true;
-        }
+            app.rate_limited(ClobClient::new())
+        };
 
         match clob_client.get_orderbook_by_asset(&token_id).await {
             Ok(orderbook) => {
@@ -464,98 +1089,58 @@ pub fn spawn_fetch_orderbook(
                     log_info!("Raw first ask: {} @ {}", ask.size, ask.price);
                 }
 
-                // Convert CLOB API Orderbook to our OrderbookData
-                // First, parse and sort the levels:
-                // - Bids: sorted descending by price (highest/best bid first)
-                // - Asks: sorted ascending by price (lowest/best ask first)
-                let mut bids: Vec<OrderbookLevel> = orderbook
-                    .bids
-                    .iter()
-                    .map(|level| {
-                        let price = level.price.parse::<f64>().unwrap_or(0.0);
-                        let size = level.size.parse::<f64>().unwrap_or(0.0);
-                        OrderbookLevel {
-                            price,
-                            size,
-                            total: 0.0, // Will calculate cumulative after sorting
-                        }
-                    })
-                    .collect();
-                // Sort bids descending by price (best bid = highest price first)
-                bids.sort_by(|a, b| {
-                    b.price
-                        .partial_cmp(&a.price)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-                // Calculate cumulative totals after sorting
-                let mut cumulative_total = 0.0;
-                for bid in &mut bids {
-                    cumulative_total += bid.price * bid.size;
-                    bid.total = cumulative_total;
-                }
-
-                let mut asks: Vec<OrderbookLevel> = orderbook
-                    .asks
-                    .iter()
-                    .map(|level| {
-                        let price = level.price.parse::<f64>().unwrap_or(0.0);
-                        let size = level.size.parse::<f64>().unwrap_or(0.0);
-                        OrderbookLevel {
-                            price,
-                            size,
-                            total: 0.0, // Will calculate cumulative after sorting
-                        }
-                    })
-                    .collect();
-                // Sort asks ascending by price (best ask = lowest price first)
-                asks.sort_by(|a, b| {
-                    a.price
-                        .partial_cmp(&b.price)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-                // Calculate cumulative totals after sorting
-                let mut cumulative_total = 0.0;
-                for ask in &mut asks {
-                    cumulative_total += ask.price * ask.size;
-                    ask.total = cumulative_total;
-                }
-
-                // Calculate spread
-                let spread = if let (Some(best_bid), Some(best_ask)) = (bids.first(), asks.first())
-                {
-                    Some(best_ask.price - best_bid.price)
-                } else {
-                    None
-                };
-
-                let orderbook_data = state::OrderbookData {
-                    bids,
-                    asks,
-                    spread,
-                    last_price: None,
-                };
-
-                // Calculate height based on data (up to 6 per side)
-                let asks_count = orderbook_data.asks.len().min(6);
-                let bids_count = orderbook_data.bids.len().min(6);
-                let new_height = (2 + 1 + asks_count + 1 + bids_count) as u16; // borders + header + asks + spread + bids
-
                 let mut app = app_state.lock().await;
-                app.orderbook_state.orderbook = Some(orderbook_data);
-                app.orderbook_state.is_loading = false;
-                app.orderbook_state.last_fetch = Some(std::time::Instant::now());
-                app.orderbook_state.token_id = Some(token_id);
-                app.orderbook_state.last_height = new_height.max(5); // min height of 5
+                app.orderbook_state.set_book(token_id.clone(), orderbook);
+                // Deltas keep the snapshot live; polling resumes if the feed drops
+                if !app.orderbook_state.has_live_feed(&token_id) && Fixtures::from_env().is_none() {
+                    let feed = spawn_orderbook_feed(Arc::clone(&app_state), token_id.clone());
+                    app.orderbook_state.start_live_feed(token_id, feed);
+                }
+            },
+            Err(e) if e.is_not_found() => {
+                // Markets that stopped trading have no book
+                log_debug!("No orderbook for {}", token_id);
+                app_state.lock().await.orderbook_state.is_loading = false;
             },
             Err(e) => {
                 log_error!("Failed to fetch orderbook for {}: {}", token_id, e);
                 let mut app = app_state.lock().await;
                 app.orderbook_state.is_loading = false;
+                if e.is_rate_limited() {
+                    app.flash(fetch_error_message("Failed to load orderbook", &e));
+                }
             },
         }
     });
 }
 
+/// Subscribe a token to the CLOB WebSocket and apply its book deltas to the panel
+fn spawn_orderbook_feed(
+    app_state: Arc<TokioMutex<TrendingAppState>>,
+    token_id: String,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        log_info!("Starting orderbook feed for token: {}", token_id);
+        // A channel keeps the deltas in order while the callback can't await the lock
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<WebSocketMessage>();
+        let mut ws = PolymarketWebSocket::new(vec![token_id.clone()]);
+        let listen = ws.connect_and_listen(move |msg| {
+            let _ = tx.send(msg);
+        });
+        let apply = async {
+            while let Some(msg) = rx.recv().await {
+                let mut app = app_state.lock().await;
+                app.orderbook_state.apply_live_update(&token_id, &msg);
+            }
+        };
+        let (result, ()) = tokio::join!(listen, apply);
+        match result {
+            Ok(()) => log_info!("Orderbook feed closed for token: {}", token_id),
+            Err(_e) => log_warn!("Orderbook feed for {} failed: {}", token_id, _e),
+        }
+    })
+}
+
 /// Fetch trade count for an event's markets using authenticated CLOB API
 /// Returns total number of trades across all markets in the event
 pub async fn fetch_event_trade_count(
@@ -615,13 +1200,8 @@ pub async fn fetch_market_prices_batch(
             let mut prices = HashMap::new();
             for orderbook in orderbooks {
                 if let Some(asset_id) = &orderbook.asset_id {
-                    // Find the best (lowest) ask price
-                    let best_price = orderbook
-                        .asks
-                        .iter()
-                        .filter_map(|ask| ask.price.parse::<f64>().ok())
-                        .min_by(|a, b| a.partial_cmp(b).unwrap());
-                    if let Some(price) = best_price {
+                    // Best (lowest) ask, skipping zero-size and malformed levels
+                    if let Some(price) = orderbook.summary(0.0, None).best_ask {
                         prices.insert(asset_id.clone(), price);
                     }
                 }
@@ -641,15 +1221,10 @@ pub async fn fetch_market_prices_batch(
             // Fallback to individual calls if batch fails
             let mut prices = HashMap::new();
             for asset_id in all_asset_ids {
-                if let Ok(orderbook) = clob_client.get_orderbook_by_asset(&asset_id).await {
-                    let best_price = orderbook
-                        .asks
-                        .iter()
-                        .filter_map(|ask| ask.price.parse::<f64>().ok())
-                        .min_by(|a, b| a.partial_cmp(b).unwrap());
-                    if let Some(price) = best_price {
-                        prices.insert(asset_id.clone(), price);
-                    }
+                if let Ok(orderbook) = clob_client.get_orderbook_by_asset(&asset_id).await
+                    && let Some(price) = orderbook.summary(0.0, None).best_ask
+                {
+                    prices.insert(asset_id.clone(), price);
                 }
             }
             prices
@@ -662,6 +1237,8 @@ pub async fn fetch_yield_opportunities(
     min_prob: f64,
     limit: usize,
     min_volume: f64,
+    min_liquidity: f64,
+    max_days_to_resolution: Option<u32>,
 ) -> Vec<YieldOpportunity> {
     let gamma_client = GammaClient::new();
 
@@ -679,85 +1256,48 @@ pub async fn fetch_yield_opportunities(
 
     log_info!("Fetched {} markets, filtering for yield...", markets.len());
 
-    let mut opportunities: Vec<YieldOpportunity> = Vec::new();
-
-    for market in &markets {
-        // Skip closed markets
-        if market.closed {
-            continue;
-        }
-
-        // Skip markets without event info
-        let event = match market.event() {
-            Some(e) => e,
-            None => continue,
-        };
-
-        // Parse end date
-        let end_date = event
-            .end_date
-            .as_ref()
-            .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-            .map(|dt| dt.with_timezone(&Utc));
-
-        // Check volume threshold
-        let volume = market.volume_24hr.unwrap_or(0.0);
-        if volume < min_volume {
-            continue;
-        }
-
-        // Check each outcome price
-        for (i, price_str) in market.outcome_prices.iter().enumerate() {
-            if let Ok(price) = price_str.parse::<f64>()
-                && price >= min_prob
-                && price < 1.0
-            // Skip 100% price (no yield)
-            {
-                let outcome = market
-                    .outcomes
-                    .get(i)
-                    .cloned()
-                    .unwrap_or_else(|| format!("Outcome {}", i));
-                let est_return = (1.0 - price) * 100.0;
-
-                // Use short name if available
-                let market_name = market
-                    .group_item_title
-                    .as_ref()
-                    .filter(|s| !s.is_empty())
-                    .cloned()
-                    .unwrap_or_else(|| market.question.clone());
-
-                opportunities.push(YieldOpportunity {
-                    market_name,
-                    market_status: market.status(),
-                    outcome,
-                    price,
-                    est_return,
-                    volume,
-                    event_slug: event.slug.clone(),
-                    event_title: event.title.clone(),
-                    end_date,
-                });
-            }
-        }
-    }
-
-    // Sort by estimated return (highest first)
-    opportunities.sort_by(|a, b| b.est_return.partial_cmp(&a.est_return).unwrap());
+    let mut opportunities = find_yield_opportunities(&markets, min_prob, min_volume);
+    retain_tradeable_yields(
+        &mut opportunities,
+        min_liquidity,
+        max_days_to_resolution,
+        Utc::now(),
+    );
 
     log_info!("Found {} yield opportunities", opportunities.len());
     opportunities
 }
 
+/// Drop opportunities backed by less than `min_liquidity` or resolving more than
+/// `max_days_to_resolution` days after `now` (those without an end date are kept)
+pub fn retain_tradeable_yields(
+    opportunities: &mut Vec<YieldOpportunity>,
+    min_liquidity: f64,
+    max_days_to_resolution: Option<u32>,
+    now: chrono::DateTime<Utc>,
+) {
+    opportunities.retain(|opp| {
+        opp.liquidity >= min_liquidity
+            && max_days_to_resolution.is_none_or(|max_days| {
+                opp.days_remaining(now)
+                    .is_none_or(|days| days <= f64::from(max_days))
+            })
+    });
+}
+
 /// Spawn async task to fetch yield opportunities
 pub fn spawn_yield_fetch(app_state: Arc<TokioMutex<TrendingAppState>>) {
     let app_state_clone = Arc::clone(&app_state);
     tokio::spawn(async move {
-        let (min_prob, min_volume) = {
+        let (min_prob, min_volume, min_liquidity, max_days) = {
             let mut app = app_state.lock().await;
             app.yield_state.is_loading = true;
-            (app.yield_state.min_prob, app.yield_state.min_volume)
+            (
+                app.yield_state.min_prob,
+                app.yield_state.min_volume,
+                app.yield_state.min_liquidity,
+                app.yield_state.max_days_to_resolution,
+            )
         };
 
         log_info!(
@@ -765,7 +1305,8 @@ pub fn spawn_yield_fetch(app_state: Arc<TokioMutex<TrendingAppState>>) {
             min_prob * 100.0
         );
 
-        let opportunities = fetch_yield_opportunities(min_prob, 500, min_volume).await;
+        let opportunities =
+            fetch_yield_opportunities(min_prob, 500, min_volume, min_liquidity, max_days).await;
 
         let slug_to_fetch = {
             let mut app = app_state.lock().await;
@@ -850,32 +1391,29 @@ pub fn spawn_fetch_favorites(app_state: Arc<TokioMutex<TrendingAppState>>) {
                 log_error!("Failed to fetch favorites: {}", e);
                 let mut app = app_state.lock().await;
                 app.favorites_state.is_loading = false;
-                app.favorites_state.error_message = Some(format!("Failed to fetch: {}", e));
+                app.favorites_state.error_message =
+                    Some(fetch_error_message("Failed to fetch", &e));
                 return;
             },
         };
 
         log_info!("Found {} favorites", favorites.len());
 
-        // Fetch full event data for each favorite (the embedded events have empty markets)
-        let mut events = Vec::with_capacity(favorites.len());
-        for fav in &favorites {
-            match gamma_client.get_event_by_id(&fav.event_id).await {
-                Ok(Some(event)) => {
-                    log_info!(
-                        "Fetched event: {} with {} markets",
-                        event.title,
-                        event.markets.len()
-                    );
-                    events.push(event);
-                },
-                Ok(None) => {
-                    log_warn!("Event {} not found", fav.event_id);
-                },
-                Err(e) => {
-                    log_error!("Failed to fetch event {}: {}", fav.event_id, e);
-                },
-            }
+        // Fetch full event data for the favorites (the embedded events have empty markets)
+        let ids: Vec<String> = favorites.iter().map(|fav| fav.event_id.clone()).collect();
+        let events = match gamma_client.get_events_batch(&ids).await {
+            Ok(events) => events,
+            Err(e) => {
+                log_error!("Failed to fetch favorite events: {}", e);
+                let mut app = app_state.lock().await;
+                app.favorites_state.is_loading = false;
+                app.favorites_state.error_message =
+                    Some(fetch_error_message("Failed to fetch", &e));
+                return;
+            },
+        };
+        if events.len() < ids.len() {
+            log_warn!("{} favorite events not found", ids.len() - events.len());
         }
 
         log_info!("Loaded {} favorite events with full data", events.len());
@@ -897,6 +1435,29 @@ pub fn spawn_fetch_favorites(app_state: Arc<TokioMutex<TrendingAppState>>) {
     });
 }
 
+/// Load the Favorites tab from locally bookmarked event slugs
+async fn load_local_favorites(app_state: &Arc<TokioMutex<TrendingAppState>>, slugs: Vec<String>) {
+    log_info!("Loading {} local favorites...", slugs.len());
+
+    let gamma_client = GammaClient::new();
+    let mut events = Vec::with_capacity(slugs.len());
+    for slug in &slugs {
+        match gamma_client.get_event_by_slug(slug).await {
+            Ok(Some(event)) => events.push(event),
+            Ok(None) => log_warn!("Event {} not found", slug),
+            Err(e) => log_error!("Failed to fetch event {}: {}", slug, e),
+        }
+    }
+
+    let mut app = app_state.lock().await;
+    app.cache_events(&events);
+    app.favorites_state.favorite_event_slugs = events.iter().map(|e| e.slug.clone()).collect();
+    app.favorites_state.events = events;
+    app.favorites_state.is_loading = false;
+    app.favorites_state.selected_index = 0;
+    app.favorites_state.scroll = 0;
+}
+
 /// Spawn async task to search events and calculate yield for each
 pub fn spawn_yield_search(app_state: Arc<TokioMutex<TrendingAppState>>, query: String) {
     use polymarket_api::GammaClient;
@@ -928,70 +1489,9 @@ pub fn spawn_yield_search(app_state: Arc<TokioMutex<TrendingAppState>>, query: S
         // Convert events to YieldSearchResults with yield info
         let mut results: Vec<YieldSearchResult> = events
             .iter()
-            .map(|event| {
-                // Parse end date for filtering/sorting
-                let end_date = event
-                    .end_date
-                    .as_ref()
-                    .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-                    .map(|dt| dt.with_timezone(&Utc));
-
-                // Find best yield opportunity across all markets
-                let mut best_yield: Option<YieldOpportunity> = None;
-
-                for market in &event.markets {
-                    if market.closed {
-                        continue;
-                    }
-
-                    for (i, price_str) in market.outcome_prices.iter().enumerate() {
-                        if let Ok(price) = price_str.parse::<f64>() {
-                            // Only consider high-probability outcomes (>= min_prob)
-                            if price >= min_prob {
-                                let outcome = market
-                                    .outcomes
-                                    .get(i)
-                                    .cloned()
-                                    .unwrap_or_else(|| format!("Outcome {}", i));
-                                let est_return = (1.0 - price) * 100.0;
-                                let volume = market.volume_24hr.unwrap_or(0.0);
-
-                                let market_name = market
-                                    .group_item_title
-                                    .as_ref()
-                                    .filter(|s| !s.is_empty())
-                                    .cloned()
-                                    .unwrap_or_else(|| market.question.clone());
-
-                                let opp = YieldOpportunity {
-                                    market_name,
-                                    market_status: market.status(),
-                                    outcome,
-                                    price,
-                                    est_return,
-                                    volume,
-                                    event_slug: event.slug.clone(),
-                                    event_title: event.title.clone(),
-                                    end_date,
-                                };
-
-                                // Keep the best (highest return) opportunity
-                                if best_yield
-                                    .as_ref()
-                                    .map(|b| opp.est_return > b.est_return)
-                                    .unwrap_or(true)
-                                {
-                                    best_yield = Some(opp);
-                                }
-                            }
-                        }
-                    }
-                }
-
-                YieldSearchResult {
-                    event_slug: event.slug.clone(),
-                    best_yield,
-                }
+            .map(|event| YieldSearchResult {
+                event_slug: event.slug.clone(),
+                best_yield: best_event_yield(event, min_prob),
             })
             .collect();
 
@@ -1045,3 +1545,90 @@ pub fn spawn_yield_search(app_state: Arc<TokioMutex<TrendingAppState>>, query: S
         );
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::trending_tui::{
+            state::YieldState,
+            test_fixtures::{fixture_markets, fixtures},
+        },
+        polymarket_api::yields::find_yield_opportunities,
+    };
+
+    #[test]
+    fn test_fetch_error_message() {
+        let rate_limited = PolymarketError::RateLimited { retry_after: None };
+        assert_eq!(
+            fetch_error_message("Failed to load events", &rate_limited),
+            "Failed to load events: rate limited, try again shortly"
+        );
+        let not_found = PolymarketError::Http {
+            status: 404,
+            body: String::new(),
+        };
+        assert_eq!(
+            fetch_error_message("Failed to bookmark", &not_found),
+            "Failed to bookmark: not found"
+        );
+        assert_eq!(
+            fetch_error_message("Failed", &PolymarketError::Timeout),
+            "Failed: Request timed out"
+        );
+    }
+
+    #[test]
+    fn test_yield_min_liquidity() {
+        let yield_state = YieldState::new();
+        let template = find_yield_opportunities(&fixture_markets(), yield_state.min_prob, 0.0)
+            .into_iter()
+            .next()
+            .expect("Should find a yield opportunity");
+        // Read from the market's liquidityNum
+        assert_eq!(template.liquidity, 25_000.0);
+
+        let opportunity = |name: &str, liquidity: f64, days: Option<i64>| YieldOpportunity {
+            market_name: name.to_string(),
+            liquidity,
+            end_date: days.map(|days| Utc::now() + chrono::Duration::days(days)),
+            ..template.clone()
+        };
+        let mut opportunities = vec![
+            opportunity("deep", 50_000.0, Some(7)),
+            opportunity("thin", 200.0, Some(7)),
+            opportunity("deep-far", 50_000.0, Some(365)),
+            opportunity("deep-undated", 50_000.0, None),
+        ];
+        retain_tradeable_yields(&mut opportunities, 1_000.0, Some(30), Utc::now());
+        let names: Vec<&str> = opportunities
+            .iter()
+            .map(|o| o.market_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["deep", "deep-undated"]);
+
+        // The default threshold keeps everything
+        let mut opportunities = vec![opportunity("unknown", 0.0, None)];
+        retain_tradeable_yields(
+            &mut opportunities,
+            yield_state.min_liquidity,
+            None,
+            Utc::now(),
+        );
+        assert_eq!(opportunities.len(), 1);
+    }
+
+    #[test]
+    fn test_market_prices_skip_zero_size_levels() {
+        // The zero-size 1¢ ask doesn't become the market price
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Should build runtime");
+        let mut clob = ClobClient::new();
+        clob.set_fixtures(fixtures());
+        let prices = runtime.block_on(fetch_market_prices_batch(&clob, vec![vec![
+            "1099".to_string(),
+        ]]));
+        assert_eq!(prices.get("1099"), Some(&0.55));
+    }
+}
@@ -0,0 +1,2120 @@
+//! Key event dispatch for the trending TUI
+//!
+//! Every key press is first resolved to the active [`InputContext`] and then routed
+//! to the handler for that context, so mode checks live in one place instead of
+//! being repeated in every key arm.
+
+// Key arms guard their action with a nested `if` on purpose: collapsing it into
+// a match guard would let unhandled keys fall through to `KeyCode::Char(c)`.
+#![allow(clippy::collapsible_match)]
+
+use {
+    super::{
+        fetch::{
+            fetch_error_message, fetch_events_for_filter, spawn_cancel_order,
+            spawn_fetch_and_toggle_favorite, spawn_fetch_event_for_cache, spawn_fetch_favorites,
+            spawn_fetch_holders, spawn_fetch_market_prices, spawn_fetch_open_orders,
+            spawn_fetch_orderbook, spawn_fetch_portfolio, spawn_fetch_related_events,
+            spawn_fetch_trade_orderbook, spawn_fetch_user_profile, spawn_filter_fetch,
+            spawn_submit_order, spawn_toggle_favorite, spawn_watch_event, spawn_yield_fetch,
+            switch_tab,
+        },
+        logging::{log_error, log_info, log_warn},
+        render::truncate,
+        state::{
+            self, AppTab, AuthState, EventFilter, FavoritesState, FocusedPanel, InputContext,
+            MainTab, OpenOrdersState, PAGE_ROWS, PageJump, PopupType, SearchMode, TrendingAppState,
+        },
+    },
+    crate::working_set::WorkingSet,
+    crossterm::event::{KeyCode, KeyEvent},
+    polymarket_api::GammaClient,
+    std::sync::Arc,
+    tokio::sync::{Mutex as TokioMutex, MutexGuard},
+};
+
+/// Event loop state updated by the key handlers
+#[derive(Debug, Default)]
+pub struct KeyState {
+    /// Time of the last API search edit (the search runs once typing pauses)
+    pub search_debounce: Option<tokio::time::Instant>,
+    /// Time of the last yield search edit
+    pub yield_search_debounce: Option<tokio::time::Instant>,
+    /// Event whose prices and orderbook were fetched last
+    pub last_selected_event_slug: Option<String>,
+    /// Time of the last event selection change (its data loads once scrolling pauses)
+    pub selection_debounce: Option<tokio::time::Instant>,
+}
+
+/// How long the event selection must stay put before its data is fetched
+pub const SELECTION_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(150);
+
+/// Record an event selection change and restart the fetch debounce
+///
+/// The orderbook is cleared right away so the old event's book isn't shown, and
+/// any price request still in flight is superseded.
+fn note_selection_change(app: &mut TrendingAppState, keys: &mut KeyState) {
+    let Some(slug) = app.selected_event().map(|event| event.slug.clone()) else {
+        return;
+    };
+    if keys.last_selected_event_slug.as_ref() == Some(&slug) {
+        return;
+    }
+    keys.last_selected_event_slug = Some(slug);
+    app.orderbook_state.reset();
+    app.next_price_request();
+    keys.selection_debounce = Some(tokio::time::Instant::now());
+}
+
+/// Move the orderbook to the previous/next market of the event shown (releases the lock)
+///
+/// Steps through the sorted (open-first) markets, clamping at the ends, and fetches
+/// the new market's first outcome.
+fn step_orderbook_market(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    delta: isize,
+) {
+    let event = if app.main_tab == MainTab::Favorites {
+        app.favorites_state.selected_event()
+    } else {
+        app.selected_event()
+    };
+    let Some(event) = event else {
+        return;
+    };
+    let mut sorted_markets: Vec<_> = event.markets.iter().collect();
+    sorted_markets.sort_by_key(|m| m.closed);
+    let current = app.orderbook_state.selected_market_index;
+    let new_idx = current
+        .saturating_add_signed(delta)
+        .min(sorted_markets.len().saturating_sub(1));
+    if new_idx == current {
+        return;
+    }
+    let token_and_active = sorted_markets.get(new_idx).and_then(|market| {
+        market
+            .clob_token_ids
+            .as_ref()
+            .and_then(|ids| ids.first().cloned().map(|id| (id, !market.closed)))
+    });
+
+    app.orderbook_state.selected_market_index = new_idx;
+    app.orderbook_state.selected_outcome = state::OrderbookOutcome::Yes;
+    app.orderbook_state.orderbook = None;
+    // Keep the selection visible in the Markets panel
+    let visible_height: usize = 5; // Markets panel height
+    if new_idx < app.scroll.markets {
+        app.scroll.markets = new_idx;
+    } else if new_idx >= app.scroll.markets + visible_height {
+        app.scroll.markets = new_idx.saturating_sub(visible_height - 1);
+    }
+    if let Some((token_id, is_active)) = token_and_active {
+        drop(app);
+        spawn_fetch_orderbook(Arc::clone(app_state), token_id, is_active);
+    }
+}
+
+/// Handle a key press in the context it was made in (releases the lock when spawning)
+pub fn handle_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+    keys: &mut KeyState,
+) {
+    let context = app.input_context();
+    dispatch(app_state, app, key, keys, context);
+}
+
+fn dispatch(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+    keys: &mut KeyState,
+    context: InputContext,
+) {
+    // While typing, characters are always literal input, whatever shortcut they map to
+    if context.is_text_entry() && edit_text(&mut app, key, keys, context) {
+        return;
+    }
+
+    match context {
+        InputContext::Popup => handle_popup_key(app_state, app, key, keys),
+        InputContext::ApiSearch | InputContext::LocalFilter => handle_search_key(app, key, keys),
+        InputContext::YieldSearch => handle_yield_search_key(app_state, app, key, keys),
+        InputContext::YieldFilter => handle_yield_filter_key(app_state, app, key, keys),
+        InputContext::Normal => handle_normal_key(app_state, app, key, keys),
+    }
+}
+
+fn handle_popup_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+    keys: &mut KeyState,
+) {
+    match app.popup {
+        Some(PopupType::Login) => handle_login_key(app_state, app, key),
+        Some(PopupType::UserProfile) => handle_profile_key(app, key),
+        Some(PopupType::Trade) => handle_trade_key(app_state, app, key),
+        Some(PopupType::OpenOrders) => handle_open_orders_key(app_state, app, key),
+        Some(PopupType::Holders) => handle_holders_key(app_state, app, key),
+        Some(PopupType::ProfileSwitcher) => handle_profile_switcher_key(app_state, app, key),
+        _ => {
+            // Informational popups only take Esc, other keys act on the screen behind them
+            if key.code == KeyCode::Esc {
+                app.close_popup();
+            } else {
+                let context = app.panel_input_context();
+                dispatch(app_state, app, key, keys, context);
+            }
+        },
+    }
+}
+
+fn handle_login_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+) {
+    match key.code {
+        KeyCode::Esc => {
+            app.login_form.clear();
+            app.close_popup();
+        },
+        KeyCode::Tab | KeyCode::Down => {
+            app.login_form.active_field = app.login_form.active_field.next();
+        },
+        KeyCode::BackTab | KeyCode::Up => {
+            app.login_form.active_field = app.login_form.active_field.prev();
+        },
+        KeyCode::Backspace => {
+            app.login_form.delete_char();
+        },
+        KeyCode::Enter => {
+            // Validate and save credentials
+            // Convert empty strings to None for optional cookie fields
+            let session_cookie = if app.login_form.session_cookie.is_empty() {
+                None
+            } else {
+                Some(app.login_form.session_cookie.clone())
+            };
+            let session_nonce = if app.login_form.session_nonce.is_empty() {
+                None
+            } else {
+                Some(app.login_form.session_nonce.clone())
+            };
+            let session_auth_type = if app.login_form.session_auth_type.is_empty() {
+                None
+            } else {
+                Some(app.login_form.session_auth_type.clone())
+            };
+
+            let saved = crate::auth::AuthConfig::load();
+            let config = crate::auth::AuthConfig {
+                api_key: app.login_form.api_key.clone(),
+                secret: app.login_form.secret.clone(),
+                passphrase: app.login_form.passphrase.clone(),
+                address: app.login_form.address.clone(),
+                username: None,
+                session_cookie,
+                session_nonce,
+                session_auth_type,
+                // Keep the signing settings across logins (they are only set in auth.json)
+                private_key: saved.as_ref().and_then(|c| c.private_key.clone()),
+                signature_type: saved.and_then(|c| c.signature_type),
+            };
+
+            match config.validate() {
+                Ok(()) => {
+                    // Save to config file
+                    match config.save() {
+                        Ok(()) => {
+                            // Update auth state
+                            let address_for_profile = config.address.clone();
+                            app.auth_state.is_authenticated = true;
+                            app.auth_state.address = Some(config.address.clone());
+                            app.auth_state.username = config.username.clone();
+                            app.auth_state.config = Some(config);
+                            app.has_clob_auth = true;
+                            app.login_form.clear();
+                            app.close_popup();
+                            log_info!("Logged in successfully");
+
+                            // Fetch user profile to get username, and the balance and positions
+                            drop(app); // Release lock before spawning
+                            spawn_fetch_user_profile(
+                                Arc::clone(app_state),
+                                address_for_profile.clone(),
+                            );
+                            spawn_fetch_portfolio(Arc::clone(app_state), address_for_profile);
+                        },
+                        Err(e) => {
+                            app.login_form.error_message = Some(e);
+                        },
+                    }
+                },
+                Err(e) => {
+                    app.login_form.error_message = Some(e);
+                },
+            }
+        },
+        KeyCode::Char(c) => {
+            app.login_form.add_char(c);
+        },
+        _ => {},
+    }
+}
+
+fn handle_profile_key(mut app: MutexGuard<'_, TrendingAppState>, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('p') => {
+            app.close_popup();
+        },
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            // Logout
+            let _ = crate::auth::AuthConfig::delete();
+            app.auth_state.is_authenticated = false;
+            app.auth_state.address = None;
+            app.auth_state.username = None;
+            app.auth_state.balance = None;
+            app.auth_state.position_sizes.clear();
+            app.auth_state.positions.clear();
+            app.auth_state.config = None;
+            app.has_clob_auth = false;
+            app.close_popup();
+            log_info!("Logged out");
+        },
+        _ => {},
+    }
+}
+
+fn handle_profile_switcher_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('P') => {
+            app.close_popup();
+        },
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.profile_switcher.move_up();
+        },
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.profile_switcher.move_down();
+        },
+        KeyCode::Enter => {
+            if let Some(name) = app.profile_switcher.selected_profile().map(String::from) {
+                switch_profile(app_state, app, name);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Make a saved profile active and reload the account data for its address
+fn switch_profile(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    name: String,
+) {
+    let config = match crate::auth::AuthConfig::set_active_profile(&name).and_then(|()| {
+        crate::auth::AuthConfig::load_profile(&name)
+            .ok_or_else(|| format!("No profile named '{}'", name))
+    }) {
+        Ok(config) => config,
+        Err(e) => {
+            app.profile_switcher.error_message = Some(e);
+            return;
+        },
+    };
+
+    // Nothing from the previous account may leak into the new one
+    app.auth_state = AuthState::new();
+    app.auth_state.is_authenticated = true;
+    app.auth_state.address = Some(config.address.clone());
+    app.auth_state.username = config.username.clone();
+    app.auth_state.config = Some(config.clone());
+    app.has_clob_auth = true;
+    app.favorites_state = FavoritesState::new();
+    app.open_orders = OpenOrdersState::new();
+    app.close_popup();
+    log_info!("Switched to profile {} ({})", name, config.short_address());
+
+    drop(app); // Release lock before spawning
+    if config.username.is_none() {
+        spawn_fetch_user_profile(Arc::clone(app_state), config.address.clone());
+    }
+    spawn_fetch_portfolio(Arc::clone(app_state), config.address);
+    spawn_fetch_favorites(Arc::clone(app_state));
+}
+
+fn handle_open_orders_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('m') => {
+            app.close_popup();
+        },
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.open_orders.move_up();
+        },
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.open_orders.move_down();
+        },
+        KeyCode::Char('r') => {
+            spawn_fetch_open_orders(Arc::clone(app_state));
+        },
+        KeyCode::Char('x') => {
+            // Cancel the selected order (one at a time)
+            if app.open_orders.cancelling.is_none()
+                && let Some(order_id) = app.open_orders.selected_order().map(|o| o.id.clone())
+            {
+                log_info!("Cancelling order {}", order_id);
+                app.open_orders.cancelling = Some(order_id.clone());
+                app.open_orders.error_message = None;
+                spawn_cancel_order(Arc::clone(app_state), order_id);
+            }
+        },
+        _ => {},
+    }
+}
+
+fn handle_holders_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('h') => {
+            app.close_popup();
+        },
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.holders.scroll_up();
+        },
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.holders.scroll_down();
+        },
+        KeyCode::Char('r') => {
+            if let Some(condition_id) = app.holders.condition_id.clone() {
+                app.holders.is_loading = true;
+                app.holders.error_message = None;
+                spawn_fetch_holders(Arc::clone(app_state), condition_id);
+            }
+        },
+        _ => {},
+    }
+}
+
+fn handle_trade_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+) {
+    // Check auth state before borrowing trade_form mutably
+    let is_authenticated = app.auth_state.is_authenticated;
+    let balance = app.auth_state.balance;
+    let max_slippage_pct = app.trade_settings.max_slippage_pct;
+    let paper_mode = app.paper_mode;
+    let mut should_close = false;
+    let mut submit = None;
+    let mut paper_fill = None;
+    let mut fetch_book = None;
+
+    // 1, 2, ... apply the quick-select amounts from settings until the size has been
+    // edited (and never in the price field); F1, F2, ... always do
+    let quick_amount = match key.code {
+        KeyCode::F(n) => Some(usize::from(n)),
+        KeyCode::Char(c @ '1'..='9')
+            if app
+                .trade_form
+                .as_ref()
+                .is_some_and(|f| f.digits_pick_quick_amounts()) =>
+        {
+            c.to_digit(10).map(|n| n as usize)
+        },
+        _ => None,
+    };
+    if let Some(n) = quick_amount {
+        app.apply_quick_amount(n.saturating_sub(1));
+        return;
+    }
+
+    if let Some(ref mut form) = app.trade_form {
+        match key.code {
+            KeyCode::Esc => {
+                should_close = true;
+            },
+            KeyCode::Tab => {
+                form.next_field();
+            },
+            KeyCode::BackTab => {
+                form.prev_field();
+            },
+            KeyCode::Char(' ') => {
+                // Space toggles order type when on that field
+                if form.active_field == state::TradeField::OrderType {
+                    form.toggle_order_type();
+                } else if form.active_field == state::TradeField::TimeInForce {
+                    form.toggle_time_in_force();
+                }
+                // Note: Side is now toggled via title tabs, not space key
+            },
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                // + increases limit price
+                if form.active_field == state::TradeField::LimitPrice {
+                    form.increment_limit_price();
+                }
+            },
+            KeyCode::Char('-') | KeyCode::Char('_') => {
+                // - decreases limit price
+                if form.active_field == state::TradeField::LimitPrice {
+                    form.decrement_limit_price();
+                }
+            },
+            KeyCode::Up => {
+                // Up arrow also increases limit price
+                if form.active_field == state::TradeField::LimitPrice {
+                    form.increment_limit_price();
+                }
+            },
+            KeyCode::Down => {
+                // Down arrow also decreases limit price
+                if form.active_field == state::TradeField::LimitPrice {
+                    form.decrement_limit_price();
+                }
+            },
+            KeyCode::Backspace => {
+                form.delete_char();
+            },
+            KeyCode::Enter => {
+                // Validate and submit trade
+                if !is_authenticated && !paper_mode {
+                    form.error_message = Some("Login required to trade".to_string());
+                } else {
+                    // Validate based on order type
+                    let is_valid = match form.order_type {
+                        state::OrderType::Limit => {
+                            !form.shares.is_empty() && form.shares_f64() > 0.0
+                        },
+                        state::OrderType::Market => {
+                            !form.amount.is_empty() && form.amount_f64() > 0.0
+                        },
+                    };
+
+                    if !is_valid {
+                        form.error_message = Some(match form.order_type {
+                            state::OrderType::Limit => {
+                                "Please enter a valid number of shares".to_string()
+                            },
+                            state::OrderType::Market => "Please enter a valid amount".to_string(),
+                        });
+                    } else if form.order_type == state::OrderType::Market
+                        && let Err(msg) = form.check_slippage(max_slippage_pct)
+                    {
+                        // Slippage protection: reject before anything is sent
+                        form.error_message = Some(msg);
+                    } else if !paper_mode && let Err(msg) = form.check_balance(balance) {
+                        form.error_message = Some(msg);
+                    } else if !form.is_submitting {
+                        match form.order_request(max_slippage_pct) {
+                            Ok(order) if paper_mode => match form.paper_trade(&order) {
+                                Ok(trade) => paper_fill = Some(trade),
+                                Err(msg) => form.error_message = Some(msg),
+                            },
+                            Ok(order) => {
+                                log_info!(
+                                    "Submitting {} {}order: {} {:.2} shares of {} at {:.1}¢",
+                                    order.time_in_force.as_str(),
+                                    if order.away_from_market {
+                                        "limit "
+                                    } else {
+                                        ""
+                                    },
+                                    form.side.label(),
+                                    order.size,
+                                    order.outcome,
+                                    order.price * 100.0
+                                );
+                                form.is_submitting = true;
+                                form.error_message = None;
+                                submit = Some(order);
+                            },
+                            Err(msg) => form.error_message = Some(msg),
+                        }
+                    }
+                }
+            },
+            KeyCode::Left | KeyCode::Right => {
+                // Switch outcome (cycles through all of them on multi-outcome markets)
+                let previous = form.selected_outcome_idx;
+                form.cycle_outcome(key.code == KeyCode::Right);
+                if form.selected_outcome_idx != previous {
+                    fetch_book = form.token_id().map(str::to_string);
+                }
+            },
+            KeyCode::Char('$') => {
+                // Toggle entering the size in shares or dollars
+                form.toggle_size_unit();
+            },
+            KeyCode::Char(c) => {
+                form.add_char(c);
+            },
+            _ => {},
+        }
+    } else {
+        // No form state, close popup
+        should_close = true;
+    }
+
+    if should_close {
+        app.close_popup();
+    } else if let Some(trade) = paper_fill {
+        app.fill_paper_order(trade);
+    } else if let Some(order) = submit {
+        drop(app);
+        spawn_submit_order(Arc::clone(app_state), order);
+    } else if let Some(token_id) = fetch_book {
+        drop(app);
+        spawn_fetch_trade_orderbook(Arc::clone(app_state), token_id);
+    }
+}
+
+/// Apply a typed character or Backspace to the query being entered
+///
+/// Returns false for any other key.
+fn edit_text(
+    app: &mut TrendingAppState,
+    key: KeyEvent,
+    keys: &mut KeyState,
+    context: InputContext,
+) -> bool {
+    let edited = match key.code {
+        KeyCode::Char(c) => app.input_char(c),
+        KeyCode::Backspace => app.input_backspace(),
+        _ => false,
+    };
+    if edited {
+        // API searches run once typing pauses, local filters apply immediately
+        match context {
+            InputContext::ApiSearch => keys.search_debounce = Some(tokio::time::Instant::now()),
+            InputContext::YieldSearch => {
+                keys.yield_search_debounce = Some(tokio::time::Instant::now())
+            },
+            _ => {},
+        }
+    }
+    edited
+}
+
+/// Events search (API) and local filter
+fn handle_search_key(
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+    keys: &mut KeyState,
+) {
+    match key.code {
+        KeyCode::Esc => {
+            app.exit_search_mode();
+        },
+        KeyCode::Tab if app.search.mode == SearchMode::ApiSearch => {
+            // Switch between event and market search and re-run the query
+            app.toggle_search_target();
+            log_info!("Searching {}", app.search.target.label());
+            if !app.search.query.is_empty() {
+                keys.search_debounce = Some(tokio::time::Instant::now());
+            }
+        },
+        KeyCode::Enter => {
+            // Exit search/filter mode and keep selection
+            if app.navigation.focused_panel == FocusedPanel::EventsList {
+                app.search.mode = SearchMode::None;
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Yield search: navigation keys still move the selection
+fn handle_yield_search_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+    keys: &mut KeyState,
+) {
+    match key.code {
+        KeyCode::Esc => {
+            app.yield_state.exit_search_mode();
+            log_info!("Exited yield search mode");
+        },
+        KeyCode::Enter => {
+            // Hide search input but keep results
+            app.yield_state.hide_search_input();
+            log_info!("Hidden yield search input, keeping results");
+        },
+        _ => handle_normal_key(app_state, app, key, keys),
+    }
+}
+
+/// Yield filter: navigation keys still move the selection
+fn handle_yield_filter_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+    keys: &mut KeyState,
+) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter => {
+            // Exit filter mode but keep filter applied
+            app.yield_state.exit_filter_mode();
+            log_info!("Exited yield filter mode");
+        },
+        _ => handle_normal_key(app_state, app, key, keys),
+    }
+}
+
+/// Main screen: keys are shortcuts
+fn handle_normal_key(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    key: KeyEvent,
+    keys: &mut KeyState,
+) {
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => {
+            app.should_quit = true;
+        },
+        KeyCode::Char('?') => {
+            app.show_popup(PopupType::Help);
+        },
+        KeyCode::Char(c @ '1'..='4') => {
+            // Switch to the numbered tab
+            if let Some(tab) = AppTab::from_key(c) {
+                switch_tab(app_state, app, tab);
+            }
+        },
+        KeyCode::Char('l') => {
+            // Toggle logs panel visibility
+            app.show_logs = !app.show_logs;
+            // If hiding logs and logs panel was focused, switch to another panel
+            if !app.show_logs && app.navigation.focused_panel == FocusedPanel::Logs {
+                app.navigation.focused_panel = FocusedPanel::EventsList;
+            }
+        },
+        KeyCode::Char('p') => {
+            // Show profile popup (if authenticated or paper trading), refreshing the
+            // positions it breaks down
+            if app.auth_state.is_authenticated || app.paper_mode {
+                app.show_popup(PopupType::UserProfile);
+                if let Some(address) = app.auth_state.address.clone() {
+                    spawn_fetch_portfolio(Arc::clone(app_state), address);
+                }
+            }
+        },
+        KeyCode::Char('P') => {
+            // Switch between the profiles saved in auth.json
+            if !app.has_popup() {
+                let profiles = crate::auth::AuthConfig::list_profiles()
+                    .into_iter()
+                    .map(|name| {
+                        let address = crate::auth::AuthConfig::load_profile(&name)
+                            .map(|config| config.short_address())
+                            .unwrap_or_default();
+                        (name, address)
+                    })
+                    .collect();
+                app.profile_switcher
+                    .set_profiles(profiles, crate::auth::AuthConfig::active_profile());
+                app.show_popup(PopupType::ProfileSwitcher);
+            }
+        },
+        KeyCode::Char('m') => {
+            // Show resting orders, or ask to log in first
+            if !app.has_popup() {
+                if app.auth_state.is_authenticated {
+                    app.open_orders.error_message = None;
+                    app.show_popup(PopupType::OpenOrders);
+                    spawn_fetch_open_orders(Arc::clone(app_state));
+                } else {
+                    app.login_form.error_message = Some("Login to view your orders".to_string());
+                    app.show_popup(PopupType::Login);
+                }
+            }
+        },
+        KeyCode::Char('g') => {
+            // Jump between the selected event's Events and Favorites entries
+            if !app.has_popup() {
+                match app.main_tab {
+                    MainTab::Trending => {
+                        if let Some(slug) = app.selected_event_slug()
+                            && !app.goto_favorite(&slug)
+                        {
+                            log_info!("{} is not in the loaded favorites", slug);
+                        }
+                    },
+                    MainTab::Favorites => {
+                        if let Some(slug) =
+                            app.favorites_state.selected_event().map(|e| e.slug.clone())
+                            && !app.goto_in_trending(&slug)
+                        {
+                            log_info!("{} is not in the events list", slug);
+                        }
+                    },
+                    MainTab::Yield => {},
+                }
+            }
+        },
+        KeyCode::Char('b') => {
+            // Toggle bookmark/favorite for current event
+            if !app.has_popup() && app.auth_state.is_authenticated {
+                // Get the event to toggle based on current tab
+                match app.main_tab {
+                    MainTab::Trending | MainTab::Favorites => {
+                        if let Some(e) = app.selected_event() {
+                            spawn_toggle_favorite(
+                                Arc::clone(app_state),
+                                e.id.clone(),
+                                e.slug.clone(),
+                                Some(e.clone()),
+                            );
+                        }
+                    },
+                    MainTab::Yield => {
+                        // For yield tab, get event_slug from selected opportunity
+                        // We need to fetch the event to get the ID
+                        if let Some(opp) = app.yield_state.selected_opportunity() {
+                            let event_slug = opp.event_slug.clone();
+                            // Try to find it in the events cache or favorites
+                            let cached_event = app
+                                .events
+                                .iter()
+                                .find(|e| e.slug == event_slug)
+                                .cloned()
+                                .or_else(|| {
+                                    app.favorites_state
+                                        .events
+                                        .iter()
+                                        .find(|e| e.slug == event_slug)
+                                        .cloned()
+                                });
+
+                            if let Some(event) = cached_event {
+                                spawn_toggle_favorite(
+                                    Arc::clone(app_state),
+                                    event.id.clone(),
+                                    event.slug.clone(),
+                                    Some(event),
+                                );
+                            } else {
+                                // Event not in cache, fetch it first then toggle
+                                spawn_fetch_and_toggle_favorite(Arc::clone(app_state), event_slug);
+                            }
+                        }
+                    },
+                };
+            }
+        },
+        KeyCode::Char('/') => {
+            // API search mode - works from any panel (except when popup is open)
+            if app.main_tab == MainTab::Yield {
+                app.yield_state.enter_search_mode();
+                log_info!("Entered yield search mode");
+            } else if !app.has_popup() {
+                // API search in Trending/Favorites tab from any panel
+                app.enter_search_mode();
+            }
+        },
+        KeyCode::Char('f') => {
+            // Local filter - works from any panel (except when popup is open)
+            if app.main_tab == MainTab::Yield {
+                app.yield_state.enter_filter_mode();
+                log_info!("Entered yield filter mode");
+            } else if !app.has_popup() {
+                // Local filter in Trending/Favorites tab from any panel
+                app.enter_local_filter_mode();
+            }
+        },
+        KeyCode::Char('o') => {
+            // Open event URL in browser (works from any panel, any tab)
+            if !app.has_popup()
+                && let Some(url) = app.selected_event_url()
+            {
+                open_in_browser(&url);
+            }
+        },
+        KeyCode::Char('O') => {
+            // Open the selected market's page, or the event's if the market has no slug
+            if !app.has_popup() {
+                let url = app.selected_market_url().or_else(|| {
+                    log_info!("Selected market has no page, opening the event instead");
+                    app.selected_event_url()
+                });
+                if let Some(url) = url {
+                    open_in_browser(&url);
+                }
+            }
+        },
+        KeyCode::Char('y') => {
+            // Yank the event URL to the clipboard (works from any panel, any tab)
+            if !app.has_popup()
+                && let Some(url) = app.selected_event_url()
+            {
+                match copy_to_clipboard(&url) {
+                    Ok(()) => {
+                        log_info!("Copied to clipboard: {}", url);
+                        app.flash("Copied!");
+                    },
+                    Err(e) => {
+                        log_error!("Failed to copy to clipboard: {}", e);
+                        app.flash(format!("Copy failed: {}", e));
+                    },
+                }
+            }
+        },
+        KeyCode::Char('e') => {
+            // Open config file in editor (only in Favorites tab when session cookie is missing)
+            if app.main_tab == MainTab::Favorites && app.favorites_state.error_message.is_some() {
+                open_config_file();
+            }
+        },
+        KeyCode::Char('s') => {
+            // Cycle sort order
+            if app.main_tab == MainTab::Yield {
+                app.yield_state.sort_by = app.yield_state.sort_by.next();
+                app.yield_state.sort_opportunities();
+                app.yield_state.selected_index = 0;
+                app.yield_state.scroll = 0;
+                log_info!("Sort changed to: {}", app.yield_state.sort_by.label());
+                if let Err(e) = app.save_yield_settings() {
+                    log_error!("Failed to save yield settings: {}", e);
+                }
+            } else if app.navigation.focused_panel == FocusedPanel::Trades {
+                // Sort the Trades panel by price, shares or value, back to newest first
+                app.cycle_trade_sort();
+                log_info!("Trades sort changed to: {}", app.trade_sort.label());
+            } else {
+                // Cycle sort order for Events tab
+                app.cycle_event_sort();
+                log_info!("Events sort changed to: {}", app.event_sort_by.label());
+                if let Err(e) = app.save_event_sort() {
+                    log_error!("Failed to save events sort: {}", e);
+                }
+                // The cached Events page was ordered by the API with the old sort;
+                // re-fetch it so events beyond the loaded page can move in
+                app.events_cache.remove(&EventFilter::Trending);
+                if app.main_tab == MainTab::Trending
+                    && app.event_filter == EventFilter::Trending
+                    && app.search.query.is_empty()
+                    && app.search.results.is_empty()
+                {
+                    let sort = app.event_sort_by;
+                    let limit = app.pagination.current_limit;
+                    app.pagination.is_fetching_more = true;
+                    drop(app);
+                    spawn_filter_fetch(Arc::clone(app_state), EventFilter::Trending, sort, limit);
+                }
+            }
+        },
+        KeyCode::Char('S') => {
+            // Save logs to file (Shift+S) when logs panel is visible
+            if app.show_logs {
+                match app.logs.save_to_file() {
+                    Ok(filename) => {
+                        log_info!("Logs saved to: {}", filename);
+                    },
+                    Err(e) => {
+                        log_error!("Failed to save logs: {}", e);
+                    },
+                }
+            }
+        },
+        KeyCode::Char('F') => {
+            // Cycle the logs panel level filter (Shift+F) when logs panel is visible
+            if app.show_logs {
+                app.logs.cycle_level_filter();
+            }
+        },
+        KeyCode::Char('x') => {
+            // Export watchlist, favorites and settings for another machine
+            export_working_set(&app);
+        },
+        KeyCode::Char('C') => {
+            // Switch prices between cents and dollars everywhere
+            app.price_format = app.price_format.toggle();
+            let label = app.price_format.label();
+            log_info!("Showing prices in {}", label);
+            app.flash(format!("Prices in {}", label));
+        },
+        KeyCode::Char('T') => {
+            // Switch the Trade popup between paper and live orders
+            app.toggle_paper_mode();
+            log_info!(
+                "Paper trading {}",
+                if app.paper_mode {
+                    "on"
+                } else {
+                    "off"
+                }
+            );
+        },
+        KeyCode::Char('v') => {
+            // Cycle the minimum value of the live trades kept for the selected event
+            let slug = match app.main_tab {
+                MainTab::Favorites => app.favorites_state.selected_event().map(|e| e.slug.clone()),
+                MainTab::Trending => app.selected_event_slug(),
+                _ => None,
+            };
+            if !app.has_popup()
+                && let Some(slug) = slug
+            {
+                let min_value = app.cycle_min_trade_value(&slug);
+                log_info!("Hiding live trades under ${:.0} for {}", min_value, slug);
+            }
+        },
+        KeyCode::Char('R') => {
+            // List the events sharing a tag with the selected event (same series)
+            if app.main_tab == MainTab::Trending
+                && !app.has_popup()
+                && let Some(event) = app.selected_event().cloned()
+            {
+                spawn_fetch_related_events(Arc::clone(app_state), event);
+            }
+        },
+        KeyCode::Char('h') => {
+            // Show the top holders of the market selected in the Markets panel
+            if !app.has_popup()
+                && app.navigation.focused_panel == FocusedPanel::Markets
+                && let Some(condition_id) = app.open_holders_popup()
+            {
+                spawn_fetch_holders(Arc::clone(app_state), condition_id);
+            }
+        },
+        KeyCode::Char('c') => {
+            // Copy the selected live trade into the Trade popup
+            if !app.has_popup() && app.navigation.focused_panel == FocusedPanel::Trades {
+                if app.open_copy_trade_popup() {
+                    log_info!("Opened trade popup mirroring selected trade");
+                    if let Some(token_id) = app.trade_form.as_ref().and_then(|f| f.token_id()) {
+                        spawn_fetch_trade_orderbook(Arc::clone(app_state), token_id.to_string());
+                    }
+                } else {
+                    log_warn!("Could not resolve the selected trade to a market");
+                }
+            }
+        },
+        KeyCode::Char('t') => {
+            // Toggle orderbook Yes/No outcome
+            if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites) && !app.has_popup() {
+                // Toggle orderbook outcome and fetch new data
+                app.orderbook_state.toggle_outcome();
+                let new_outcome = app.orderbook_state.selected_outcome;
+                log_info!(
+                    "Toggled orderbook to {:?}, market_idx={}",
+                    new_outcome,
+                    app.orderbook_state.selected_market_index
+                );
+                // token_ids[0] = Yes, token_ids[1] = No
+                let outcome_idx = match new_outcome {
+                    state::OrderbookOutcome::Yes => 0,
+                    state::OrderbookOutcome::No => 1,
+                };
+                // Trigger orderbook fetch for the new outcome (use sorted markets)
+                // Get event from appropriate source based on tab
+                let orderbook_info: Option<(String, bool)> = if app.main_tab == MainTab::Favorites {
+                    app.favorites_state.selected_event().and_then(|event| {
+                        let mut sorted_markets: Vec<_> = event.markets.iter().collect();
+                        sorted_markets.sort_by_key(|m| m.closed);
+                        let market_idx = app.orderbook_state.selected_market_index;
+                        sorted_markets.get(market_idx).and_then(|market| {
+                            log_info!(
+                                "Toggle: market={}, token_ids={:?}",
+                                market.question,
+                                market.clob_token_ids
+                            );
+                            market.clob_token_ids.as_ref().and_then(|ids| {
+                                ids.get(outcome_idx).cloned().map(|id| (id, !market.closed))
+                            })
+                        })
+                    })
+                } else {
+                    app.selected_event().and_then(|event| {
+                        let mut sorted_markets: Vec<_> = event.markets.iter().collect();
+                        sorted_markets.sort_by_key(|m| m.closed);
+                        let market_idx = app.orderbook_state.selected_market_index;
+                        sorted_markets.get(market_idx).and_then(|market| {
+                            log_info!(
+                                "Toggle: market={}, token_ids={:?}",
+                                market.question,
+                                market.clob_token_ids
+                            );
+                            market.clob_token_ids.as_ref().and_then(|ids| {
+                                ids.get(outcome_idx).cloned().map(|id| (id, !market.closed))
+                            })
+                        })
+                    })
+                };
+                if let Some((token_id, is_active)) = orderbook_info {
+                    log_info!(
+                        "Fetching orderbook for outcome_idx={}, token={}",
+                        outcome_idx,
+                        token_id
+                    );
+                    spawn_fetch_orderbook(Arc::clone(app_state), token_id, is_active);
+                } else {
+                    log_warn!("No token_id found for outcome_idx={}", outcome_idx);
+                }
+            }
+        },
+        KeyCode::Char('[') => {
+            if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites) {
+                step_orderbook_market(app_state, app, -1);
+            }
+        },
+        KeyCode::Char(']') => {
+            if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites) {
+                step_orderbook_market(app_state, app, 1);
+            }
+        },
+        KeyCode::Char('d') => {
+            // Switch the orderbook between the level list and the depth chart
+            if matches!(app.main_tab, MainTab::Trending | MainTab::Favorites) && !app.has_popup() {
+                app.orderbook_state.toggle_depth_chart();
+            }
+        },
+        KeyCode::Char('r') => {
+            if app.main_tab == MainTab::Yield {
+                // Refresh yield opportunities
+                if !app.yield_state.is_loading {
+                    log_info!("Refreshing yield opportunities...");
+                    spawn_yield_fetch(Arc::clone(app_state));
+                    // Also refresh favorites in background
+                    if app.auth_state.is_authenticated {
+                        spawn_fetch_favorites(Arc::clone(app_state));
+                    }
+                }
+            } else if app.main_tab == MainTab::Favorites {
+                // Refresh favorites list
+                if !app.favorites_state.is_loading && app.auth_state.is_authenticated {
+                    log_info!("Refreshing favorites...");
+                    spawn_fetch_favorites(Arc::clone(app_state));
+                }
+            } else if app.navigation.focused_panel == FocusedPanel::EventsList {
+                // Refresh events list and update cache
+                let current_filter = app.event_filter;
+                let sort = app.event_sort_by;
+                let limit = app.pagination.current_limit;
+                let app_state_clone = Arc::clone(app_state);
+                let gamma_client = GammaClient::new();
+                let is_authenticated = app.auth_state.is_authenticated;
+                app.price_cache.clear();
+
+                log_info!("Refreshing events list...");
+
+                tokio::spawn(async move {
+                    match fetch_events_for_filter(&gamma_client, current_filter, sort, limit).await
+                    {
+                        Ok(new_events) => {
+                            let mut app = app_state_clone.lock().await;
+                            // Update cache for current filter
+                            app.events_cache.insert(current_filter, new_events.clone());
+                            app.events = new_events;
+                            log_info!("Events refreshed ({} events)", app.events.len());
+                        },
+                        Err(_e) => {
+                            log_info!("Failed to refresh events: {}", _e);
+                        },
+                    }
+                });
+
+                // Also refresh favorites in background to sync bookmark icons
+                if is_authenticated {
+                    spawn_fetch_favorites(Arc::clone(app_state));
+                }
+            } else if app.navigation.focused_panel == FocusedPanel::Markets
+                && let Some(event) = app.selected_event()
+            {
+                // Refresh market prices
+                // Only fetch prices for active (non-closed) markets
+                let active_markets: Vec<_> = event
+                    .markets
+                    .iter()
+                    .filter(|m| !m.closed)
+                    .filter_map(|m| m.clob_token_ids.clone())
+                    .collect();
+
+                let _active_count = active_markets.len();
+                let _closed_count = event.markets.iter().filter(|m| m.closed).count();
+                log_info!(
+                    "Refreshing market prices for event: {} ({} active, {} resolved)",
+                    event.slug,
+                    _active_count,
+                    _closed_count
+                );
+
+                if active_markets.is_empty() {
+                    log_info!("No active markets to refresh");
+                } else {
+                    // Forced refresh: skip cached orderbooks
+                    app.price_cache.clear();
+                    spawn_fetch_market_prices(app_state, &mut app, active_markets);
+                }
+            }
+        },
+        KeyCode::Tab => {
+            // Cycle through panels, skipping Logs if hidden
+            app.navigation.focused_panel = match app.navigation.focused_panel {
+                FocusedPanel::Header => FocusedPanel::EventsList,
+                FocusedPanel::EventsList => FocusedPanel::EventDetails,
+                FocusedPanel::EventDetails => FocusedPanel::Markets,
+                FocusedPanel::Markets => FocusedPanel::Trades,
+                FocusedPanel::Trades => {
+                    if app.show_logs {
+                        FocusedPanel::Logs
+                    } else {
+                        FocusedPanel::Header
+                    }
+                },
+                FocusedPanel::Logs => FocusedPanel::Header,
+            };
+        },
+        KeyCode::Left => {
+            if app.navigation.focused_panel == FocusedPanel::Header {
+                // Cycle through tabs in the same order as the number keys
+                let tab = app.current_tab().prev();
+                switch_tab(app_state, app, tab);
+            }
+        },
+        KeyCode::Right => {
+            if app.navigation.focused_panel == FocusedPanel::Header {
+                // Cycle through tabs in the same order as the number keys
+                let tab = app.current_tab().next();
+                switch_tab(app_state, app, tab);
+            }
+        },
+        KeyCode::Up => {
+            // Handle favorites tab navigation
+            if app.main_tab == MainTab::Favorites {
+                app.favorites_state.move_up();
+                return;
+            }
+            // Handle yield tab navigation
+            if app.main_tab == MainTab::Yield {
+                app.yield_state.move_up();
+                // Fetch event if not in cache
+                if let Some(opp) = app.yield_state.selected_opportunity() {
+                    let slug = opp.event_slug.clone();
+                    if app.get_cached_event(&slug).is_none() {
+                        spawn_fetch_event_for_cache(Arc::clone(app_state), slug);
+                    }
+                }
+                return;
+            }
+            match app.navigation.focused_panel {
+                FocusedPanel::Header => {
+                    // Header doesn't scroll, but we can allow it for consistency
+                },
+                FocusedPanel::EventsList => {
+                    app.move_up();
+                    note_selection_change(&mut app, keys);
+                },
+                FocusedPanel::EventDetails => {
+                    if app.scroll.event_details > 0 {
+                        app.scroll.event_details -= 1;
+                    }
+                },
+                FocusedPanel::Markets => {
+                    // Move selected market up and fetch orderbook
+                    if app.orderbook_state.selected_market_index > 0 {
+                        app.orderbook_state.selected_market_index -= 1;
+                        // Adjust scroll if needed to keep selection visible
+                        if app.orderbook_state.selected_market_index < app.scroll.markets {
+                            app.scroll.markets = app.orderbook_state.selected_market_index;
+                        }
+                        // Fetch orderbook for new selection (use sorted markets)
+                        if let Some(event) = app.selected_event() {
+                            let mut sorted_markets: Vec<_> = event.markets.iter().collect();
+                            sorted_markets.sort_by_key(|m| m.closed);
+                            let market_idx = app.orderbook_state.selected_market_index;
+                            let outcome_idx = match app.orderbook_state.selected_outcome {
+                                state::OrderbookOutcome::Yes => 0,
+                                state::OrderbookOutcome::No => 1,
+                            };
+                            if let Some(market) = sorted_markets.get(market_idx)
+                                && let Some(token_id) = market
+                                    .clob_token_ids
+                                    .as_ref()
+                                    .and_then(|ids| ids.get(outcome_idx).cloned())
+                            {
+                                let is_active = !market.closed;
+                                app.orderbook_state.orderbook = None;
+                                drop(app);
+                                spawn_fetch_orderbook(Arc::clone(app_state), token_id, is_active);
+                            }
+                        }
+                    }
+                },
+                FocusedPanel::Trades => {
+                    if app.scroll.trades > 0 {
+                        app.scroll.trades -= 1;
+                    }
+                },
+                FocusedPanel::Logs => {
+                    if app.logs.scroll > 0 {
+                        app.logs.scroll -= 1;
+                    }
+                },
+            }
+        },
+        KeyCode::Down => {
+            // Handle favorites tab navigation
+            if app.main_tab == MainTab::Favorites {
+                let visible_height = 20; // Approximate visible rows
+                app.favorites_state.move_down(visible_height);
+                return;
+            }
+            // Handle yield tab navigation
+            if app.main_tab == MainTab::Yield {
+                // Calculate visible height (approximate)
+                let visible_height = 20; // Approximate visible rows
+                app.yield_state.move_down(visible_height);
+                // Fetch event if not in cache
+                if let Some(opp) = app.yield_state.selected_opportunity() {
+                    let slug = opp.event_slug.clone();
+                    if app.get_cached_event(&slug).is_none() {
+                        spawn_fetch_event_for_cache(Arc::clone(app_state), slug);
+                    }
+                }
+                return;
+            }
+            match app.navigation.focused_panel {
+                FocusedPanel::Header => {
+                    // Header doesn't scroll, but we can allow it for consistency
+                },
+                FocusedPanel::EventsList => {
+                    app.move_down();
+                    note_selection_change(&mut app, keys);
+                    fetch_more_events_if_needed(app_state, &mut app);
+                },
+                FocusedPanel::EventDetails => {
+                    if app.scroll.event_details < event_details_max_scroll(&app) {
+                        app.scroll.event_details += 1;
+                    }
+                },
+                FocusedPanel::Markets => {
+                    // Move selected market down and fetch orderbook
+                    // Extract data we need before modifying app state (use sorted markets)
+                    let market_info = app.selected_event().and_then(|event| {
+                        let mut sorted_markets: Vec<_> = event.markets.iter().collect();
+                        sorted_markets.sort_by_key(|m| m.closed);
+                        let max_index = sorted_markets.len().saturating_sub(1);
+                        let current_idx = app.orderbook_state.selected_market_index;
+                        if current_idx < max_index {
+                            let new_idx = current_idx + 1;
+                            let outcome_idx = match app.orderbook_state.selected_outcome {
+                                state::OrderbookOutcome::Yes => 0,
+                                state::OrderbookOutcome::No => 1,
+                            };
+                            let token_and_active = sorted_markets.get(new_idx).and_then(|market| {
+                                market.clob_token_ids.as_ref().and_then(|ids| {
+                                    ids.get(outcome_idx).cloned().map(|id| (id, !market.closed))
+                                })
+                            });
+                            Some((new_idx, token_and_active))
+                        } else {
+                            None
+                        }
+                    });
+
+                    if let Some((new_idx, token_and_active)) = market_info {
+                        app.orderbook_state.selected_market_index = new_idx;
+                        // Adjust scroll if needed to keep selection visible
+                        let visible_height: usize = 5; // Markets panel height
+                        if new_idx >= app.scroll.markets + visible_height {
+                            app.scroll.markets = new_idx.saturating_sub(visible_height - 1);
+                        }
+                        // Fetch orderbook for new selection
+                        if let Some((token_id, is_active)) = token_and_active {
+                            app.orderbook_state.orderbook = None;
+                            drop(app);
+                            spawn_fetch_orderbook(Arc::clone(app_state), token_id, is_active);
+                        }
+                    }
+                },
+                FocusedPanel::Trades => {
+                    let trades_len = if let Some(event) = app.selected_event() {
+                        app.get_trades(&event.slug).len()
+                    } else {
+                        0
+                    };
+                    // Move the selection; render keeps it inside the visible window
+                    if app.scroll.trades + 1 < trades_len {
+                        app.scroll.trades += 1;
+                    }
+                },
+                FocusedPanel::Logs => {
+                    // Calculate max scroll based on visible height (approximate)
+                    // The render function will clamp it to the exact visible height
+                    let visible_height: usize = 10; // Approximate, will be clamped in render
+                    let max_scroll = app.logs.visible_len().saturating_sub(visible_height.max(1));
+                    if app.logs.scroll < max_scroll {
+                        app.logs.scroll += 1;
+                    }
+                },
+            }
+        },
+        KeyCode::PageUp => jump_focused_panel(app_state, app, keys, PageJump::PageUp),
+        KeyCode::PageDown => jump_focused_panel(app_state, app, keys, PageJump::PageDown),
+        KeyCode::Home => jump_focused_panel(app_state, app, keys, PageJump::Home),
+        KeyCode::End => jump_focused_panel(app_state, app, keys, PageJump::End),
+        KeyCode::Enter => {
+            // Only handle Enter when EventsList panel is focused
+            if app.navigation.focused_panel == FocusedPanel::EventsList {
+                // Toggle watching the selected event
+                if let Some(event_slug) = app.selected_event_slug() {
+                    if app.is_watching(&event_slug) {
+                        // Stop watching
+                        app.stop_watching(&event_slug);
+                    } else {
+                        spawn_watch_event(Arc::clone(app_state), &mut app, event_slug);
+                    }
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Fetch the next page of events once the selection nears the end (infinite scroll)
+fn fetch_more_events_if_needed(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    app: &mut TrendingAppState,
+) {
+    if app.should_fetch_more() {
+        let app_state_clone = Arc::clone(app_state);
+        let gamma_client_clone = GammaClient::new();
+        let current_filter = app.event_filter;
+        let current_sort = app.event_sort_by;
+        let current_limit = app.pagination.current_limit;
+
+        // Set fetching flag to prevent duplicate requests
+        app.pagination.is_fetching_more = true;
+
+        // Fetch 50 more events
+        let new_limit = current_limit + 50;
+        log_info!("Fetching more events (limit: {})", new_limit);
+
+        tokio::spawn(async move {
+            match fetch_events_for_filter(
+                &gamma_client_clone,
+                current_filter,
+                current_sort,
+                new_limit,
+            )
+            .await
+            {
+                Ok(mut new_events) => {
+                    // Remove duplicates by comparing slugs
+                    let existing_slugs: std::collections::HashSet<_> = {
+                        let app = app_state_clone.lock().await;
+                        app.events.iter().map(|e| e.slug.clone()).collect()
+                    };
+
+                    new_events.retain(|e| !existing_slugs.contains(&e.slug));
+
+                    if !new_events.is_empty() {
+                        log_info!("Fetched {} new events", new_events.len());
+                        let mut app = app_state_clone.lock().await;
+                        app.events.append(&mut new_events);
+                        app.pagination.current_limit = new_limit;
+                    } else {
+                        log_info!("No new events to add (already have all events)");
+                    }
+
+                    let mut app = app_state_clone.lock().await;
+                    app.pagination.is_fetching_more = false;
+                },
+                Err(e) => {
+                    log_error!("Failed to fetch more events: {}", e);
+                    let mut app = app_state_clone.lock().await;
+                    app.pagination.is_fetching_more = false;
+                    app.flash(fetch_error_message("Failed to load more events", &e));
+                },
+            }
+        });
+    }
+}
+
+/// Furthest the Event Details panel scrolls for the selected event
+fn event_details_max_scroll(app: &TrendingAppState) -> usize {
+    let Some(event) = app.selected_event() else {
+        return 0;
+    };
+    // Base lines: Title, Slug, Event ID, Status, Estimated End, Total Volume
+    let mut total_lines = 6;
+
+    // Calculate wrapped tags lines
+    if !event.tags.is_empty() {
+        let tag_labels: Vec<String> = event
+            .tags
+            .iter()
+            .map(|tag| truncate(&tag.label, 20))
+            .collect();
+        let tags_text = tag_labels.join(", ");
+        // Approximate available width (will be calculated more accurately in render)
+        // Assume ~60 chars available for tags content
+        let tags_content_width = 60;
+        if tags_text.len() > tags_content_width {
+            // Tags wrap - calculate how many lines
+            let wrapped_lines = tags_text.len().div_ceil(tags_content_width);
+            total_lines += wrapped_lines;
+        } else {
+            total_lines += 1; // Single line for tags
+        }
+    }
+
+    // Get visible height from the actual area (approximate)
+    let visible_height: usize = EVENT_DETAILS_ROWS;
+    total_lines.saturating_sub(visible_height.max(1))
+}
+
+/// Rows the Event Details panel shows at once (approximate)
+const EVENT_DETAILS_ROWS: usize = 6;
+/// Rows the Logs panel shows at once (approximate, clamped in render)
+const LOGS_ROWS: usize = 10;
+/// Rows the Markets panel shows at once
+const MARKETS_ROWS: usize = 5;
+
+/// PageUp/PageDown/Home/End in the focused panel of the current tab (releases the
+/// lock when spawning)
+fn jump_focused_panel(
+    app_state: &Arc<TokioMutex<TrendingAppState>>,
+    mut app: MutexGuard<'_, TrendingAppState>,
+    keys: &mut KeyState,
+    jump: PageJump,
+) {
+    if app.main_tab == MainTab::Favorites {
+        let favorites = &mut app.favorites_state;
+        let target = jump.target(favorites.selected_index, favorites.events.len(), PAGE_ROWS);
+        favorites.select_index(target, PAGE_ROWS);
+        return;
+    }
+    if app.main_tab == MainTab::Yield {
+        let len = app.yield_state.filtered_opportunities().len();
+        let target = jump.target(app.yield_state.selected_index, len, PAGE_ROWS);
+        app.yield_state.select_index(target, PAGE_ROWS);
+        // Fetch event if not in cache
+        if let Some(opp) = app.yield_state.selected_opportunity() {
+            let slug = opp.event_slug.clone();
+            if app.get_cached_event(&slug).is_none() {
+                spawn_fetch_event_for_cache(Arc::clone(app_state), slug);
+            }
+        }
+        return;
+    }
+    match app.navigation.focused_panel {
+        FocusedPanel::Header => {},
+        FocusedPanel::EventsList => {
+            let len = app.filtered_events().len();
+            let target = jump.target(app.navigation.selected_index, len, PAGE_ROWS);
+            app.select_event_index(target);
+            note_selection_change(&mut app, keys);
+            fetch_more_events_if_needed(app_state, &mut app);
+        },
+        FocusedPanel::EventDetails => {
+            let max_scroll = event_details_max_scroll(&app);
+            app.scroll.event_details =
+                jump.target(app.scroll.event_details, max_scroll + 1, EVENT_DETAILS_ROWS);
+        },
+        FocusedPanel::Markets => {
+            let delta = match jump {
+                PageJump::PageUp => -(MARKETS_ROWS as isize),
+                PageJump::PageDown => MARKETS_ROWS as isize,
+                PageJump::Home => isize::MIN,
+                PageJump::End => isize::MAX,
+            };
+            step_orderbook_market(app_state, app, delta);
+        },
+        FocusedPanel::Trades => {
+            let trades_len = app
+                .selected_event()
+                .map_or(0, |event| app.get_trades(&event.slug).len());
+            app.scroll.trades = jump.target(app.scroll.trades, trades_len, PAGE_ROWS);
+        },
+        FocusedPanel::Logs => {
+            let max_scroll = app.logs.visible_len().saturating_sub(LOGS_ROWS);
+            app.logs.scroll = jump.target(app.logs.scroll, max_scroll + 1, LOGS_ROWS);
+        },
+    }
+}
+
+/// Write the current working set to a timestamped file in the working directory
+fn export_working_set(app: &TrendingAppState) {
+    let mut settings = crate::settings::Settings::load();
+    settings.watchlist = app.watched_event_slugs();
+    for event in &app.favorites_state.events {
+        if !settings.favorites.contains(&event.slug) {
+            settings.favorites.push(event.slug.clone());
+        }
+    }
+
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let filename = format!("polymarket_state_{}.json", timestamp);
+    match WorkingSet::new(settings).save(std::path::Path::new(&filename)) {
+        Ok(()) => log_info!("Working set exported to: {}", filename),
+        Err(e) => log_error!("Failed to export working set: {}", e),
+    }
+}
+
+/// Copy text to the system clipboard using the platform's clipboard tool
+fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    use std::{
+        io::Write,
+        process::{Command, Stdio},
+    };
+
+    #[cfg(target_os = "macos")]
+    let tools: &[(&str, &[&str])] = &[("pbcopy", &[])];
+    #[cfg(target_os = "windows")]
+    let tools: &[(&str, &[&str])] = &[("clip", &[])];
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let tools: &[(&str, &[&str])] = &[
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+    ];
+
+    for (program, args) in tools {
+        let Ok(mut child) = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        // Dropping stdin closes the pipe so the tool can finish
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(text.as_bytes())
+                .map_err(|e| e.to_string())?;
+        }
+        if child.wait().map_err(|e| e.to_string())?.success() {
+            return Ok(());
+        }
+    }
+    Err("no clipboard tool found".to_string())
+}
+
+/// Open a URL in the default browser
+fn open_in_browser(url: &str) {
+    #[cfg(target_os = "macos")]
+    let _ = std::process::Command::new("open").arg(url).spawn();
+    #[cfg(target_os = "linux")]
+    let _ = std::process::Command::new("xdg-open").arg(url).spawn();
+    #[cfg(target_os = "windows")]
+    let _ = std::process::Command::new("cmd")
+        .args(["/C", "start", url])
+        .spawn();
+}
+
+/// Open the auth config file in the system editor
+fn open_config_file() {
+    let config_path = crate::auth::AuthConfig::config_path();
+    let config_path_str = config_path.display().to_string();
+
+    // Use system open command (opens in GUI editor, not terminal)
+    // We can't use terminal editors like vim/nvim while the TUI is running
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("open")
+        .arg("-t") // Open in default text editor (usually TextEdit)
+        .arg(&config_path_str)
+        .spawn();
+
+    #[cfg(target_os = "linux")]
+    let result = std::process::Command::new("xdg-open")
+        .arg(&config_path_str)
+        .spawn();
+
+    #[cfg(target_os = "windows")]
+    let result = std::process::Command::new("cmd")
+        .args(["/C", "start", "notepad", &config_path_str])
+        .spawn();
+
+    match result {
+        Ok(_) => log_info!("Opened config file: {}", config_path_str),
+        Err(e) => log_error!("Failed to open config file: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            auth::AuthProfiles,
+            trending_tui::{
+                fetch::fetch_usdc_balance,
+                state::{EventFilter, EventSortBy, MainTab, OutcomeInfo, TradeSide},
+                test_fixtures::{app_with_events, fixture_events, fixtures, loaded_app},
+            },
+        },
+        crossterm::event::KeyModifiers,
+    };
+
+    #[test]
+    fn test_quick_amount_digit_keys() {
+        let app_state = Arc::new(TokioMutex::new(loaded_app()));
+        let mut keys = KeyState::default();
+        let press = |code: KeyCode, keys: &mut KeyState| {
+            let app = app_state.try_lock().expect("Should lock app state");
+            handle_key(
+                &app_state,
+                app,
+                KeyEvent::new(code, KeyModifiers::NONE),
+                keys,
+            );
+        };
+        app_state
+            .try_lock()
+            .expect("Should lock app state")
+            .open_trade_popup(
+                "Will Candidate A win?".to_string(),
+                vec![OutcomeInfo {
+                    name: "Yes".to_string(),
+                    token_id: "1002".to_string(),
+                    price: 0.5,
+                }],
+                0,
+            );
+        let shares = || {
+            app_state
+                .try_lock()
+                .expect("Should lock app state")
+                .trade_form
+                .as_ref()
+                .map(|f| f.shares.clone())
+        };
+
+        // The popup opens on the empty shares field, where digits pick presets
+        press(KeyCode::Char('1'), &mut keys);
+        assert_eq!(shares().as_deref(), Some("20"), "1 = $10 at 50¢");
+        press(KeyCode::Char('2'), &mut keys);
+        assert_eq!(shares().as_deref(), Some("100"), "2 = $50 at 50¢");
+
+        // Once the size is edited, digits type into it and F-keys pick presets
+        press(KeyCode::Backspace, &mut keys);
+        press(KeyCode::Char('2'), &mut keys);
+        assert_eq!(shares().as_deref(), Some("102"));
+        press(KeyCode::F(1), &mut keys);
+        assert_eq!(shares().as_deref(), Some("20"), "F1 = $10 at 50¢");
+        press(KeyCode::Char('5'), &mut keys);
+        assert_eq!(shares().as_deref(), Some("205"));
+
+        // Away from the price and size fields, the digit keys pick presets
+        app_state
+            .try_lock()
+            .expect("Should lock app state")
+            .trade_form
+            .as_mut()
+            .expect("Should open trade form")
+            .active_field = state::TradeField::OrderType;
+        press(KeyCode::Char('2'), &mut keys);
+        assert_eq!(shares().as_deref(), Some("100"), "2 = $50 at 50¢");
+    }
+
+    #[test]
+    fn test_text_entry_keys_are_literal() {
+        // Every one of these is a shortcut on the main screen
+        let typed = "qlpbsroefctS?/12345";
+
+        for context in [
+            InputContext::ApiSearch,
+            InputContext::LocalFilter,
+            InputContext::YieldSearch,
+            InputContext::YieldFilter,
+        ] {
+            let mut app = loaded_app();
+            match context {
+                InputContext::ApiSearch => app.enter_search_mode(),
+                InputContext::LocalFilter => app.enter_local_filter_mode(),
+                InputContext::YieldSearch => {
+                    app.main_tab = MainTab::Yield;
+                    app.yield_state.enter_search_mode();
+                },
+                _ => {
+                    app.main_tab = MainTab::Yield;
+                    app.yield_state.enter_filter_mode();
+                },
+            }
+            let show_logs = app.show_logs;
+            let app_state = Arc::new(TokioMutex::new(app));
+            let mut keys = KeyState::default();
+
+            let press = |code: KeyCode, keys: &mut KeyState| {
+                let app = app_state.try_lock().expect("Should lock app state");
+                handle_key(
+                    &app_state,
+                    app,
+                    KeyEvent::new(code, KeyModifiers::NONE),
+                    keys,
+                );
+            };
+            for c in typed.chars().chain(['x']) {
+                press(KeyCode::Char(c), &mut keys);
+            }
+            press(KeyCode::Backspace, &mut keys);
+
+            let app = app_state.try_lock().expect("Should lock app state");
+            let query = match context {
+                InputContext::YieldSearch => &app.yield_state.search_query,
+                InputContext::YieldFilter => &app.yield_state.filter_query,
+                _ => &app.search.query,
+            };
+            assert_eq!(query, typed, "{:?}", context);
+            assert_eq!(app.input_context(), context, "{:?}", context);
+            assert!(!app.should_quit && !app.has_popup());
+            assert_eq!(app.show_logs, show_logs);
+        }
+    }
+
+    #[test]
+    fn test_fast_scrolling_debounces_price_fetches() {
+        let mut app = loaded_app();
+        // Keep infinite scroll from fetching more events
+        app.pagination.is_fetching_more = true;
+        let app_state = Arc::new(TokioMutex::new(app));
+        let mut keys = KeyState::default();
+        for code in [KeyCode::Down, KeyCode::Up, KeyCode::Down] {
+            let app = app_state.try_lock().expect("Should lock app state");
+            handle_key(
+                &app_state,
+                app,
+                KeyEvent::new(code, KeyModifiers::NONE),
+                &mut keys,
+            );
+        }
+
+        // Each selection change only restarts the debounce and supersedes old requests
+        let mut app = app_state.try_lock().expect("Should lock app state");
+        assert!(keys.selection_debounce.is_some());
+        assert_eq!(
+            keys.last_selected_event_slug.as_deref(),
+            app.selected_event().map(|event| event.slug.as_str())
+        );
+        assert_eq!(app.price_request_generation, 3);
+
+        // A response for an earlier selection is dropped
+        let prices = std::collections::HashMap::from([("asset".to_string(), 0.42)]);
+        assert!(!app.apply_market_prices(2, prices.clone()));
+        assert!(!app.market_prices.contains_key("asset"));
+
+        let generation = app.next_price_request();
+        assert!(app.apply_market_prices(generation, prices));
+        assert_eq!(app.market_prices.get("asset"), Some(&0.42));
+    }
+
+    #[test]
+    fn test_page_jump_keys() {
+        assert_eq!(PageJump::PageDown.target(0, 50, PAGE_ROWS), 20);
+        assert_eq!(PageJump::PageDown.target(40, 50, PAGE_ROWS), 49);
+        assert_eq!(PageJump::PageUp.target(10, 50, PAGE_ROWS), 0);
+        assert_eq!(PageJump::End.target(3, 50, PAGE_ROWS), 49);
+        assert_eq!(PageJump::Home.target(3, 50, PAGE_ROWS), 0);
+        assert_eq!(PageJump::End.target(0, 0, PAGE_ROWS), 0);
+
+        let events = fixture_events();
+        let last = events.len() - 1;
+        let mut app = app_with_events(events);
+        // More events than were loaded, so only End near the bottom fetches more
+        app.pagination.current_limit = last + 2;
+        let app_state = Arc::new(TokioMutex::new(app));
+        let mut keys = KeyState::default();
+        let mut press = |code: KeyCode| {
+            let app = app_state.try_lock().expect("Should lock app state");
+            handle_key(
+                &app_state,
+                app,
+                KeyEvent::new(code, KeyModifiers::NONE),
+                &mut keys,
+            );
+            let app = app_state.try_lock().expect("Should lock app state");
+            app.navigation.selected_index
+        };
+
+        assert_eq!(press(KeyCode::End), last);
+        assert_eq!(press(KeyCode::PageDown), last);
+        assert_eq!(press(KeyCode::Home), 0);
+        assert_eq!(press(KeyCode::PageDown), last.min(PAGE_ROWS));
+        assert!(!app_state.try_lock().unwrap().pagination.is_fetching_more);
+
+        // A full page was loaded: End starts the infinite-scroll fetch. The runtime is
+        // never driven, so the spawned request doesn't run.
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Should build runtime");
+        let _guard = runtime.enter();
+        app_state.try_lock().unwrap().pagination.current_limit = last + 1;
+        press(KeyCode::Home);
+        assert_eq!(press(KeyCode::End), last);
+        assert!(app_state.try_lock().unwrap().pagination.is_fetching_more);
+    }
+
+    #[test]
+    fn test_paper_trading() {
+        let app_state = Arc::new(TokioMutex::new(loaded_app()));
+        let mut keys = KeyState::default();
+        let press = |code: KeyCode, keys: &mut KeyState| {
+            let app = app_state.try_lock().expect("Should lock app state");
+            handle_key(
+                &app_state,
+                app,
+                KeyEvent::new(code, KeyModifiers::NONE),
+                keys,
+            );
+        };
+        let open_form = |side: TradeSide| {
+            let mut app = app_state.try_lock().expect("Should lock app state");
+            app.open_trade_popup(
+                "Will Candidate A win?".to_string(),
+                vec![OutcomeInfo {
+                    name: "Yes".to_string(),
+                    token_id: "1002".to_string(),
+                    price: 0.63,
+                }],
+                0,
+            );
+            let form = app.trade_form.as_mut().expect("Should open trade form");
+            form.side = side;
+            form.orderbook = fixtures()
+                .load_or_default::<Vec<polymarket_api::clob::Orderbook>>("clob/books")
+                .expect("Should load orderbook fixtures")
+                .into_iter()
+                .find(|b| b.asset_id.as_deref() == Some("1002"));
+        };
+
+        press(KeyCode::Char('T'), &mut keys);
+        assert!(app_state.try_lock().expect("Should lock").paper_mode);
+
+        // Market buy fills at the book's average price, without logging in
+        open_form(TradeSide::Buy);
+        let estimate = {
+            let mut app = app_state.try_lock().expect("Should lock app state");
+            let form = app.trade_form.as_mut().expect("Should open trade form");
+            form.toggle_order_type();
+            form.amount = "100".to_string();
+            form.fill_estimate().expect("Should estimate fill")
+        };
+        press(KeyCode::Enter, &mut keys);
+        {
+            let app = app_state.try_lock().expect("Should lock app state");
+            assert_eq!(app.popup, None, "Filled paper order closes the popup");
+            let positions = app.paper_ledger.positions();
+            assert_eq!(positions.len(), 1);
+            assert!((positions[0].shares - estimate.shares).abs() < 1e-9);
+            assert!((positions[0].avg_price - estimate.avg_price).abs() < 1e-9);
+        }
+
+        // Selling more than the paper position is rejected on the form
+        open_form(TradeSide::Sell);
+        {
+            let mut app = app_state.try_lock().expect("Should lock app state");
+            let form = app.trade_form.as_mut().expect("Should open trade form");
+            form.shares = "100000".to_string();
+            form.set_limit_price(0.6);
+        }
+        press(KeyCode::Enter, &mut keys);
+        {
+            let app = app_state.try_lock().expect("Should lock app state");
+            let form = app.trade_form.as_ref().expect("Form should stay open");
+            assert!(
+                form.error_message
+                    .as_deref()
+                    .is_some_and(|e| e.contains("paper shares"))
+            );
+            assert_eq!(app.paper_ledger.trades.len(), 1);
+        }
+
+        // A limit sell above the best bid doesn't cross, and paper orders don't rest
+        {
+            let mut app = app_state.try_lock().expect("Should lock app state");
+            let shares = app.paper_ledger.held_shares("1002");
+            let form = app.trade_form.as_mut().expect("Should open trade form");
+            form.shares = format!("{}", shares);
+            form.set_limit_price(0.9);
+        }
+        press(KeyCode::Enter, &mut keys);
+        {
+            let app = app_state.try_lock().expect("Should lock app state");
+            let form = app.trade_form.as_ref().expect("Form should stay open");
+            assert!(
+                form.error_message
+                    .as_deref()
+                    .is_some_and(|e| e.contains("doesn't cross"))
+            );
+            assert_eq!(app.paper_ledger.trades.len(), 1);
+        }
+
+        // A crossing limit sell fills at the bid (61¢), not at its 60¢ limit
+        app_state
+            .try_lock()
+            .expect("Should lock app state")
+            .trade_form
+            .as_mut()
+            .expect("Should open trade form")
+            .set_limit_price(0.6);
+        press(KeyCode::Enter, &mut keys);
+        let app = app_state.try_lock().expect("Should lock app state");
+        assert!(app.paper_ledger.positions().is_empty());
+        let expected = estimate.shares * (0.61 - estimate.avg_price);
+        assert!((app.paper_ledger.realized_pnl() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_live_buy_with_auth_json_credentials() {
+        // auth.json credentials with a signing key (private key 1, not a real account),
+        // so nothing comes from the environment
+        let profiles = AuthProfiles::from_json(
+            r#"{
+                "api_key": "key",
+                "secret": "c2VjcmV0",
+                "passphrase": "pass",
+                "address": "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf",
+                "private_key": "0x0000000000000000000000000000000000000000000000000000000000000001"
+            }"#,
+        )
+        .expect("Should parse auth.json");
+        let config = profiles
+            .active_config()
+            .cloned()
+            .expect("Should have a profile");
+        let mut app = loaded_app();
+        app.auth_state.is_authenticated = true;
+        app.auth_state.address = Some(config.address.clone());
+        app.auth_state.config = Some(config);
+
+        // The balance comes from the logged-in account's client
+        let mut client = app.clob_client().expect("Should build client");
+        assert!(client.can_trade());
+        client.set_fixtures(fixtures());
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Should build runtime");
+        let balance = runtime
+            .block_on(fetch_usdc_balance(&client))
+            .expect("Should fetch balance");
+        assert_eq!(balance, 250.0);
+        app.auth_state.balance = Some(balance);
+
+        app.open_trade_popup(
+            "Will Candidate A win?".to_string(),
+            vec![OutcomeInfo {
+                name: "Yes".to_string(),
+                token_id: "1002".to_string(),
+                price: 0.63,
+            }],
+            0,
+        );
+        let form = app.trade_form.as_mut().expect("Should open trade form");
+        form.shares = "10".to_string();
+        form.set_limit_price(0.6);
+
+        // The buy passes the balance check and is submitted. The runtime is never
+        // driven, so the spawned order isn't posted.
+        let _guard = runtime.enter();
+        let app_state = Arc::new(TokioMutex::new(app));
+        handle_key(
+            &app_state,
+            app_state.try_lock().expect("Should lock app state"),
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+            &mut KeyState::default(),
+        );
+        let app = app_state.try_lock().expect("Should lock app state");
+        let form = app.trade_form.as_ref().expect("Form should stay open");
+        assert_eq!(form.error_message, None);
+        assert!(form.is_submitting);
+    }
+
+    #[test]
+    fn test_goto_favorite_and_back() {
+        let app_state = Arc::new(TokioMutex::new(loaded_app()));
+        let mut keys = KeyState::default();
+        let press = |keys: &mut KeyState| {
+            let app = app_state.try_lock().expect("Should lock app state");
+            handle_key(
+                &app_state,
+                app,
+                KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE),
+                keys,
+            );
+        };
+
+        // Nothing favorited yet: stay on the events list
+        press(&mut keys);
+        assert_eq!(
+            app_state.try_lock().expect("Should lock").main_tab,
+            MainTab::Trending
+        );
+
+        {
+            let mut app = app_state.try_lock().expect("Should lock app state");
+            let mut favorites = fixture_events();
+            favorites.reverse();
+            app.favorites_state.events = favorites;
+            app.select_event_index(1);
+            assert_eq!(
+                app.selected_event_slug().as_deref(),
+                Some("example-championship-final")
+            );
+        }
+        press(&mut keys);
+        {
+            let mut app = app_state.try_lock().expect("Should lock app state");
+            assert_eq!(app.main_tab, MainTab::Favorites);
+            assert_eq!(app.favorites_state.selected_index, 0);
+            app.favorites_state.select_index(1, 20);
+        }
+
+        // Back to the election event's row in the events list
+        press(&mut keys);
+        let app = app_state.try_lock().expect("Should lock app state");
+        assert_eq!(app.main_tab, MainTab::Trending);
+        assert_eq!(
+            app.selected_event_slug().as_deref(),
+            Some("example-election-winner")
+        );
+    }
+
+    #[test]
+    fn test_event_sort_key_refetches_events() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Should build runtime");
+        let _guard = runtime.enter();
+        // The new sort is saved to a temporary settings file, not the user's
+        let dir = std::env::temp_dir().join(format!("polymarket-sort-key-{}", std::process::id()));
+        let path = dir.join("settings.json");
+        let mut app = loaded_app();
+        app.settings_path = Some(path.clone());
+        let app_state = Arc::new(TokioMutex::new(app));
+        let mut keys = KeyState::default();
+        let app = app_state.try_lock().expect("Should lock app state");
+        assert!(app.events_cache.contains_key(&EventFilter::Trending));
+        handle_key(
+            &app_state,
+            app,
+            KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE),
+            &mut keys,
+        );
+        let app = app_state.try_lock().expect("Should lock app state");
+        assert_eq!(app.event_sort_by, EventSortBy::VolumeTotal);
+        assert!(app.pagination.is_fetching_more);
+        assert!(!app.events_cache.contains_key(&EventFilter::Trending));
+        let saved = crate::settings::Settings::read_from(&path).expect("Should read settings");
+        assert_eq!(saved.event_sort, EventSortBy::VolumeTotal);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
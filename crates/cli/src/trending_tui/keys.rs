@@ -7,6 +7,7 @@
 //! - **Header**: ←/→ to switch filters
 //! - **EventsList**: / for API search, f for local filter, r to refresh, Enter to watch/unwatch
 //! - **Markets**: r to refresh prices
+//! - **Trades**: c to copy the selected trade into the Trade popup
 //! - **All panels**: ↑/↓ to scroll, Tab to switch panels, l to toggle logs, q to quit
 
 use super::state::FocusedPanel;
@@ -19,7 +20,7 @@ impl FocusedPanel {
             FocusedPanel::EventsList => "/: Search | f: Filter | r: Refresh | Enter: Watch",
             FocusedPanel::EventDetails => "o: Open URL | ↑/↓: Scroll",
             FocusedPanel::Markets => "r: Refresh | ↑/↓: Scroll",
-            FocusedPanel::Trades => "↑/↓: Scroll",
+            FocusedPanel::Trades => "↑/↓: Select | c: Copy trade",
             FocusedPanel::Logs => "↑/↓: Scroll",
         }
     }
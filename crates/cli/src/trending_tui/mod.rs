@@ -2,11 +2,16 @@
 
 mod event_loop;
 mod fetch;
+mod input;
 mod keys;
 mod layout;
 #[macro_use]
-mod logging;
+pub(crate) mod logging;
 mod render;
+#[cfg(test)]
+mod snapshot_tests;
 pub mod state;
+#[cfg(test)]
+mod test_fixtures;
 
 pub use {event_loop::run_trending_tui, state::TrendingAppState};
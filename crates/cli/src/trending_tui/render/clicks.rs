@@ -1,71 +1,109 @@
 //! Click detection for the trending TUI
 
-use {crate::trending_tui::state::TrendingAppState, ratatui::layout::Rect};
+use {
+    super::header::{STATUS_DOT, TAB_DIVIDER, login_button_label, tab_titles},
+    crate::trending_tui::state::{AppTab, TrendingAppState},
+    ratatui::{layout::Rect, text::Line},
+    std::ops::Range,
+};
 
-/// Unified tab enum for click detection (combines MainTab and EventFilter)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ClickedTab {
-    Trending, // Displayed as "Events"
-    Favorites,
-    Breaking,
-    Yield,
+/// Column where the login/profile button starts (the status dot follows it)
+fn login_button_start(size: Rect, app: &TrendingAppState) -> u16 {
+    let button_width = Line::from(login_button_label(app)).width() + Line::from(STATUS_DOT).width();
+    size.width.saturating_sub(button_width as u16)
 }
 
 /// Check if the login button was clicked (top right)
 /// Returns true if click is on the login button area
 pub fn is_login_button_clicked(x: u16, y: u16, size: Rect, app: &TrendingAppState) -> bool {
     // Login button is on the first line (y = 0) and at the right edge
-    if y != 0 {
-        return false;
-    }
-
-    // Calculate button width dynamically based on auth state
-    let button_width = if app.auth_state.is_authenticated {
-        let name = app.auth_state.display_name();
-        (name.len() + 4) as u16 // "[ " + name + " ]"
-    } else {
-        10 // "[ Login ]"
-    };
+    y == 0 && x >= login_button_start(size, app)
+}
 
-    let login_button_start = size.width.saturating_sub(button_width);
-    x >= login_button_start
+/// Columns covered by each header tab, in `AppTab::ALL` order
+///
+/// The Tabs widget pads each title with a space on both sides and separates them
+/// with `TAB_DIVIDER`:
+/// " Events [1]   Favorites [2]   Breaking [3]   Yield [4]"
+/// A click on a title or its padding selects the tab.
+pub fn tab_bounds() -> Vec<(AppTab, Range<u16>)> {
+    let divider = Line::from(TAB_DIVIDER).width() as u16;
+    let mut tab_start: u16 = 0;
+    AppTab::ALL
+        .into_iter()
+        .zip(tab_titles())
+        .map(|(tab, title)| {
+            let tab_end = tab_start + title.width() as u16 + 2; // padding on both sides
+            let bounds = tab_start..tab_end;
+            tab_start = tab_end + divider;
+            (tab, bounds)
+        })
+        .collect()
 }
 
 /// Tabs are rendered on the first line (y = 0)
-/// Returns which tab was clicked: Trending [1], Breaking [2], New [3], Yield [4]
-pub fn get_clicked_tab(x: u16, y: u16, size: Rect, app: &TrendingAppState) -> Option<ClickedTab> {
-    // Tabs are on the first line (y = 0)
-    if y != 0 {
+/// Returns which tab was clicked, following the `AppTab::ALL` order used by the header
+pub fn get_clicked_tab(x: u16, y: u16, size: Rect, app: &TrendingAppState) -> Option<AppTab> {
+    // Tabs are on the first line (y = 0), and the login button covers them on the right
+    if y != 0 || is_login_button_clicked(x, y, size, app) {
         return None;
     }
+    tab_bounds()
+        .into_iter()
+        .find(|(_, bounds)| bounds.contains(&x))
+        .map(|(tab, _)| tab)
+}
 
-    // Don't match tabs if clicking on login button area (right side)
-    // Calculate button width dynamically based on auth state
-    let button_width = if app.auth_state.is_authenticated {
-        let name = app.auth_state.display_name();
-        (name.len() + 4) as u16 // "[ " + name + " ]"
-    } else {
-        10 // "[ Login ]"
-    };
-    let login_button_start = size.width.saturating_sub(button_width);
-    if x >= login_button_start {
-        return None;
-    }
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::trending_tui::test_fixtures::loaded_app};
+
+    const WIDTH: u16 = 120;
+    const HEIGHT: u16 = 40;
+
+    #[test]
+    fn test_tab_click_positions() {
+        // " Events [1]   Favorites [2]   Breaking [3]   Yield [4]"
+        let bounds: Vec<_> = tab_bounds().into_iter().map(|(_, b)| b).collect();
+        assert_eq!(bounds, vec![0..12, 13..28, 29..43, 44..55]);
+
+        let mut app = loaded_app();
+        let size = ratatui::layout::Rect::new(0, 0, WIDTH, HEIGHT);
+        for (x, expected) in [
+            (0, Some(AppTab::Events)),
+            (11, Some(AppTab::Events)),
+            (12, None), // divider
+            (13, Some(AppTab::Favorites)),
+            (30, Some(AppTab::Breaking)),
+            (54, Some(AppTab::Yield)),
+            (55, None),
+        ] {
+            assert_eq!(
+                get_clicked_tab(x, 0, size, &app),
+                expected,
+                "Click at {}",
+                x
+            );
+        }
+        assert_eq!(get_clicked_tab(0, 1, size, &app), None);
+
+        // "[ Login ] •" takes the last 11 columns
+        assert!(is_login_button_clicked(WIDTH - 11, 0, size, &app));
+        assert!(!is_login_button_clicked(WIDTH - 12, 0, size, &app));
+
+        // On a narrow terminal the button wins over a tab it covers
+        let narrow = ratatui::layout::Rect::new(0, 0, 40, HEIGHT);
+        assert_eq!(get_clicked_tab(30, 0, narrow, &app), None);
+        assert!(is_login_button_clicked(29, 0, narrow, &app));
+        assert_eq!(
+            get_clicked_tab(13, 0, narrow, &app),
+            Some(AppTab::Favorites)
+        );
 
-    // Actual rendered output (Tabs widget adds leading space and " " divider):
-    // " Events [1] Favorites [2] Breaking [3] Yield [4]"
-    // 0         1         2         3         4         5
-    // 012345678901234567890123456789012345678901234567890
-    //  Events [1] Favorites [2] Breaking [3] Yield [4]
-    // Positions: 1-10 = Events, 12-25 = Favorites, 27-38 = Breaking, 40-49 = Yield
-    if x <= 10 {
-        return Some(ClickedTab::Trending);
-    } else if (12..26).contains(&x) {
-        return Some(ClickedTab::Favorites);
-    } else if (27..39).contains(&x) {
-        return Some(ClickedTab::Breaking);
-    } else if (40..50).contains(&x) {
-        return Some(ClickedTab::Yield);
+        // The profile button grows with the user's name
+        app.auth_state.is_authenticated = true;
+        app.auth_state.username = Some("example-trader".to_string());
+        assert!(is_login_button_clicked(WIDTH - 20, 0, size, &app));
+        assert!(!is_login_button_clicked(WIDTH - 21, 0, size, &app));
     }
-    None
 }
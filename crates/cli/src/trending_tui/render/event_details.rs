@@ -1,8 +1,8 @@
 //! Event details panel rendering functions
 
 use {
-    super::utils::truncate,
-    crate::trending_tui::state::{FocusedPanel, TrendingAppState},
+    super::utils::{braille_sparkline, resolution_countdown, sum_volumes, truncate},
+    crate::trending_tui::state::{FocusedPanel, HISTORY_DAYS, TrendingAppState},
     chrono::{DateTime, Utc},
     polymarket_api::gamma::Event,
     ratatui::{
@@ -37,14 +37,15 @@ pub fn render_event_details(
             ("-".to_string(), "Trades")
         };
     // Calculate total volume from all markets (use 24hr volume, more reliable)
-    let total_volume: f64 = event
-        .markets
-        .iter()
-        .map(|m| m.volume_24hr.or(m.volume_total).unwrap_or(0.0))
-        .sum();
+    let total_volume = sum_volumes(
+        event
+            .markets
+            .iter()
+            .map(|m| m.volume_24hr.or(m.volume_total).unwrap_or(0.0)),
+    );
 
-    // Format end date if available
-    let end_date_str = event
+    // Recomputed on every draw so the countdown ticks while the TUI is open
+    let (end_date_str, end_date_style) = event
         .end_date
         .as_ref()
         .and_then(|date_str| {
@@ -53,23 +54,8 @@ pub fn render_event_details(
                 .ok()
                 .map(|dt| dt.with_timezone(&Utc))
         })
-        .map(|dt| {
-            // Format as relative time or absolute date
-            let now = Utc::now();
-            let duration = dt.signed_duration_since(now);
-            if duration.num_days() > 0 {
-                format!("{} days", duration.num_days())
-            } else if duration.num_hours() > 0 {
-                format!("{} hours", duration.num_hours())
-            } else if duration.num_minutes() > 0 {
-                format!("{} min", duration.num_minutes())
-            } else if duration.num_seconds() < 0 {
-                format!("Expired ({})", dt.format("%Y-%m-%d %H:%M UTC"))
-            } else {
-                format!("{}", dt.format("%Y-%m-%d %H:%M UTC"))
-            }
-        })
-        .unwrap_or_else(|| "N/A".to_string());
+        .map(|dt| resolution_countdown(dt, Utc::now(), event.closed))
+        .unwrap_or_else(|| ("N/A".to_string(), Style::default().fg(Color::Magenta)));
 
     // Build compact lines without blank lines (title is in panel header)
     let mut lines = vec![Line::from(vec![
@@ -124,7 +110,7 @@ pub fn render_event_details(
     ]));
     lines.push(Line::from(vec![
         Span::styled("Estimated End: ", Style::default().fg(Color::Yellow).bold()),
-        Span::styled(end_date_str, Style::default().fg(Color::Magenta)),
+        Span::styled(end_date_str, end_date_style),
     ]));
     // Format volume in short form
     let volume_str = if total_volume >= 1_000_000.0 {
@@ -163,6 +149,36 @@ pub fn render_event_details(
     ];
     lines.push(Line::from(trades_spans));
 
+    // Leading outcome's probability trend (fetched lazily on selection)
+    if let Some(history) = app.history.get(&event.slug)
+        && let (Some(&first), Some(&last)) = (history.points.first(), history.points.last())
+    {
+        let prefix = format!("Trend {}d: ", HISTORY_DAYS);
+        let change = format!(" {:.0}%→{:.0}% ", first * 100.0, last * 100.0);
+        let fixed_width = prefix.chars().count() + change.chars().count() + 2; // + borders
+        let chart_width = (area.width as usize)
+            .saturating_sub(fixed_width + 12) // keep room for the outcome label
+            .min(30);
+        let label_width = (area.width as usize).saturating_sub(fixed_width + chart_width);
+        let trend_color = if last >= first {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        lines.push(Line::from(vec![
+            Span::styled(prefix, Style::default().fg(Color::Yellow).bold()),
+            Span::styled(
+                braille_sparkline(&history.points, chart_width),
+                Style::default().fg(trend_color),
+            ),
+            Span::styled(change, Style::default().fg(trend_color)),
+            Span::styled(
+                truncate(&history.outcome, label_width),
+                Style::default().fg(Color::Gray),
+            ),
+        ]));
+    }
+
     // Add tags - may wrap to multiple lines
     if !event.tags.is_empty() {
         let tag_labels: Vec<String> = event
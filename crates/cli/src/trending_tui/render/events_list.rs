@@ -1,8 +1,13 @@
 //! Events list rendering for the trending TUI
 
 use {
-    super::utils::{event_has_yield, format_volume, truncate_to_width},
-    crate::trending_tui::state::{EventFilter, EventSortBy, FocusedPanel, TrendingAppState},
+    super::utils::{
+        event_has_yield, event_winner, format_volume, highlight_spans, sum_volumes, truncate,
+        truncate_to_width,
+    },
+    crate::trending_tui::state::{
+        EventFilter, EventSortBy, FocusedPanel, SearchMode, TrendingAppState, fuzzy_match,
+    },
     ratatui::{
         Frame,
         layout::Rect,
@@ -75,8 +80,13 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 Style::default().fg(Color::White)
             };
 
-            let markets_count = event.markets.len();
-            let markets_str = format!("{:>width$}", markets_count, width = max_markets_width);
+            // Events without tokens have no volume or prices to show
+            let has_markets = event.has_tradeable_markets();
+            let markets_str = if has_markets {
+                format!("{:>width$}", event.markets.len(), width = max_markets_width)
+            } else {
+                "(no markets)".to_string()
+            };
 
             // Show metric based on current sort option (or price change for Breaking tab)
             let (metric_str, metric_color) = if app.event_filter == EventFilter::Breaking {
@@ -97,25 +107,27 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 match app.event_sort_by {
                     EventSortBy::Volume24hr => {
                         // Calculate 24h volume from all markets
-                        let total_volume: f64 = event
-                            .markets
-                            .iter()
-                            .map(|m| m.volume_24hr.unwrap_or(0.0))
-                            .sum();
+                        let total_volume =
+                            sum_volumes(event.markets.iter().map(|m| m.volume_24hr.unwrap_or(0.0)));
                         (format_volume(total_volume), Color::Green)
                     },
                     EventSortBy::VolumeTotal => {
                         // Use event's total volume or sum from markets
                         let total_volume = event.volume.unwrap_or_else(|| {
-                            event
-                                .markets
-                                .iter()
-                                .map(|m| m.volume_total.unwrap_or(0.0))
-                                .sum()
+                            sum_volumes(event.markets.iter().map(|m| m.volume_total.unwrap_or(0.0)))
                         });
                         (format_volume(total_volume), Color::Green)
                     },
-                    EventSortBy::Liquidity | EventSortBy::Newest | EventSortBy::EndingSoon => {
+                    EventSortBy::Volume7d => {
+                        (format_volume(event.volume_1wk.unwrap_or(0.0)), Color::Green)
+                    },
+                    EventSortBy::Volume30d => {
+                        (format_volume(event.volume_1mo.unwrap_or(0.0)), Color::Green)
+                    },
+                    EventSortBy::Liquidity
+                    | EventSortBy::Newest
+                    | EventSortBy::StartDate
+                    | EventSortBy::EndingSoon => {
                         // Show liquidity for these sort options
                         let liquidity = event.liquidity.unwrap_or(0.0);
                         (format_volume(liquidity), Color::Cyan)
@@ -130,7 +142,11 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                     },
                 }
             };
-            let volume_str = metric_str;
+            let volume_str = if has_markets {
+                metric_str
+            } else {
+                String::new()
+            };
             let volume_color = metric_color;
 
             // Format: "title ...spaces... [trades] volume markets" (right-aligned)
@@ -162,7 +178,7 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             let closed_icon_width = closed_icon.width();
 
             // Check for yield opportunity (high probability market)
-            let has_yield = !is_closed && event_has_yield(event);
+            let has_yield = !is_closed && event_has_yield(event, app.yield_state.min_prob);
             let yield_icon = if has_yield {
                 "$ "
             } else {
@@ -170,6 +186,15 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             };
             let yield_icon_width = yield_icon.width();
 
+            // Resolved events show the winner next to the title
+            let winner = if is_closed {
+                event_winner(event, app.outcome_style)
+                    .map(|name| format!("✓ {} ", truncate(&name, 20)))
+            } else {
+                None
+            };
+            let winner_width = winner.as_deref().map_or(0, |w| w.width());
+
             // Check if event is favorited
             let is_favorite = app.favorites_state.is_favorite(&event.slug);
             let favorite_icon = if is_favorite {
@@ -179,12 +204,23 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             };
             let favorite_icon_width = favorite_icon.width();
 
-            let reserved_width =
-                right_text_width + 1 + closed_icon_width + yield_icon_width + favorite_icon_width;
+            let reserved_width = right_text_width
+                + 1
+                + closed_icon_width
+                + yield_icon_width
+                + favorite_icon_width
+                + winner_width;
             let available_width = usable_width.saturating_sub(reserved_width);
 
+            // Market search rows lead with the matched market, then its parent event
+            let matched_market = app.search.matched_market(idx);
+            let full_title = match matched_market {
+                Some(market) => format!("{} · {}", market.question, event.title),
+                None => event.title.clone(),
+            };
+
             // Truncate title to fit available space (using display width)
-            let title = truncate_to_width(&event.title, available_width);
+            let title = truncate_to_width(&full_title, available_width);
 
             let title_width = title.width();
             let remaining_width = usable_width
@@ -192,6 +228,7 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 .saturating_sub(yield_icon_width)
                 .saturating_sub(favorite_icon_width)
                 .saturating_sub(title_width)
+                .saturating_sub(winner_width)
                 .saturating_sub(right_text_width);
 
             let mut line_spans = Vec::new();
@@ -207,12 +244,51 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             if has_yield {
                 line_spans.push(Span::styled(yield_icon, Style::default().fg(Color::Green)));
             }
-            line_spans.push(Span::styled(title, style));
+            // Highlight the characters matched by the local filter
+            let title_match = if app.search.mode == SearchMode::LocalFilter {
+                fuzzy_match(&app.search.query, &event.title)
+            } else {
+                None
+            };
+            if let Some(title_match) = title_match {
+                // Don't highlight the ellipsis of a truncated title
+                let visible_chars = if title == event.title {
+                    title.chars().count()
+                } else {
+                    title.chars().count().saturating_sub(1)
+                };
+                let positions: Vec<usize> = title_match
+                    .positions
+                    .into_iter()
+                    .filter(|&idx| idx < visible_chars)
+                    .collect();
+                let highlight = style.fg(Color::Cyan).add_modifier(Modifier::UNDERLINED);
+                line_spans.extend(highlight_spans(&title, &positions, style, highlight));
+            } else if let Some(market) = matched_market {
+                // Dim the parent event title unless the row is selected
+                let split = title
+                    .char_indices()
+                    .nth(market.question.chars().count())
+                    .map_or(title.len(), |(pos, _)| pos);
+                let (question, parent) = title.split_at(split);
+                let parent_style = if is_selected {
+                    style
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                line_spans.push(Span::styled(question.to_string(), style));
+                line_spans.push(Span::styled(parent.to_string(), parent_style));
+            } else {
+                line_spans.push(Span::styled(title, style));
+            }
 
             // Add spaces to right-align the markets/trades count
             if remaining_width > 0 {
                 line_spans.push(Span::styled(" ".repeat(remaining_width), Style::default()));
             }
+            if let Some(winner) = winner {
+                line_spans.push(Span::styled(winner, Style::default().fg(Color::Green)));
+            }
 
             // Add the right-aligned text with appropriate styling
             // Trade count in yellow, volume/price-change in green/red, markets in cyan
@@ -229,7 +305,15 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 ));
                 line_spans.push(Span::styled(" ", Style::default()));
             }
-            line_spans.push(Span::styled(markets_str, Style::default().fg(Color::Cyan)));
+            let markets_color = if has_markets {
+                Color::Cyan
+            } else {
+                Color::DarkGray
+            };
+            line_spans.push(Span::styled(
+                markets_str,
+                Style::default().fg(markets_color),
+            ));
 
             // Alternating row colors (zebra striping) for better readability
             let bg_color = if idx % 2 == 0 {
@@ -252,7 +336,9 @@ pub fn render_events_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     // Build title with sort option and search query if applicable (count moved to bottom)
     let event_count = app.filtered_events().len();
     let sort_label = app.event_sort_by.label();
-    let title = if !app.search.last_searched_query.is_empty() && !app.search.results.is_empty() {
+    let title = if app.search.showing_markets() {
+        format!("Markets - \"{}\"", app.search.last_searched_query)
+    } else if !app.search.last_searched_query.is_empty() && !app.search.results.is_empty() {
         // Show search query in title when displaying API search results
         format!(
             "Events - Sort: {} - \"{}\"",
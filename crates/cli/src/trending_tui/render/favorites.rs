@@ -1,7 +1,7 @@
 //! Favorites tab rendering functions
 
 use {
-    super::utils::{event_has_yield, format_volume, truncate_to_width},
+    super::utils::{event_has_yield, format_volume, sum_volumes, truncate_to_width},
     crate::trending_tui::state::{FocusedPanel, TrendingAppState},
     ratatui::{
         Frame,
@@ -217,7 +217,15 @@ pub fn render_favorites_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         render_orderbook(f, app, event, right_chunks[2]);
 
         // Render trades
-        render_trades_panel(f, app, trades, is_watching, right_chunks[3]);
+        render_trades_panel(
+            f,
+            app,
+            trades,
+            &app.trade_stats(&event.slug),
+            event,
+            is_watching,
+            right_chunks[3],
+        );
     } else {
         // No event selected - show empty panel
         let empty = Paragraph::new("Select a favorite event to view details")
@@ -263,11 +271,12 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             let is_closed = event.closed || !event.active;
 
             // Calculate total volume from markets
-            let total_volume: f64 = event
-                .markets
-                .iter()
-                .map(|m| m.volume_24hr.or(m.volume_total).unwrap_or(0.0))
-                .sum();
+            let total_volume = sum_volumes(
+                event
+                    .markets
+                    .iter()
+                    .map(|m| m.volume_24hr.or(m.volume_total).unwrap_or(0.0)),
+            );
 
             // Format volume
             let volume_str = format_volume(total_volume);
@@ -281,7 +290,7 @@ fn render_favorites_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             // Icons: favorite (always shown) + yield (if applicable)
             let favorite_icon = "⚑ ";
             let favorite_icon_width = favorite_icon.width();
-            let has_yield = !is_closed && event_has_yield(event);
+            let has_yield = !is_closed && event_has_yield(event, app.yield_state.min_prob);
             let yield_icon = if has_yield {
                 "$ "
             } else {
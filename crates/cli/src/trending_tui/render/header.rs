@@ -2,7 +2,7 @@
 
 use {
     super::utils::render_search_input,
-    crate::trending_tui::state::{EventFilter, MainTab, SearchMode, TrendingAppState},
+    crate::trending_tui::state::{AppTab, SearchMode, SearchTarget, TrendingAppState},
     ratatui::{
         Frame,
         layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -12,16 +12,31 @@ use {
     },
 };
 
+/// Divider between tab titles on the header line
+pub(super) const TAB_DIVIDER: &str = " ";
+/// API status indicator drawn right of the login/profile button
+pub(super) const STATUS_DOT: &str = " •";
+
+/// Tab titles in `AppTab::ALL` order, as rendered on the header line
+pub(super) fn tab_titles() -> Vec<Line<'static>> {
+    AppTab::ALL
+        .iter()
+        .map(|tab| Line::from(tab.title()))
+        .collect()
+}
+
+/// Login button, or the profile button with the user's name once authenticated
+pub(super) fn login_button_label(app: &TrendingAppState) -> String {
+    if app.auth_state.is_authenticated {
+        format!("[ {} ]", app.auth_state.display_name())
+    } else {
+        "[ Login ]".to_string()
+    }
+}
+
 pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
-    // Calculate unified tab index: 0=Events, 1=Favorites, 2=Breaking, 3=Yield
-    let tab_index = match app.main_tab {
-        MainTab::Trending => match app.event_filter {
-            EventFilter::Trending => 0,
-            EventFilter::Breaking => 2,
-        },
-        MainTab::Favorites => 1,
-        MainTab::Yield => 3,
-    };
+    // Unified tab index in AppTab::ALL order
+    let tab_index = app.current_tab().index();
 
     if app.is_in_filter_mode() {
         // Split header into tabs, separator, and search input
@@ -35,13 +50,7 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             .split(area);
 
         // Render unified tabs
-        let tab_titles: Vec<Line> = vec![
-            Line::from("Events [1]"),
-            Line::from("Favorites [2]"),
-            Line::from("Breaking [3]"),
-            Line::from("Yield [4]"),
-        ];
-        let tabs = Tabs::new(tab_titles)
+        let tabs = Tabs::new(tab_titles())
             .select(tab_index)
             .style(Style::default().fg(Color::DarkGray))
             .highlight_style(
@@ -49,7 +58,7 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
             )
-            .divider(" ");
+            .divider(TAB_DIVIDER);
         f.render_widget(tabs, header_chunks[0]);
 
         // Horizontal separator line (gitui-style) - full width line of ─ characters
@@ -59,21 +68,28 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         f.render_widget(separator, header_chunks[1]);
 
         // Search input field with proper styling
-        let placeholder = match app.search.mode {
-            SearchMode::ApiSearch => "Type to search via API...",
-            SearchMode::LocalFilter => "Type to filter current list...",
-            SearchMode::None => "Type to search...",
+        let placeholder = match (app.search.mode, app.search.target) {
+            (SearchMode::ApiSearch, SearchTarget::Events) => "Type to search events via API...",
+            (SearchMode::ApiSearch, SearchTarget::Markets) => "Type to search markets via API...",
+            (SearchMode::LocalFilter, _) => "Type to filter current list...",
+            (SearchMode::None, _) => "Type to search...",
         };
         let title = if app.search.is_searching {
-            "Search (loading...)"
+            "Search (loading...)".to_string()
+        } else if app.search.mode == SearchMode::ApiSearch {
+            format!(
+                "Search {} (Tab: {}, Esc to close)",
+                app.search.target.label(),
+                app.search.target.toggle().label()
+            )
         } else {
-            "Search (Esc to close)"
+            "Search (Esc to close)".to_string()
         };
         render_search_input(
             f,
             header_chunks[2],
             &app.search.query,
-            title,
+            &title,
             placeholder,
             app.search.is_searching,
             Color::Yellow,
@@ -120,24 +136,26 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             }
 
             // Profile button
-            let name = app.auth_state.display_name();
             right_spans.push(Span::styled(
-                format!("[ {} ]", name),
+                login_button_label(app),
                 Style::default().fg(Color::Green),
             ));
         } else {
-            right_spans.push(Span::styled("[ Login ]", Style::default().fg(Color::Cyan)));
+            right_spans.push(Span::styled(
+                login_button_label(app),
+                Style::default().fg(Color::Cyan),
+            ));
         }
 
         // API status indicator dot (using smaller bullet •)
         // Green = both APIs healthy, Yellow = one API down, Red = both down, Gray = unknown
         let status_dot = match (app.gamma_api_status, app.data_api_status) {
-            (Some(true), Some(true)) => Span::styled(" •", Style::default().fg(Color::Green)),
-            (Some(false), Some(false)) => Span::styled(" •", Style::default().fg(Color::Red)),
+            (Some(true), Some(true)) => Span::styled(STATUS_DOT, Style::default().fg(Color::Green)),
+            (Some(false), Some(false)) => Span::styled(STATUS_DOT, Style::default().fg(Color::Red)),
             (Some(true), Some(false)) | (Some(false), Some(true)) => {
-                Span::styled(" •", Style::default().fg(Color::Yellow))
+                Span::styled(STATUS_DOT, Style::default().fg(Color::Yellow))
             },
-            _ => Span::styled(" •", Style::default().fg(Color::DarkGray)),
+            _ => Span::styled(STATUS_DOT, Style::default().fg(Color::DarkGray)),
         };
         right_spans.push(status_dot);
 
@@ -154,13 +172,7 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             .split(header_chunks[0]);
 
         // Render unified tabs in gitui-style (underline for selected, keyboard shortcuts)
-        let tab_titles: Vec<Line> = vec![
-            Line::from("Events [1]"),
-            Line::from("Favorites [2]"),
-            Line::from("Breaking [3]"),
-            Line::from("Yield [4]"),
-        ];
-        let tabs = Tabs::new(tab_titles)
+        let tabs = Tabs::new(tab_titles())
             .select(tab_index)
             .style(Style::default().fg(Color::DarkGray))
             .highlight_style(
@@ -168,7 +180,7 @@ pub fn render_header(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
             )
-            .divider(" ");
+            .divider(TAB_DIVIDER);
         f.render_widget(tabs, tabs_line_chunks[0]);
 
         // Render portfolio info + login/user button on the right
@@ -1,7 +1,7 @@
 //! Logs panel rendering
 
 use {
-    crate::trending_tui::state::{FocusedPanel, TrendingAppState},
+    crate::trending_tui::state::{FocusedPanel, LogLevel, LogLevelFilter, TrendingAppState},
     ratatui::{
         Frame,
         layout::Rect,
@@ -20,16 +20,18 @@ pub fn render_logs(f: &mut Frame, app: &mut TrendingAppState, area: Rect) {
     // Auto-scroll to bottom only if Logs panel is NOT focused
     // When focused, user controls scrolling manually
     let is_focused = app.navigation.focused_panel == FocusedPanel::Logs;
+    // Scroll indexes into the messages that pass the level filter
+    let visible_len = app.logs.visible_len();
 
     if !is_focused {
         // Auto-scroll to bottom if we're near the bottom or if logs have grown
         // This ensures new logs are always visible when panel is not focused
-        if app.logs.messages.len() > visible_height {
+        if visible_len > visible_height {
             // Check if we're already showing the bottom (within 1 line)
             let current_bottom = app.logs.scroll + visible_height;
-            if current_bottom >= app.logs.messages.len().saturating_sub(1) {
+            if current_bottom >= visible_len.saturating_sub(1) {
                 // We're at or near the bottom, keep it there
-                app.logs.scroll = app.logs.messages.len() - visible_height;
+                app.logs.scroll = visible_len - visible_height;
             }
         } else {
             // Not enough logs to scroll, show from the beginning
@@ -37,32 +39,28 @@ pub fn render_logs(f: &mut Frame, app: &mut TrendingAppState, area: Rect) {
         }
     } else {
         // When focused, ensure scroll position is within valid bounds
-        let max_scroll = app
-            .logs
-            .messages
-            .len()
-            .saturating_sub(visible_height.max(1));
+        let max_scroll = visible_len.saturating_sub(visible_height.max(1));
         app.logs.scroll = app.logs.scroll.min(max_scroll);
     }
 
     // First, flatten logs by wrapping long lines
     let max_width = (area.width as usize).saturating_sub(2); // Account for borders
-    let wrapped_logs: Vec<String> = app
+    let wrapped_logs: Vec<(LogLevel, String)> = app
         .logs
-        .messages
-        .iter()
+        .visible_messages()
         .skip(app.logs.scroll)
-        .flat_map(|log| {
+        .flat_map(|msg| {
+            let log = msg.formatted();
             // Split long lines by wrapping them to fit the available width
             if log.len() > max_width {
-                // Split into multiple lines
+                // Split into multiple lines, each colored by the message's level
                 log.chars()
                     .collect::<Vec<_>>()
                     .chunks(max_width)
-                    .map(|chunk| chunk.iter().collect::<String>())
+                    .map(|chunk| (msg.level, chunk.iter().collect::<String>()))
                     .collect::<Vec<_>>()
             } else {
-                vec![log.clone()]
+                vec![(msg.level, log)]
             }
         })
         .take(visible_height)
@@ -70,15 +68,8 @@ pub fn render_logs(f: &mut Frame, app: &mut TrendingAppState, area: Rect) {
 
     let log_items: Vec<ListItem> = wrapped_logs
         .iter()
-        .map(|log| {
-            let color = if log.starts_with("[WARN]") {
-                Color::Yellow
-            } else if log.starts_with("[ERROR]") {
-                Color::Red
-            } else {
-                Color::Gray
-            };
-            ListItem::new(log.as_str()).style(Style::default().fg(color))
+        .map(|(level, log)| {
+            ListItem::new(log.as_str()).style(Style::default().fg(level_color(*level)))
         })
         .collect();
     let is_focused = app.navigation.focused_panel == FocusedPanel::Logs;
@@ -92,11 +83,7 @@ pub fn render_logs(f: &mut Frame, app: &mut TrendingAppState, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title(if is_focused {
-                    "Logs (Focused)"
-                } else {
-                    "Logs"
-                })
+                .title(logs_title(app, is_focused))
                 .border_style(block_style),
         )
         .style(Style::default().fg(Color::White));
@@ -105,7 +92,7 @@ pub fn render_logs(f: &mut Frame, app: &mut TrendingAppState, area: Rect) {
     // Render scrollbar for logs if needed
     // Note: We scroll by message count, but display wrapped lines
     // The scrollbar represents message positions, and thumb size is proportional to visible messages
-    let total_log_messages = app.logs.messages.len();
+    let total_log_messages = visible_len;
     if total_log_messages > 0 {
         // Estimate visible messages based on visible height and average wrapping
         // This is approximate but ensures the scrollbar thumb is reasonably proportional
@@ -120,3 +107,24 @@ pub fn render_logs(f: &mut Frame, app: &mut TrendingAppState, area: Rect) {
         );
     }
 }
+
+fn level_color(level: LogLevel) -> Color {
+    match level {
+        LogLevel::Error => Color::Red,
+        LogLevel::Warn => Color::Yellow,
+        LogLevel::Info => Color::Gray,
+        LogLevel::Debug | LogLevel::Trace => Color::DarkGray,
+    }
+}
+
+/// "Logs", with the level filter when one is active
+fn logs_title(app: &TrendingAppState, is_focused: bool) -> String {
+    let mut title = String::from("Logs");
+    if app.logs.level_filter != LogLevelFilter::All {
+        title.push_str(&format!(" [{}]", app.logs.level_filter.label()));
+    }
+    if is_focused {
+        title.push_str(" (Focused)");
+    }
+    title
+}
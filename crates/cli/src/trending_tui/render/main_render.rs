@@ -17,7 +17,7 @@ use {
     ratatui::{
         Frame,
         layout::{Alignment, Constraint, Direction, Layout, Rect},
-        style::{Color, Style},
+        style::{Color, Modifier, Style},
         widgets::{Block, BorderType, Borders, Paragraph},
     },
 };
@@ -106,6 +106,28 @@ pub fn render(f: &mut Frame, app: &mut TrendingAppState) {
             panel_help, panel_name
         )
     };
+    // A resolved position's realized P&L takes over the footer for a while
+    let (footer_text, footer_style) = match app.resolutions.current() {
+        Some(notice) => (
+            notice.message(),
+            Style::default()
+                .fg(if notice.won {
+                    Color::Green
+                } else {
+                    Color::Red
+                })
+                .add_modifier(Modifier::BOLD),
+        ),
+        None => match app.flash_message() {
+            Some(message) => (
+                message.to_string(),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            None => (footer_text, Style::default().fg(Color::Gray)),
+        },
+    };
     let footer = Paragraph::new(footer_text)
         .block(
             Block::default()
@@ -113,7 +135,7 @@ pub fn render(f: &mut Frame, app: &mut TrendingAppState) {
                 .border_type(BorderType::Rounded),
         )
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Gray));
+        .style(footer_style);
     f.render_widget(footer, chunks[footer_idx]);
 
     // Render popup if active (on top of everything)
@@ -126,6 +148,7 @@ fn render_trades(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     if let Some(event) = app.selected_event() {
         let event_slug = &event.slug;
         let trades = app.get_trades(event_slug);
+        let stats = app.trade_stats(event_slug);
         let is_watching = app.is_watching(event_slug);
 
         // Use a fixed minimum height for event details panel
@@ -156,7 +179,7 @@ fn render_trades(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         render_orderbook(f, app, event, chunks[2]);
 
         // Render trades table
-        render_trades_table(f, app, trades, Some(event), is_watching, chunks[3]);
+        render_trades_table(f, app, trades, &stats, Some(event), is_watching, chunks[3]);
     } else {
         let paragraph = Paragraph::new("No event selected")
             .block(
@@ -1,10 +1,11 @@
 //! Markets panel rendering functions
 
 use {
-    super::utils::{
-        YIELD_MIN_PROB, format_price_cents, market_has_yield, truncate, truncate_to_width,
+    super::utils::{braille_sparkline, market_has_yield, truncate, truncate_to_width},
+    crate::{
+        display_trait::format_price,
+        trending_tui::state::{FocusedPanel, TrendingAppState},
     },
-    crate::trending_tui::state::{FocusedPanel, TrendingAppState},
     polymarket_api::gamma::Event,
     ratatui::{
         Frame,
@@ -55,10 +56,64 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
     const YIELD_COL_WIDTH: usize = 6;
     const VOLUME_COL_WIDTH: usize = 7;
     const BUTTONS_COL_WIDTH: usize = 25; // Both buttons combined (12 + 13)
+    const SPARKLINE_COL_WIDTH: usize = 8;
+    // 24h range: "99.9¢-99.9¢" = 11 chars max
+    const RANGE_COL_WIDTH: usize = 11;
+
+    // Recent live trade prices of each market's first outcome
+    let sparkline_points = |market: &polymarket_api::gamma::Market| {
+        market
+            .clob_token_ids
+            .as_ref()
+            .and_then(|ids| ids.first())
+            .and_then(|asset_id| app.price_history.get(asset_id))
+            .filter(|history| history.len() >= 2)
+            .map(|history| history.iter().copied().collect::<Vec<f64>>())
+    };
+    // Only reserve the sparkline column once a watched market has traded
+    let show_sparklines = sorted_markets
+        .iter()
+        .any(|m| !m.closed && sparkline_points(m).is_some());
+
+    // 24h low-high of each market's first outcome, once fetched
+    let price_range = |market: &polymarket_api::gamma::Market| {
+        market
+            .clob_token_ids
+            .as_ref()
+            .and_then(|ids| ids.first())
+            .and_then(|asset_id| app.price_stats.get(asset_id))
+            .map(|stats| {
+                format!(
+                    "{}-{}",
+                    format_price(stats.low, app.price_format),
+                    format_price(stats.high, app.price_format)
+                )
+            })
+    };
+    // Omit the column while no open market has stats
+    let show_ranges = sorted_markets
+        .iter()
+        .any(|m| !m.closed && price_range(m).is_some());
 
     // Calculate total fixed right content width for active markets
-    // Layout: [yield 6][space][volume 7][space][buttons 32] = 46
-    let fixed_right_width = YIELD_COL_WIDTH + 1 + VOLUME_COL_WIDTH + 1 + BUTTONS_COL_WIDTH;
+    // Layout: [sparkline 8][space][range 11][space][yield 6][space][volume 7][space][buttons 25]
+    let sparkline_width = if show_sparklines {
+        SPARKLINE_COL_WIDTH + 1
+    } else {
+        0
+    };
+    let range_width = if show_ranges {
+        RANGE_COL_WIDTH + 1
+    } else {
+        0
+    };
+    let fixed_right_width = sparkline_width
+        + range_width
+        + YIELD_COL_WIDTH
+        + 1
+        + VOLUME_COL_WIDTH
+        + 1
+        + BUTTONS_COL_WIDTH;
     let usable_width = (area.width as usize).saturating_sub(2); // -2 for borders
     let icon_width = 2; // "● " or "$ " etc.
 
@@ -87,16 +142,16 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
 
             // Status indicator: ● for active, ◐ for in-review, ○ for resolved
             // Add $ for yield opportunity (high probability market)
-            let has_yield = market_has_yield(market);
+            let has_yield = market_has_yield(market, app.yield_state.min_prob);
 
             // Calculate yield return if there's a yield opportunity
-            // Find the highest price outcome that qualifies as yield (>= 95%)
+            // Find the highest price outcome that qualifies as yield (>= min_prob)
             let yield_return: Option<f64> = if has_yield {
                 market
                     .outcome_prices
                     .iter()
                     .filter_map(|price_str| price_str.parse::<f64>().ok())
-                    .filter(|&price| (YIELD_MIN_PROB..1.0).contains(&price))
+                    .filter(|&price| (app.yield_state.min_prob..1.0).contains(&price))
                     .map(|price| (1.0 / price - 1.0) * 100.0) // Convert to percentage return
                     .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)) // Best (lowest cost = highest price) yield
             } else {
@@ -117,28 +172,32 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
                 "● "
             };
 
-            // Build outcome display string for closed markets
-            let outcomes_str = if market.closed {
-                // For resolved markets, show only the winning side
-                let winner = market
-                    .outcomes
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(idx, outcome)| {
-                        let price = market
-                            .outcome_prices
-                            .get(idx)
-                            .and_then(|p| p.parse::<f64>().ok())?;
-                        Some((outcome, price))
-                    })
-                    .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
-
-                winner
-                    .map(|(outcome, _)| format!("Winner: {}", outcome))
-                    .unwrap_or_else(|| "Resolved".to_string())
+            // For closed markets: the winner in green with a check, the other outcomes dimmed
+            let closed_outcomes: Vec<(String, Style)> = if market.closed {
+                match market.resolved_outcome() {
+                    Some(winner) => market
+                        .outcomes
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, outcome)| {
+                            let name = market.display_outcome(outcome, app.outcome_style);
+                            if idx == winner {
+                                (format!("✓ {}", name), Style::default().fg(Color::Green))
+                            } else {
+                                (name, Style::default().fg(Color::DarkGray))
+                            }
+                        })
+                        .collect(),
+                    None => vec![("Closed".to_string(), Style::default().fg(Color::DarkGray))],
+                }
             } else {
-                String::new()
+                Vec::new()
             };
+            let outcomes_width = closed_outcomes
+                .iter()
+                .map(|(text, _)| text.width() + 1)
+                .sum::<usize>()
+                .saturating_sub(1);
 
             // Get prices for active markets (for Buy buttons)
             // Priority: 1) orderbook best ask (for selected market), 2) market_prices from batch API, 3) outcome_prices
@@ -214,10 +273,10 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
             const MAX_NO_BUTTON_WIDTH: usize = 12;
             let (yes_button, yes_padding, no_button, no_padding) = if !market.closed {
                 let yes_price_str = yes_price
-                    .map(format_price_cents)
+                    .map(|price| format_price(price, app.price_format))
                     .unwrap_or_else(|| "N/A".to_string());
                 let no_price_str = no_price
-                    .map(format_price_cents)
+                    .map(|price| format_price(price, app.price_format))
                     .unwrap_or_else(|| "N/A".to_string());
 
                 // Get outcome names, truncate to max 3 chars to keep buttons compact
@@ -255,7 +314,6 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
                 fixed_right_width
             } else {
                 // For closed markets: just outcomes + volume
-                let outcomes_width = outcomes_str.width();
                 let vol_width = volume_str.len();
                 outcomes_width + 1 + vol_width
             };
@@ -265,12 +323,7 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
                 .saturating_sub(1); // 1 space padding
 
             // Truncate question to fit available width
-            let display_name = market
-                .group_item_title
-                .as_deref()
-                .filter(|s| !s.is_empty())
-                .unwrap_or(&market.question);
-            let question = truncate_to_width(display_name, available_width);
+            let question = truncate_to_width(market.display_name(), available_width);
             let question_width = question.width();
 
             // Calculate remaining width for spacing
@@ -301,6 +354,33 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
 
             if has_buttons {
                 // For active markets: compact layout with buttons right-aligned to panel edge
+                if show_sparklines {
+                    let (sparkline, color) = match sparkline_points(market) {
+                        Some(points) => {
+                            let rising = points.last() >= points.first();
+                            (
+                                braille_sparkline(&points, SPARKLINE_COL_WIDTH),
+                                if rising {
+                                    Color::Green
+                                } else {
+                                    Color::Red
+                                },
+                            )
+                        },
+                        None => (String::new(), Color::Reset),
+                    };
+                    line_spans.push(Span::styled(
+                        format!("{:>width$} ", sparkline, width = SPARKLINE_COL_WIDTH),
+                        Style::default().fg(color),
+                    ));
+                }
+                if show_ranges {
+                    let range = price_range(market).unwrap_or_default();
+                    line_spans.push(Span::styled(
+                        format!("{:>width$} ", range, width = RANGE_COL_WIDTH),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
                 // Yield column (right-aligned within YIELD_COL_WIDTH)
                 let yield_display = yield_str.as_deref().unwrap_or("");
                 let yield_padded = format!("{:>width$}", yield_display, width = YIELD_COL_WIDTH);
@@ -331,14 +411,14 @@ pub fn render_markets(f: &mut Frame, app: &TrendingAppState, event: &Event, area
                 line_spans.push(Span::styled(no_button, Style::default().fg(Color::Red)));
             } else {
                 // For closed markets: show outcomes and volume
-                if !outcomes_str.is_empty() {
-                    line_spans.push(Span::styled(
-                        outcomes_str.clone(),
-                        Style::default().fg(Color::Cyan),
-                    ));
-                    if !volume_str.is_empty() {
-                        line_spans.push(Span::styled(" ", Style::default()));
+                for (idx, (text, style)) in closed_outcomes.into_iter().enumerate() {
+                    if idx > 0 {
+                        line_spans.push(Span::raw(" "));
                     }
+                    line_spans.push(Span::styled(text, style));
+                }
+                if outcomes_width > 0 && !volume_str.is_empty() {
+                    line_spans.push(Span::styled(" ", Style::default()));
                 }
                 if !volume_str.is_empty() {
                     line_spans.push(Span::styled(
@@ -1,6 +1,6 @@
 //! Render functions for the trending TUI
 
-mod clicks;
+pub mod clicks;
 mod event_details;
 mod events_list;
 mod favorites;
@@ -15,9 +15,10 @@ pub mod utils;
 mod yield_tab;
 
 pub use {
-    clicks::{ClickedTab, get_clicked_tab, is_login_button_clicked},
+    clicks::{get_clicked_tab, is_login_button_clicked},
     main_render::render,
     orderbook::check_orderbook_title_click,
     popups::TRADE_POPUP_WIDTH,
     utils::{centered_rect_fixed_width, truncate},
+    yield_tab::yield_histogram_height,
 };
@@ -2,7 +2,7 @@
 
 use {
     super::utils::{format_with_thousands, truncate},
-    crate::trending_tui::state::{FocusedPanel, OrderbookOutcome, TrendingAppState},
+    crate::trending_tui::state::{FocusedPanel, OrderbookData, OrderbookOutcome, TrendingAppState},
     polymarket_api::gamma::Event,
     ratatui::{
         Frame,
@@ -43,8 +43,8 @@ pub fn calculate_orderbook_height(
     } else if let Some(orderbook) = &app.orderbook_state.orderbook {
         let asks_count = orderbook.asks.len().min(MAX_PER_SIDE);
         let bids_count = orderbook.bids.len().min(MAX_PER_SIDE);
-        // Height = borders(2) + header(1) + asks + spread(1) + bids
-        let height = 2 + 1 + asks_count + 1 + bids_count;
+        // Height = borders(2) + header(1) + asks + spread(1) + bids + imbalance(1)
+        let height = 2 + 1 + asks_count + 1 + bids_count + 1;
         (height as u16).max(MESSAGE_HEIGHT)
     } else {
         // No data yet, use last height or message height
@@ -195,6 +195,20 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
 
     let title_line = Line::from(title_spans);
 
+    // Name of the market whose book is shown, when the event has several (`[`/`]` to switch)
+    let market_title = market.filter(|_| sorted_markets.len() > 1).map(|m| {
+        Line::from(Span::styled(
+            format!(
+                " {} ({}/{}) ",
+                truncate(m.display_name(), 30),
+                selected_market_idx + 1,
+                sorted_markets.len()
+            ),
+            Style::default().fg(Color::Cyan),
+        ))
+        .right_aligned()
+    });
+
     let is_focused = app.navigation.focused_panel == FocusedPanel::Markets; // TODO: Add FocusedPanel::Orderbook
     let block_style = if is_focused {
         Style::default().fg(Color::Yellow)
@@ -212,6 +226,19 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
     if has_orders {
         let orderbook = orderbook_state.orderbook.as_ref().unwrap();
 
+        if orderbook_state.show_depth_chart {
+            let mut block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title(title_line)
+                .border_style(block_style);
+            if let Some(market_title) = market_title {
+                block = block.title(market_title);
+            }
+            render_depth_chart(f, orderbook, block, area);
+            return;
+        }
+
         // Find max cumulative total for scaling the depth bars
         // Scale each side (bids/asks) independently for better visualization
         let max_bid_total = orderbook.bids.last().map(|l| l.total).unwrap_or(0.0);
@@ -309,10 +336,13 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
         f.render_widget(depth_para, chunks[0]);
 
         // Render price levels (right side)
-        let levels_block = Block::default()
+        let mut levels_block = Block::default()
             .borders(Borders::RIGHT | Borders::TOP | Borders::BOTTOM)
             .border_type(BorderType::Rounded)
             .border_style(block_style);
+        if let Some(market_title) = market_title {
+            levels_block = levels_block.title(market_title);
+        }
 
         let panel_width = (chunks[1].width as usize).saturating_sub(2); // Account for border
 
@@ -344,16 +374,6 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
             Style::default().fg(Color::DarkGray).bold(),
         )]));
 
-        // Helper to format price in cents or dollars (1 decimal place for cents)
-        let format_price = |price: f64| -> String {
-            let cents = price * 100.0;
-            if cents >= 100.0 {
-                format!("${:.2}", price)
-            } else {
-                format!("{:.1}¢", cents)
-            }
-        };
-
         // Helper to format a level line with proper alignment
         let format_level =
             |level: &crate::trending_tui::state::OrderbookLevel, price_color: Color| -> Line {
@@ -405,6 +425,11 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
             level_lines.push(format_level(level, Color::LightGreen));
         }
 
+        // Bid/ask size imbalance over the levels shown above
+        if let Some(bid_share) = orderbook.bid_imbalance(MAX_PER_SIDE) {
+            level_lines.push(imbalance_line(bid_share, panel_width));
+        }
+
         let levels_para = Paragraph::new(level_lines).block(levels_block);
         f.render_widget(levels_para, chunks[1]);
     } else {
@@ -423,11 +448,14 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
             "No markets available"
         };
 
-        let block = Block::default()
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .title(title_line)
             .border_style(block_style);
+        if let Some(market_title) = market_title {
+            block = block.title(market_title);
+        }
 
         let paragraph = Paragraph::new(message)
             .block(block)
@@ -436,3 +464,129 @@ pub fn render_orderbook(f: &mut Frame, app: &TrendingAppState, event: &Event, ar
         f.render_widget(paragraph, area);
     }
 }
+
+/// Format price in cents or dollars (1 decimal place for cents)
+fn format_price(price: f64) -> String {
+    let cents = price * 100.0;
+    if cents >= 100.0 {
+        format!("${:.2}", price)
+    } else {
+        format!("{:.1}¢", cents)
+    }
+}
+
+/// Cumulative depth chart: bid size grows leftward from the mid, ask size rightward
+///
+/// Columns step through price away from the mid, with the prices at the edges and
+/// the mid on the bottom row.
+fn render_depth_chart(f: &mut Frame, orderbook: &OrderbookData, block: Block, area: Rect) {
+    const MAX_LEVELS: usize = 6;
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let bar_rows = area.height.saturating_sub(3) as usize; // borders + axis row
+    let half = inner_width.saturating_sub(1) / 2; // columns per side, around a mid divider
+
+    let (bids, asks) = orderbook.depth_columns(MAX_LEVELS, half);
+    let max_size = bids
+        .last()
+        .into_iter()
+        .chain(asks.last())
+        .fold(0.0, |max: f64, size| max.max(*size));
+    let bar_height = |size: f64| -> usize {
+        if max_size > 0.0 && size > 0.0 {
+            ((size / max_size) * bar_rows as f64).ceil() as usize
+        } else {
+            0
+        }
+    };
+    let bid_heights: Vec<usize> = bids.iter().map(|s| bar_height(*s)).collect();
+    let ask_heights: Vec<usize> = asks.iter().map(|s| bar_height(*s)).collect();
+
+    let mut lines: Vec<Line> = Vec::new();
+    for row in 0..bar_rows {
+        let threshold = bar_rows - row;
+        let cells = |heights: &[usize], reversed: bool| -> String {
+            let mut cells: Vec<&str> = heights
+                .iter()
+                .map(|h| {
+                    if *h >= threshold {
+                        "█"
+                    } else {
+                        " "
+                    }
+                })
+                .collect();
+            if reversed {
+                cells.reverse();
+            }
+            cells.concat()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                cells(&bid_heights, true),
+                Style::default().fg(Color::LightGreen),
+            ),
+            Span::styled("│", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                cells(&ask_heights, false),
+                Style::default().fg(Color::LightRed),
+            ),
+        ]));
+    }
+
+    // Axis: lowest price shown on the left, mid in the middle, highest on the right
+    if let (Some(mid), Some(range)) = (orderbook.mid_price(), orderbook.depth_range(MAX_LEVELS)) {
+        let low = format_price(mid - range);
+        let mid_label = format_price(mid);
+        let high = format_price(mid + range);
+        let mid_start = half.saturating_sub(mid_label.chars().count() / 2);
+        let gap_left = mid_start.saturating_sub(low.chars().count()).max(1);
+        let used = low.chars().count() + gap_left + mid_label.chars().count();
+        let gap_right = inner_width
+            .saturating_sub(used + high.chars().count())
+            .max(1);
+        lines.push(Line::from(vec![
+            Span::styled(low, Style::default().fg(Color::LightGreen)),
+            Span::raw(" ".repeat(gap_left)),
+            Span::styled(mid_label, Style::default().fg(Color::Yellow)),
+            Span::raw(" ".repeat(gap_right)),
+            Span::styled(high, Style::default().fg(Color::LightRed)),
+        ]));
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// "Bid 62% ██████░░░░ 38% Ask", the bar split green/red by bid share
+fn imbalance_line(bid_share: f64, width: usize) -> Line<'static> {
+    let bid_pct = (bid_share * 100.0).round();
+    let ask_pct = 100.0 - bid_pct;
+    let (bid_style, ask_style) = if bid_pct >= ask_pct {
+        (
+            Style::default()
+                .fg(Color::LightGreen)
+                .add_modifier(Modifier::BOLD),
+            Style::default().fg(Color::DarkGray),
+        )
+    } else {
+        (
+            Style::default().fg(Color::DarkGray),
+            Style::default()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+        )
+    };
+    let bid_label = format!("Bid {:>3.0}% ", bid_pct);
+    let ask_label = format!(" {:>3.0}% Ask", ask_pct);
+
+    let bar_width = width.saturating_sub(bid_label.len() + ask_label.len());
+    let bid_width = ((bar_width as f64) * bid_share).round() as usize;
+    Line::from(vec![
+        Span::styled(bid_label, bid_style),
+        Span::styled("█".repeat(bid_width), Style::default().fg(Color::Green)),
+        Span::styled(
+            "█".repeat(bar_width.saturating_sub(bid_width)),
+            Style::default().fg(Color::Red),
+        ),
+        Span::styled(ask_label, ask_style),
+    ])
+}
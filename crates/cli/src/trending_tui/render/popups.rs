@@ -1,10 +1,18 @@
 //! Popup/modal rendering functions
 
 use {
-    super::utils::{centered_rect, centered_rect_fixed_width, format_pnl, truncate},
-    crate::trending_tui::state::{
-        LoginField, MainTab, OrderType, PopupType, TradeField, TradeSide, TrendingAppState,
+    super::utils::{
+        centered_rect, centered_rect_fixed_width, format_pnl, format_price_cents, format_volume,
+        truncate, truncate_to_width,
     },
+    crate::{
+        display_trait::{format_price, format_price_exact},
+        trending_tui::state::{
+            LoginField, MainTab, OrderType, PopupType, SizeUnit, TradeField, TradeFormState,
+            TradeSide, TrendingAppState, remaining_size,
+        },
+    },
+    polymarket_api::clob::TimeInForce,
     ratatui::{
         Frame,
         layout::{Alignment, Rect},
@@ -151,21 +159,56 @@ fn build_help_content(app: &TrendingAppState) -> Vec<Line<'static>> {
         Style::default().fg(Color::Yellow).bold(),
     )]));
     lines.push(Line::from("  ↑/k, ↓/j  Move up/down in lists"));
+    lines.push(Line::from(
+        "  PgUp/PgDn Page up/down, Home/End jump to first/last",
+    ));
     lines.push(Line::from("  Tab       Switch between panels"));
     lines.push(Line::from(
         "  1-4       Switch tabs (Events/Favorites/Breaking/Yield)",
     ));
-    lines.push(Line::from("  s         Cycle sort options"));
+    lines.push(Line::from(
+        "  s         Cycle sort options (Trades panel: price, shares, value)",
+    ));
     lines.push(Line::from("  /         API search (searches Polymarket)"));
+    lines.push(Line::from(
+        "  R         List events related to the selected one",
+    ));
+    lines.push(Line::from("  C         Show prices in cents or dollars"));
+    lines.push(Line::from(
+        "  T         Switch between paper and live trading",
+    ));
     lines.push(Line::from(
         "  f         Local filter (filters current list)",
     ));
     lines.push(Line::from("  o         Open event in browser"));
+    lines.push(Line::from("  O         Open selected market in browser"));
+    lines.push(Line::from("  y         Copy event URL to clipboard"));
+    lines.push(Line::from("  g         Jump between Events and Favorites"));
+    lines.push(Line::from(
+        "  [ / ]     Previous/next market in the orderbook",
+    ));
+    lines.push(Line::from(
+        "  d         Orderbook depth chart / price levels",
+    ));
     lines.push(Line::from(
         "  Enter     Toggle watching event for live trades",
     ));
+    lines.push(Line::from(
+        "  v         Hide live trades under $100/$1K/$10K",
+    ));
     lines.push(Line::from("  L         Login to Polymarket"));
+    lines.push(Line::from("  m         My orders (cancel with x)"));
+    lines.push(Line::from("  P         Switch profile"));
+    lines.push(Line::from(
+        "  h         Top holders of the selected market (Markets panel)",
+    ));
     lines.push(Line::from("  l         Toggle logs panel"));
+    lines.push(Line::from(
+        "  F         Cycle logs level filter (All/Info/Warn/Error)",
+    ));
+    lines.push(Line::from(
+        "  x         Export watchlist, favorites and settings",
+    ));
     lines.push(Line::from("  Esc       Cancel/close"));
     lines.push(Line::from("  q         Quit"));
     lines.push(Line::from(""));
@@ -195,6 +238,18 @@ pub fn render_popup(f: &mut Frame, app: &TrendingAppState, popup: &PopupType) {
             render_trade_popup(f, app);
             return;
         },
+        PopupType::OpenOrders => {
+            render_open_orders_popup(f, app);
+            return;
+        },
+        PopupType::ProfileSwitcher => {
+            render_profile_switcher_popup(f, app);
+            return;
+        },
+        PopupType::Holders => {
+            render_holders_popup(f, app);
+            return;
+        },
         _ => {},
     }
 
@@ -234,7 +289,14 @@ pub fn render_popup(f: &mut Frame, app: &TrendingAppState, popup: &PopupType) {
             )]),
         ]),
         // These are handled above with early return
-        PopupType::Login | PopupType::UserProfile | PopupType::Trade => unreachable!(),
+        PopupType::Login
+        | PopupType::UserProfile
+        | PopupType::Trade
+        | PopupType::OpenOrders
+        | PopupType::ProfileSwitcher
+        | PopupType::Holders => {
+            unreachable!()
+        },
     };
 
     let block = Block::default()
@@ -556,11 +618,18 @@ fn render_login_popup(f: &mut Frame, app: &TrendingAppState) {
 
 /// Render user profile popup
 fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
-    // Fixed width: 55 (content) + 2 (borders) = 57
-    let area = centered_rect_fixed_width(57, 60, f.area());
-    f.render_widget(Clear, area);
-
     let auth = &app.auth_state;
+    let (winners, losers) = auth.pnl_leaders(5);
+
+    // Fixed width: 55 (content) + 2 (borders) = 57, taller with the per-position lists
+    let show_paper = app.paper_mode || !app.paper_ledger.trades.is_empty();
+    let height_percent = if winners.is_empty() && losers.is_empty() && !show_paper {
+        60
+    } else {
+        90
+    };
+    let area = centered_rect_fixed_width(57, height_percent, f.area());
+    f.render_widget(Clear, area);
 
     let mut content = vec![Line::from("")];
 
@@ -746,6 +815,29 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
         )]));
         content.push(Line::from(""));
 
+        // What the open positions cost vs what they're worth now
+        if let Some(cost_basis) = auth.cost_basis {
+            content.push(Line::from(vec![
+                Span::styled("Cost basis:", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!(" ${:.2}", cost_basis),
+                    Style::default().fg(Color::White),
+                ),
+            ]));
+        }
+        if let Some(portfolio_value) = auth.portfolio_value {
+            content.push(Line::from(vec![
+                Span::styled("Value:     ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!(" ${:.2}", portfolio_value.max(0.0)),
+                    Style::default().fg(Color::White),
+                ),
+            ]));
+        }
+        if auth.cost_basis.is_some() || auth.portfolio_value.is_some() {
+            content.push(Line::from(""));
+        }
+
         // Unrealized P&L
         if let Some(unrealized) = auth.unrealized_pnl {
             let (pnl_str, pnl_color) = format_pnl(unrealized);
@@ -777,6 +869,31 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
                     .add_modifier(Modifier::BOLD),
             ),
         ]));
+
+        for (label, positions) in [("Top winners", &winners), ("Top losers", &losers)] {
+            if positions.is_empty() {
+                continue;
+            }
+            content.push(Line::from(""));
+            content.push(Line::from(vec![Span::styled(
+                label,
+                Style::default().fg(Color::White).bold(),
+            )]));
+            for position in positions.iter() {
+                let (pnl_str, pnl_color) = format_pnl(position.cash_pnl.unwrap_or(0.0));
+                content.push(Line::from(vec![
+                    Span::styled(format!("{:>10} ", pnl_str), Style::default().fg(pnl_color)),
+                    Span::styled(
+                        truncate(&format!("{} · {}", position.title, position.outcome), 42),
+                        Style::default().fg(Color::White),
+                    ),
+                ]));
+            }
+        }
+    }
+
+    if show_paper {
+        push_paper_positions(&mut content, app);
     }
 
     content.push(Line::from(""));
@@ -816,10 +933,458 @@ fn render_user_profile_popup(f: &mut Frame, app: &TrendingAppState) {
     f.render_widget(paragraph, area);
 }
 
+/// Paper ledger section of the profile popup, marked at the latest market prices
+fn push_paper_positions(content: &mut Vec<Line<'static>>, app: &TrendingAppState) {
+    content.push(Line::from(""));
+    content.push(Line::from(vec![Span::styled(
+        "─".repeat((TRADE_POPUP_WIDTH - 4) as usize),
+        Style::default().fg(Color::DarkGray),
+    )]));
+    content.push(Line::from(""));
+    content.push(Line::from(vec![
+        Span::styled(
+            "Positions",
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        ),
+        Span::styled(" (paper)", Style::default().fg(Color::Magenta).bold()),
+    ]));
+    content.push(Line::from(""));
+
+    let positions = app.paper_ledger.positions();
+    if positions.is_empty() {
+        content.push(Line::from(vec![Span::styled(
+            "No paper positions yet",
+            Style::default().fg(Color::DarkGray),
+        )]));
+    }
+    for position in &positions {
+        let mark = app
+            .market_prices
+            .get(&position.token_id)
+            .copied()
+            .unwrap_or(position.avg_price);
+        let (pnl_str, pnl_color) = format_pnl(position.shares * mark - position.cost());
+        content.push(Line::from(vec![
+            Span::styled(format!("{:>10} ", pnl_str), Style::default().fg(pnl_color)),
+            Span::styled(
+                truncate(
+                    &format!(
+                        "{:.2} {} @ {} · {}",
+                        position.shares,
+                        position.outcome,
+                        format_price(position.avg_price, app.price_format),
+                        position.market
+                    ),
+                    42,
+                ),
+                Style::default().fg(Color::White),
+            ),
+        ]));
+    }
+
+    let (realized_str, realized_color) = format_pnl(app.paper_ledger.realized_pnl());
+    content.push(Line::from(""));
+    content.push(Line::from(vec![
+        Span::styled("Realized:  ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!(" {}", realized_str),
+            Style::default().fg(realized_color),
+        ),
+    ]));
+}
+
+/// Fixed width for the "My Orders" popup (content + borders)
+const OPEN_ORDERS_POPUP_WIDTH: u16 = 72;
+
+fn render_open_orders_popup(f: &mut Frame, app: &TrendingAppState) {
+    let area = centered_rect_fixed_width(OPEN_ORDERS_POPUP_WIDTH, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let state = &app.open_orders;
+    const MARKET_WIDTH: usize = 34;
+    let mut content = vec![
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            format!(
+                " {:<MARKET_WIDTH$} {:<4} {:>7} {:>9} {:>8}",
+                "Market", "Side", "Price", "Remaining", "Outcome"
+            ),
+            Style::default().fg(Color::DarkGray),
+        )]),
+    ];
+
+    // Header, status and hint lines take 7 rows including the borders
+    let visible_rows = (area.height as usize).saturating_sub(7).max(1);
+    let scroll = state.selected_index.saturating_sub(visible_rows - 1);
+    if state.orders.is_empty() {
+        content.push(Line::from(vec![Span::styled(
+            if state.is_loading {
+                " Loading orders..."
+            } else {
+                " No open orders"
+            },
+            Style::default().fg(Color::DarkGray),
+        )]));
+    }
+    for (idx, order) in state
+        .orders
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible_rows)
+    {
+        let market = app
+            .market_name_for_asset(&order.asset_id)
+            .unwrap_or(&order.market);
+        let price = order
+            .price
+            .parse::<f64>()
+            .map(format_price_cents)
+            .unwrap_or_else(|_| order.price.clone());
+        let side_color = if order.side.eq_ignore_ascii_case("buy") {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        let mut style = Style::default();
+        if idx == state.selected_index {
+            style = style.bg(Color::Rgb(60, 60, 80));
+        }
+        if state.cancelling.as_deref() == Some(order.id.as_str()) {
+            style = style.fg(Color::DarkGray);
+        }
+        content.push(
+            Line::from(vec![
+                Span::raw(format!(
+                    " {:<MARKET_WIDTH$} ",
+                    truncate_to_width(market, MARKET_WIDTH)
+                )),
+                Span::styled(
+                    format!("{:<4}", truncate(&order.side.to_uppercase(), 4)),
+                    Style::default().fg(side_color),
+                ),
+                Span::raw(format!(
+                    " {:>7} {:>9.2} {:>8}",
+                    price,
+                    remaining_size(order),
+                    truncate(&order.outcome, 8)
+                )),
+            ])
+            .style(style),
+        );
+    }
+
+    content.push(Line::from(""));
+    if state.cancelling.is_some() {
+        content.push(Line::from(vec![Span::styled(
+            " Cancelling order...",
+            Style::default().fg(Color::Yellow),
+        )]));
+    } else if let Some(ref error) = state.error_message {
+        content.push(Line::from(vec![Span::styled(
+            format!(" {}", error),
+            Style::default().fg(Color::Red),
+        )]));
+    } else {
+        content.push(Line::from(""));
+    }
+    content.push(Line::from(vec![
+        Span::styled(" ↑/↓", Style::default().fg(Color::Cyan).bold()),
+        Span::styled(" select   ", Style::default().fg(Color::DarkGray)),
+        Span::styled("x", Style::default().fg(Color::Red).bold()),
+        Span::styled(" cancel order   ", Style::default().fg(Color::DarkGray)),
+        Span::styled("r", Style::default().fg(Color::Cyan).bold()),
+        Span::styled(" refresh   ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Esc", Style::default().fg(Color::Cyan).bold()),
+        Span::styled(" close", Style::default().fg(Color::DarkGray)),
+    ]));
+
+    let block = Block::default()
+        .title(format!(" My Orders ({}) ", state.orders.len()))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(Paragraph::new(content).block(block), area);
+}
+
+/// Fixed width for the "Top Holders" popup (content + borders)
+const HOLDERS_POPUP_WIDTH: u16 = 64;
+
+fn render_holders_popup(f: &mut Frame, app: &TrendingAppState) {
+    let area = centered_rect_fixed_width(HOLDERS_POPUP_WIDTH, 60, f.area());
+    f.render_widget(Clear, area);
+
+    let state = &app.holders;
+    const HOLDER_WIDTH: usize = 24;
+    let mut content = vec![
+        Line::from(vec![Span::styled(
+            format!(" {}", truncate_to_width(&state.question, 60)),
+            Style::default().fg(Color::White).bold(),
+        )]),
+        Line::from(vec![Span::styled(
+            format!(
+                " {:<HOLDER_WIDTH$} {:<10} {:>12} {:>10}",
+                "Holder", "Outcome", "Shares", "Value"
+            ),
+            Style::default().fg(Color::DarkGray),
+        )]),
+    ];
+
+    // Header, status and hint lines take 7 rows including the borders
+    let visible_rows = (area.height as usize).saturating_sub(7).max(1);
+    if state.holders.is_empty() {
+        content.push(Line::from(vec![Span::styled(
+            if state.is_loading {
+                " Loading holders..."
+            } else {
+                " No holders reported for this market"
+            },
+            Style::default().fg(Color::DarkGray),
+        )]));
+    }
+    for holder in state.holders.iter().skip(state.scroll).take(visible_rows) {
+        let outcome = holder.outcome(&state.outcomes).unwrap_or("?");
+        let outcome_color = if holder.outcome_index == 0 {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        let value = state
+            .price_for(holder)
+            .map(|price| format_volume(holder.value(price)))
+            .unwrap_or_else(|| "-".to_string());
+        content.push(Line::from(vec![
+            Span::raw(format!(
+                " {:<HOLDER_WIDTH$} ",
+                truncate_to_width(&holder.display_name(), HOLDER_WIDTH)
+            )),
+            Span::styled(
+                format!("{:<10}", truncate(outcome, 10)),
+                Style::default().fg(outcome_color),
+            ),
+            Span::raw(format!(" {:>12.0} {:>10}", holder.size, value)),
+        ]));
+    }
+
+    content.push(Line::from(""));
+    if let Some(ref error) = state.error_message {
+        content.push(Line::from(vec![Span::styled(
+            format!(" {}", error),
+            Style::default().fg(Color::Red),
+        )]));
+    } else {
+        content.push(Line::from(""));
+    }
+    content.push(Line::from(vec![
+        Span::styled(" ↑/↓", Style::default().fg(Color::Cyan).bold()),
+        Span::styled(" scroll   ", Style::default().fg(Color::DarkGray)),
+        Span::styled("r", Style::default().fg(Color::Cyan).bold()),
+        Span::styled(" refresh   ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Esc", Style::default().fg(Color::Cyan).bold()),
+        Span::styled(" close", Style::default().fg(Color::DarkGray)),
+    ]));
+
+    let block = Block::default()
+        .title(format!(" Top Holders ({}) ", state.holders.len()))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(Paragraph::new(content).block(block), area);
+}
+
+/// Fixed width for the profile switcher popup (content + borders)
+const PROFILE_SWITCHER_POPUP_WIDTH: u16 = 48;
+
+fn render_profile_switcher_popup(f: &mut Frame, app: &TrendingAppState) {
+    let area = centered_rect_fixed_width(PROFILE_SWITCHER_POPUP_WIDTH, 40, f.area());
+    f.render_widget(Clear, area);
+
+    let state = &app.profile_switcher;
+    let mut content = vec![Line::from("")];
+    if state.profiles.is_empty() {
+        content.push(Line::from(vec![Span::styled(
+            " No saved profiles (log in with p first)",
+            Style::default().fg(Color::DarkGray),
+        )]));
+    }
+    for (idx, (name, address)) in state.profiles.iter().enumerate() {
+        let is_active = state.active.as_deref() == Some(name.as_str());
+        let mut style = Style::default();
+        if idx == state.selected_index {
+            style = style.bg(Color::Rgb(60, 60, 80));
+        }
+        content.push(
+            Line::from(vec![
+                Span::styled(
+                    if is_active {
+                        " ● "
+                    } else {
+                        "   "
+                    },
+                    Style::default().fg(Color::Green),
+                ),
+                Span::raw(format!("{:<24} ", truncate_to_width(name, 24))),
+                Span::styled(address.clone(), Style::default().fg(Color::DarkGray)),
+            ])
+            .style(style),
+        );
+    }
+
+    content.push(Line::from(""));
+    if let Some(ref error) = state.error_message {
+        content.push(Line::from(vec![Span::styled(
+            format!(" {}", error),
+            Style::default().fg(Color::Red),
+        )]));
+    } else {
+        content.push(Line::from(""));
+    }
+    content.push(Line::from(vec![
+        Span::styled(" ↑/↓", Style::default().fg(Color::Cyan).bold()),
+        Span::styled(" select   ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Enter", Style::default().fg(Color::Green).bold()),
+        Span::styled(" switch   ", Style::default().fg(Color::DarkGray)),
+        Span::styled("Esc", Style::default().fg(Color::Cyan).bold()),
+        Span::styled(" close", Style::default().fg(Color::DarkGray)),
+    ]));
+
+    let block = Block::default()
+        .title(" Profiles ")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(Paragraph::new(content).block(block), area);
+}
+
 /// Render trade popup with buy/sell form
 /// Fixed width for trade popup (content + borders)
 pub const TRADE_POPUP_WIDTH: u16 = 57;
 
+/// Hint line listing the quick-select amount keys (1, 2, ... then Max)
+///
+/// Once digits type into the active field (price, or an edited size), the F-keys
+/// are shown instead.
+fn quick_amounts_line(app: &TrendingAppState) -> Line<'static> {
+    let presets = &app.trade_settings.quick_amounts;
+    let key_prefix = if app
+        .trade_form
+        .as_ref()
+        .is_some_and(|f| !f.digits_pick_quick_amounts())
+    {
+        "F"
+    } else {
+        ""
+    };
+    let mut spans = vec![Span::styled(
+        "Quick:      ",
+        Style::default().fg(Color::DarkGray),
+    )];
+    let labels = presets
+        .iter()
+        .map(|amount| format!("${}", amount))
+        .chain(std::iter::once("Max".to_string()));
+    for (i, label) in labels.enumerate() {
+        if i > 0 {
+            spans.push(Span::raw("  "));
+        }
+        spans.push(Span::styled(
+            format!("{}{}", key_prefix, i + 1),
+            Style::default().fg(Color::Cyan).bold(),
+        ));
+        spans.push(Span::styled(
+            format!(" {}", label),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Shares, cost and payout of the order as entered
+///
+/// Buys show the cost and what the shares pay if the outcome wins; sells show the
+/// proceeds and the collateral (the shares' $1 payout) released.
+fn trade_preview_lines(form: &TradeFormState) -> Vec<Line<'static>> {
+    let preview = form.preview();
+    let label = |text| Span::styled(text, Style::default().fg(Color::DarkGray));
+    let value = |text| Span::styled(text, Style::default().fg(Color::White));
+    let shares_line = |dollars_label| {
+        Line::from(vec![
+            label("Shares:     "),
+            value(format!("{:<9.2}", preview.shares)),
+            label(dollars_label),
+            Span::styled(
+                format!("${:.2}", preview.dollars),
+                Style::default().fg(Color::Yellow).bold(),
+            ),
+        ])
+    };
+    match form.side {
+        TradeSide::Buy => {
+            let profit = preview.max_profit();
+            vec![
+                shares_line("Cost: "),
+                Line::from(vec![
+                    label("Max payout: "),
+                    value(format!("{:<9}", format!("${:.2}", preview.max_payout()))),
+                    label("Max profit: "),
+                    Span::styled(
+                        format!(
+                            "{}${:.2}",
+                            if profit >= 0.0 {
+                                "+"
+                            } else {
+                                "-"
+                            },
+                            profit.abs()
+                        ),
+                        Style::default()
+                            .fg(if profit >= 0.0 {
+                                Color::Green
+                            } else {
+                                Color::Red
+                            })
+                            .bold(),
+                    ),
+                ]),
+            ]
+        },
+        TradeSide::Sell => vec![
+            shares_line("Proceeds: "),
+            Line::from(vec![
+                label("Released:   "),
+                value(format!("${:.2}", preview.max_payout())),
+                label(" collateral"),
+            ]),
+        ],
+    }
+}
+
+/// Label in front of the size input, matching the unit it is typed in
+fn size_input_label(form: &TradeFormState) -> &'static str {
+    match (form.size_unit, form.order_type) {
+        (SizeUnit::Shares, _) => "Shares:     ",
+        (SizeUnit::Dollars, OrderType::Limit) => "Cost:       $ ",
+        (SizeUnit::Dollars, OrderType::Market) => "Amount:     $ ",
+    }
+}
+
+/// Hint after the size input for switching between shares and dollars
+fn size_unit_hint(form: &TradeFormState) -> Span<'static> {
+    let target = match form.size_unit {
+        SizeUnit::Shares => "$: in dollars",
+        SizeUnit::Dollars => "$: in shares",
+    };
+    Span::styled(target, Style::default().fg(Color::DarkGray))
+}
+
 fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
     use ratatui::layout::Position;
 
@@ -881,6 +1446,9 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
         };
         outcome_spans.push(Span::styled(format!(" {} ", outcome.name), tab_style));
     }
+    if form.outcomes.len() > 1 {
+        outcome_spans.push(Span::styled("  ←/→", Style::default().fg(Color::DarkGray)));
+    }
     content.push(Line::from(outcome_spans));
     current_line += 1;
 
@@ -898,7 +1466,7 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
     content.push(Line::from(vec![
         Span::styled("Best Ask:   ", Style::default().fg(Color::DarkGray)),
         Span::styled(
-            format!("{:.1}¢", form.best_ask() * 100.0),
+            format_price_exact(form.best_ask(), app.price_format),
             Style::default().fg(Color::Yellow),
         ),
     ]));
@@ -971,7 +1539,9 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
         OrderType::Limit => {
             // Limit Price with +/- controls
             let limit_price_active = form.active_field == TradeField::LimitPrice;
-            let price_style = if limit_price_active {
+            let price_style = if form.price_f64().is_err() {
+                Style::default().fg(Color::Red).bold()
+            } else if limit_price_active {
                 Style::default().fg(Color::White).bold()
             } else {
                 Style::default().fg(Color::White)
@@ -987,7 +1557,18 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
                         Style::default().fg(Color::DarkGray)
                     },
                 ),
-                Span::styled(format!(" {:.1}¢ ", form.limit_price * 100.0), price_style),
+                Span::styled(
+                    format!(
+                        " {}{}¢ ",
+                        form.price,
+                        if limit_price_active {
+                            "_"
+                        } else {
+                            ""
+                        }
+                    ),
+                    price_style,
+                ),
                 Span::styled(
                     " + ",
                     if limit_price_active {
@@ -997,7 +1578,10 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
                     },
                 ),
                 if limit_price_active {
-                    Span::styled("  ← -/+ to adjust", Style::default().fg(Color::DarkGray))
+                    Span::styled(
+                        "  ← type or -/+ to adjust",
+                        Style::default().fg(Color::DarkGray),
+                    )
                 } else {
                     Span::raw("")
                 },
@@ -1012,21 +1596,57 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
             input_field_line = Some(current_line);
 
             content.push(Line::from(vec![
-                Span::styled("Shares:     ", Style::default().fg(Color::DarkGray)),
+                Span::styled(size_input_label(form), Style::default().fg(Color::DarkGray)),
                 // Placeholder for input field
                 Span::raw("                    "),
+                size_unit_hint(form),
             ]));
 
-            content.push(Line::from(""));
+            content.push(quick_amounts_line(app));
+
+            if form.is_converting() {
+                // Shares bought with the entered dollars (calculated)
+                content.push(Line::from(vec![
+                    Span::styled("Shares:     ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(
+                        format!("{:.2}", form.shares_f64()),
+                        Style::default().fg(Color::Yellow).bold(),
+                    ),
+                ]));
+            } else {
+                // Total (calculated)
+                let total = form.total_cost();
+                content.push(Line::from(vec![
+                    Span::styled("Total:      ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(
+                        format!("${:.2}", total),
+                        Style::default().fg(Color::Yellow).bold(),
+                    ),
+                ]));
+            }
 
-            // Total (calculated)
-            let total = form.total_cost();
+            // Time in force (GTC rests on the book, FOK fills at once or cancels)
+            let tif_active = form.active_field == TradeField::TimeInForce;
+            let tif_style = |tif: TimeInForce| {
+                if form.time_in_force == tif {
+                    Style::default().fg(Color::Black).bg(Color::Cyan).bold()
+                } else if tif_active {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                }
+            };
+            content.push(Line::from(""));
             content.push(Line::from(vec![
-                Span::styled("Total:      ", Style::default().fg(Color::DarkGray)),
-                Span::styled(
-                    format!("${:.2}", total),
-                    Style::default().fg(Color::Yellow).bold(),
-                ),
+                Span::styled("Fill:       ", Style::default().fg(Color::DarkGray)),
+                Span::styled(" GTC ", tif_style(TimeInForce::Gtc)),
+                Span::raw("  "),
+                Span::styled(" FOK ", tif_style(TimeInForce::Fok)),
+                if tif_active {
+                    Span::styled("  ← Space to toggle", Style::default().fg(Color::DarkGray))
+                } else {
+                    Span::raw("")
+                },
             ]));
 
             // Store active state for later use
@@ -1038,20 +1658,83 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
             input_field_line = Some(current_line);
 
             content.push(Line::from(vec![
-                Span::styled("Amount:     $ ", Style::default().fg(Color::DarkGray)),
+                Span::styled(size_input_label(form), Style::default().fg(Color::DarkGray)),
                 // Placeholder for input field
                 Span::raw("                    "),
+                size_unit_hint(form),
             ]));
 
-            content.push(Line::from(""));
+            content.push(quick_amounts_line(app));
 
-            // Estimated shares
-            let shares = form.estimated_shares();
+            // Estimated shares (from book depth once loaded, else top-of-book),
+            // or the estimated cost when the size is entered in shares
+            let estimate = form.fill_estimate();
+            let (label, value) = if form.is_converting() {
+                ("Est. Cost:   ", format!("${:.2}", form.amount_f64()))
+            } else {
+                ("Est. Shares: ", format!("{:.2}", form.estimated_shares()))
+            };
             content.push(Line::from(vec![
-                Span::styled("Est. Shares: ", Style::default().fg(Color::DarkGray)),
-                Span::styled(format!("{:.2}", shares), Style::default().fg(Color::White)),
+                Span::styled(label, Style::default().fg(Color::DarkGray)),
+                Span::styled(value, Style::default().fg(Color::White)),
+                match estimate {
+                    Some(e) if !e.complete => Span::styled(
+                        format!(" (only ${:.2} fillable)", e.amount),
+                        Style::default().fg(Color::Red),
+                    ),
+                    Some(_) => Span::raw(""),
+                    None if form.orderbook.is_none() && form.amount_f64() > 0.0 => {
+                        Span::styled(" (top of book)", Style::default().fg(Color::DarkGray))
+                    },
+                    None => Span::raw(""),
+                },
             ]));
 
+            // Depth-based fill estimate with the slippage limit from settings
+            if let Some(estimate) = estimate {
+                let max_slippage_pct = app.trade_settings.max_slippage_pct;
+                let within_limit = form.check_slippage(max_slippage_pct).is_ok();
+                let limit = form
+                    .slippage_limit(max_slippage_pct)
+                    .unwrap_or(estimate.worst_price);
+                // Average fill and its slippage from the best price; the worst
+                // fill is called out in red once it passes the slippage limit
+                let fill_color = if within_limit {
+                    Color::White
+                } else {
+                    Color::Red
+                };
+                content.push(Line::from(vec![
+                    Span::styled("Avg fill:   ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(
+                        format!(
+                            "{} (slippage {})",
+                            format_price_exact(estimate.avg_price, app.price_format),
+                            format_price_exact(estimate.price_impact(), app.price_format)
+                        ),
+                        Style::default().fg(fill_color),
+                    ),
+                    if estimate.worst_slippage() * 100.0 <= max_slippage_pct + 1e-9 {
+                        Span::styled(
+                            format!("  limit {}", format_price_exact(limit, app.price_format)),
+                            Style::default().fg(Color::DarkGray),
+                        )
+                    } else {
+                        Span::styled(
+                            format!(
+                                "  worst {} > {}",
+                                format_price_exact(estimate.worst_price, app.price_format),
+                                format_price_exact(
+                                    form.slippage_bound(max_slippage_pct).unwrap_or(limit),
+                                    app.price_format
+                                )
+                            ),
+                            Style::default().fg(Color::Red).bold(),
+                        )
+                    },
+                ]));
+            }
+
             // Store active state for later use
             let _ = amount_active;
         },
@@ -1059,48 +1742,22 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
 
     content.push(Line::from(""));
 
-    // Potential profit (shown for both order types)
-    let profit = form.potential_profit();
-    let profit_color = if profit >= 0.0 {
-        Color::Green
-    } else {
-        Color::Red
-    };
-    content.push(Line::from(vec![
-        Span::styled(
-            if form.side == TradeSide::Buy {
-                "Profit:     "
-            } else {
-                "Proceeds:   "
-            },
-            Style::default().fg(Color::DarkGray),
-        ),
-        Span::styled(
-            format!(
-                "{}${:.2}",
-                if profit >= 0.0 {
-                    "+"
-                } else {
-                    ""
-                },
-                profit.abs()
-            ),
-            Style::default().fg(profit_color).bold(),
-        ),
-        if form.side == TradeSide::Buy {
-            Span::styled(" (if outcome wins)", Style::default().fg(Color::DarkGray))
-        } else {
-            Span::raw("")
-        },
-    ]));
-
-    content.push(Line::from(""));
+    // Cost and payout summary (shown for both order types)
+    content.extend(trade_preview_lines(form));
 
     content.push(Line::from(vec![Span::styled(
         "─".repeat((TRADE_POPUP_WIDTH - 4) as usize),
         Style::default().fg(Color::DarkGray),
     )]));
 
+    if form.is_submitting {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            "Submitting order...",
+            Style::default().fg(Color::Yellow),
+        )]));
+    }
+
     // Error message if any
     if let Some(ref error) = form.error_message {
         content.push(Line::from(""));
@@ -1151,11 +1808,18 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
         Style::default().fg(Color::DarkGray)
     };
 
-    let title_line = Line::from(vec![
+    let mut title_spans = vec![
         Span::styled("BUY", buy_style),
         Span::styled(" - ", Style::default().fg(Color::DarkGray)),
         Span::styled("SELL", sell_style),
-    ]);
+    ];
+    if app.paper_mode {
+        title_spans.push(Span::styled(
+            " (paper)",
+            Style::default().fg(Color::Magenta).bold(),
+        ));
+    }
+    let title_line = Line::from(title_spans);
 
     let border_color = if form.side == TradeSide::Buy {
         Color::Green
@@ -1163,13 +1827,24 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
         Color::Red
     };
 
-    let block = Block::default()
+    let mut block = Block::default()
         .title(title_line)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(Style::default().fg(border_color))
         .style(Style::default().bg(Color::Black));
 
+    // Copy trade notice in the bottom border (always visible, doesn't shift clickable rows)
+    if let Some(ref copied_from) = form.copied_from {
+        block = block.title_bottom(Line::from(vec![Span::styled(
+            truncate(
+                &format!("New order, mirroring {}", copied_from),
+                (TRADE_POPUP_WIDTH - 4) as usize,
+            ),
+            Style::default().fg(Color::Cyan),
+        )]));
+    }
+
     let paragraph = Paragraph::new(content)
         .block(block)
         .alignment(Alignment::Left)
@@ -1179,22 +1854,26 @@ fn render_trade_popup(f: &mut Frame, app: &TrendingAppState) {
 
     // Render input field with background color and cursor
     if let Some(field_line) = input_field_line {
-        let (input_value, is_active, label_len) = match form.order_type {
-            OrderType::Limit => {
+        let is_active = match form.order_type {
+            OrderType::Limit => form.active_field == TradeField::Shares,
+            OrderType::Market => form.active_field == TradeField::Amount,
+        };
+        let (input_value, label_len) = match form.size_unit {
+            SizeUnit::Shares => {
                 let display = if form.shares.is_empty() {
                     "0".to_string()
                 } else {
                     form.shares.clone()
                 };
-                (display, form.active_field == TradeField::Shares, 12) // "Shares:     " = 12
+                (display, 12) // "Shares:     " = 12
             },
-            OrderType::Market => {
+            SizeUnit::Dollars => {
                 let display = if form.amount.is_empty() {
                     "0.00".to_string()
                 } else {
                     form.amount.clone()
                 };
-                (display, form.active_field == TradeField::Amount, 14) // "Amount:     $ " = 14
+                (display, 14) // "Amount:     $ " = 14
             },
         };
 
@@ -1,26 +1,153 @@
 //! Trades panel rendering functions
 
 use {
-    super::utils::truncate,
-    crate::trending_tui::state::{FocusedPanel, Trade, TrendingAppState},
+    super::utils::{
+        format_pnl, format_volume, format_with_thousands, highlight_spans,
+        substring_match_positions, truncate,
+    },
+    crate::{
+        display_trait::format_price,
+        trending_tui::state::{
+            FocusedPanel, RtdsStatus, SearchMode, Trade, TradeSortColumn, TradeStats,
+            TrendingAppState,
+        },
+    },
     chrono::DateTime,
-    polymarket_api::gamma::Event,
+    polymarket_api::gamma::{Event, Market},
     ratatui::{
         Frame,
-        layout::{Alignment, Constraint, Rect},
+        layout::{Alignment, Constraint, Direction, Layout, Rect},
         style::{Color, Modifier, Style},
+        text::{Line, Span},
         widgets::{
             Block, BorderType, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation,
             ScrollbarState, Table,
         },
     },
+    std::{fmt::Write, time::Instant},
 };
 
+/// "Trades (N)" plus a colored dot for the live-trades connection while watching
+fn trades_title(
+    app: &TrendingAppState,
+    event_slug: Option<&str>,
+    count: usize,
+    is_focused: bool,
+) -> Line<'static> {
+    let mut spans = vec![Span::raw(format!("Trades ({})", count))];
+    let now = Instant::now();
+    if let Some(slug) = event_slug
+        && let Some(status) = app.trades.rtds_status(slug, now)
+    {
+        let color = match status {
+            RtdsStatus::Connecting => Color::Cyan,
+            RtdsStatus::Live => Color::Green,
+            RtdsStatus::Stale => Color::Yellow,
+            RtdsStatus::Disconnected => Color::Red,
+        };
+        let mut label = format!(" ● {}", status.label());
+        if let Some(age) = app.trades.last_rtds_message_age(slug, now)
+            && status != RtdsStatus::Connecting
+        {
+            let _ = write!(label, " · last msg {}s ago", age.as_secs());
+        }
+        spans.push(Span::styled(label, Style::default().fg(color)));
+    }
+    if let Some(slug) = event_slug {
+        let min_value = app.min_trade_value(slug);
+        if min_value > 0.0 {
+            spans.push(Span::styled(
+                format!(" ≥{}", format_volume(min_value)),
+                Style::default().fg(Color::Magenta),
+            ));
+        }
+    }
+    if is_focused {
+        spans.push(Span::raw(" (Focused)"));
+    }
+    Line::from(spans)
+}
+
+/// Column titles, with an arrow on the column the trades are sorted by
+fn trades_header(app: &TrendingAppState) -> Row<'static> {
+    let sort = app.trade_sort;
+    let title = |name: &str, column: TradeSortColumn| {
+        if sort.column == column && column != TradeSortColumn::Time {
+            format!("{} {}", name, sort.arrow())
+        } else {
+            name.to_string()
+        }
+    };
+    Row::new(vec![
+        "Time".to_string(),
+        "Side".to_string(),
+        "Out".to_string(),
+        title("Price", TradeSortColumn::Price),
+        title("Shares", TradeSortColumn::Shares),
+        title("Value", TradeSortColumn::Value),
+        "Market".to_string(),
+        "User".to_string(),
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
+/// Market column cell, emphasizing the part of the name matched by the active local filter
+fn market_cell(app: &TrendingAppState, market_name: &str) -> Cell<'static> {
+    let title = truncate(market_name, 30);
+    if app.search.mode != SearchMode::LocalFilter {
+        return Cell::from(title);
+    }
+    // Don't highlight the ellipsis of a truncated name
+    let visible_chars = if title == market_name {
+        title.chars().count()
+    } else {
+        title.chars().count().saturating_sub(1)
+    };
+    let positions: Vec<usize> = substring_match_positions(market_name, &app.search.query)
+        .into_iter()
+        .filter(|&idx| idx < visible_chars)
+        .collect();
+    if positions.is_empty() {
+        return Cell::from(title);
+    }
+    let highlight = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    Cell::from(Line::from(highlight_spans(
+        &title,
+        &positions,
+        Style::default(),
+        highlight,
+    )))
+}
+
+/// Market of `event` that trades `asset_id`
+fn trade_market<'a>(event: &'a Event, asset_id: &str) -> Option<&'a Market> {
+    event.markets.iter().find(|m| {
+        m.clob_token_ids
+            .as_ref()
+            .is_some_and(|ids| ids.iter().any(|id| id == asset_id))
+    })
+}
+
+/// Outcome name in the app's style, using the market's group title when known
+fn outcome_label(app: &TrendingAppState, market: Option<&Market>, outcome: &str) -> String {
+    match market {
+        Some(m) => m.display_outcome(outcome, app.outcome_style),
+        None => app.outcome_style.shorten(outcome),
+    }
+}
+
 /// Render the trades table with event context (for finding market names)
 pub fn render_trades_table(
     f: &mut Frame,
     app: &TrendingAppState,
     trades: &[Trade],
+    stats: &TradeStats,
     event: Option<&Event>,
     is_watching: bool,
     area: Rect,
@@ -31,6 +158,12 @@ pub fn render_trades_table(
     } else {
         Style::default()
     };
+    let title = trades_title(
+        app,
+        event.map(|e| e.slug.as_str()),
+        trades.len(),
+        is_focused,
+    );
 
     if trades.is_empty() {
         let status_text = if is_watching {
@@ -43,11 +176,7 @@ pub fn render_trades_table(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .title(if is_focused {
-                        format!("Trades ({}) (Focused)", trades.len())
-                    } else {
-                        format!("Trades ({})", trades.len())
-                    })
+                    .title(title)
                     .border_style(block_style),
             )
             .alignment(Alignment::Center)
@@ -56,14 +185,25 @@ pub fn render_trades_table(
         return;
     }
 
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .title(title)
+        .border_style(block_style);
+    let [stats_area, table_area] = split_stats_row(block.inner(area));
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(trade_stats_line(stats)), stats_area);
+    let trades = app.trade_sort.apply(trades);
+
     // Calculate visible rows and apply scroll
-    let visible_height = (area.height as usize).saturating_sub(3); // -3 for header
+    let visible_height = table_area.height.saturating_sub(1) as usize; // -1 for header
     let total_rows = trades.len();
     let scroll = app
         .scroll
         .trades
         .min(total_rows.saturating_sub(visible_height.max(1)));
 
+    let now = Instant::now();
     let rows: Vec<Row> = trades
         .iter()
         .enumerate()
@@ -87,25 +227,10 @@ pub fn render_trades_table(
             };
 
             // Find the market by asset_id and use short name if available
-            let market_name = event
-                .and_then(|e| {
-                    e.markets
-                        .iter()
-                        .find(|m| {
-                            m.clob_token_ids
-                                .as_ref()
-                                .is_some_and(|ids| ids.contains(&trade.asset_id))
-                        })
-                        .and_then(|m| {
-                            m.group_item_title
-                                .as_deref()
-                                .filter(|s| !s.is_empty())
-                                .or(Some(m.question.as_str()))
-                        })
-                })
-                .unwrap_or(&trade.title);
-
-            let title_truncated = truncate(market_name, 30);
+            let market = event.and_then(|e| trade_market(e, &trade.asset_id));
+            let market_name = market.map(|m| m.display_name()).unwrap_or(&trade.title);
+            let outcome_display = outcome_label(app, market, &trade.outcome);
+
             // Use user, fall back to pseudonym, or show "-" if both empty
             let user_display = if !trade.user.is_empty() {
                 &trade.user
@@ -117,20 +242,16 @@ pub fn render_trades_table(
             let user_truncated = truncate(user_display, 15);
 
             // Alternating row colors (zebra striping) for better readability
-            let bg_color = if idx % 2 == 0 {
-                Color::Reset
-            } else {
-                Color::Rgb(30, 30, 40)
-            };
+            let bg_color = row_background(idx, trade.flash_level(now));
 
             Row::new(vec![
                 Cell::from(time).style(Style::default().fg(Color::Gray)),
                 Cell::from(trade.side.clone()).style(side_style),
-                Cell::from(trade.outcome.clone()).style(outcome_style),
-                Cell::from(format!("${:.4}", trade.price)),
+                Cell::from(outcome_display).style(outcome_style),
+                Cell::from(format_price(trade.price, app.price_format)),
                 Cell::from(format!("{:.2}", trade.shares)),
                 Cell::from(format!("${:.2}", trade.total_value)),
-                Cell::from(title_truncated),
+                market_cell(app, market_name),
                 Cell::from(user_truncated),
             ])
             .style(Style::default().bg(bg_color))
@@ -147,27 +268,7 @@ pub fn render_trades_table(
         Constraint::Fill(1),    // Market (takes remaining space)
         Constraint::Length(12), // User
     ])
-    .header(
-        Row::new(vec![
-            "Time", "Side", "Out", "Price", "Shares", "Value", "Market", "User",
-        ])
-        .style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        ),
-    )
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .title(if is_focused {
-                format!("Trades ({}) (Focused)", trades.len())
-            } else {
-                format!("Trades ({})", trades.len())
-            })
-            .border_style(block_style),
-    )
+    .header(trades_header(app))
     .column_spacing(1)
     .row_highlight_style(
         Style::default()
@@ -179,13 +280,11 @@ pub fn render_trades_table(
     if is_focused && !trades.is_empty() {
         // Copy the state (TableState implements Copy in ratatui 0.30)
         let mut table_state = app.trades_table_state;
-        // Set selection if not already set
-        if table_state.selected().is_none() {
-            table_state.select(Some(0));
-        }
-        f.render_stateful_widget(table, area, &mut table_state);
+        // Highlight the selected trade (scroll.trades) relative to the visible window
+        table_state.select(Some(app.scroll.trades.saturating_sub(scroll)));
+        f.render_stateful_widget(table, table_area, &mut table_state);
     } else {
-        f.render_widget(table, area);
+        f.render_widget(table, table_area);
     }
 
     // Render scrollbar for trades if needed
@@ -206,6 +305,8 @@ pub fn render_trades_panel(
     f: &mut Frame,
     app: &TrendingAppState,
     trades: &[Trade],
+    stats: &TradeStats,
+    event: &Event,
     is_watching: bool,
     area: Rect,
 ) {
@@ -215,6 +316,7 @@ pub fn render_trades_panel(
     } else {
         Style::default()
     };
+    let title = trades_title(app, Some(&event.slug), trades.len(), is_focused);
 
     if trades.is_empty() {
         let status_text = if is_watching {
@@ -227,25 +329,32 @@ pub fn render_trades_panel(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .title(if is_focused {
-                        format!("Trades ({}) (Focused)", trades.len())
-                    } else {
-                        format!("Trades ({})", trades.len())
-                    })
+                    .title(title)
                     .border_style(block_style),
             )
             .alignment(Alignment::Center)
             .style(Style::default().fg(Color::Gray));
         f.render_widget(paragraph, area);
     } else {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .title(title)
+            .border_style(block_style);
+        let [stats_area, table_area] = split_stats_row(block.inner(area));
+        f.render_widget(block, area);
+        f.render_widget(Paragraph::new(trade_stats_line(stats)), stats_area);
+        let trades = app.trade_sort.apply(trades);
+
         // Calculate visible rows and apply scroll
-        let visible_height = (area.height as usize).saturating_sub(3);
+        let visible_height = table_area.height.saturating_sub(1) as usize;
         let total_rows = trades.len();
         let scroll = app
             .scroll
             .trades
             .min(total_rows.saturating_sub(visible_height.max(1)));
 
+        let now = Instant::now();
         let rows: Vec<Row> = trades
             .iter()
             .enumerate()
@@ -268,7 +377,6 @@ pub fn render_trades_panel(
                     Style::default().fg(Color::Red)
                 };
 
-                let title_truncated = truncate(&trade.title, 30);
                 let user_display = if !trade.user.is_empty() {
                     &trade.user
                 } else if !trade.pseudonym.is_empty() {
@@ -278,20 +386,21 @@ pub fn render_trades_panel(
                 };
                 let user_truncated = truncate(user_display, 15);
 
-                let bg_color = if idx % 2 == 0 {
-                    Color::Reset
-                } else {
-                    Color::Rgb(30, 30, 40)
-                };
+                let bg_color = row_background(idx, trade.flash_level(now));
 
                 Row::new(vec![
                     Cell::from(time).style(Style::default().fg(Color::Gray)),
                     Cell::from(trade.side.clone()).style(side_style),
-                    Cell::from(trade.outcome.clone()).style(outcome_style),
-                    Cell::from(format!("${:.4}", trade.price)),
+                    Cell::from(outcome_label(
+                        app,
+                        trade_market(event, &trade.asset_id),
+                        &trade.outcome,
+                    ))
+                    .style(outcome_style),
+                    Cell::from(format_price(trade.price, app.price_format)),
                     Cell::from(format!("{:.2}", trade.shares)),
                     Cell::from(format!("${:.2}", trade.total_value)),
-                    Cell::from(title_truncated),
+                    market_cell(app, &trade.title),
                     Cell::from(user_truncated),
                 ])
                 .style(Style::default().bg(bg_color))
@@ -308,30 +417,10 @@ pub fn render_trades_panel(
             Constraint::Fill(1),
             Constraint::Length(12),
         ])
-        .header(
-            Row::new(vec![
-                "Time", "Side", "Out", "Price", "Shares", "Value", "Market", "User",
-            ])
-            .style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        )
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .title(if is_focused {
-                    format!("Trades ({}) (Focused)", trades.len())
-                } else {
-                    format!("Trades ({})", trades.len())
-                })
-                .border_style(block_style),
-        )
+        .header(trades_header(app))
         .column_spacing(1);
 
-        f.render_widget(table, area);
+        f.render_widget(table, table_area);
 
         // Render scrollbar if needed
         if total_rows > visible_height {
@@ -346,3 +435,54 @@ pub fn render_trades_panel(
         }
     }
 }
+
+/// Split a trades panel's inner area into the stats row and the table below it
+/// Zebra-striped row background, blended with a flash for newly arrived trades
+fn row_background(idx: usize, flash_level: f32) -> Color {
+    const STRIPE: (u8, u8, u8) = (30, 30, 40);
+    const FLASH: (u8, u8, u8) = (110, 95, 20);
+    if flash_level <= 0.0 {
+        return if idx.is_multiple_of(2) {
+            Color::Reset
+        } else {
+            Color::Rgb(STRIPE.0, STRIPE.1, STRIPE.2)
+        };
+    }
+    // Terminal default background is unknown, so even rows fade towards the stripe color
+    let blend = |from: u8, to: u8| (to as f32 + (from as f32 - to as f32) * flash_level) as u8;
+    Color::Rgb(
+        blend(FLASH.0, STRIPE.0),
+        blend(FLASH.1, STRIPE.1),
+        blend(FLASH.2, STRIPE.2),
+    )
+}
+
+fn split_stats_row(inner: Rect) -> [Rect; 2] {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+    [chunks[0], chunks[1]]
+}
+
+/// One-line summary of the session's buy/sell volume and net flow
+fn trade_stats_line(stats: &TradeStats) -> Line<'static> {
+    let label = Style::default().fg(Color::Gray);
+    let (net, net_color) = format_pnl(stats.net_flow());
+    Line::from(vec![
+        Span::styled("Buy ", label),
+        Span::styled(
+            format!("${}", format_with_thousands(stats.buy_volume, 2)),
+            Style::default().fg(Color::Green),
+        ),
+        Span::styled("  Sell ", label),
+        Span::styled(
+            format!("${}", format_with_thousands(stats.sell_volume, 2)),
+            Style::default().fg(Color::Red),
+        ),
+        Span::styled("  Net ", label),
+        Span::styled(net, Style::default().fg(net_color)),
+        Span::styled("  Trades ", label),
+        Span::raw(stats.trade_count.to_string()),
+    ])
+}
@@ -1,8 +1,9 @@
 //! Utility functions for rendering
 
 use {
+    crate::display_trait::{MISSING_VALUE, PriceFormat, format_price},
     chrono::{DateTime, Utc},
-    polymarket_api::gamma::Event,
+    polymarket_api::gamma::{Event, OutcomeNameStyle},
     ratatui::{
         Frame,
         layout::{Position, Rect},
@@ -15,13 +16,19 @@ use {
 
 /// Format a number with thousands separators (e.g., 1234567 -> "1,234,567")
 pub fn format_with_thousands(n: f64, decimals: usize) -> String {
+    if !n.is_finite() {
+        return MISSING_VALUE.to_string();
+    }
     let formatted = format!("{:.prec$}", n, prec = decimals);
     let parts: Vec<&str> = formatted.split('.').collect();
-    let int_part = parts[0];
+    let (sign, int_part) = match parts[0].strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", parts[0]),
+    };
 
     // Add thousands separators to integer part
     let chars: Vec<char> = int_part.chars().collect();
-    let mut result = String::new();
+    let mut result = sign.to_string();
     for (i, c) in chars.iter().enumerate() {
         if i > 0 && (chars.len() - i).is_multiple_of(3) {
             result.push(',');
@@ -36,30 +43,18 @@ pub fn format_with_thousands(n: f64, decimals: usize) -> String {
     }
 }
 
-/// Format a price (0.0-1.0) as cents like the Polymarket website
-/// Uses 1 decimal place for sub-cent and high prices to match website rounding
-/// Examples: 0.01 -> "1¢", 0.11 -> "11¢", 0.89 -> "89¢", 0.003 -> "0.3¢", 0.998 -> "99.8¢"
+/// Format a price (0.0-1.0) as cents like the Polymarket website (see [`format_price`])
 pub fn format_price_cents(price: f64) -> String {
-    let cents = price * 100.0;
-    if cents < 0.1 {
-        // Very small prices, show with 2 decimal places
-        format!("{:.2}¢", cents)
-    } else if cents < 1.0 {
-        // Sub-cent prices, show with 1 decimal place (e.g., 0.3¢)
-        format!("{:.1}¢", cents)
-    } else if cents < 10.0 {
-        format!("{:.1}¢", cents)
-    } else if cents > 99.0 && cents < 100.0 {
-        // High prices (99-100%), show with 1 decimal place to match website
-        format!("{:.1}¢", cents)
-    } else {
-        format!("{:.0}¢", cents)
-    }
+    format_price(price, PriceFormat::Cents)
 }
 
 /// Format a volume/liquidity value with appropriate units (K, M)
+///
+/// Zero and negative values are blank; NaN and infinity show [`MISSING_VALUE`].
 pub fn format_volume(value: f64) -> String {
-    if value >= 1_000_000.0 {
+    if !value.is_finite() {
+        MISSING_VALUE.to_string()
+    } else if value >= 1_000_000.0 {
         format!("${:.1}M", value / 1_000_000.0)
     } else if value >= 1_000.0 {
         format!("${:.0}K", value / 1_000.0)
@@ -84,8 +79,10 @@ pub fn truncate(s: &str, max_chars: usize) -> String {
 /// Format a profit/loss value with appropriate sign and color
 /// Returns (formatted_string, color)
 pub fn format_pnl(value: f64) -> (String, Color) {
-    // Treat near-zero values as zero to avoid -$0.00
-    if value.abs() < 0.005 {
+    if !value.is_finite() {
+        (MISSING_VALUE.to_string(), Color::DarkGray)
+    } else if value.abs() < 0.005 {
+        // Treat near-zero values as zero to avoid -$0.00
         ("$0.00".to_string(), Color::DarkGray)
     } else if value > 0.0 {
         (format!("+${:.2}", value), Color::Green)
@@ -120,11 +117,65 @@ pub fn truncate_to_width(s: &str, max_width: usize) -> String {
     result
 }
 
-/// Yield opportunity threshold (95% probability = 5% potential return)
-pub const YIELD_MIN_PROB: f64 = 0.95;
+/// Split text into spans, styling the chars at `positions` (char indices) with `highlight`
+pub fn highlight_spans(
+    text: &str,
+    positions: &[usize],
+    style: Style,
+    highlight: Style,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_highlighted = false;
+    for (idx, c) in text.chars().enumerate() {
+        let highlighted = positions.contains(&idx);
+        if highlighted != run_highlighted && !run.is_empty() {
+            let run_style = if run_highlighted {
+                highlight
+            } else {
+                style
+            };
+            spans.push(Span::styled(std::mem::take(&mut run), run_style));
+        }
+        run_highlighted = highlighted;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        let run_style = if run_highlighted {
+            highlight
+        } else {
+            style
+        };
+        spans.push(Span::styled(run, run_style));
+    }
+    spans
+}
 
-/// Check if a market has a yield opportunity (any outcome with price >= 95% and < 100%)
-pub fn market_has_yield(market: &polymarket_api::gamma::Market) -> bool {
+/// Char indices of every case-insensitive occurrence of `query` in `text`
+pub fn substring_match_positions(text: &str, query: &str) -> Vec<usize> {
+    let needle: Vec<char> = query.trim().chars().flat_map(char::to_lowercase).collect();
+    let haystack: Vec<char> = text
+        .chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect();
+    let mut positions = Vec::new();
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return positions;
+    }
+    let mut idx = 0;
+    while idx + needle.len() <= haystack.len() {
+        if haystack[idx..idx + needle.len()] == needle[..] {
+            positions.extend(idx..idx + needle.len());
+            idx += needle.len();
+        } else {
+            idx += 1;
+        }
+    }
+    positions
+}
+
+/// Check if a market has a yield opportunity (any outcome with price >= min_prob and < 100%)
+pub fn market_has_yield(market: &polymarket_api::gamma::Market, min_prob: f64) -> bool {
     // Skip closed/resolved markets - no yield opportunity
     if market.closed {
         return false;
@@ -134,13 +185,32 @@ pub fn market_has_yield(market: &polymarket_api::gamma::Market) -> bool {
         price_str
             .parse::<f64>()
             .ok()
-            .is_some_and(|price| (YIELD_MIN_PROB..1.0).contains(&price))
+            .is_some_and(|price| (min_prob..1.0).contains(&price))
     })
 }
 
 /// Check if an event has any yield opportunities (any market with high probability outcome)
-pub fn event_has_yield(event: &polymarket_api::gamma::Event) -> bool {
-    event.markets.iter().any(market_has_yield)
+pub fn event_has_yield(event: &polymarket_api::gamma::Event, min_prob: f64) -> bool {
+    event
+        .markets
+        .iter()
+        .any(|market| market_has_yield(market, min_prob))
+}
+
+/// Winner of a resolved event
+///
+/// For a single-market event this is its winning outcome; for a multi-market event
+/// it is the market whose first outcome ("Yes") won.
+pub fn event_winner(event: &Event, style: OutcomeNameStyle) -> Option<String> {
+    match event.markets.as_slice() {
+        [market] => market
+            .winning_outcome()
+            .map(|outcome| market.display_outcome(outcome, style)),
+        markets => markets
+            .iter()
+            .find(|market| market.resolved_outcome() == Some(0))
+            .map(|market| market.display_name().to_string()),
+    }
 }
 
 /// Create a centered rectangle with percentage-based dimensions
@@ -263,6 +333,62 @@ pub fn render_search_input(
     }
 }
 
+/// "Estimated End" text and style: relative time plus a d:h:m countdown
+///
+/// Red/bold inside the last 24 hours. Past the end date an event that hasn't
+/// closed yet is awaiting resolution.
+pub fn resolution_countdown(
+    end: DateTime<Utc>,
+    now: DateTime<Utc>,
+    closed: bool,
+) -> (String, Style) {
+    let remaining = end.signed_duration_since(now);
+    let end_str = end.format("%Y-%m-%d %H:%M UTC");
+    if remaining.num_seconds() < 0 {
+        return if closed {
+            (
+                format!("Expired ({})", end_str),
+                Style::default().fg(Color::Magenta),
+            )
+        } else {
+            (
+                format!("Awaiting resolution ({})", end_str),
+                Style::default().fg(Color::Yellow),
+            )
+        };
+    }
+
+    let relative = if remaining.num_days() > 0 {
+        format!("{} days", remaining.num_days())
+    } else if remaining.num_hours() > 0 {
+        format!("{} hours", remaining.num_hours())
+    } else {
+        format!("{} min", remaining.num_minutes())
+    };
+    let countdown = format!(
+        "{}d {:02}h {:02}m",
+        remaining.num_days(),
+        remaining.num_hours() % 24,
+        remaining.num_minutes() % 60
+    );
+    let style = if remaining.num_hours() < 24 {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Magenta)
+    };
+    (format!("{} ({})", relative, countdown), style)
+}
+
+/// Sum volumes, skipping NaN, infinite and negative values
+///
+/// Saturates at `f64::MAX` instead of overflowing to infinity.
+pub fn sum_volumes(values: impl IntoIterator<Item = f64>) -> f64 {
+    values
+        .into_iter()
+        .filter(|v| v.is_finite() && *v > 0.0)
+        .fold(0.0, |total, v| (total + v).min(f64::MAX))
+}
+
 /// Shared function to build event info lines for display
 /// Used by both Events tab and Yield tab to show consistent event details
 pub fn build_event_info_lines(
@@ -273,14 +399,15 @@ pub fn build_event_info_lines(
     area_width: u16,
 ) -> Vec<Line<'static>> {
     // Calculate total volume from all markets
-    let total_volume: f64 = event
-        .markets
-        .iter()
-        .map(|m| m.volume_24hr.or(m.volume_total).unwrap_or(0.0))
-        .sum();
+    let total_volume = sum_volumes(
+        event
+            .markets
+            .iter()
+            .map(|m| m.volume_24hr.or(m.volume_total).unwrap_or(0.0)),
+    );
 
-    // Format end date with relative time
-    let end_date_str = event
+    // Recomputed on every draw so the countdown ticks while the TUI is open
+    let (end_date_str, end_date_style) = event
         .end_date
         .as_ref()
         .and_then(|date_str| {
@@ -288,22 +415,8 @@ pub fn build_event_info_lines(
                 .ok()
                 .map(|dt| dt.with_timezone(&Utc))
         })
-        .map(|dt| {
-            let now = Utc::now();
-            let duration = dt.signed_duration_since(now);
-            if duration.num_days() > 0 {
-                format!("{} days", duration.num_days())
-            } else if duration.num_hours() > 0 {
-                format!("{} hours", duration.num_hours())
-            } else if duration.num_minutes() > 0 {
-                format!("{} min", duration.num_minutes())
-            } else if duration.num_seconds() < 0 {
-                format!("Expired ({})", dt.format("%Y-%m-%d %H:%M UTC"))
-            } else {
-                format!("{}", dt.format("%Y-%m-%d %H:%M UTC"))
-            }
-        })
-        .unwrap_or_else(|| "N/A".to_string());
+        .map(|dt| resolution_countdown(dt, Utc::now(), event.closed))
+        .unwrap_or_else(|| ("N/A".to_string(), Style::default().fg(Color::Magenta)));
 
     // Format volume
     let volume_str = if total_volume >= 1_000_000.0 {
@@ -373,7 +486,7 @@ pub fn build_event_info_lines(
         // Estimated End
         Line::from(vec![
             Span::styled("Estimated End: ", Style::default().fg(Color::Yellow).bold()),
-            Span::styled(end_date_str, Style::default().fg(Color::Magenta)),
+            Span::styled(end_date_str, end_date_style),
         ]),
         // Total Volume | Trades
         Line::from(vec![
@@ -436,3 +549,113 @@ pub fn build_event_info_lines(
 
     lines
 }
+
+/// Render values as a one-line braille sparkline of `width` characters
+///
+/// Each braille cell holds two samples as bars of up to four dots, scaled between the
+/// minimum and maximum value. Values are resampled to fit the width.
+pub fn braille_sparkline(values: &[f64], width: usize) -> String {
+    if values.is_empty() || width == 0 {
+        return String::new();
+    }
+    let samples = (width * 2).min(values.len().max(1));
+    let resampled: Vec<f64> = (0..samples)
+        .map(|i| {
+            let start = i * values.len() / samples;
+            let end = ((i + 1) * values.len() / samples).max(start + 1);
+            let bucket = &values[start..end];
+            bucket.iter().sum::<f64>() / bucket.len() as f64
+        })
+        .collect();
+
+    let min = resampled.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = resampled.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    let level = |v: f64| -> usize {
+        if range <= f64::EPSILON {
+            2
+        } else {
+            1 + ((v - min) / range * 3.0).round() as usize
+        }
+    };
+
+    // Dot bits from the bottom row up, for the left and right column of a cell
+    const LEFT: [u32; 4] = [0x40, 0x04, 0x02, 0x01];
+    const RIGHT: [u32; 4] = [0x80, 0x20, 0x10, 0x08];
+    resampled
+        .chunks(2)
+        .map(|pair| {
+            let mut bits = 0;
+            for (i, &v) in pair.iter().enumerate() {
+                let column = if i == 0 {
+                    &LEFT
+                } else {
+                    &RIGHT
+                };
+                bits |= column[..level(v)].iter().fold(0, |acc, b| acc | b);
+            }
+            char::from_u32(0x2800 + bits).unwrap_or(' ')
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_braille_sparkline() {
+        assert_eq!(braille_sparkline(&[], 10), "");
+        // Rising pair fills one cell: bottom-left dot plus the full right column
+        assert_eq!(braille_sparkline(&[0.0, 1.0], 5), "\u{28f8}");
+        // Flat series renders at mid height, resampled to the width
+        assert_eq!(braille_sparkline(&[0.5; 10], 2), "\u{28e4}\u{28e4}");
+    }
+
+    #[test]
+    fn test_resolution_countdown() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        let now = Utc
+            .with_ymd_and_hms(2030, 3, 17, 12, 0, 0)
+            .single()
+            .expect("Should build date");
+
+        let (text, style) =
+            resolution_countdown(now + Duration::minutes(3 * 1440 + 5 * 60 + 7), now, false);
+        assert_eq!(text, "3 days (3d 05h 07m)");
+        assert_eq!(style.fg, Some(Color::Magenta));
+
+        let (text, style) = resolution_countdown(now + Duration::minutes(90), now, false);
+        assert_eq!(text, "1 hours (0d 01h 30m)");
+        assert_eq!(style.fg, Some(Color::Red));
+        assert!(style.add_modifier.contains(Modifier::BOLD));
+
+        let (text, _) = resolution_countdown(now - Duration::hours(1), now, false);
+        assert_eq!(text, "Awaiting resolution (2030-03-17 11:00 UTC)");
+        let (text, _) = resolution_countdown(now - Duration::hours(1), now, true);
+        assert_eq!(text, "Expired (2030-03-17 11:00 UTC)");
+    }
+
+    #[test]
+    fn test_format_helpers_handle_malformed_values() {
+        use crate::display_trait::MISSING_VALUE;
+
+        assert_eq!(format_volume(f64::NAN), MISSING_VALUE);
+        assert_eq!(format_volume(f64::INFINITY), MISSING_VALUE);
+        assert_eq!(format_volume(-5_000.0), "");
+        assert_eq!(format_volume(2_500_000.0), "$2.5M");
+        assert_eq!(format_price_cents(f64::NAN), MISSING_VALUE);
+        assert_eq!(format_pnl(f64::NEG_INFINITY).0, MISSING_VALUE);
+        assert_eq!(format_pnl(-12.5).0, "-$12.50");
+        assert_eq!(format_with_thousands(f64::NAN, 2), MISSING_VALUE);
+        assert_eq!(format_with_thousands(-123_456.0, 0), "-123,456");
+        assert_eq!(format_with_thousands(-1_234.5, 1), "-1,234.5");
+
+        assert_eq!(
+            sum_volumes([10.0, f64::NAN, -3.0, f64::INFINITY, 5.0]),
+            15.0
+        );
+        assert_eq!(sum_volumes([f64::MAX, f64::MAX]), f64::MAX);
+    }
+}
@@ -1,8 +1,10 @@
 //! Yield tab rendering functions
 
 use {
-    super::utils::{format_price_cents, truncate},
-    crate::trending_tui::state::{FocusedPanel, TrendingAppState},
+    super::utils::{format_price_cents, format_volume, truncate},
+    crate::trending_tui::state::{
+        FocusedPanel, RETURN_BUCKETS, TrendingAppState, YieldSortBy, YieldSummary,
+    },
     chrono::{DateTime, Utc},
     ratatui::{
         Frame,
@@ -10,8 +12,8 @@ use {
         style::{Color, Modifier, Style},
         text::{Line, Span},
         widgets::{
-            Block, BorderType, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation,
-            ScrollbarState, Table, Wrap,
+            Bar, BarChart, BarGroup, Block, BorderType, Borders, Cell, Paragraph, Row, Scrollbar,
+            ScrollbarOrientation, ScrollbarState, Table, Wrap,
         },
     },
 };
@@ -113,6 +115,61 @@ pub fn render_yield_tab(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     }
 }
 
+/// Rows taken by the returns histogram above the yield list (0 when hidden)
+///
+/// The histogram is dropped when the list is too small to leave room for rows.
+pub fn yield_histogram_height(app: &TrendingAppState, list_area: Rect) -> u16 {
+    let yield_state = &app.yield_state;
+    let showing_list =
+        yield_state.search_results.is_empty() && yield_state.last_searched_query.is_empty();
+    if !showing_list
+        || yield_state.is_loading
+        || yield_state.filtered_opportunities().is_empty()
+        || list_area.height < 16
+        || list_area.width < 30
+    {
+        return 0;
+    }
+    RETURN_BUCKETS.len() as u16 + 2
+}
+
+/// Horizontal bar chart of opportunities per return bucket
+fn render_yield_histogram(f: &mut Frame, app: &TrendingAppState, area: Rect) {
+    let counts = app.yield_state.return_histogram();
+    let bars: Vec<Bar> = RETURN_BUCKETS
+        .iter()
+        .zip(counts)
+        .map(|((label, lower), count)| {
+            // Same thresholds as the Return column colors
+            let color = if *lower >= 5.0 {
+                Color::Green
+            } else if *lower >= 2.0 {
+                Color::Yellow
+            } else {
+                Color::Red
+            };
+            Bar::default()
+                .label(Line::from(format!("{:>5}", label)))
+                .value(count)
+                .style(Style::default().fg(color))
+                .value_style(Style::default().fg(Color::Black).bg(color))
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title("Returns"),
+        )
+        .direction(Direction::Horizontal)
+        .bar_width(1)
+        .bar_gap(0)
+        .data(BarGroup::default().bars(&bars));
+    f.render_widget(chart, area);
+}
+
 fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     let yield_state = &app.yield_state;
 
@@ -164,6 +221,21 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         return;
     }
 
+    let histogram_height = yield_histogram_height(app, area);
+    let area = if histogram_height > 0 {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(histogram_height), // Returns histogram
+                Constraint::Min(0),                   // Opportunities table
+            ])
+            .split(area);
+        render_yield_histogram(f, app, chunks[0]);
+        chunks[1]
+    } else {
+        area
+    };
+
     // Calculate visible height (accounting for borders and header row)
     let visible_height = (area.height as usize).saturating_sub(3); // -2 borders, -1 header
     let total_items = filtered.len();
@@ -203,6 +275,17 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 "-".to_string()
             };
 
+            let liquidity_str = match format_volume(opp.liquidity) {
+                s if s.is_empty() => "-".to_string(),
+                s => s,
+            };
+            // Thin books can't fill a meaningful size at the quoted price
+            let liquidity_color = if opp.liquidity >= 10_000.0 {
+                Color::Green
+            } else {
+                Color::DarkGray
+            };
+
             // Format end date from cached event
             let end_str = cached_event
                 .and_then(|e| e.end_date.as_ref())
@@ -225,7 +308,13 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 })
                 .unwrap_or_else(|| "N/A".to_string());
 
-            let return_str = format!("{:.2}%", opp.est_return);
+            let return_str = if yield_state.sort_by == YieldSortBy::Annualized {
+                opp.est_return_annualized(Utc::now())
+                    .map(|annualized| format!("{:.0}%/y", annualized))
+                    .unwrap_or_else(|| "-".to_string())
+            } else {
+                format!("{:.2}%", opp.est_return)
+            };
             let price_str = format_price_cents(opp.price);
 
             // Check if event is favorited
@@ -260,6 +349,7 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 Cell::from(return_str).style(Style::default().fg(return_color)),
                 Cell::from(price_str).style(Style::default().fg(Color::Cyan)),
                 Cell::from(volume_str).style(Style::default().fg(Color::Green)),
+                Cell::from(liquidity_str).style(Style::default().fg(liquidity_color)),
                 Cell::from(end_str).style(Style::default().fg(Color::Magenta)),
             ])
             .style(Style::default().bg(bg_color))
@@ -313,7 +403,9 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
             Span::raw(format!("{}─", position_indicator)),
         ]));
     } else {
-        block = block.title_bottom(Line::from(format!("{}─", position_indicator)).right_aligned());
+        block = block
+            .title_bottom(yield_summary_line(&yield_state.summary()))
+            .title_bottom(Line::from(format!("{}─", position_indicator)).right_aligned());
     }
 
     let table = Table::new(rows, [
@@ -321,16 +413,19 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
         Constraint::Length(7), // Return (e.g., "12.34%")
         Constraint::Length(7), // Price (e.g., "95.5¢")
         Constraint::Length(8), // Volume (e.g., "$123.4K")
+        Constraint::Length(7), // Liquidity (e.g., "$45K")
         Constraint::Length(7), // Expires (e.g., "expired")
     ])
     .header(
-        Row::new(vec!["Market", "Return", "Price", "Volume", "Expires"])
-            .style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .bottom_margin(0),
+        Row::new(vec![
+            "Market", "Return", "Price", "Volume", "Liq", "Expires",
+        ])
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(0),
     )
     .block(block)
     .column_spacing(1)
@@ -359,6 +454,27 @@ fn render_yield_list(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     }
 }
 
+/// Footer with the count, average and best return, and volume of the listed opportunities
+fn yield_summary_line(summary: &YieldSummary) -> Line<'static> {
+    let volume = match format_volume(summary.total_volume) {
+        s if s.is_empty() => "$0".to_string(),
+        s => s,
+    };
+    Line::from(vec![
+        Span::raw(format!(" {} shown · avg ", summary.count)),
+        Span::styled(
+            format!("{:.2}%", summary.avg_return),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::raw(" · best "),
+        Span::styled(
+            format!("{:.2}%", summary.best_return),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw(format!(" · vol {} ", volume)),
+    ])
+}
+
 fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
     let yield_state = &app.yield_state;
 
@@ -452,7 +568,7 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                 Line::from(vec![
                     Span::styled("Outcome: ", Style::default().fg(Color::Yellow).bold()),
                     Span::styled(
-                        opp.outcome.clone(),
+                        opp.display_outcome(app.outcome_style),
                         Style::default().fg(if opp.outcome == "Yes" {
                             Color::Green
                         } else {
@@ -479,6 +595,12 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                             .fg(return_color)
                             .add_modifier(Modifier::BOLD),
                     ),
+                    Span::styled(
+                        opp.est_return_annualized(Utc::now())
+                            .map(|annualized| format!(" ({:.0}%/yr)", annualized))
+                            .unwrap_or_default(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
                 ]),
                 Line::from(vec![
                     Span::styled("24h Volume: ", Style::default().fg(Color::Yellow).bold()),
@@ -630,6 +752,12 @@ fn render_yield_details(f: &mut Frame, app: &TrendingAppState, area: Rect) {
                             .fg(return_color)
                             .add_modifier(Modifier::BOLD),
                     ),
+                    Span::styled(
+                        opp.est_return_annualized(Utc::now())
+                            .map(|annualized| format!(" ({:.0}%/yr)", annualized))
+                            .unwrap_or_default(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
                 ]),
                 Line::from(vec![
                     Span::styled("24h Volume: ", Style::default().fg(Color::Yellow).bold()),
@@ -0,0 +1,1081 @@
+//! Snapshot tests for the trending TUI rendering
+//!
+//! Each test builds a `TrendingAppState` from the shared API fixtures, renders it into a
+//! ratatui `TestBackend` and compares the buffer text against `tests/snapshots/<name>.txt`.
+//! Run with `UPDATE_SNAPSHOTS=1` to (re)write the snapshot files after an intended change.
+
+use {
+    super::{
+        input::{KeyState, handle_key},
+        render::{get_clicked_tab, render},
+        state::{
+            self, AppTab, EventTrades, FocusedPanel, InputContext, MainTab, OutcomeInfo, PopupType,
+            ProbabilityHistory, Trade, TradeFormState, TradeSide, TrendingAppState,
+            leading_outcome,
+        },
+        test_fixtures::{
+            app_with_events, fixture_events, fixture_markets, fixtures, loaded_app, rtds_trade,
+        },
+    },
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    polymarket_api::{clob::OpenOrder, yields::find_yield_opportunities},
+    ratatui::{Terminal, backend::TestBackend},
+    std::{path::PathBuf, sync::Arc},
+    tokio::sync::Mutex as TokioMutex,
+};
+
+const WIDTH: u16 = 120;
+const HEIGHT: u16 = 40;
+
+/// Render the app into an in-memory buffer and return it as text, one line per row
+fn render_to_string(app: &mut TrendingAppState) -> String {
+    let mut terminal =
+        Terminal::new(TestBackend::new(WIDTH, HEIGHT)).expect("Should create terminal");
+    terminal
+        .draw(|f| render(f, app))
+        .expect("Should render frame");
+
+    let buffer = terminal.backend().buffer();
+    let mut output = String::new();
+    for y in 0..buffer.area.height {
+        let line: String = (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect();
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+/// Compare rendered output against the stored snapshot (or write it with UPDATE_SNAPSHOTS=1)
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{}.txt", name));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().expect("Should have parent dir"))
+            .expect("Should create snapshot dir");
+        std::fs::write(&path, actual).expect("Should write snapshot");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "Missing snapshot {} (run with UPDATE_SNAPSHOTS=1 to create it)",
+            path.display()
+        )
+    });
+    assert_eq!(
+        actual,
+        expected,
+        "Rendered output differs from snapshot {} (run with UPDATE_SNAPSHOTS=1 to update)",
+        path.display()
+    );
+}
+
+#[test]
+fn test_snapshot_empty_list() {
+    let mut app = app_with_events(Vec::new());
+    assert_snapshot("empty_list", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_loaded_events() {
+    let mut app = loaded_app();
+    assert_snapshot("loaded_events", &render_to_string(&mut app));
+}
+
+/// Loaded app with the election event's fixture trades in its live feed
+fn app_with_trades() -> TrendingAppState {
+    let mut app = loaded_app();
+    let trades = fixtures()
+        .load_or_default::<Vec<polymarket_api::data::DataTrade>>("data/trades")
+        .expect("Should load trade fixtures");
+
+    let mut event_trades = EventTrades::new();
+    event_trades.trades = trades
+        .iter()
+        .filter(|t| t.event_slug == "example-election-winner")
+        .map(|t| Trade {
+            timestamp: t.timestamp,
+            side: t.side.clone(),
+            outcome: t.outcome.clone(),
+            price: t.price,
+            shares: t.size,
+            total_value: t.price * t.size,
+            title: t.title.clone(),
+            asset_id: t.asset.clone(),
+            user: t.name.clone(),
+            pseudonym: t.pseudonym.clone(),
+            arrived_at: std::time::Instant::now(),
+        })
+        .collect();
+    for trade in &event_trades.trades {
+        event_trades.stats.record(trade);
+    }
+    event_trades.is_watching = true;
+    app.trades
+        .event_trades
+        .insert("example-election-winner".to_string(), event_trades);
+    app
+}
+
+#[test]
+fn test_price_format_toggle() {
+    use crate::display_trait::{PriceFormat, format_price};
+
+    assert_eq!(format_price(0.95, PriceFormat::Cents), "95¢");
+    assert_eq!(format_price(0.998, PriceFormat::Cents), "99.8¢");
+    assert_eq!(format_price(0.003, PriceFormat::Cents), "0.3¢");
+    assert_eq!(format_price(0.95, PriceFormat::Dollars), "$0.950");
+
+    let app_state = Arc::new(TokioMutex::new(app_with_trades()));
+    let mut keys = KeyState::default();
+    let app = app_state.try_lock().expect("Should lock app state");
+    handle_key(
+        &app_state,
+        app,
+        KeyEvent::new(KeyCode::Char('C'), KeyModifiers::NONE),
+        &mut keys,
+    );
+    let mut app = app_state.try_lock().expect("Should lock app state");
+    assert_eq!(app.price_format, PriceFormat::Dollars);
+    let screen = render_to_string(&mut app);
+    // Markets panel buttons and trades table both follow the toggle
+    assert!(screen.contains("$0.620"), "{}", screen);
+    assert!(!screen.contains("62¢"), "{}", screen);
+}
+
+#[test]
+fn test_snapshot_resolved_event() {
+    let mut events = fixture_events();
+    let election = &mut events[0];
+    election.closed = true;
+    for (market, prices) in election
+        .markets
+        .iter_mut()
+        .zip([["0", "1"], ["0", "1"], ["1", "0"]])
+    {
+        market.closed = true;
+        market.outcome_prices = prices.iter().map(|p| p.to_string()).collect();
+    }
+    assert_eq!(events[0].markets[2].winning_outcome(), Some("Yes"));
+
+    let mut app = app_with_events(events);
+    assert_snapshot("resolved_event", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_selected_event_with_trades() {
+    let mut app = app_with_trades();
+    assert_snapshot("selected_event_with_trades", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_trades_highlight_local_filter_match() {
+    use {
+        super::{render::utils::substring_match_positions, state::SearchMode},
+        ratatui::style::{Color, Modifier},
+    };
+
+    assert_eq!(substring_match_positions("Candidate A", "cand"), vec![
+        0, 1, 2, 3
+    ]);
+    assert_eq!(substring_match_positions("a-A", "a"), vec![0, 2]);
+    assert!(substring_match_positions("Candidate A", "  ").is_empty());
+
+    /// Market column cells rendered bold cyan, as text
+    fn highlighted_text(app: &mut TrendingAppState) -> String {
+        let mut terminal =
+            Terminal::new(TestBackend::new(WIDTH, HEIGHT)).expect("Should create terminal");
+        terminal
+            .draw(|f| render(f, app))
+            .expect("Should render frame");
+        let buffer = terminal.backend().buffer();
+        let mut text = String::new();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                let cell = &buffer[(x, y)];
+                if cell.fg == Color::Cyan && cell.modifier.contains(Modifier::BOLD) {
+                    text.push_str(cell.symbol());
+                }
+            }
+        }
+        text
+    }
+
+    let mut app = app_with_trades();
+    assert_eq!(highlighted_text(&mut app), "");
+
+    app.search.mode = SearchMode::LocalFilter;
+    app.search.query = "cand".to_string();
+    assert_eq!(
+        highlighted_text(&mut app),
+        "CandCandCand",
+        "Each trade row should emphasize its matching market name"
+    );
+}
+
+#[test]
+fn test_min_trade_value_filter() {
+    let trade_worth = |value: f64| {
+        let mut msg = rtds_trade("1002", 0.5);
+        msg.payload.size = value / 0.5;
+        msg
+    };
+    let mut app = loaded_app();
+    let slug = "example-election-winner";
+    app.trades
+        .event_trades
+        .insert(slug.to_string(), EventTrades::new());
+    app.add_trade(slug, &trade_worth(50.0));
+    app.add_trade(slug, &trade_worth(500.0));
+
+    // $0 -> $100 drops the small trade already shown
+    assert_eq!(app.cycle_min_trade_value(slug), 100.0);
+    assert_eq!(app.get_trades(slug).len(), 1);
+    assert_eq!(app.cycle_min_trade_value(slug), 1_000.0);
+    app.add_trade(slug, &trade_worth(999.0));
+    app.add_trade(slug, &trade_worth(5_000.0));
+    assert_eq!(app.get_trades(slug).len(), 1);
+    // Filtered trades still count towards the session totals
+    assert_eq!(app.trade_stats(slug).trade_count, 4);
+
+    let output = render_to_string(&mut app);
+    assert!(output.contains("Trades (1) ≥$1K"), "{}", output);
+
+    assert_eq!(app.cycle_min_trade_value(slug), 10_000.0);
+    assert_eq!(app.cycle_min_trade_value(slug), 0.0);
+    assert!(!render_to_string(&mut app).contains('≥'));
+}
+
+#[test]
+fn test_snapshot_event_probability_trend() {
+    let mut app = loaded_app();
+    let event = app.selected_event().expect("Should select an event");
+    let (token_id, outcome) = leading_outcome(event).expect("Should find leading outcome");
+    assert_eq!(token_id, "1006");
+    assert_eq!(outcome, "Candidate C");
+
+    let history: polymarket_api::clob::PriceHistoryResponse = fixtures()
+        .load_required("clob/prices-history")
+        .expect("Should load price history fixture");
+    app.history
+        .insert("example-election-winner".to_string(), ProbabilityHistory {
+            outcome,
+            points: history.history.iter().map(|p| p.p).collect(),
+        });
+    assert_snapshot("event_probability_trend", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_resolution_notice() {
+    let positions: Vec<polymarket_api::data::Position> = fixtures()
+        .load_required("data/positions")
+        .expect("Should load position fixtures");
+    let mut events = fixture_events();
+    let mut app = loaded_app();
+
+    // The first check only records the open market
+    assert!(app.resolutions.observe(&positions, &events).is_empty());
+
+    // Candidate A wins: 150 Yes shares bought for $87 pay out $150
+    let market = &mut events[0].markets[0];
+    market.closed = true;
+    market.outcome_prices = vec!["1".to_string(), "0".to_string()];
+    let notices = app.resolutions.observe(&positions, &events);
+    assert_eq!(notices.len(), 1);
+    assert!(notices[0].won);
+    assert_eq!(notices[0].pnl, 63.0);
+
+    // Each resolution is announced once
+    assert!(app.resolutions.observe(&positions, &events).is_empty());
+
+    let now = std::time::Instant::now();
+    app.resolutions.tick(now);
+    assert_snapshot("footer_resolution_notice", &render_to_string(&mut app));
+
+    app.resolutions
+        .tick(now + std::time::Duration::from_secs(60));
+    assert!(app.resolutions.current().is_none());
+}
+
+#[test]
+fn test_snapshot_markets_price_sparkline() {
+    let mut app = loaded_app();
+    assert!(!app.add_trade("unwatched-event", &rtds_trade("1002", 0.5)));
+    assert!(app.price_history.is_empty());
+
+    app.trades
+        .event_trades
+        .insert("example-election-winner".to_string(), EventTrades::new());
+    for i in 0..60 {
+        let price = 0.40 + (i % 10) as f64 * 0.02;
+        assert!(app.add_trade("example-election-winner", &rtds_trade("1002", price)));
+    }
+    let history = &app.price_history["1002"];
+    assert_eq!(history.len(), 50);
+    // The oldest ten prices were dropped
+    assert_eq!(history.front().copied(), Some(0.40));
+    assert_eq!(app.get_trades("example-election-winner").len(), 60);
+
+    assert_snapshot("markets_price_sparkline", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_markets_24h_range() {
+    use {
+        polymarket_api::clob::PriceStats,
+        std::time::{Duration, Instant},
+    };
+
+    let mut app = loaded_app();
+    let now = Instant::now();
+    assert!(app.price_stats.needs_fetch("1002", now));
+    app.price_stats
+        .mark_pending(&["1002".to_string(), "1004".to_string()]);
+    assert!(!app.price_stats.needs_fetch("1002", now));
+
+    app.price_stats.insert(
+        "1002".to_string(),
+        Some(PriceStats {
+            high: 0.66,
+            low: 0.55,
+        }),
+        now,
+    );
+    // Markets without history leave their cell blank
+    app.price_stats.insert("1004".to_string(), None, now);
+    assert!(!app.price_stats.needs_fetch("1004", now));
+    // Refetched once the entry is more than five minutes old
+    assert!(
+        app.price_stats
+            .needs_fetch("1002", now + Duration::from_secs(5 * 60))
+    );
+
+    assert_snapshot("markets_24h_range", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_footer_copied_flash() {
+    let mut app = loaded_app();
+    assert_eq!(
+        app.selected_event_url().as_deref(),
+        Some("https://polymarket.com/event/example-election-winner")
+    );
+
+    app.flash("Copied!");
+    assert_eq!(app.flash_message(), Some("Copied!"));
+    assert_snapshot("footer_copied_flash", &render_to_string(&mut app));
+
+    let shown_at = std::time::Instant::now() - std::time::Duration::from_secs(5);
+    app.flash = Some(("Copied!".to_string(), shown_at));
+    assert_eq!(app.flash_message(), None);
+}
+
+#[test]
+fn test_snapshot_copy_trade_popup() {
+    let mut app = app_with_trades();
+    app.navigation.focused_panel = FocusedPanel::Trades;
+    app.scroll.trades = 1;
+    assert!(app.open_copy_trade_popup(), "Should resolve trade market");
+
+    let form = app.trade_form.as_ref().expect("Should open trade form");
+    assert_eq!(form.market_question, "Will Candidate B win?");
+    assert_eq!(form.side, TradeSide::Sell);
+    assert!(form.shares.is_empty(), "Size should not be copied");
+
+    assert_snapshot("popup_copy_trade", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_trades_sort_by_column() {
+    use super::state::{TradeSort, TradeSortColumn};
+
+    let mut app = app_with_trades();
+    app.navigation.focused_panel = FocusedPanel::Trades;
+    app.scroll.trades = 2;
+    let newest_first: Vec<i64> = app
+        .get_trades("example-election-winner")
+        .iter()
+        .map(|t| t.timestamp)
+        .collect();
+
+    let app_state = Arc::new(TokioMutex::new(app));
+    let mut keys = KeyState::default();
+    let app = app_state.try_lock().expect("Should lock app state");
+    handle_key(
+        &app_state,
+        app,
+        KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE),
+        &mut keys,
+    );
+    let mut app = app_state.try_lock().expect("Should lock app state");
+    assert_eq!(app.trade_sort, TradeSort {
+        column: TradeSortColumn::Price,
+        ascending: false,
+    });
+    assert_eq!(app.scroll.trades, 0);
+    let prices: Vec<f64> = app
+        .trade_sort
+        .apply(app.get_trades("example-election-winner"))
+        .iter()
+        .map(|t| t.price)
+        .collect();
+    assert!(prices.windows(2).all(|w| w[0] >= w[1]), "{:?}", prices);
+    // The highlighted row is the highest-priced trade
+    assert_eq!(
+        app.selected_trade().map(|t| t.price),
+        prices.first().copied()
+    );
+    assert!(render_to_string(&mut app).contains("Price ↓"));
+
+    app.trade_sort = app.trade_sort.next();
+    assert!(render_to_string(&mut app).contains("Price ↑"));
+
+    // Cycling through every column comes back to newest first
+    for _ in 0..5 {
+        app.trade_sort = app.trade_sort.next();
+    }
+    assert_eq!(app.trade_sort, TradeSort::default());
+    let timestamps: Vec<i64> = app
+        .trade_sort
+        .apply(app.get_trades("example-election-winner"))
+        .iter()
+        .map(|t| t.timestamp)
+        .collect();
+    assert_eq!(timestamps, newest_first);
+}
+
+#[test]
+fn test_snapshot_yield_tab() {
+    let mut app = loaded_app();
+    app.main_tab = MainTab::Yield;
+    app.yield_state.opportunities =
+        find_yield_opportunities(&fixture_markets(), app.yield_state.min_prob, 0.0);
+    assert_snapshot("yield_tab", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_favorites_needs_auth() {
+    let mut app = loaded_app();
+    app.main_tab = MainTab::Favorites;
+    assert_snapshot("favorites_needs_auth", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_help_popup() {
+    let mut app = loaded_app();
+    app.popup = Some(PopupType::Help);
+    assert_snapshot("popup_help", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_logs_level_filter() {
+    let mut app = loaded_app();
+    app.show_logs = true;
+    app.add_log("DEBUG", "Fetching orderbook for 1002".to_string());
+    app.add_log("INFO", "Loaded 3 events".to_string());
+    app.add_log("WARN", "Orderbook request retried".to_string());
+    app.add_log("ERROR", "Failed to fetch trades".to_string());
+    // All -> Info -> Warn
+    app.logs.cycle_level_filter();
+    app.logs.cycle_level_filter();
+    assert_eq!(app.logs.visible_len(), 2);
+    assert_snapshot("logs_warn_filter", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_holders_popup() {
+    use polymarket_api::data::TokenHolders;
+
+    let mut app = loaded_app();
+    // Fixture markets carry no condition ID, so the popup explains why it is empty
+    assert_eq!(app.open_holders_popup(), None);
+    assert_eq!(app.popup, Some(PopupType::Holders));
+    assert_eq!(app.holders.question, "Will Candidate A win?");
+    assert!(app.holders.error_message.is_some());
+
+    let groups: Vec<TokenHolders> = fixtures()
+        .load_or_default("data/holders")
+        .expect("Should load holder fixtures");
+    let mut holders: Vec<_> = groups.into_iter().flat_map(|g| g.holders).collect();
+    holders.sort_by(|a, b| b.size.total_cmp(&a.size));
+    app.holders.error_message = None;
+    app.holders.set_holders(holders);
+    assert_snapshot("popup_holders", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_confirm_quit_popup() {
+    let mut app = loaded_app();
+    app.popup = Some(PopupType::ConfirmQuit);
+    assert_snapshot("popup_confirm_quit", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_event_info_popup() {
+    let mut app = loaded_app();
+    app.popup = Some(PopupType::EventInfo("example-election-winner".to_string()));
+    assert_snapshot("popup_event_info", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_login_popup() {
+    let mut app = loaded_app();
+    app.popup = Some(PopupType::Login);
+    assert_snapshot("popup_login", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_user_profile_popup() {
+    let mut app = loaded_app();
+    app.auth_state.is_authenticated = true;
+    app.auth_state.username = Some("example-trader".to_string());
+    app.auth_state.address = Some("0x0000000000000000000000000000000000000000".to_string());
+    app.auth_state.balance = Some(250.0);
+    app.auth_state.portfolio_value = Some(93.0);
+    app.auth_state.positions_count = Some(1);
+    app.popup = Some(PopupType::UserProfile);
+    assert_snapshot("popup_user_profile", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_user_profile_pnl_breakdown() {
+    let position = |title: &str, outcome: &str, cost: f64, pnl: f64| {
+        serde_json::from_value::<polymarket_api::data::Position>(serde_json::json!({
+            "asset": format!("{}-{}", title, outcome),
+            "conditionId": "0x01",
+            "initialValue": cost,
+            "currentValue": cost + pnl,
+            "cashPnl": pnl,
+            "title": title,
+            "slug": "example",
+            "eventSlug": "example",
+            "outcome": outcome,
+            "outcomeIndex": 0,
+        }))
+        .expect("Should build position")
+    };
+
+    let mut app = loaded_app();
+    app.auth_state.is_authenticated = true;
+    app.auth_state.username = Some("example-trader".to_string());
+    app.auth_state.positions = vec![
+        position("Will Candidate A win?", "Yes", 87.0, 6.0),
+        position("Will Candidate B win?", "No", 40.0, -12.5),
+        position("Will it rain?", "Yes", 10.0, 0.0),
+        position("Will Candidate C win?", "Yes", 20.0, 1.5),
+    ];
+    let (winners, losers) = app.auth_state.pnl_leaders(5);
+    assert_eq!(winners.iter().map(|p| p.cash_pnl).collect::<Vec<_>>(), [
+        Some(6.0),
+        Some(1.5)
+    ]);
+    assert_eq!(losers.len(), 1);
+    // Flat positions are in neither list
+    let (winners, _) = app.auth_state.pnl_leaders(1);
+    assert_eq!(winners[0].title, "Will Candidate A win?");
+
+    app.auth_state.cost_basis = Some(157.0);
+    app.auth_state.portfolio_value = Some(152.0);
+    app.auth_state.unrealized_pnl = Some(-5.0);
+    app.auth_state.realized_pnl = Some(20.0);
+    app.popup = Some(PopupType::UserProfile);
+    assert_snapshot("popup_user_profile_pnl", &render_to_string(&mut app));
+}
+
+/// Resting order for `asset_id` with `matched` of 100 shares filled
+fn open_order(id: &str, asset_id: &str, side: &str, price: &str, matched: &str) -> OpenOrder {
+    serde_json::from_value(serde_json::json!({
+        "id": id,
+        "status": "LIVE",
+        "owner": "owner",
+        "maker_address": "0x0000000000000000000000000000000000000000",
+        "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+        "asset_id": asset_id,
+        "side": side,
+        "original_size": "100",
+        "size_matched": matched,
+        "price": price,
+        "outcome": "Yes",
+        "created_at": 1_700_000_000
+    }))
+    .expect("Should build open order")
+}
+
+#[test]
+fn test_snapshot_open_orders_popup() {
+    let mut app = loaded_app();
+    app.auth_state.is_authenticated = true;
+    app.popup = Some(PopupType::OpenOrders);
+    app.open_orders.is_loading = true;
+    assert_snapshot("popup_open_orders_loading", &render_to_string(&mut app));
+
+    app.open_orders.set_orders(vec![
+        open_order("0x01", "1002", "BUY", "0.55", "40"),
+        open_order("0x02", "1004", "SELL", "0.4", "0"),
+        open_order("0x03", "unknown-token", "BUY", "0.02", "0"),
+    ]);
+    assert!(!app.open_orders.is_loading);
+    assert_eq!(state::remaining_size(&app.open_orders.orders[0]), 60.0);
+    assert_eq!(app.market_name_for_asset("1004"), Some("Candidate B"));
+
+    app.open_orders.move_down();
+    app.open_orders.move_down();
+    app.open_orders.move_down();
+    assert_eq!(app.open_orders.selected_index, 2);
+    app.open_orders.move_up();
+    assert_eq!(
+        app.open_orders.selected_order().map(|o| o.id.as_str()),
+        Some("0x02")
+    );
+    assert_snapshot("popup_open_orders", &render_to_string(&mut app));
+
+    // A shorter list after a cancel keeps the selection in range
+    app.open_orders.selected_index = 2;
+    app.open_orders
+        .set_orders(vec![open_order("0x01", "1002", "BUY", "0.55", "40")]);
+    assert_eq!(app.open_orders.selected_index, 0);
+}
+
+#[test]
+fn test_snapshot_profile_switcher_popup() {
+    let mut app = loaded_app();
+    app.profile_switcher.set_profiles(
+        vec![
+            ("main".to_string(), "0x0000...0001".to_string()),
+            ("trading".to_string(), "0x0000...0002".to_string()),
+        ],
+        Some("trading".to_string()),
+    );
+    assert_eq!(app.profile_switcher.selected_profile(), Some("trading"));
+    app.profile_switcher.move_down();
+    assert_eq!(app.profile_switcher.selected_index, 1);
+    app.profile_switcher.move_up();
+    assert_eq!(app.profile_switcher.selected_profile(), Some("main"));
+
+    app.popup = Some(PopupType::ProfileSwitcher);
+    assert_snapshot("popup_profile_switcher", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_snapshot_trade_popup() {
+    let mut app = loaded_app();
+    app.trade_form = Some(TradeFormState::new(
+        "Will Candidate A win?".to_string(),
+        vec![
+            OutcomeInfo {
+                name: "Yes".to_string(),
+                token_id: "1002".to_string(),
+                price: 0.62,
+            },
+            OutcomeInfo {
+                name: "No".to_string(),
+                token_id: "1003".to_string(),
+                price: 0.38,
+            },
+        ],
+        0,
+    ));
+    app.popup = Some(PopupType::Trade);
+    assert_snapshot("popup_trade", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_profile_shows_closed_paper_positions() {
+    use {crate::paper::PaperTrade, polymarket_api::clob::Side};
+
+    let mut app = loaded_app();
+    for side in [Side::Buy, Side::Sell] {
+        app.paper_ledger
+            .record(PaperTrade {
+                timestamp: 0,
+                token_id: "1002".to_string(),
+                market: "Will Candidate A win?".to_string(),
+                outcome: "Yes".to_string(),
+                side,
+                price: 0.6,
+                size: 10.0,
+            })
+            .expect("Should record paper trade");
+    }
+
+    app.show_popup(PopupType::UserProfile);
+    let screen = render_to_string(&mut app);
+    assert!(screen.contains("Positions (paper)"));
+    assert!(screen.contains("No paper positions yet"));
+}
+
+#[test]
+fn test_snapshot_market_trade_popup_fill_preview() {
+    let mut app = loaded_app();
+    let mut form = TradeFormState::new(
+        "Will Candidate A win?".to_string(),
+        vec![OutcomeInfo {
+            name: "Yes".to_string(),
+            token_id: "1002".to_string(),
+            price: 0.63,
+        }],
+        0,
+    );
+    form.toggle_order_type();
+    form.amount = "500".to_string();
+    form.orderbook = fixtures()
+        .load_or_default::<Vec<polymarket_api::clob::Orderbook>>("clob/books")
+        .expect("Should load orderbook fixtures")
+        .into_iter()
+        .find(|b| b.asset_id.as_deref() == Some("1002"));
+    app.trade_form = Some(form);
+    app.popup = Some(PopupType::Trade);
+    assert_snapshot("popup_trade_market_fill", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_market_trade_popup_slippage_warning() {
+    let mut app = loaded_app();
+    let mut form = TradeFormState::new(
+        "Will Candidate A win?".to_string(),
+        vec![OutcomeInfo {
+            name: "Yes".to_string(),
+            token_id: "1002".to_string(),
+            price: 0.63,
+        }],
+        0,
+    );
+    form.toggle_order_type();
+    form.amount = "500".to_string();
+    form.orderbook = fixtures()
+        .load_or_default::<Vec<polymarket_api::clob::Orderbook>>("clob/books")
+        .expect("Should load orderbook fixtures")
+        .into_iter()
+        .find(|b| b.asset_id.as_deref() == Some("1002"));
+    app.trade_form = Some(form);
+    app.popup = Some(PopupType::Trade);
+
+    // $500 walks from 63¢ into the 64¢ level: 0.6¢ of slippage on average, with
+    // the 2% limit (64.26¢) rounded up to the 1¢ tick
+    let rendered = render_to_string(&mut app);
+    assert!(rendered.contains("Avg fill:   63.6¢ (slippage 0.6¢)  limit 65.0¢"));
+
+    // A tighter limit flags the worst fill
+    app.trade_settings.max_slippage_pct = 0.1;
+    let rendered = render_to_string(&mut app);
+    assert!(rendered.contains("Avg fill:   63.6¢ (slippage 0.6¢)  worst 64.0¢ > 63.1¢"));
+}
+
+#[test]
+fn test_trade_form_cost_payout_preview() {
+    let mut app = loaded_app();
+    let mut form = TradeFormState::new(
+        "Will Candidate A win?".to_string(),
+        vec![OutcomeInfo {
+            name: "Yes".to_string(),
+            token_id: "1002".to_string(),
+            price: 0.25,
+        }],
+        0,
+    );
+
+    // 40 shares at 25¢ cost $10 and pay $40 if the outcome wins
+    for c in "40".chars() {
+        form.add_char(c);
+    }
+    let preview = form.preview();
+    assert_eq!((preview.shares, preview.dollars), (40.0, 10.0));
+    assert_eq!(preview.max_payout(), 40.0);
+    assert_eq!(preview.max_profit(), 30.0);
+    app.trade_form = Some(form.clone());
+    app.popup = Some(PopupType::Trade);
+    let rendered = render_to_string(&mut app);
+    assert!(rendered.contains("Shares:     40.00    Cost: $10.00"));
+    assert!(rendered.contains("Max payout: $40.00   Max profit: +$30.00"));
+
+    // Sells show the proceeds and the collateral released
+    form.toggle_side();
+    app.trade_form = Some(form);
+    let rendered = render_to_string(&mut app);
+    assert!(rendered.contains("Shares:     40.00    Proceeds: $10.00"));
+    assert!(rendered.contains("Released:   $40.00 collateral"));
+}
+
+#[test]
+fn test_tab_order_is_consistent() {
+    let app = loaded_app();
+    let header = render_to_string(&mut loaded_app())
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let size = ratatui::layout::Rect::new(0, 0, WIDTH, HEIGHT);
+
+    for (i, tab) in AppTab::ALL.iter().enumerate() {
+        // Number keys follow the display order
+        let key = char::from_digit(i as u32 + 1, 10).expect("Should be a digit");
+        assert_eq!(AppTab::from_key(key), Some(*tab));
+
+        // Left/Right step through the same order
+        assert_eq!(tab.next(), AppTab::ALL[(i + 1) % AppTab::ALL.len()]);
+        assert_eq!(tab.next().prev(), *tab);
+
+        // Clicking the rendered title selects the same tab
+        let column = header.find(&tab.title()).expect("Should render tab title") as u16;
+        assert_eq!(
+            get_clicked_tab(column, 0, size, &app),
+            Some(*tab),
+            "Click on {}",
+            tab.label()
+        );
+    }
+    assert_eq!(AppTab::from_key('0'), None);
+}
+
+#[test]
+fn test_orderbook_imbalance() {
+    let level = |price: f64, size: f64| state::OrderbookLevel {
+        price,
+        size,
+        total: price * size,
+    };
+    let orderbook = state::OrderbookData {
+        bids: vec![level(0.50, 300.0), level(0.49, 300.0)],
+        asks: vec![level(0.52, 200.0)],
+        spread: Some(0.02),
+        last_price: None,
+    };
+    assert_eq!(orderbook.bid_imbalance(6), Some(0.75));
+    // Only the top levels count
+    assert_eq!(orderbook.bid_imbalance(1), Some(0.6));
+    assert_eq!(state::OrderbookData::default().bid_imbalance(6), None);
+
+    let mut app = loaded_app();
+    app.orderbook_state.orderbook = Some(orderbook);
+    let output = render_to_string(&mut app);
+    assert!(output.contains("Bid  75%"), "{}", output);
+    assert!(output.contains(" 25% Ask"), "{}", output);
+}
+
+#[test]
+fn test_event_without_markets() {
+    let mut event = fixture_events().remove(0);
+    event.title = "Event without markets".to_string();
+    event.markets.clear();
+    let mut app = app_with_events(vec![event]);
+
+    let output = render_to_string(&mut app);
+    assert!(output.contains("(no markets)"), "{}", output);
+    assert!(output.contains("No markets available"), "{}", output);
+
+    // Selecting it starts no price request
+    let app_state = std::sync::Arc::new(tokio::sync::Mutex::new(app_with_events(Vec::new())));
+    super::fetch::spawn_fetch_selected_event(&app_state, &mut app);
+    assert_eq!(app.next_price_request(), 1);
+}
+
+#[test]
+fn test_orderbook_depth_chart() {
+    let level = |price: f64, size: f64| state::OrderbookLevel {
+        price,
+        size,
+        total: price * size,
+    };
+    let orderbook = state::OrderbookData {
+        bids: vec![level(0.50, 100.0), level(0.48, 300.0)],
+        asks: vec![level(0.52, 200.0), level(0.54, 100.0)],
+        spread: Some(0.02),
+        last_price: None,
+    };
+    assert_eq!(orderbook.mid_price(), Some(0.51));
+    // Mid 0.51, furthest levels are 0.03 away: two steps of 1.5¢ each side
+    let (bids, asks) = orderbook.depth_columns(6, 2);
+    assert_eq!(bids, vec![100.0, 400.0]);
+    assert_eq!(asks, vec![200.0, 300.0]);
+    // Only the top levels count, and set the scale (0.01 away, the minimum)
+    let (bids, asks) = orderbook.depth_columns(1, 2);
+    assert_eq!(bids, vec![0.0, 100.0]);
+    assert_eq!(asks, vec![0.0, 200.0]);
+
+    let mut app = loaded_app();
+    app.orderbook_state.orderbook = Some(orderbook);
+    app.orderbook_state.toggle_depth_chart();
+    assert_snapshot("orderbook_depth_chart", &render_to_string(&mut app));
+}
+
+#[test]
+fn test_rtds_status_goes_stale() {
+    use {
+        state::RtdsStatus,
+        std::time::{Duration, Instant},
+    };
+
+    let mut app = loaded_app();
+    let slug = app
+        .selected_event()
+        .map(|e| e.slug.clone())
+        .expect("Should select an event");
+    let start = Instant::now();
+
+    // Updates from a socket that was stopped are ignored
+    app.trades.record_rtds_message(&slug, start);
+    assert_eq!(app.trades.rtds_status(&slug, start), None);
+
+    app.trades
+        .rtds_status
+        .insert(slug.clone(), RtdsStatus::Connecting);
+    assert_eq!(
+        app.trades.rtds_status(&slug, start),
+        Some(RtdsStatus::Connecting)
+    );
+    app.trades.record_rtds_message(&slug, start);
+    assert_eq!(
+        app.trades
+            .rtds_status(&slug, start + Duration::from_secs(29)),
+        Some(RtdsStatus::Live)
+    );
+    assert_eq!(
+        app.trades
+            .rtds_status(&slug, start + Duration::from_secs(30)),
+        Some(RtdsStatus::Stale)
+    );
+    assert!(render_to_string(&mut app).contains("Trades (0) ● Live · last msg 0s ago"));
+
+    app.trades.set_rtds_status(&slug, RtdsStatus::Disconnected);
+    assert_eq!(
+        app.trades.rtds_status(&slug, start),
+        Some(RtdsStatus::Disconnected)
+    );
+}
+
+#[test]
+fn test_bracket_keys_step_orderbook_market() {
+    let mut events = fixture_events();
+    // No token IDs, so stepping doesn't spawn orderbook fetches
+    for market in &mut events[0].markets {
+        market.clob_token_ids = None;
+    }
+    let mut app = app_with_events(events);
+    app.orderbook_state.selected_outcome = state::OrderbookOutcome::No;
+    let app_state = Arc::new(TokioMutex::new(app));
+    let mut keys = KeyState::default();
+    let mut press = |c: char| {
+        let app = app_state.try_lock().expect("Should lock app state");
+        handle_key(
+            &app_state,
+            app,
+            KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE),
+            &mut keys,
+        );
+        let app = app_state.try_lock().expect("Should lock app state");
+        app.orderbook_state.selected_market_index
+    };
+
+    // Clamped to the event's three markets
+    assert_eq!(press('['), 0);
+    assert_eq!(press(']'), 1);
+    assert_eq!(press(']'), 2);
+    assert_eq!(press(']'), 2);
+    assert_eq!(press('['), 1);
+
+    let mut app = app_state.try_lock().expect("Should lock app state");
+    assert_eq!(
+        app.orderbook_state.selected_outcome,
+        state::OrderbookOutcome::Yes
+    );
+    assert!(render_to_string(&mut app).contains(" Candidate B (2/3) "));
+}
+
+#[test]
+fn test_related_events_replace_list() {
+    let events = fixture_events();
+    let related = vec![events[1].clone()];
+    let mut app = app_with_events(events);
+    app.navigation.selected_index = 0;
+    let source = app
+        .selected_event()
+        .expect("Should select event")
+        .title
+        .clone();
+
+    app.set_related_events(related, &source);
+    assert_eq!(app.panel_input_context(), InputContext::Normal);
+    let slugs: Vec<&str> = app
+        .filtered_events()
+        .iter()
+        .map(|e| e.slug.as_str())
+        .collect();
+    assert_eq!(slugs, vec!["example-championship-final"]);
+    assert!(render_to_string(&mut app).contains(&format!("\"related: {}", &source[..10])));
+
+    // A new search brings back the full list
+    app.enter_search_mode();
+    app.exit_search_mode();
+    assert_eq!(app.filtered_events().len(), 2);
+}
+
+#[test]
+fn test_events_list_shows_sort_label() {
+    let mut app = loaded_app();
+    app.cycle_event_sort();
+    assert!(render_to_string(&mut app).contains("Total Vol"));
+}
+
+#[test]
+fn test_event_details_hide_malformed_volumes() {
+    let mut app = loaded_app();
+    let event = &mut app.events[0];
+    event.markets[0].volume_24hr = Some(f64::NAN);
+    event.markets[1].volume_24hr = Some(f64::MAX);
+    event.markets[2].volume_24hr = Some(f64::MAX);
+    let screen = render_to_string(&mut app);
+    assert!(!screen.contains("NaN") && !screen.contains("inf"));
+}
+
+#[test]
+fn test_snapshot_market_search_results() {
+    use {super::state::SearchTarget, polymarket_api::gamma::MarketSearchResult};
+
+    let mut app = loaded_app();
+    app.enter_search_mode();
+    app.toggle_search_target();
+    assert_eq!(app.search.target, SearchTarget::Markets);
+    app.search.query = "win".to_string();
+
+    // One row per matched market, each pointing at its parent event
+    let results: Vec<MarketSearchResult> = fixture_events()
+        .into_iter()
+        .rev()
+        .flat_map(|event| {
+            event
+                .markets
+                .clone()
+                .into_iter()
+                .map(move |market| MarketSearchResult {
+                    event: event.clone(),
+                    market,
+                })
+        })
+        .collect();
+    app.set_market_search_results(results, "win".to_string());
+    assert_eq!(app.filtered_events().len(), 5);
+    assert_snapshot("market_search_results", &render_to_string(&mut app));
+
+    // Selecting a market opens its event scrolled to that market
+    app.move_down();
+    assert_eq!(
+        app.selected_event().map(|e| e.slug.as_str()),
+        Some("example-championship-final")
+    );
+    assert_eq!(app.scroll.markets, 1);
+    app.move_down();
+    assert_eq!(
+        app.selected_event().map(|e| e.slug.as_str()),
+        Some("example-election-winner")
+    );
+    assert_eq!(app.scroll.markets, 0);
+
+    // Switching back to event search drops the market results
+    app.toggle_search_target();
+    assert!(app.search.market_results.is_empty());
+    assert!(app.search.matched_market(0).is_none());
+}
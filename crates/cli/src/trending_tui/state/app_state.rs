@@ -2,16 +2,43 @@
 
 use {
     super::{
-        AuthState, EventFilter, EventSortBy, EventTrades, FavoritesState, LogsState, MainTab,
-        NavigationState, OrderbookState, OutcomeInfo, PaginationState, PopupType, ScrollState,
-        SearchMode, SearchState, Trade, TradeFormState, TradesState, YieldState,
+        AppTab, AuthState, EventFilter, EventSortBy, EventTrades, FavoritesState, HistoryState,
+        HoldersState, InputContext, LogLevel, LogsState, MainTab, NavigationState, OpenOrdersState,
+        OrderbookState, OutcomeInfo, PAGE_ROWS, PaginationState, PopupType, PriceStatsState,
+        ProfileSwitcherState, ResolutionState, RtdsStatus, ScrollState, SearchMode, SearchState,
+        Trade, TradeFormState, TradeSort, TradeStats, TradesState, YieldState, event_fuzzy_score,
+    },
+    crate::{
+        display_trait::{PriceFormat, format_price},
+        paper::{PaperLedger, PaperTrade},
+        settings::{Settings, TradeSettings},
+    },
+    polymarket_api::{
+        ClobClient, OrderbookCache, RateLimiter,
+        gamma::{Event, Market, MarketSearchResult, OutcomeNameStyle},
+        rtds::RTDSMessage,
     },
-    polymarket_api::gamma::Event,
     ratatui::widgets::TableState,
-    std::collections::HashMap,
+    std::{
+        collections::{HashMap, VecDeque},
+        path::PathBuf,
+        time::{Duration, Instant},
+    },
     tokio::task::JoinHandle,
 };
 
+/// How long batch orderbooks are reused when moving between events
+pub const PRICE_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// Default time between market price refreshes for the selected event
+pub const DEFAULT_PRICE_REFRESH: Duration = Duration::from_secs(10);
+
+/// How long a footer flash message (e.g. "Copied!") stays up
+pub const FLASH_DURATION: Duration = Duration::from_secs(2);
+
+/// Live trade prices kept per token for the Markets panel sparklines
+pub const PRICE_HISTORY_LEN: usize = 50;
+
 /// Main application state
 pub struct TrendingAppState {
     pub events: Vec<Event>,
@@ -24,10 +51,15 @@ pub struct TrendingAppState {
     pub trades: TradesState,
     pub event_filter: EventFilter, // Current filter (Trending, Breaking)
     pub market_prices: HashMap<String, f64>, // asset_id -> current price from API
+    pub price_cache: OrderbookCache, // Batch orderbooks shared by price refreshes
+    pub rate_limiter: RateLimiter, // Request budget shared by every CLOB client
+    pub price_request_generation: u64, // Bumped per price fetch so stale responses are dropped
+    pub price_refresh_interval: Option<Duration>, /* Selected event price refresh period (None = off) */
+    pub price_history: HashMap<String, VecDeque<f64>>, // asset_id -> recent live trade prices
     pub event_trade_counts: HashMap<String, usize>, // event_slug -> total trade count from API
-    pub has_clob_auth: bool,       // Whether CLOB API authentication is available
-    pub popup: Option<PopupType>,  // Currently active popup/modal
-    pub trades_table_state: TableState, // State for trades table selection
+    pub has_clob_auth: bool,                      // Whether CLOB API authentication is available
+    pub popup: Option<PopupType>,                 // Currently active popup/modal
+    pub trades_table_state: TableState,           // State for trades table selection
     pub events_cache: HashMap<EventFilter, Vec<Event>>, // Cache for each filter tab
     /// Global event cache keyed by slug - single source of truth for event data
     pub event_cache: HashMap<String, Event>,
@@ -39,9 +71,24 @@ pub struct TrendingAppState {
     pub login_form: super::LoginFormState, // Login form state
     pub trade_form: Option<TradeFormState>, // Trade form state (when trade popup is open)
     pub event_sort_by: EventSortBy, // Current sort option for events list
+    pub trade_sort: TradeSort, // Order of the Trades panel (newest first by default)
     pub gamma_api_status: Option<bool>, /* Gamma API health: Some(true) = healthy, Some(false) = unhealthy, None = unknown */
     pub data_api_status: Option<bool>, /* Data API health: Some(true) = healthy, Some(false) = unhealthy, None = unknown */
     pub orderbook_state: OrderbookState, // Orderbook panel state
+    pub outcome_style: OutcomeNameStyle, // How outcome names are abbreviated
+    pub price_format: PriceFormat,     // Cents or dollars in the trades, markets and trade popup
+    pub trade_settings: TradeSettings, // Default and quick-select trade amounts
+    pub history: HistoryState,         // Leading-outcome probability history per event
+    pub price_stats: PriceStatsState,  // 24h high/low per market token
+    pub resolutions: ResolutionState,  // Realized P&L notices for held markets that resolve
+    pub flash: Option<(String, Instant)>, // Short footer message and when it was shown
+    pub open_orders: OpenOrdersState,  // "My Orders" popup state
+    pub profile_switcher: ProfileSwitcherState,
+    pub holders: HoldersState, // "Top Holders" popup state
+    pub paper_mode: bool,      // Trade popup orders go to the paper ledger instead of the CLOB
+    pub paper_ledger: PaperLedger,
+    pub paper_ledger_path: Option<PathBuf>, // Where the ledger is saved (None keeps it in memory)
+    pub settings_path: Option<PathBuf>, // Where sort choices are saved (None keeps them in memory)
 }
 
 impl TrendingAppState {
@@ -75,6 +122,11 @@ impl TrendingAppState {
             trades: TradesState::new(),
             event_filter,
             market_prices: HashMap::new(),
+            price_cache: OrderbookCache::new(PRICE_CACHE_TTL),
+            rate_limiter: RateLimiter::default(),
+            price_request_generation: 0,
+            price_refresh_interval: Some(DEFAULT_PRICE_REFRESH),
+            price_history: HashMap::new(),
             event_trade_counts: HashMap::new(),
             has_clob_auth,
             popup: None,
@@ -89,10 +141,129 @@ impl TrendingAppState {
             login_form: super::LoginFormState::new(),
             trade_form: None,
             event_sort_by: EventSortBy::default(),
+            trade_sort: TradeSort::default(),
             gamma_api_status: None,
             data_api_status: None,
             orderbook_state: OrderbookState::new(),
+            outcome_style: OutcomeNameStyle::default(),
+            price_format: PriceFormat::default(),
+            trade_settings: TradeSettings::default(),
+            history: HistoryState::new(),
+            price_stats: PriceStatsState::new(),
+            resolutions: ResolutionState::new(),
+            flash: None,
+            open_orders: OpenOrdersState::new(),
+            profile_switcher: ProfileSwitcherState::new(),
+            holders: HoldersState::new(),
+            paper_mode: false,
+            paper_ledger: PaperLedger::default(),
+            paper_ledger_path: None,
+            settings_path: None,
+        }
+    }
+
+    /// Currently displayed header tab
+    pub fn current_tab(&self) -> AppTab {
+        AppTab::current(self.main_tab, self.event_filter)
+    }
+
+    /// Show a short message in the footer
+    pub fn flash(&mut self, message: impl Into<String>) {
+        self.flash = Some((message.into(), Instant::now()));
+    }
+
+    /// Footer flash message, until it has been shown for `FLASH_DURATION`
+    pub fn flash_message(&self) -> Option<&str> {
+        self.flash
+            .as_ref()
+            .filter(|(_, shown_at)| shown_at.elapsed() < FLASH_DURATION)
+            .map(|(message, _)| message.as_str())
+    }
+
+    /// Switch between paper and live trading for the Trade popup
+    pub fn toggle_paper_mode(&mut self) {
+        self.paper_mode = !self.paper_mode;
+        self.flash(if self.paper_mode {
+            "Paper trading on - orders are simulated"
+        } else {
+            "Paper trading off - orders are live"
+        });
+    }
+
+    /// Record a simulated fill from the Trade popup and close it
+    ///
+    /// Sells of more shares than the ledger holds are rejected on the form.
+    pub fn fill_paper_order(&mut self, trade: PaperTrade) {
+        let message = format!(
+            "Paper {} {:.2} {} at {}",
+            if trade.side == polymarket_api::clob::Side::Buy {
+                "BUY"
+            } else {
+                "SELL"
+            },
+            trade.size,
+            trade.outcome,
+            format_price(trade.price, self.price_format)
+        );
+        if let Err(e) = self.paper_ledger.record(trade) {
+            if let Some(form) = self.trade_form.as_mut() {
+                form.error_message = Some(e);
+            }
+            return;
+        }
+        if let Some(ref path) = self.paper_ledger_path
+            && let Err(e) = self.paper_ledger.save(path)
+        {
+            self.flash(e);
+            self.close_popup();
+            return;
+        }
+        self.flash(message);
+        self.close_popup();
+    }
+
+    /// Display name of the cached market trading `asset_id`
+    pub fn market_name_for_asset(&self, asset_id: &str) -> Option<&str> {
+        self.event_cache
+            .values()
+            .chain(self.events.iter())
+            .flat_map(|event| &event.markets)
+            .find(|market| {
+                market
+                    .clob_token_ids
+                    .as_ref()
+                    .is_some_and(|ids| ids.iter().any(|id| id == asset_id))
+            })
+            .map(|market| market.display_name())
+    }
+
+    /// Polymarket URL of the selected event or yield opportunity
+    pub fn selected_event_url(&self) -> Option<String> {
+        let slug = match self.main_tab {
+            MainTab::Yield => self
+                .yield_state
+                .selected_opportunity()
+                .map(|o| o.event_slug.as_str()),
+            MainTab::Trending | MainTab::Favorites => {
+                self.selected_event().map(|e| e.slug.as_str())
+            },
+        }?;
+        Some(format!("https://polymarket.com/event/{}", slug))
+    }
+
+    /// Polymarket URL of the market selected in the Markets panel
+    ///
+    /// None on the Yield tab and for markets without a slug (open the event instead).
+    pub fn selected_market_url(&self) -> Option<String> {
+        if self.main_tab == MainTab::Yield {
+            return None;
         }
+        let slug = self
+            .selected_market()?
+            .slug
+            .as_deref()
+            .filter(|slug| !slug.is_empty())?;
+        Some(format!("https://polymarket.com/market/{}", slug))
     }
 
     /// Add events to the global cache
@@ -107,6 +278,38 @@ impl TrendingAppState {
         self.event_cache.get(slug)
     }
 
+    /// Switch to the next events sort, re-sort and jump back to the top
+    pub fn cycle_event_sort(&mut self) {
+        self.event_sort_by = self.event_sort_by.next();
+        self.sort_events();
+        self.navigation.selected_index = 0;
+        self.scroll.events_list = 0;
+    }
+
+    /// Switch the Trades panel to its next sort and jump back to the top row
+    pub fn cycle_trade_sort(&mut self) {
+        self.trade_sort = self.trade_sort.next();
+        self.scroll.trades = 0;
+    }
+
+    /// Write the events sort order to the settings file (if there is one)
+    pub fn save_event_sort(&self) -> Result<(), String> {
+        let Some(ref path) = self.settings_path else {
+            return Ok(());
+        };
+        let mut settings = Settings::read_from(path)?;
+        settings.event_sort = self.event_sort_by;
+        settings.save_to(path)
+    }
+
+    /// Write the Yield tab filters and sort order to the settings file (if there is one)
+    pub fn save_yield_settings(&self) -> Result<(), String> {
+        match self.settings_path {
+            Some(ref path) => self.yield_state.save_settings(path),
+            None => Ok(()),
+        }
+    }
+
     /// Sort events by the current sort option
     pub fn sort_events(&mut self) {
         match self.event_sort_by {
@@ -124,6 +327,20 @@ impl TrendingAppState {
                         .unwrap_or(std::cmp::Ordering::Equal)
                 });
             },
+            EventSortBy::Volume7d => {
+                self.events.sort_by(|a, b| {
+                    b.volume_1wk
+                        .partial_cmp(&a.volume_1wk)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+            },
+            EventSortBy::Volume30d => {
+                self.events.sort_by(|a, b| {
+                    b.volume_1mo
+                        .partial_cmp(&a.volume_1mo)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+            },
             EventSortBy::Liquidity => {
                 self.events.sort_by(|a, b| {
                     b.liquidity
@@ -141,6 +358,16 @@ impl TrendingAppState {
                         (None, None) => std::cmp::Ordering::Equal,
                     });
             },
+            EventSortBy::StartDate => {
+                // Sort by start_date descending (most recently started first)
+                self.events
+                    .sort_by(|a, b| match (&b.start_date, &a.start_date) {
+                        (Some(b_date), Some(a_date)) => b_date.cmp(a_date),
+                        (Some(_), None) => std::cmp::Ordering::Less,
+                        (None, Some(_)) => std::cmp::Ordering::Greater,
+                        (None, None) => std::cmp::Ordering::Equal,
+                    });
+            },
             EventSortBy::EndingSoon => {
                 // Sort by end_date ascending (soonest first), None at end
                 self.events
@@ -183,10 +410,112 @@ impl TrendingAppState {
         outcomes: Vec<OutcomeInfo>,
         selected_idx: usize,
     ) {
-        self.trade_form = Some(TradeFormState::new(market_question, outcomes, selected_idx));
+        let mut form = TradeFormState::new(market_question, outcomes, selected_idx);
+        if let Some(amount) = self.trade_settings.default_amount {
+            form.set_amount(amount);
+        }
+        self.trade_form = Some(form);
         self.popup = Some(PopupType::Trade);
     }
 
+    /// Apply quick-select preset `idx` to the trade form (the index after the last preset is "max")
+    ///
+    /// Max uses the cached USDC balance for buys and the held position for sells.
+    pub fn apply_quick_amount(&mut self, idx: usize) {
+        let preset = self.trade_settings.quick_amounts.get(idx).copied();
+        let is_max = idx == self.trade_settings.quick_amounts.len();
+        let balance = self.auth_state.balance;
+        let Some(ref mut form) = self.trade_form else {
+            return;
+        };
+        if let Some(amount) = preset {
+            form.set_amount(amount);
+        } else if is_max {
+            let position = form
+                .token_id()
+                .and_then(|token_id| self.auth_state.position_sizes.get(token_id))
+                .copied();
+            form.set_max(balance, position);
+        }
+    }
+
+    /// Build trade outcomes for a market, preferring live prices over the Gamma snapshot
+    pub fn trade_outcomes(&self, market: &Market) -> Vec<OutcomeInfo> {
+        let Some(ref token_ids) = market.clob_token_ids else {
+            return Vec::new();
+        };
+        token_ids
+            .iter()
+            .enumerate()
+            .map(|(i, token_id)| {
+                let name = market.outcomes.get(i).cloned().unwrap_or_else(|| {
+                    if i == 0 {
+                        "Yes".to_string()
+                    } else {
+                        "No".to_string()
+                    }
+                });
+                let price = self
+                    .market_prices
+                    .get(token_id)
+                    .copied()
+                    .or_else(|| {
+                        market
+                            .outcome_prices
+                            .get(i)
+                            .and_then(|p| p.parse::<f64>().ok())
+                    })
+                    .unwrap_or(0.5);
+                OutcomeInfo {
+                    name,
+                    token_id: token_id.clone(),
+                    price,
+                }
+            })
+            .collect()
+    }
+
+    /// Get the highlighted trade in the Trades panel of the selected event
+    pub fn selected_trade(&self) -> Option<&Trade> {
+        let event = self.selected_event()?;
+        self.trade_sort
+            .apply(self.get_trades(&event.slug))
+            .get(self.scroll.trades)
+            .copied()
+    }
+
+    /// Open the trade popup pre-filled to mirror the highlighted live trade
+    ///
+    /// The trade's asset ID is resolved to its market and outcome within the selected
+    /// event. Returns false if no trade is highlighted or its market can't be found.
+    pub fn open_copy_trade_popup(&mut self) -> bool {
+        let form = {
+            let (Some(event), Some(trade)) = (self.selected_event(), self.selected_trade()) else {
+                return false;
+            };
+            let Some((market, outcome_idx)) = event.markets.iter().find_map(|market| {
+                let ids = market.clob_token_ids.as_ref()?;
+                let idx = ids.iter().position(|id| *id == trade.asset_id)?;
+                Some((market, idx))
+            }) else {
+                return false;
+            };
+            let mut form = TradeFormState::mirror_trade(
+                market.question.clone(),
+                self.trade_outcomes(market),
+                outcome_idx,
+                trade,
+            );
+            if let Some(amount) = self.trade_settings.default_amount {
+                form.set_amount(amount);
+            }
+            form
+        };
+        self.trade_form = Some(form);
+        self.popup = Some(PopupType::Trade);
+        true
+    }
+
     /// Check if a popup is active
     pub fn has_popup(&self) -> bool {
         self.popup.is_some()
@@ -210,22 +539,15 @@ impl TrendingAppState {
 
     #[allow(dead_code)]
     pub fn add_log(&mut self, level: &str, message: String) {
-        // Format: [LEVEL] message
-        let formatted = format!("[{}] {}", level, message);
-        self.logs.messages.push(formatted);
-        // Keep only last 1000 logs
-        if self.logs.messages.len() > 1000 {
-            self.logs.messages.remove(0);
-        }
-        // Auto-scroll to bottom - always show the latest logs
+        self.logs.push(LogLevel::from_name(level), message);
+        // Auto-scroll to bottom - always show the latest logs that pass the level filter
         // The logs area is Constraint::Length(8), so visible height is ~6 lines (minus borders)
         // We'll set scroll to show from the bottom, and render_logs will adjust if needed
         let estimated_visible_height = 6; // Approximate visible lines (8 - 2 for borders)
-        if self.logs.messages.len() > estimated_visible_height {
-            self.logs.scroll = self.logs.messages.len() - estimated_visible_height;
-        } else {
-            self.logs.scroll = 0;
-        }
+        self.logs.scroll = self
+            .logs
+            .visible_len()
+            .saturating_sub(estimated_visible_height);
     }
 
     /// Get filtered events based on search query
@@ -251,48 +573,21 @@ impl TrendingAppState {
             return self.events.iter().collect();
         }
 
-        // If in local filter mode, always filter from the source list
+        // In local filter mode, fuzzy-match the displayed list (API search results if
+        // any, otherwise the events) and put the best matches first
         if self.search.mode == SearchMode::LocalFilter {
-            // Determine source list: if we have search results, filter from those; otherwise filter from events
-            let query_lower = self.search.query.to_lowercase();
-            if !self.search.results.is_empty() {
-                // Filter from search results (current displayed list)
-                return self
-                    .search
-                    .results
-                    .iter()
-                    .filter(|event| {
-                        event.title.to_lowercase().contains(&query_lower)
-                            || event.slug.to_lowercase().contains(&query_lower)
-                            || event
-                                .tags
-                                .iter()
-                                .any(|tag| tag.label.to_lowercase().contains(&query_lower))
-                            || event
-                                .markets
-                                .iter()
-                                .any(|market| market.question.to_lowercase().contains(&query_lower))
-                    })
-                    .collect();
+            let source = if self.search.results.is_empty() {
+                &self.events
             } else {
-                // Filter from events list
-                return self
-                    .events
-                    .iter()
-                    .filter(|event| {
-                        event.title.to_lowercase().contains(&query_lower)
-                            || event.slug.to_lowercase().contains(&query_lower)
-                            || event
-                                .tags
-                                .iter()
-                                .any(|tag| tag.label.to_lowercase().contains(&query_lower))
-                            || event
-                                .markets
-                                .iter()
-                                .any(|market| market.question.to_lowercase().contains(&query_lower))
-                    })
-                    .collect();
-            }
+                &self.search.results
+            };
+            let mut matches: Vec<(i64, &Event)> = source
+                .iter()
+                .filter_map(|event| Some((event_fuzzy_score(&self.search.query, event)?, event)))
+                .collect();
+            // Stable sort keeps the list order for equal scores
+            matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            return matches.into_iter().map(|(_, event)| event).collect();
         }
 
         // API search mode: use API results if available
@@ -341,11 +636,70 @@ impl TrendingAppState {
         self.search.mode = SearchMode::None;
         self.search.query.clear();
         self.search.results.clear();
+        self.search.market_results.clear();
         self.search.last_searched_query.clear();
         self.navigation.selected_index = 0;
         self.scroll.events_list = 0;
     }
 
+    /// Switch the API search between events and markets, dropping old results
+    pub fn toggle_search_target(&mut self) {
+        self.search.target = self.search.target.toggle();
+        self.search.results.clear();
+        self.search.market_results.clear();
+        self.search.last_searched_query.clear();
+        self.navigation.selected_index = 0;
+        self.scroll.events_list = 0;
+    }
+
+    /// Context the next key press is handled in
+    pub fn input_context(&self) -> InputContext {
+        if self.has_popup() {
+            InputContext::Popup
+        } else {
+            self.panel_input_context()
+        }
+    }
+
+    /// Context of the main screen, ignoring any open popup
+    pub fn panel_input_context(&self) -> InputContext {
+        if self.main_tab == MainTab::Yield && self.yield_state.is_searching {
+            InputContext::YieldSearch
+        } else if self.main_tab == MainTab::Yield && self.yield_state.is_filtering {
+            InputContext::YieldFilter
+        } else {
+            match self.search.mode {
+                SearchMode::ApiSearch => InputContext::ApiSearch,
+                SearchMode::LocalFilter => InputContext::LocalFilter,
+                SearchMode::None => InputContext::Normal,
+            }
+        }
+    }
+
+    /// Type a character into the query of the active text-entry context
+    /// Returns false if no text is being entered.
+    pub fn input_char(&mut self, c: char) -> bool {
+        match self.panel_input_context() {
+            InputContext::ApiSearch | InputContext::LocalFilter => self.add_search_char(c),
+            InputContext::YieldSearch => self.yield_state.add_search_char(c),
+            InputContext::YieldFilter => self.yield_state.add_filter_char(c),
+            InputContext::Popup | InputContext::Normal => return false,
+        }
+        true
+    }
+
+    /// Delete the last character of the query of the active text-entry context
+    /// Returns false if no text is being entered.
+    pub fn input_backspace(&mut self) -> bool {
+        match self.panel_input_context() {
+            InputContext::ApiSearch | InputContext::LocalFilter => self.delete_search_char(),
+            InputContext::YieldSearch => self.yield_state.delete_search_char(),
+            InputContext::YieldFilter => self.yield_state.delete_filter_char(),
+            InputContext::Popup | InputContext::Normal => return false,
+        }
+        true
+    }
+
     pub fn is_in_filter_mode(&self) -> bool {
         self.search.is_active()
     }
@@ -368,16 +722,157 @@ impl TrendingAppState {
 
     pub fn set_search_results(&mut self, results: Vec<Event>, query: String) {
         self.search.results = results;
+        self.search.market_results.clear();
         self.search.last_searched_query = query;
         self.search.is_searching = false;
         self.navigation.selected_index = 0;
         self.scroll.events_list = 0;
     }
 
+    /// Show events related to `event_title` in the events list
+    ///
+    /// They are kept like API search results (the list title shows what they relate
+    /// to) until the next search or tab switch.
+    pub fn set_related_events(&mut self, events: Vec<Event>, event_title: &str) {
+        let label = format!("related: {}", event_title);
+        self.search.mode = SearchMode::None;
+        self.search.query = label.clone();
+        self.set_search_results(events, label);
+    }
+
+    /// Show market search results, one row per market under its parent event
+    pub fn set_market_search_results(&mut self, results: Vec<MarketSearchResult>, query: String) {
+        let (events, markets) = results
+            .into_iter()
+            .map(|result| (result.event, result.market))
+            .unzip();
+        self.set_search_results(events, query);
+        self.search.market_results = markets;
+        self.scroll.markets = self.matched_market_scroll();
+    }
+
+    /// Markets panel scroll that brings the selected market search result to the top
+    ///
+    /// 0 unless a market search result is selected.
+    pub fn matched_market_scroll(&self) -> usize {
+        let Some(market) = self.search.matched_market(self.navigation.selected_index) else {
+            return 0;
+        };
+        let Some(event) = self.selected_event() else {
+            return 0;
+        };
+        // Same order as the Markets panel: open markets first
+        let mut sorted_markets: Vec<_> = event.markets.iter().collect();
+        sorted_markets.sort_by_key(|m| m.closed);
+        sorted_markets
+            .iter()
+            .position(|m| m.question == market.question)
+            .unwrap_or(0)
+    }
+
     pub fn set_searching(&mut self, searching: bool) {
         self.search.is_searching = searching;
     }
 
+    /// `client` drawing from the app's shared rate limiter
+    pub fn rate_limited(&self, mut client: ClobClient) -> ClobClient {
+        client.set_rate_limiter(self.rate_limiter.clone());
+        client
+    }
+
+    /// CLOB client with the logged-in account's credentials, unauthenticated when
+    /// logged out
+    ///
+    /// Errors when the account's private key can't be parsed.
+    pub fn clob_client(&self) -> Result<ClobClient, String> {
+        let client = match &self.auth_state.config {
+            Some(config) => config.clob_client()?,
+            None => ClobClient::new(),
+        };
+        Ok(self.rate_limited(client))
+    }
+
+    /// CLOB client for batch price refreshes, sharing the app's orderbook cache
+    pub fn price_client(&self) -> ClobClient {
+        // Prices are public, so a bad signing key doesn't stop them
+        let mut client = self
+            .clob_client()
+            .unwrap_or_else(|_| self.rate_limited(ClobClient::new()));
+        client.set_price_cache(self.price_cache.clone());
+        client
+    }
+
+    /// Token IDs of the selected event's open markets, for a price refresh
+    /// Empty when no event is selected or the tab has no Markets panel.
+    pub fn selected_price_tokens(&self) -> Vec<Vec<String>> {
+        let selected = match self.main_tab {
+            MainTab::Favorites => self.favorites_state.selected_event(),
+            MainTab::Trending => self.selected_event(),
+            _ => None,
+        };
+        selected
+            .map(|event| {
+                event
+                    .markets
+                    .iter()
+                    .filter(|m| !m.closed)
+                    .filter_map(|m| m.clob_token_ids.clone())
+                    .filter(|ids| !ids.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Start a new price request, superseding any still in flight
+    pub fn next_price_request(&mut self) -> u64 {
+        self.price_request_generation += 1;
+        self.price_request_generation
+    }
+
+    /// Apply a batch price response unless a newer request was started since
+    /// Returns false if the response was stale and discarded.
+    pub fn apply_market_prices(&mut self, generation: u64, prices: HashMap<String, f64>) -> bool {
+        if generation != self.price_request_generation {
+            return false;
+        }
+        self.market_prices.extend(prices);
+        true
+    }
+
+    /// Market selected in the Markets panel (open markets first, as displayed)
+    pub fn selected_market(&self) -> Option<&Market> {
+        let event = if self.main_tab == MainTab::Favorites {
+            self.favorites_state.selected_event()
+        } else {
+            self.selected_event()
+        }?;
+        let mut sorted_markets: Vec<_> = event.markets.iter().collect();
+        sorted_markets.sort_by_key(|m| m.closed);
+        sorted_markets
+            .get(self.orderbook_state.selected_market_index)
+            .copied()
+    }
+
+    /// Open the Top Holders popup for the selected market
+    ///
+    /// Returns the condition ID to fetch holders for, or None when there is no
+    /// market selected or it has no condition ID (the popup then says so).
+    pub fn open_holders_popup(&mut self) -> Option<String> {
+        let market = self.selected_market()?;
+        self.holders = HoldersState::for_market(market);
+        self.show_popup(PopupType::Holders);
+        match self.holders.condition_id.clone() {
+            Some(condition_id) => {
+                self.holders.is_loading = true;
+                Some(condition_id)
+            },
+            None => {
+                self.holders.error_message = Some("Market has no condition ID".to_string());
+                None
+            },
+        }
+    }
+
     pub fn selected_event(&self) -> Option<&Event> {
         // Always use filtered events to ensure we get the event from the currently displayed list
         // This works for:
@@ -424,7 +919,7 @@ impl TrendingAppState {
                 self.scroll.events_list = self.navigation.selected_index;
             }
             // Reset markets scroll when changing events
-            self.scroll.markets = 0;
+            self.scroll.markets = self.matched_market_scroll();
         }
     }
 
@@ -437,10 +932,58 @@ impl TrendingAppState {
                 self.scroll.events_list = self.navigation.selected_index - visible_height + 1;
             }
             // Reset markets scroll when changing events
-            self.scroll.markets = 0;
+            self.scroll.markets = self.matched_market_scroll();
         }
     }
 
+    /// Switch to the Favorites tab with the event `slug` selected
+    ///
+    /// Returns false (leaving the tab unchanged) if the event isn't in the loaded favorites.
+    pub fn goto_favorite(&mut self, slug: &str) -> bool {
+        let Some(index) = self
+            .favorites_state
+            .events
+            .iter()
+            .position(|e| e.slug == slug)
+        else {
+            return false;
+        };
+        self.main_tab = MainTab::Favorites;
+        self.favorites_state.select_index(index, PAGE_ROWS);
+        true
+    }
+
+    /// Switch to the events list with the event `slug` selected
+    ///
+    /// Returns false (leaving the tab unchanged) if the event isn't in the current list.
+    pub fn goto_in_trending(&mut self, slug: &str) -> bool {
+        let previous_tab = self.main_tab;
+        self.main_tab = MainTab::Trending;
+        let Some(index) = self.filtered_events().iter().position(|e| e.slug == slug) else {
+            self.main_tab = previous_tab;
+            return false;
+        };
+        self.select_event_index(index);
+        true
+    }
+
+    /// Select the filtered event at `index` (clamped to the list), scrolling it into view
+    pub fn select_event_index(&mut self, index: usize) {
+        let index = index.min(self.filtered_events().len().saturating_sub(1));
+        if index == self.navigation.selected_index {
+            return;
+        }
+        self.navigation.selected_index = index;
+        let visible_height = 20;
+        if index < self.scroll.events_list {
+            self.scroll.events_list = index;
+        } else if index >= self.scroll.events_list + visible_height {
+            self.scroll.events_list = index + 1 - visible_height;
+        }
+        // Reset markets scroll when changing events
+        self.scroll.markets = self.matched_market_scroll();
+    }
+
     pub fn is_watching(&self, event_slug: &str) -> bool {
         self.trades
             .event_trades
@@ -457,28 +1000,378 @@ impl TrendingAppState {
             .unwrap_or(&[])
     }
 
-    pub fn start_watching(&mut self, event_slug: String, ws_handle: JoinHandle<()>) {
+    /// Session totals for an event's live trades
+    pub fn trade_stats(&self, event_slug: &str) -> TradeStats {
         self.trades
             .event_trades
-            .entry(event_slug.clone())
+            .get(event_slug)
+            .map(|et| et.stats)
+            .unwrap_or_default()
+    }
+
+    /// USD value below which an event's live trades are hidden (0 = none)
+    pub fn min_trade_value(&self, event_slug: &str) -> f64 {
+        self.trades
+            .event_trades
+            .get(event_slug)
+            .map_or(0.0, |et| et.min_trade_value)
+    }
+
+    /// Step an event's trade-value filter to the next threshold
+    pub fn cycle_min_trade_value(&mut self, event_slug: &str) -> f64 {
+        self.trades
+            .event_trades
+            .entry(event_slug.to_string())
             .or_insert_with(EventTrades::new)
-            .is_watching = true;
-        self.trades.ws_handles.insert(event_slug, ws_handle);
+            .cycle_min_trade_value()
+    }
+
+    /// Add a live trade to a watched event and record its price for the sparklines
+    ///
+    /// Returns false if the event has no trades entry.
+    pub fn add_trade(&mut self, event_slug: &str, msg: &RTDSMessage) -> bool {
+        let Some(event_trades) = self.trades.event_trades.get_mut(event_slug) else {
+            return false;
+        };
+        event_trades.add_trade(msg);
+
+        let history = self
+            .price_history
+            .entry(msg.payload.asset.clone())
+            .or_default();
+        history.push_back(msg.payload.price);
+        if history.len() > PRICE_HISTORY_LEN {
+            history.pop_front();
+        }
+        true
+    }
+
+    /// Watch an event for live trades, evicting the least recently active watches
+    /// beyond `max_watched_events`
+    ///
+    /// Evicted watches are aborted and their trades dropped; returns their slugs.
+    pub fn start_watching(&mut self, event_slug: String, ws_handle: JoinHandle<()>) -> Vec<String> {
+        let event_trades = self
+            .trades
+            .event_trades
+            .entry(event_slug.clone())
+            .or_insert_with(EventTrades::new);
+        event_trades.is_watching = true;
+        event_trades.last_active_at = Some(Instant::now());
+        self.trades
+            .rtds_status
+            .insert(event_slug.clone(), RtdsStatus::Connecting);
+        self.trades.last_rtds_message_at.remove(&event_slug);
+        self.trades.ws_handles.insert(event_slug.clone(), ws_handle);
+
+        let mut evicted = Vec::new();
+        while self.watched_event_slugs().len() > self.trades.max_watched_events.max(1) {
+            let Some(oldest) = self
+                .trades
+                .event_trades
+                .iter()
+                .filter(|(slug, et)| et.is_watching && **slug != event_slug)
+                .min_by_key(|(_, et)| et.last_active_at)
+                .map(|(slug, _)| slug.clone())
+            else {
+                break;
+            };
+            self.stop_watching(&oldest);
+            self.trades.event_trades.remove(&oldest);
+            evicted.push(oldest);
+        }
+        evicted
     }
 
     pub fn stop_watching(&mut self, event_slug: &str) {
         if let Some(handle) = self.trades.ws_handles.remove(event_slug) {
             handle.abort();
         }
+        self.trades.rtds_status.remove(event_slug);
+        self.trades.last_rtds_message_at.remove(event_slug);
         if let Some(event_trades) = self.trades.event_trades.get_mut(event_slug) {
             event_trades.is_watching = false;
         }
     }
 
+    /// Events watched for live trades, sorted by slug
+    pub fn watched_event_slugs(&self) -> Vec<String> {
+        let mut slugs: Vec<String> = self
+            .trades
+            .event_trades
+            .iter()
+            .filter(|(_, et)| et.is_watching)
+            .map(|(slug, _)| slug.clone())
+            .collect();
+        slugs.sort();
+        slugs
+    }
+
+    /// Watched and favorited events, checked for resolved positions
+    pub fn resolution_watch_slugs(&self) -> Vec<String> {
+        let mut slugs = self.watched_event_slugs();
+        for event in &self.favorites_state.events {
+            if !slugs.contains(&event.slug) {
+                slugs.push(event.slug.clone());
+            }
+        }
+        slugs
+    }
+
     pub fn cleanup(&mut self) {
         for handle in self.trades.ws_handles.values() {
             handle.abort();
         }
         self.trades.ws_handles.clear();
+        self.orderbook_state.stop_live_feed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::trending_tui::{
+            state::{TradeSide, fuzzy_match},
+            test_fixtures::{app_with_events, fixture_events, fixtures, loaded_app, rtds_trade},
+        },
+    };
+
+    #[test]
+    fn test_trade_quick_amounts() {
+        let mut app = loaded_app();
+        app.trade_settings.default_amount = Some(50.0);
+        app.open_trade_popup(
+            "Will Candidate A win?".to_string(),
+            vec![OutcomeInfo {
+                name: "Yes".to_string(),
+                token_id: "1002".to_string(),
+                price: 0.5,
+            }],
+            0,
+        );
+        let form = app.trade_form.as_ref().expect("Should open trade form");
+        assert_eq!(form.shares, "100", "Default amount should buy $50 at 50¢");
+
+        // F3 = $100 preset
+        app.apply_quick_amount(2);
+        assert_eq!(
+            app.trade_form.as_ref().map(|f| f.shares.as_str()),
+            Some("200")
+        );
+
+        // Max without a cached balance reports an error and keeps the amount
+        app.apply_quick_amount(app.trade_settings.quick_amounts.len());
+        let form = app.trade_form.as_ref().expect("Should keep trade form");
+        assert_eq!(form.shares, "200");
+        assert!(form.error_message.is_some());
+
+        // Max sell uses the held position
+        app.auth_state
+            .position_sizes
+            .insert("1002".to_string(), 42.5);
+        if let Some(ref mut form) = app.trade_form {
+            form.side = TradeSide::Sell;
+        }
+        app.apply_quick_amount(app.trade_settings.quick_amounts.len());
+        assert_eq!(
+            app.trade_form.as_ref().map(|f| f.shares.as_str()),
+            Some("42.5")
+        );
+
+        // A market sell is sized by the bids (61¢), not the outcome's ask
+        if let Some(ref mut form) = app.trade_form {
+            form.toggle_order_type();
+            form.orderbook = fixtures()
+                .load_or_default::<Vec<polymarket_api::clob::Orderbook>>("clob/books")
+                .expect("Should load orderbook fixtures")
+                .into_iter()
+                .find(|b| b.asset_id.as_deref() == Some("1002"));
+        }
+        app.apply_quick_amount(app.trade_settings.quick_amounts.len());
+        assert_eq!(
+            app.trade_form.as_ref().map(|f| f.amount.as_str()),
+            Some("25.92")
+        );
+    }
+
+    #[test]
+    fn test_input_context_resolution() {
+        let mut app = loaded_app();
+        assert_eq!(app.input_context(), InputContext::Normal);
+
+        app.enter_search_mode();
+        assert_eq!(app.input_context(), InputContext::ApiSearch);
+        app.exit_search_mode();
+        app.enter_local_filter_mode();
+        assert_eq!(app.input_context(), InputContext::LocalFilter);
+        app.exit_search_mode();
+
+        // Yield search/filter only capture keys while the Yield tab is shown
+        app.yield_state.enter_filter_mode();
+        assert_eq!(app.input_context(), InputContext::Normal);
+        app.main_tab = MainTab::Yield;
+        assert_eq!(app.input_context(), InputContext::YieldFilter);
+        app.yield_state.exit_filter_mode();
+        app.yield_state.enter_search_mode();
+        assert_eq!(app.input_context(), InputContext::YieldSearch);
+
+        // Popups take precedence over the screen behind them
+        app.show_popup(PopupType::Help);
+        assert_eq!(app.input_context(), InputContext::Popup);
+        assert_eq!(app.panel_input_context(), InputContext::YieldSearch);
+        app.close_popup();
+        assert_eq!(app.input_context(), InputContext::YieldSearch);
+    }
+
+    #[test]
+    fn test_local_filter_fuzzy_match() {
+        let m = fuzzy_match("uselec", "US Election").expect("Should match subsequence");
+        assert_eq!(m.positions, vec![0, 1, 3, 4, 5, 6]);
+        assert!(fuzzy_match("elecusa", "US Election").is_none());
+        assert!(fuzzy_match("", "US Election").is_none());
+
+        // A word-start match outranks the same letters mid-word
+        let mut events = fixture_events();
+        for (event, title) in events.iter_mut().zip(["Select a leader", "Election day"]) {
+            event.title = title.to_string();
+            event.slug = "slug".to_string();
+            event.markets.clear();
+        }
+        let mut app = app_with_events(events);
+        app.enter_local_filter_mode();
+        let titles = |app: &TrendingAppState| -> Vec<String> {
+            app.filtered_events()
+                .iter()
+                .map(|e| e.title.clone())
+                .collect()
+        };
+        assert_eq!(titles(&app), vec!["Select a leader", "Election day"]);
+
+        app.search.query = "elec".to_string();
+        assert_eq!(titles(&app), vec!["Election day", "Select a leader"]);
+        app.search.query = "eday".to_string();
+        assert_eq!(titles(&app), vec!["Election day"]);
+    }
+
+    #[test]
+    fn test_event_sort_cycles_and_persists_in_settings() {
+        let mut app = loaded_app();
+        app.navigation.selected_index = 2;
+        app.cycle_event_sort();
+        assert_eq!(app.event_sort_by, EventSortBy::VolumeTotal);
+        assert_eq!(app.navigation.selected_index, 0);
+        let volumes: Vec<f64> = app.events.iter().map(|e| e.volume.unwrap_or(0.0)).collect();
+        assert!(volumes.windows(2).all(|w| w[0] >= w[1]), "{:?}", volumes);
+
+        let settings = Settings {
+            event_sort: app.event_sort_by,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&settings).expect("Should serialize settings");
+        assert!(json.contains("\"event_sort\":\"volume_total\""));
+        let loaded: Settings = serde_json::from_str(&json).expect("Should parse settings");
+        assert_eq!(loaded.event_sort, EventSortBy::VolumeTotal);
+
+        // Files written before the sort was saved keep the default
+        let old: Settings = serde_json::from_str(r#"{"favorites": []}"#).expect("Should parse");
+        assert_eq!(old.event_sort, EventSortBy::Volume24hr);
+
+        // Saving keeps the file's other settings
+        let dir =
+            std::env::temp_dir().join(format!("polymarket-event-sort-{}", std::process::id()));
+        let path = dir.join("settings.json");
+        Settings {
+            favorites: vec!["kept".to_string()],
+            ..Default::default()
+        }
+        .save_to(&path)
+        .expect("Should write settings");
+        app.settings_path = Some(path.clone());
+        app.save_event_sort().expect("Should save sort");
+        let saved = Settings::read_from(&path).expect("Should read settings");
+        assert_eq!(saved.event_sort, EventSortBy::VolumeTotal);
+        assert_eq!(saved.favorites, vec!["kept".to_string()]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_selected_price_tokens() {
+        let mut app = loaded_app();
+        assert_eq!(
+            app.price_refresh_interval,
+            Some(std::time::Duration::from_secs(10))
+        );
+        // Open markets of the selected event only
+        let event = app
+            .selected_event()
+            .cloned()
+            .expect("Should select an event");
+        let expected: Vec<Vec<String>> = event
+            .markets
+            .iter()
+            .filter(|m| !m.closed)
+            .filter_map(|m| m.clob_token_ids.clone())
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(app.selected_price_tokens(), expected);
+
+        // Nothing to refresh on tabs without a Markets panel or with no selection
+        app.main_tab = MainTab::Yield;
+        assert!(app.selected_price_tokens().is_empty());
+        assert!(
+            app_with_events(Vec::new())
+                .selected_price_tokens()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_watch_limit_evicts_least_active() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Should build runtime");
+        let mut app = app_with_events(fixture_events());
+        app.trades.max_watched_events = 2;
+
+        assert!(
+            app.start_watching("a".to_string(), runtime.spawn(async {}))
+                .is_empty()
+        );
+        assert!(
+            app.start_watching("b".to_string(), runtime.spawn(async {}))
+                .is_empty()
+        );
+        // "a" traded after "b" started, so "b" is the least recently active
+        app.trades.event_trades.get_mut("b").unwrap().last_active_at =
+            std::time::Instant::now().checked_sub(std::time::Duration::from_secs(60));
+        assert!(app.add_trade("a", &rtds_trade("1001", 0.5)));
+
+        let evicted = app.start_watching("c".to_string(), runtime.spawn(async {}));
+        assert_eq!(evicted, vec!["b"]);
+        assert_eq!(app.watched_event_slugs(), vec!["a", "c"]);
+        assert!(!app.trades.event_trades.contains_key("b"));
+        assert!(!app.trades.ws_handles.contains_key("b"));
+    }
+
+    #[test]
+    fn test_selected_market_url() {
+        let mut app = loaded_app();
+        app.orderbook_state.selected_market_index = 1;
+        assert_eq!(
+            app.selected_market_url().as_deref(),
+            Some("https://polymarket.com/market/example-election-winner-candidate-b")
+        );
+
+        // Markets without a slug fall back to the event page (`O` then opens `o`'s URL)
+        app.events[0].markets[1].slug = None;
+        assert_eq!(app.selected_market_url(), None);
+        assert_eq!(
+            app.selected_event_url().as_deref(),
+            Some("https://polymarket.com/event/example-election-winner")
+        );
+
+        app.main_tab = MainTab::Yield;
+        assert_eq!(app.selected_market_url(), None);
     }
 }
@@ -1,5 +1,7 @@
 //! Authentication state types
 
+use {crate::auth::AuthConfig, polymarket_api::data::Position, std::collections::HashMap};
+
 /// Login form field being edited
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LoginField {
@@ -159,7 +161,12 @@ pub struct AuthState {
     pub positions_count: Option<usize>, // Number of open positions
     pub unrealized_pnl: Option<f64>,    // Unrealized profit/loss
     pub realized_pnl: Option<f64>,      // Realized profit/loss
+    pub cost_basis: Option<f64>,        // What the open positions cost (initial value)
+    pub positions: Vec<Position>,       // Open positions from the last portfolio fetch
     pub profile: Option<UserProfile>,
+    pub position_sizes: HashMap<String, f64>, // asset_id -> shares held (for "max" sells)
+    /// Credentials of the logged-in account (auth.json profile or environment)
+    pub config: Option<AuthConfig>,
 }
 
 impl AuthState {
@@ -173,10 +180,42 @@ impl AuthState {
             positions_count: None,
             unrealized_pnl: None,
             realized_pnl: None,
+            cost_basis: None,
+            positions: Vec::new(),
             profile: None,
+            position_sizes: HashMap::new(),
+            config: None,
         }
     }
 
+    /// Up to `n` positions with the largest gains and the largest losses (by cash P&L)
+    pub fn pnl_leaders(&self, n: usize) -> (Vec<&Position>, Vec<&Position>) {
+        let mut ranked: Vec<&Position> = self
+            .positions
+            .iter()
+            .filter(|p| p.cash_pnl.is_some_and(|pnl| pnl.abs() >= 0.005))
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.cash_pnl
+                .unwrap_or(0.0)
+                .total_cmp(&a.cash_pnl.unwrap_or(0.0))
+        });
+        let winners = ranked
+            .iter()
+            .filter(|p| p.cash_pnl.unwrap_or(0.0) > 0.0)
+            .take(n)
+            .copied()
+            .collect();
+        let losers = ranked
+            .iter()
+            .rev()
+            .filter(|p| p.cash_pnl.unwrap_or(0.0) < 0.0)
+            .take(n)
+            .copied()
+            .collect();
+        (winners, losers)
+    }
+
     pub fn display_name(&self) -> String {
         if let Some(ref name) = self.username {
             name.clone()
@@ -187,3 +226,47 @@ impl AuthState {
         }
     }
 }
+
+/// Profile switcher popup state
+#[derive(Debug, Default)]
+pub struct ProfileSwitcherState {
+    /// Saved profiles as (name, short address)
+    pub profiles: Vec<(String, String)>,
+    /// Profile currently in use
+    pub active: Option<String>,
+    pub selected_index: usize,
+    pub error_message: Option<String>,
+}
+
+impl ProfileSwitcherState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the list, selecting the active profile
+    pub fn set_profiles(&mut self, profiles: Vec<(String, String)>, active: Option<String>) {
+        self.selected_index = active
+            .as_ref()
+            .and_then(|active| profiles.iter().position(|(name, _)| name == active))
+            .unwrap_or(0);
+        self.profiles = profiles;
+        self.active = active;
+        self.error_message = None;
+    }
+
+    pub fn selected_profile(&self) -> Option<&str> {
+        self.profiles
+            .get(self.selected_index)
+            .map(|(name, _)| name.as_str())
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected_index + 1 < self.profiles.len() {
+            self.selected_index += 1;
+        }
+    }
+}
@@ -55,6 +55,16 @@ impl FavoritesState {
         }
     }
 
+    /// Select the event at `index` (clamped to the list), scrolling it into view
+    pub fn select_index(&mut self, index: usize, visible_height: usize) {
+        self.selected_index = index.min(self.events.len().saturating_sub(1));
+        if self.selected_index < self.scroll {
+            self.scroll = self.selected_index;
+        } else if self.selected_index >= self.scroll + visible_height {
+            self.scroll = self.selected_index + 1 - visible_height;
+        }
+    }
+
     pub fn clear(&mut self) {
         self.events.clear();
         self.favorite_ids.clear();
@@ -0,0 +1,162 @@
+//! Event probability history state
+
+use {
+    polymarket_api::gamma::Event,
+    std::{
+        collections::{HashMap, HashSet},
+        time::{Duration, Instant},
+    },
+};
+
+/// How many days of price history the event trend covers
+pub const HISTORY_DAYS: i64 = 30;
+
+/// How long after a failed fetch the history is requested again
+pub const HISTORY_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Price history of an event's leading outcome
+#[derive(Debug, Clone)]
+pub struct ProbabilityHistory {
+    /// Label of the outcome the history belongs to
+    pub outcome: String,
+    /// Prices (0.0-1.0), oldest first; empty if no history is available
+    pub points: Vec<f64>,
+}
+
+/// Probability history per event, fetched lazily when an event is selected
+#[derive(Debug, Default)]
+pub struct HistoryState {
+    cache: HashMap<String, ProbabilityHistory>, // event_slug -> history
+    pending: HashSet<String>,                   // event slugs with a fetch in flight
+    failed: HashMap<String, Instant>,           // event_slug -> when its last fetch failed
+}
+
+impl HistoryState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, slug: &str) -> Option<&ProbabilityHistory> {
+        self.cache.get(slug)
+    }
+
+    /// Whether the history for an event still has to be fetched
+    ///
+    /// Failed fetches are retried after [`HISTORY_RETRY_AFTER`].
+    pub fn needs_fetch(&self, slug: &str, now: Instant) -> bool {
+        !self.cache.contains_key(slug)
+            && !self.pending.contains(slug)
+            && self.failed.get(slug).is_none_or(|failed_at| {
+                now.saturating_duration_since(*failed_at) >= HISTORY_RETRY_AFTER
+            })
+    }
+
+    pub fn mark_pending(&mut self, slug: String) {
+        self.pending.insert(slug);
+    }
+
+    /// Store a fetched history (empty points cache the absence of data)
+    pub fn insert(&mut self, slug: String, history: ProbabilityHistory) {
+        self.pending.remove(&slug);
+        self.failed.remove(&slug);
+        self.cache.insert(slug, history);
+    }
+
+    /// Record a failed fetch, retried once [`HISTORY_RETRY_AFTER`] has passed
+    pub fn record_failure(&mut self, slug: String, now: Instant) {
+        self.pending.remove(&slug);
+        self.failed.insert(slug, now);
+    }
+}
+
+/// Token ID and label of the most likely outcome across an event's open markets
+///
+/// Single-market events compare their outcomes; multi-market events compare the
+/// "Yes" price of each market.
+pub fn leading_outcome(event: &Event) -> Option<(String, String)> {
+    let multi_market = event.markets.len() > 1;
+    event
+        .markets
+        .iter()
+        .filter(|m| !m.closed)
+        .flat_map(|market| {
+            let token_ids = market.clob_token_ids.as_ref();
+            // Indexes follow the raw arrays, which line up with `clob_token_ids`
+            market
+                .outcomes
+                .iter()
+                .zip(&market.outcome_prices)
+                .enumerate()
+                // In multi-market events each market's first ("Yes") outcome is a candidate
+                .take(if multi_market { 1 } else { usize::MAX })
+                .filter_map(move |(idx, (outcome, price))| {
+                    let price = price.parse::<f64>().ok()?;
+                    let token_id = token_ids?.get(idx)?.clone();
+                    let label = if multi_market {
+                        market.display_name().to_string()
+                    } else {
+                        outcome.to_string()
+                    };
+                    Some((price, token_id, label))
+                })
+        })
+        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(_, token_id, label)| (token_id, label))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, polymarket_api::Fixtures};
+
+    fn fixture_event() -> Event {
+        let events: Vec<Event> = Fixtures::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../polymarket-api/tests/fixtures"
+        ))
+        .load_or_default("gamma/events")
+        .expect("Should load event fixtures");
+        events.into_iter().next().expect("Should have an event")
+    }
+
+    #[test]
+    fn test_leading_outcome_keeps_token_index_past_bad_prices() {
+        // Single market: the unparseable "Yes" price must not shift "No" onto its token
+        let mut event = fixture_event();
+        event.markets.truncate(1);
+        event.markets[0].outcome_prices = vec!["n/a".to_string(), "0.7".to_string()];
+        assert_eq!(
+            leading_outcome(&event),
+            Some(("1003".to_string(), "No".to_string()))
+        );
+
+        // Multi-market: a market whose "Yes" price is bad is skipped, not read as "No"
+        let mut event = fixture_event();
+        for market in &mut event.markets {
+            market.outcome_prices = vec!["0.2".to_string(), "0.8".to_string()];
+        }
+        event.markets[0].outcome_prices = vec!["bad".to_string(), "0.9".to_string()];
+        event.markets[1].outcome_prices = vec!["0.3".to_string(), "0.7".to_string()];
+        let (token_id, _) = leading_outcome(&event).expect("Should find leading outcome");
+        assert_eq!(token_id, "1004");
+    }
+
+    #[test]
+    fn test_failed_history_fetch_is_retried() {
+        let mut history = HistoryState::new();
+        let start = Instant::now();
+        assert!(history.needs_fetch("slug", start));
+        history.mark_pending("slug".to_string());
+        assert!(!history.needs_fetch("slug", start));
+
+        history.record_failure("slug".to_string(), start);
+        assert!(!history.needs_fetch("slug", start + Duration::from_secs(1)));
+        assert!(history.needs_fetch("slug", start + HISTORY_RETRY_AFTER));
+
+        // An empty history from a successful fetch stays cached
+        history.insert("slug".to_string(), ProbabilityHistory {
+            outcome: "Yes".to_string(),
+            points: Vec::new(),
+        });
+        assert!(!history.needs_fetch("slug", start + HISTORY_RETRY_AFTER * 2));
+    }
+}
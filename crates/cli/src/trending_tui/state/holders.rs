@@ -0,0 +1,64 @@
+//! "Top Holders" popup state
+
+use polymarket_api::{data::Holder, gamma::Market};
+
+/// Number of holders requested per outcome token
+pub const HOLDERS_LIMIT: usize = 20;
+
+/// Biggest positions in the market selected in the Markets panel
+#[derive(Debug, Default)]
+pub struct HoldersState {
+    pub question: String,
+    pub condition_id: Option<String>,
+    pub outcomes: Vec<String>,
+    /// Last known price per outcome, used to value the positions
+    pub prices: Vec<f64>,
+    pub holders: Vec<Holder>,
+    pub scroll: usize,
+    pub is_loading: bool,
+    pub error_message: Option<String>,
+}
+
+impl HoldersState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fresh state for a market, waiting for its holders
+    pub fn for_market(market: &Market) -> Self {
+        Self {
+            question: market.question.clone(),
+            condition_id: market.condition_id.clone(),
+            outcomes: market.outcomes.clone(),
+            prices: market
+                .outcome_prices
+                .iter()
+                .map(|price| price.parse().unwrap_or(0.0))
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Price of a holder's outcome, if the market reported one
+    pub fn price_for(&self, holder: &Holder) -> Option<f64> {
+        usize::try_from(holder.outcome_index)
+            .ok()
+            .and_then(|idx| self.prices.get(idx).copied())
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        if self.scroll + 1 < self.holders.len() {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn set_holders(&mut self, holders: Vec<Holder>) {
+        self.holders = holders;
+        self.scroll = 0;
+        self.is_loading = false;
+    }
+}
@@ -1,10 +1,100 @@
 //! Logs state
 
+/// Oldest messages are dropped past this many
+const MAX_LOG_MESSAGES: usize = 1000;
+
+/// Severity of a log message, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Parse a level name such as "WARN" (unknown names are treated as Trace)
+    pub fn from_name(name: &str) -> Self {
+        match name.to_ascii_uppercase().as_str() {
+            "ERROR" => Self::Error,
+            "WARN" => Self::Warn,
+            "INFO" => Self::Info,
+            "DEBUG" => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+/// A single line in the logs panel
+#[derive(Debug, Clone)]
+pub struct LogMessage {
+    pub level: LogLevel,
+    pub message: String,
+}
+
+impl LogMessage {
+    /// Message as displayed and saved: `[LEVEL] message`
+    pub fn formatted(&self) -> String {
+        format!("[{}] {}", self.level.label(), self.message)
+    }
+}
+
+/// Minimum level shown in the logs panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogLevelFilter {
+    #[default]
+    All,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevelFilter {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::All => Self::Info,
+            Self::Info => Self::Warn,
+            Self::Warn => Self::Error,
+            Self::Error => Self::All,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::All => "All",
+            Self::Info => "Info+",
+            Self::Warn => "Warn+",
+            Self::Error => "Error",
+        }
+    }
+
+    pub fn allows(&self, level: LogLevel) -> bool {
+        match self {
+            Self::All => true,
+            Self::Info => level >= LogLevel::Info,
+            Self::Warn => level >= LogLevel::Warn,
+            Self::Error => level >= LogLevel::Error,
+        }
+    }
+}
+
 /// Logs state
 #[derive(Debug)]
 pub struct LogsState {
-    pub messages: Vec<String>,
+    pub messages: Vec<LogMessage>,
     pub scroll: usize,
+    pub level_filter: LogLevelFilter,
 }
 
 impl LogsState {
@@ -12,9 +102,36 @@ impl LogsState {
         Self {
             messages: Vec::new(),
             scroll: 0,
+            level_filter: LogLevelFilter::default(),
         }
     }
 
+    /// Append a message, keeping only the last `MAX_LOG_MESSAGES`
+    pub fn push(&mut self, level: LogLevel, message: String) {
+        self.messages.push(LogMessage { level, message });
+        if self.messages.len() > MAX_LOG_MESSAGES {
+            self.messages.remove(0);
+        }
+    }
+
+    /// Messages that pass the level filter, oldest first
+    pub fn visible_messages(&self) -> impl Iterator<Item = &LogMessage> {
+        self.messages
+            .iter()
+            .filter(|msg| self.level_filter.allows(msg.level))
+    }
+
+    /// Number of messages that pass the level filter (what `scroll` indexes into)
+    pub fn visible_len(&self) -> usize {
+        self.visible_messages().count()
+    }
+
+    /// Switch to the next level filter and jump to the latest matching messages
+    pub fn cycle_level_filter(&mut self) {
+        self.level_filter = self.level_filter.next();
+        self.scroll = self.visible_len();
+    }
+
     /// Save all logs to a file. Returns the path to the saved file.
     pub fn save_to_file(&self) -> std::io::Result<String> {
         use std::io::Write;
@@ -35,7 +152,7 @@ impl LogsState {
         writeln!(file)?;
 
         for (i, msg) in self.messages.iter().enumerate() {
-            writeln!(file, "[{}] {}", i + 1, msg)?;
+            writeln!(file, "[{}] {}", i + 1, msg.formatted())?;
         }
 
         Ok(filename)
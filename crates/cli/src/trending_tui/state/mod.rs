@@ -2,13 +2,17 @@
 //!
 //! This module contains all state types used by the TUI, organized into submodules:
 //! - `app_state`: Main application state (TrendingAppState)
-//! - `auth`: Authentication state (AuthState, LoginFormState, etc.)
+//! - `auth`: Authentication state (AuthState, LoginFormState, ProfileSwitcherState, etc.)
 //! - `favorites`: Favorites tab state
+//! - `history`: Event probability history (trend sparkline)
 //! - `logs`: Logs panel state
 //! - `navigation`: Navigation, focus, and scroll state
 //! - `orderbook`: Orderbook panel state
+//! - `orders`: "My Orders" popup state
 //! - `pagination`: Pagination state for infinite scrolling
 //! - `popup`: Popup/modal types
+//! - `price_stats`: 24h high/low per market
+//! - `resolution`: Realized P&L notices for resolved markets
 //! - `search`: Search state
 //! - `trade`: Trade form and trade data types
 //! - `trades_ws`: WebSocket trade management state
@@ -17,11 +21,16 @@
 mod app_state;
 mod auth;
 mod favorites;
+mod history;
+mod holders;
 mod logs;
 mod navigation;
 mod orderbook;
+mod orders;
 mod pagination;
 mod popup;
+mod price_stats;
+mod resolution;
 mod search;
 mod trade;
 mod trades_ws;
@@ -30,15 +39,28 @@ mod yield_state;
 // Re-export all public types
 pub use {
     app_state::TrendingAppState,
-    auth::{AuthState, LoginField, LoginFormState, UserProfile},
+    auth::{AuthState, LoginField, LoginFormState, ProfileSwitcherState, UserProfile},
     favorites::FavoritesState,
-    logs::LogsState,
-    navigation::{EventFilter, EventSortBy, FocusedPanel, MainTab, NavigationState, ScrollState},
+    history::{HISTORY_DAYS, HistoryState, ProbabilityHistory, leading_outcome},
+    holders::{HOLDERS_LIMIT, HoldersState},
+    logs::{LogLevel, LogLevelFilter, LogsState},
+    navigation::{
+        AppTab, EventFilter, EventSortBy, FocusedPanel, InputContext, MainTab, NavigationState,
+        PAGE_ROWS, PageJump, ScrollState,
+    },
     orderbook::{OrderbookData, OrderbookLevel, OrderbookOutcome, OrderbookState},
+    orders::{OpenOrdersState, remaining_size},
     pagination::PaginationState,
     popup::PopupType,
-    search::{SearchMode, SearchState},
-    trade::{EventTrades, OrderType, OutcomeInfo, Trade, TradeField, TradeFormState, TradeSide},
-    trades_ws::TradesState,
-    yield_state::{YieldOpportunity, YieldSearchResult, YieldState},
+    price_stats::PriceStatsState,
+    resolution::ResolutionState,
+    search::{SearchMode, SearchState, SearchTarget, event_fuzzy_score, fuzzy_match},
+    trade::{
+        EventTrades, OrderRequest, OrderType, OutcomeInfo, SizeUnit, Trade, TradeField,
+        TradeFormState, TradeSide, TradeSort, TradeSortColumn, TradeStats,
+    },
+    trades_ws::{RtdsStatus, TradesState},
+    yield_state::{
+        RETURN_BUCKETS, YieldOpportunity, YieldSearchResult, YieldSortBy, YieldState, YieldSummary,
+    },
 };
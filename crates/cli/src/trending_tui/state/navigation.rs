@@ -1,5 +1,7 @@
 //! Navigation and focus state types
 
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FocusedPanel {
     Header,       // Top panel with filter options
@@ -47,6 +49,120 @@ impl MainTab {
     }
 }
 
+/// Tab shown in the header (combines MainTab and EventFilter)
+///
+/// `AppTab::ALL` is the single source of truth for the tab order: header rendering,
+/// click detection, number keys (1, 2, ...) and Left/Right cycling all follow it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppTab {
+    Events,
+    Favorites,
+    Breaking,
+    Yield,
+}
+
+impl AppTab {
+    /// All tabs in display order
+    pub const ALL: [AppTab; 4] = [
+        AppTab::Events,
+        AppTab::Favorites,
+        AppTab::Breaking,
+        AppTab::Yield,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AppTab::Events => "Events",
+            AppTab::Favorites => "Favorites",
+            AppTab::Breaking => "Breaking",
+            AppTab::Yield => "Yield",
+        }
+    }
+
+    /// Header title, including the number key that selects the tab
+    pub fn title(&self) -> String {
+        format!("{} [{}]", self.label(), self.index() + 1)
+    }
+
+    /// Position of the tab in `ALL`
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|t| t == self).unwrap_or(0)
+    }
+
+    /// Tab selected by a number key ('1' is the first tab)
+    pub fn from_key(c: char) -> Option<Self> {
+        let n = c.to_digit(10)? as usize;
+        Self::ALL.get(n.checked_sub(1)?).copied()
+    }
+
+    pub fn next(&self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(&self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Tab for the current main tab and event filter
+    pub fn current(main_tab: MainTab, event_filter: EventFilter) -> Self {
+        match main_tab {
+            MainTab::Trending => match event_filter {
+                EventFilter::Trending => AppTab::Events,
+                EventFilter::Breaking => AppTab::Breaking,
+            },
+            MainTab::Favorites => AppTab::Favorites,
+            MainTab::Yield => AppTab::Yield,
+        }
+    }
+
+    pub fn main_tab(&self) -> MainTab {
+        match self {
+            AppTab::Events | AppTab::Breaking => MainTab::Trending,
+            AppTab::Favorites => MainTab::Favorites,
+            AppTab::Yield => MainTab::Yield,
+        }
+    }
+
+    /// Event filter for tabs backed by the events list
+    pub fn event_filter(&self) -> Option<EventFilter> {
+        match self {
+            AppTab::Events => Some(EventFilter::Trending),
+            AppTab::Breaking => Some(EventFilter::Breaking),
+            AppTab::Favorites | AppTab::Yield => None,
+        }
+    }
+}
+
+/// What key presses currently act on (resolved once per key, then dispatched)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputContext {
+    /// A popup is open
+    Popup,
+    /// Typing an API search query for events
+    ApiSearch,
+    /// Typing a local filter for the loaded events
+    LocalFilter,
+    /// Typing a yield search query
+    YieldSearch,
+    /// Typing a yield filter
+    YieldFilter,
+    /// Keys are shortcuts
+    Normal,
+}
+
+impl InputContext {
+    /// Whether characters typed in this context are text rather than shortcuts
+    pub fn is_text_entry(&self) -> bool {
+        matches!(
+            self,
+            InputContext::ApiSearch
+                | InputContext::LocalFilter
+                | InputContext::YieldSearch
+                | InputContext::YieldFilter
+        )
+    }
+}
+
 /// Event filter type for different views
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventFilter {
@@ -88,13 +204,20 @@ impl EventFilter {
 }
 
 /// Sort options for events list (matches Polymarket website options)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+///
+/// Each option maps to a Gamma `order` field so the Events tab is sorted by the API,
+/// not just within the page already fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum EventSortBy {
     #[default]
     Volume24hr, // 24h Volume (default for Trending)
     VolumeTotal, // Total Volume
+    Volume7d,    // 7-day Volume
+    Volume30d,   // 30-day Volume
     Liquidity,   // Liquidity
     Newest,      // Newest (by created date)
+    StartDate,   // Most recently started
     EndingSoon,  // Ending Soon
     Competitive, // Competitive (closer odds)
 }
@@ -104,8 +227,11 @@ impl EventSortBy {
         match self {
             EventSortBy::Volume24hr => "24h Vol",
             EventSortBy::VolumeTotal => "Total Vol",
+            EventSortBy::Volume7d => "7d Vol",
+            EventSortBy::Volume30d => "30d Vol",
             EventSortBy::Liquidity => "Liquidity",
             EventSortBy::Newest => "Newest",
+            EventSortBy::StartDate => "Started",
             EventSortBy::EndingSoon => "Ending Soon",
             EventSortBy::Competitive => "Competitive",
         }
@@ -114,34 +240,80 @@ impl EventSortBy {
     pub fn next(&self) -> Self {
         match self {
             EventSortBy::Volume24hr => EventSortBy::VolumeTotal,
-            EventSortBy::VolumeTotal => EventSortBy::Liquidity,
+            EventSortBy::VolumeTotal => EventSortBy::Volume7d,
+            EventSortBy::Volume7d => EventSortBy::Volume30d,
+            EventSortBy::Volume30d => EventSortBy::Liquidity,
             EventSortBy::Liquidity => EventSortBy::Newest,
-            EventSortBy::Newest => EventSortBy::EndingSoon,
+            EventSortBy::Newest => EventSortBy::StartDate,
+            EventSortBy::StartDate => EventSortBy::EndingSoon,
             EventSortBy::EndingSoon => EventSortBy::Competitive,
             EventSortBy::Competitive => EventSortBy::Volume24hr,
         }
     }
 
     /// Get the API order parameter for this sort option
-    #[allow(dead_code)]
     pub fn api_order_param(&self) -> &'static str {
         match self {
             EventSortBy::Volume24hr => "volume24hr",
             EventSortBy::VolumeTotal => "volume",
+            EventSortBy::Volume7d => "volume7d",
+            EventSortBy::Volume30d => "volume30d",
             EventSortBy::Liquidity => "liquidity",
             EventSortBy::Newest => "createdAt",
+            EventSortBy::StartDate => "startDate",
             EventSortBy::EndingSoon => "endDate",
             EventSortBy::Competitive => "competitive",
         }
     }
 
+    /// Sort option for an API order parameter (e.g. `--order-by volume7d`)
+    ///
+    /// Also accepts Gamma's `volume1wk`/`volume1mo` spellings of the 7 and 30 day volumes.
+    pub fn from_api_order_param(order_by: &str) -> Option<Self> {
+        match order_by {
+            "volume24hr" => Some(EventSortBy::Volume24hr),
+            "volume" => Some(EventSortBy::VolumeTotal),
+            "volume7d" | "volume1wk" => Some(EventSortBy::Volume7d),
+            "volume30d" | "volume1mo" => Some(EventSortBy::Volume30d),
+            "liquidity" => Some(EventSortBy::Liquidity),
+            "createdAt" => Some(EventSortBy::Newest),
+            "startDate" => Some(EventSortBy::StartDate),
+            "endDate" => Some(EventSortBy::EndingSoon),
+            "competitive" => Some(EventSortBy::Competitive),
+            _ => None,
+        }
+    }
+
     /// Whether this sort should be ascending (true) or descending (false)
-    #[allow(dead_code)]
     pub fn is_ascending(&self) -> bool {
         match self {
             EventSortBy::EndingSoon => true, // Soonest first
-            EventSortBy::Newest => false,    // Most recent first
-            _ => false,                      // Highest values first
+            _ => false,                      // Most recent or highest values first
+        }
+    }
+}
+
+/// Rows a list moves with PageUp/PageDown, about what a panel shows at once
+pub const PAGE_ROWS: usize = 20;
+
+/// Jump through a list with PageUp/PageDown/Home/End
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageJump {
+    PageUp,
+    PageDown,
+    Home,
+    End,
+}
+
+impl PageJump {
+    /// Row reached from `current` in a list of `len` rows, paging by `page` rows
+    pub fn target(self, current: usize, len: usize, page: usize) -> usize {
+        let last = len.saturating_sub(1);
+        match self {
+            Self::PageUp => current.saturating_sub(page).min(last),
+            Self::PageDown => current.saturating_add(page).min(last),
+            Self::Home => 0,
+            Self::End => last,
         }
     }
 }
@@ -184,3 +356,45 @@ impl NavigationState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_sort_api_order_params() {
+        let mut sort = EventSortBy::default();
+        let mut seen = Vec::new();
+        loop {
+            let param = sort.api_order_param();
+            assert_eq!(
+                EventSortBy::from_api_order_param(param),
+                Some(sort),
+                "{}",
+                param
+            );
+            seen.push(param);
+            sort = sort.next();
+            if sort == EventSortBy::default() {
+                break;
+            }
+        }
+        for param in [
+            "volume24hr",
+            "volume7d",
+            "volume30d",
+            "liquidity",
+            "competitive",
+            "startDate",
+        ] {
+            assert!(seen.contains(&param), "{} should be a sort option", param);
+        }
+        assert_eq!(
+            EventSortBy::from_api_order_param("volume1wk"),
+            Some(EventSortBy::Volume7d)
+        );
+        assert_eq!(EventSortBy::from_api_order_param("oneDayPriceChange"), None);
+        assert!(EventSortBy::EndingSoon.is_ascending());
+        assert!(!EventSortBy::StartDate.is_ascending());
+    }
+}
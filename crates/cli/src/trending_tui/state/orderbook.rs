@@ -1,5 +1,24 @@
 //! Orderbook state types
 
+use {
+    polymarket_api::{
+        clob::{Orderbook, Side},
+        websocket::WebSocketMessage,
+    },
+    std::time::{Duration, Instant},
+    tokio::task::JoinHandle,
+};
+
+/// How often the REST snapshot is refetched without a working live feed
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often the REST snapshot is refetched while live deltas keep arriving, to
+/// correct any drift from missed messages
+const LIVE_RESNAPSHOT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A live feed without an applied delta for this long falls back to REST polling
+const LIVE_FEED_STALE_AFTER: Duration = Duration::from_secs(15);
+
 /// Orderbook outcome tab (Yes or No)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum OrderbookOutcome {
@@ -43,6 +62,100 @@ pub struct OrderbookData {
     pub last_price: Option<f64>,
 }
 
+impl OrderbookData {
+    /// Levels of a CLOB book, bids highest-first and asks lowest-first, with running
+    /// dollar totals from the best price
+    ///
+    /// Zero-size and malformed levels are dropped so they don't show up as rows or
+    /// count as the best price.
+    pub fn from_book(book: &Orderbook) -> Self {
+        let side = |levels: Vec<(f64, f64)>| {
+            let mut cumulative_total = 0.0;
+            levels
+                .into_iter()
+                .map(|(price, size)| {
+                    cumulative_total += price * size;
+                    OrderbookLevel {
+                        price,
+                        size,
+                        total: cumulative_total,
+                    }
+                })
+                .collect()
+        };
+        Self {
+            bids: side(book.levels_for(Side::Sell)),
+            asks: side(book.levels_for(Side::Buy)),
+            spread: book.summary(0.0, None).spread,
+            last_price: None,
+        }
+    }
+
+    /// Panel height for this book: borders, header, up to 6 levels per side and the
+    /// spread row (at least 5)
+    pub fn panel_height(&self) -> u16 {
+        let asks_count = self.asks.len().min(6);
+        let bids_count = self.bids.len().min(6);
+        ((2 + 1 + asks_count + 1 + bids_count) as u16).max(5)
+    }
+
+    /// Bid share (0-1) of the resting size within the top `levels` of each side
+    /// Returns None when both sides are empty.
+    pub fn bid_imbalance(&self, levels: usize) -> Option<f64> {
+        let bid_size: f64 = self.bids.iter().take(levels).map(|l| l.size).sum();
+        let ask_size: f64 = self.asks.iter().take(levels).map(|l| l.size).sum();
+        let total = bid_size + ask_size;
+        (total > 0.0).then(|| bid_size / total)
+    }
+
+    /// Cumulative size within the top `levels` of each side, bucketed into `columns`
+    /// steps of price distance from the mid
+    ///
+    /// `bids[i]` (`asks[i]`) is the size resting within `i + 1` steps below (above)
+    /// the mid; both sides share the price scale so the chart is symmetric.
+    pub fn depth_columns(&self, levels: usize, columns: usize) -> (Vec<f64>, Vec<f64>) {
+        let bids: Vec<_> = self.bids.iter().take(levels).collect();
+        let asks: Vec<_> = self.asks.iter().take(levels).collect();
+        let (Some(mid), Some(range)) = (self.mid_price(), self.depth_range(levels)) else {
+            return (vec![0.0; columns], vec![0.0; columns]);
+        };
+        let cumulative = |levels: &[&OrderbookLevel], distance: &dyn Fn(f64) -> f64| {
+            (1..=columns)
+                .map(|step| {
+                    let reach = range * step as f64 / columns as f64 + 1e-9;
+                    levels
+                        .iter()
+                        .filter(|level| distance(level.price) <= reach)
+                        .map(|level| level.size)
+                        .sum()
+                })
+                .collect::<Vec<f64>>()
+        };
+        (
+            cumulative(&bids, &|price| mid - price),
+            cumulative(&asks, &|price| price - mid),
+        )
+    }
+
+    /// Midpoint of the best bid and ask (or the best price of the only side)
+    pub fn mid_price(&self) -> Option<f64> {
+        match (self.bids.first(), self.asks.first()) {
+            (Some(bid), Some(ask)) => Some((bid.price + ask.price) / 2.0),
+            (Some(level), None) | (None, Some(level)) => Some(level.price),
+            (None, None) => None,
+        }
+    }
+
+    /// Price distance from the mid to the furthest of the top `levels` on either side
+    /// (never zero, so a single level still gets a scale)
+    pub fn depth_range(&self, levels: usize) -> Option<f64> {
+        let mid = self.mid_price()?;
+        let furthest_bid = self.bids.iter().take(levels).map(|l| mid - l.price);
+        let furthest_ask = self.asks.iter().take(levels).map(|l| l.price - mid);
+        Some(furthest_bid.chain(furthest_ask).fold(0.01, f64::max))
+    }
+}
+
 /// State for the orderbook panel
 #[derive(Debug)]
 pub struct OrderbookState {
@@ -50,9 +163,13 @@ pub struct OrderbookState {
     pub selected_outcome: OrderbookOutcome, // Yes or No tab
     pub orderbook: Option<OrderbookData>, // Current orderbook data
     pub is_loading: bool,
-    pub last_fetch: Option<std::time::Instant>,
+    pub last_fetch: Option<Instant>,
     pub token_id: Option<String>, // Current token ID being displayed
     pub last_height: u16,         // Last rendered height to prevent jumps during loading
+    pub show_depth_chart: bool,   // Cumulative depth chart instead of the level list (`d`)
+    pub book: Option<Orderbook>,  // REST snapshot of `token_id` with live deltas applied
+    pub live_feed: Option<(String, JoinHandle<()>)>, // CLOB WebSocket task keeping `book` live
+    pub last_live_update: Option<Instant>, // When a live message last changed `book`
 }
 
 impl OrderbookState {
@@ -65,6 +182,10 @@ impl OrderbookState {
             last_fetch: None,
             token_id: None,
             last_height: 5, // Start with min height
+            show_depth_chart: false,
+            book: None,
+            live_feed: None,
+            last_live_update: None,
         }
     }
 
@@ -73,6 +194,7 @@ impl OrderbookState {
         self.orderbook = None;
         self.is_loading = false;
         self.token_id = None;
+        self.stop_live_feed();
     }
 
     pub fn toggle_outcome(&mut self) {
@@ -80,11 +202,90 @@ impl OrderbookState {
         // Clear orderbook data when switching outcomes
         self.orderbook = None;
         self.token_id = None;
+        self.stop_live_feed();
     }
 
+    /// Show a freshly fetched REST snapshot, the base live deltas are applied to
+    pub fn set_book(&mut self, token_id: String, book: Orderbook) {
+        let data = OrderbookData::from_book(&book);
+        self.last_height = data.panel_height();
+        self.orderbook = Some(data);
+        self.book = Some(book);
+        self.token_id = Some(token_id);
+        self.is_loading = false;
+        self.last_fetch = Some(Instant::now());
+    }
+
+    /// Whether a WebSocket feed for `token_id` is still running
+    pub fn has_live_feed(&self, token_id: &str) -> bool {
+        self.live_feed
+            .as_ref()
+            .is_some_and(|(feed_token, handle)| feed_token == token_id && !handle.is_finished())
+    }
+
+    /// Replace the live feed (aborting the previous one)
+    pub fn start_live_feed(&mut self, token_id: String, handle: JoinHandle<()>) {
+        if let Some((_, previous)) = self.live_feed.take() {
+            previous.abort();
+        }
+        self.live_feed = Some((token_id, handle));
+        self.last_live_update = None;
+    }
+
+    pub fn stop_live_feed(&mut self) {
+        if let Some((_, handle)) = self.live_feed.take() {
+            handle.abort();
+        }
+        self.book = None;
+        self.last_live_update = None;
+    }
+
+    /// Apply a WebSocket delta for `token_id` to the displayed book
+    /// Returns false if the book is for another token or the message changed nothing.
+    pub fn apply_live_update(&mut self, token_id: &str, msg: &WebSocketMessage) -> bool {
+        if self.token_id.as_deref() != Some(token_id) {
+            return false;
+        }
+        let Some(book) = self.book.as_mut() else {
+            return false;
+        };
+        if !book.apply_update(msg) {
+            return false;
+        }
+        let data = OrderbookData::from_book(book);
+        self.last_height = data.panel_height();
+        self.orderbook = Some(data);
+        self.last_live_update = Some(Instant::now());
+        true
+    }
+
+    pub fn toggle_depth_chart(&mut self) {
+        self.show_depth_chart = !self.show_depth_chart;
+    }
+
+    /// Whether live deltas have been applied recently enough to trust the feed
+    pub fn live_feed_is_fresh(&self) -> bool {
+        self.token_id
+            .as_deref()
+            .is_some_and(|token_id| self.has_live_feed(token_id))
+            && self
+                .last_live_update
+                .is_some_and(|last| last.elapsed() < LIVE_FEED_STALE_AFTER)
+    }
+
+    /// Whether the REST snapshot is due for a refetch
+    ///
+    /// Polled every few seconds unless a live feed is applying deltas, which only
+    /// stretches the interval: a connected socket that delivers nothing usable
+    /// can't freeze the book.
     pub fn needs_refresh(&self) -> bool {
+        let interval = if self.live_feed_is_fresh() {
+            LIVE_RESNAPSHOT_INTERVAL
+        } else {
+            REFRESH_INTERVAL
+        };
         match self.last_fetch {
-            Some(last) => last.elapsed() >= std::time::Duration::from_secs(5),
+            Some(last) => last.elapsed() >= interval,
             None => true,
         }
     }
@@ -95,3 +296,98 @@ impl Default for OrderbookState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::trending_tui::test_fixtures::fixtures};
+
+    #[test]
+    fn test_orderbook_live_update() {
+        use polymarket_api::websocket::PriceUpdate;
+
+        let books: Vec<polymarket_api::clob::Orderbook> = fixtures()
+            .load_required("clob/books")
+            .expect("Should load orderbook fixtures");
+        let book = books
+            .into_iter()
+            .find(|b| b.asset_id.as_deref() == Some("1002"))
+            .expect("Should have a book for 1002");
+        let mut state = OrderbookState::new();
+        state.set_book("1002".to_string(), book);
+        let data = state.orderbook.as_ref().expect("Should show the book");
+        assert_eq!(data.bids[0].price, 0.61);
+        assert_eq!(data.asks[0].price, 0.63);
+
+        // A better bid joins and the best ask is taken
+        let change = |asset_id: &str, price: &str, side: &str, size: &str| {
+            WebSocketMessage::Price(PriceUpdate {
+                market: "0x01".to_string(),
+                asset_id: asset_id.to_string(),
+                price: price.to_string(),
+                side: Some(side.to_string()),
+                size: Some(size.to_string()),
+                timestamp: None,
+            })
+        };
+        let delta = |asset_id: &str| change(asset_id, "0.62", "buy", "100");
+        assert!(!state.apply_live_update("1003", &delta("1003")));
+        assert!(state.apply_live_update("1002", &delta("1002")));
+        assert!(state.apply_live_update("1002", &change("1002", "0.63", "sell", "0")));
+        let data = state.orderbook.as_ref().expect("Should show the book");
+        let bids: Vec<f64> = data.bids.iter().map(|l| l.price).collect();
+        let asks: Vec<f64> = data.asks.iter().map(|l| l.price).collect();
+        assert_eq!(bids, vec![0.62, 0.61, 0.60]);
+        assert_eq!(asks, vec![0.64]);
+        assert!((data.bids[1].total - (62.0 + 0.61 * 250.0)).abs() < 1e-9);
+        assert!((data.spread.expect("Should have a spread") - 0.02).abs() < 1e-9);
+
+        // Without a running feed the snapshot is still polled
+        state.last_fetch = None;
+        assert!(state.needs_refresh());
+
+        let ago = |secs: u64| Instant::now().checked_sub(Duration::from_secs(secs));
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Should build runtime");
+        let _guard = runtime.enter();
+        let feed = tokio::spawn(std::future::pending::<()>());
+        state.start_live_feed("1002".to_string(), feed);
+        state.last_fetch = ago(10);
+        // A connected feed that applies nothing doesn't stop polling
+        assert!(!state.live_feed_is_fresh());
+        assert!(state.needs_refresh());
+        // Fresh deltas stretch the interval to a periodic re-snapshot
+        assert!(state.apply_live_update("1002", &delta("1002")));
+        assert!(!state.needs_refresh());
+        state.last_fetch = ago(61);
+        assert!(state.needs_refresh());
+        // Deltas that stop arriving bring polling back
+        state.last_fetch = ago(10);
+        state.last_live_update = ago(20);
+        assert!(state.needs_refresh());
+
+        // Leaving the book drops it
+        state.reset();
+        assert!(!state.apply_live_update("1002", &delta("1002")));
+    }
+
+    #[test]
+    fn test_orderbook_skips_zero_and_malformed_levels() {
+        let books: Vec<polymarket_api::clob::Orderbook> = fixtures()
+            .load_required("clob/books")
+            .expect("Should load orderbook fixtures");
+        let book = books
+            .into_iter()
+            .find(|b| b.asset_id.as_deref() == Some("1099"))
+            .expect("Should have a mixed-quality book for 1099");
+
+        // Zero-size, unparseable and non-finite levels are not shown
+        let data = OrderbookData::from_book(&book);
+        let prices = |levels: &[OrderbookLevel]| levels.iter().map(|l| l.price).collect::<Vec<_>>();
+        assert_eq!(prices(&data.bids), vec![0.45, 0.40]);
+        assert_eq!(prices(&data.asks), vec![0.55, 0.60]);
+        assert_eq!(data.asks[1].total, 0.55 * 20.0 + 0.60 * 10.0);
+        assert!((data.spread.expect("Should have a spread") - 0.10).abs() < 1e-9);
+        assert_eq!(data.panel_height(), 8);
+    }
+}
@@ -0,0 +1,48 @@
+//! "My Orders" popup state
+
+use polymarket_api::clob::OpenOrder;
+
+/// Resting orders of the logged-in user
+#[derive(Debug, Default)]
+pub struct OpenOrdersState {
+    pub orders: Vec<OpenOrder>,
+    pub selected_index: usize,
+    pub is_loading: bool,
+    /// Order currently being cancelled
+    pub cancelling: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl OpenOrdersState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn selected_order(&self) -> Option<&OpenOrder> {
+        self.orders.get(self.selected_index)
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected_index + 1 < self.orders.len() {
+            self.selected_index += 1;
+        }
+    }
+
+    /// Replace the list after a fetch, keeping the selection in range
+    pub fn set_orders(&mut self, orders: Vec<OpenOrder>) {
+        self.orders = orders;
+        self.selected_index = self.selected_index.min(self.orders.len().saturating_sub(1));
+        self.is_loading = false;
+    }
+}
+
+/// Shares still waiting to be filled
+pub fn remaining_size(order: &OpenOrder) -> f64 {
+    let original = order.original_size.parse::<f64>().unwrap_or(0.0);
+    let matched = order.size_matched.parse::<f64>().unwrap_or(0.0);
+    (original - matched).max(0.0)
+}
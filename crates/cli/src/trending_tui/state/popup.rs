@@ -10,4 +10,7 @@ pub enum PopupType {
     Login,             // Login modal with credential input
     UserProfile,       // Show authenticated user profile
     Trade,             // Trade modal (form state is in app.trade_form)
+    OpenOrders,        // Resting orders of the logged-in user (state is in app.open_orders)
+    ProfileSwitcher,   // Saved auth.json profiles (state is in app.profile_switcher)
+    Holders,           // Top holders of the selected market (state is in app.holders)
 }
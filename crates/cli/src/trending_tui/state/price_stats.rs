@@ -0,0 +1,48 @@
+//! 24h high/low per market shown in the Markets panel
+
+use {
+    polymarket_api::clob::PriceStats,
+    std::{
+        collections::{HashMap, HashSet},
+        time::{Duration, Instant},
+    },
+};
+
+/// How long fetched stats are reused before the next selection refetches them
+pub const PRICE_STATS_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// 24h price stats per token, fetched lazily for the selected event's markets
+#[derive(Debug, Default)]
+pub struct PriceStatsState {
+    cache: HashMap<String, (Instant, Option<PriceStats>)>, // token_id -> (fetched at, stats)
+    pending: HashSet<String>,                              // token IDs with a fetch in flight
+}
+
+impl PriceStatsState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stats for a token, if fetched and available (stale entries are still shown)
+    pub fn get(&self, token_id: &str) -> Option<PriceStats> {
+        self.cache.get(token_id).and_then(|(_, stats)| *stats)
+    }
+
+    /// Whether a token's stats are missing or older than `PRICE_STATS_TTL`
+    pub fn needs_fetch(&self, token_id: &str, now: Instant) -> bool {
+        !self.pending.contains(token_id)
+            && self.cache.get(token_id).is_none_or(|(fetched_at, _)| {
+                now.saturating_duration_since(*fetched_at) >= PRICE_STATS_TTL
+            })
+    }
+
+    pub fn mark_pending(&mut self, token_ids: &[String]) {
+        self.pending.extend(token_ids.iter().cloned());
+    }
+
+    /// Store fetched stats (None caches the absence of history)
+    pub fn insert(&mut self, token_id: String, stats: Option<PriceStats>, now: Instant) {
+        self.pending.remove(&token_id);
+        self.cache.insert(token_id, (now, stats));
+    }
+}
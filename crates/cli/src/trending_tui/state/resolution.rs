@@ -0,0 +1,140 @@
+//! Realized P&L notices for positions in markets that resolve
+
+use {
+    polymarket_api::{
+        data::Position,
+        gamma::{Event, Market},
+    },
+    std::{
+        collections::{HashSet, VecDeque},
+        time::{Duration, Instant},
+    },
+};
+
+/// How long each notice stays in the footer
+pub const NOTICE_DURATION: Duration = Duration::from_secs(15);
+
+/// Realized result of a position whose market resolved
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolutionNotice {
+    pub market: String,  // Market display name
+    pub outcome: String, // Outcome the position holds
+    pub shares: f64,
+    pub won: bool,
+    pub pnl: f64, // Payout minus entry cost
+}
+
+impl ResolutionNotice {
+    /// Realized result of holding `position` in a market won by `winner`
+    pub fn new(position: &Position, market: &Market, winner: usize) -> Self {
+        let shares = position.size.unwrap_or(0.0);
+        let held = market
+            .clob_token_ids
+            .as_ref()
+            .and_then(|ids| ids.iter().position(|id| *id == position.asset))
+            .unwrap_or(position.outcome_index.max(0) as usize);
+        let won = held == winner;
+        let cost = position
+            .initial_value
+            .unwrap_or(shares * position.avg_price.unwrap_or(0.0));
+        let payout = if won {
+            shares
+        } else {
+            0.0
+        };
+        Self {
+            market: market.display_name().to_string(),
+            outcome: position.outcome.clone(),
+            shares,
+            won,
+            pnl: payout - cost,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        let sign = if self.pnl < 0.0 {
+            "-"
+        } else {
+            "+"
+        };
+        format!(
+            "{} resolved: your {:.0} {} shares {} ({}${:.2})",
+            self.market,
+            self.shares,
+            self.outcome,
+            if self.won {
+                "won"
+            } else {
+                "lost"
+            },
+            sign,
+            self.pnl.abs()
+        )
+    }
+}
+
+/// Tracks held markets so each resolution is announced exactly once
+#[derive(Debug, Default)]
+pub struct ResolutionState {
+    /// Held assets last seen in a market that had not resolved
+    open_assets: HashSet<String>,
+    /// Notices waiting to be shown (the front one is on screen)
+    notices: VecDeque<ResolutionNotice>,
+    shown_at: Option<Instant>,
+}
+
+impl ResolutionState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compare held positions against fresh event data
+    ///
+    /// Queues (and returns) a notice for every position whose market resolved since it was
+    /// last seen open. Markets that had already resolved when first seen are not announced.
+    pub fn observe(&mut self, positions: &[Position], events: &[Event]) -> Vec<ResolutionNotice> {
+        let mut resolved = Vec::new();
+        for position in positions.iter().filter(|p| p.size.unwrap_or(0.0) > 0.0) {
+            let Some(market) = events.iter().flat_map(|e| &e.markets).find(|m| {
+                m.clob_token_ids
+                    .as_ref()
+                    .is_some_and(|ids| ids.contains(&position.asset))
+            }) else {
+                continue;
+            };
+
+            match market.resolved_outcome() {
+                Some(winner) => {
+                    if self.open_assets.remove(&position.asset) {
+                        resolved.push(ResolutionNotice::new(position, market, winner));
+                    }
+                },
+                None => {
+                    self.open_assets.insert(position.asset.clone());
+                },
+            }
+        }
+        self.notices.extend(resolved.iter().cloned());
+        resolved
+    }
+
+    /// Notice currently on screen
+    pub fn current(&self) -> Option<&ResolutionNotice> {
+        self.notices.front()
+    }
+
+    /// Move on to the next notice once the current one has been shown long enough
+    pub fn tick(&mut self, now: Instant) {
+        if self.notices.is_empty() {
+            return;
+        }
+        match self.shown_at {
+            Some(shown_at) if now.duration_since(shown_at) >= NOTICE_DURATION => {
+                self.notices.pop_front();
+                self.shown_at = (!self.notices.is_empty()).then_some(now);
+            },
+            Some(_) => {},
+            None => self.shown_at = Some(now),
+        }
+    }
+}
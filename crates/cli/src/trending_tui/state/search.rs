@@ -1,6 +1,6 @@
 //! Search state types
 
-use polymarket_api::gamma::Event;
+use polymarket_api::gamma::{Event, Market};
 
 /// Search mode enum to replace boolean flags
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,13 +10,39 @@ pub enum SearchMode {
     LocalFilter, // Local filter mode (triggered by 'f')
 }
 
+/// What the API search (`/`) looks for, toggled with Tab while searching
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchTarget {
+    Events,
+    Markets,
+}
+
+impl SearchTarget {
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Events => Self::Markets,
+            Self::Markets => Self::Events,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Events => "events",
+            Self::Markets => "markets",
+        }
+    }
+}
+
 /// Search-related state
 #[derive(Debug)]
 pub struct SearchState {
     pub mode: SearchMode,
+    pub target: SearchTarget,
     pub query: String,
-    pub results: Vec<Event>,         // Results from API search
-    pub is_searching: bool,          // Whether a search API call is in progress
+    pub results: Vec<Event>, // Results from API search
+    /// Matched market for each entry of `results` (market search only)
+    pub market_results: Vec<Market>,
+    pub is_searching: bool, // Whether a search API call is in progress
     pub last_searched_query: String, // Last query that was searched
 }
 
@@ -24,8 +50,10 @@ impl SearchState {
     pub fn new() -> Self {
         Self {
             mode: SearchMode::None,
+            target: SearchTarget::Events,
             query: String::new(),
             results: Vec::new(),
+            market_results: Vec::new(),
             is_searching: false,
             last_searched_query: String::new(),
         }
@@ -34,4 +62,82 @@ impl SearchState {
     pub fn is_active(&self) -> bool {
         self.mode != SearchMode::None
     }
+
+    /// Whether the list shows market search results row for row
+    pub fn showing_markets(&self) -> bool {
+        self.target == SearchTarget::Markets
+            && self.mode != SearchMode::LocalFilter
+            && !self.market_results.is_empty()
+            && self.query == self.last_searched_query
+    }
+
+    /// Market matched by the result at `index`, if showing market results
+    pub fn matched_market(&self, index: usize) -> Option<&Market> {
+        self.showing_markets()
+            .then(|| self.market_results.get(index))
+            .flatten()
+    }
+}
+
+/// A fuzzy match of a query in some text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    /// Char indices of the matched characters in the text
+    pub positions: Vec<usize>,
+}
+
+/// Match `query` as a case-insensitive subsequence of `text` (fzf-style)
+///
+/// Consecutive matches and matches at the start of a word score higher, gaps score
+/// lower. Whitespace in the query is ignored. Returns None if a character is missing.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let mut query_chars = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+    query_chars.peek()?;
+
+    let mut score = 0;
+    let mut positions = Vec::new();
+    let mut prev: Option<char> = None;
+    let mut gap = 0;
+    for (idx, c) in text.chars().enumerate() {
+        let Some(&wanted) = query_chars.peek() else {
+            break;
+        };
+        if c.to_lowercase().eq(std::iter::once(wanted)) {
+            score += 2;
+            if positions.last().is_some_and(|&last| last + 1 == idx) {
+                score += 4;
+            } else if !positions.is_empty() {
+                score -= gap.min(3);
+            }
+            if prev.is_none_or(|p| !p.is_alphanumeric()) {
+                score += 6;
+            }
+            positions.push(idx);
+            gap = 0;
+            query_chars.next();
+        } else {
+            gap += 1;
+        }
+        prev = Some(c);
+    }
+
+    query_chars
+        .peek()
+        .is_none()
+        .then_some(FuzzyMatch { score, positions })
+}
+
+/// Best fuzzy score of `query` against an event's title, slug, tags and market questions
+pub fn event_fuzzy_score(query: &str, event: &Event) -> Option<i64> {
+    std::iter::once(event.title.as_str())
+        .chain(std::iter::once(event.slug.as_str()))
+        .chain(event.tags.iter().map(|tag| tag.label.as_str()))
+        .chain(event.markets.iter().map(|market| market.question.as_str()))
+        .filter_map(|text| fuzzy_match(query, text).map(|m| m.score))
+        .max()
 }
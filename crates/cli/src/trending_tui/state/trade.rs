@@ -1,6 +1,19 @@
 //! Trade-related state types
 
-use polymarket_api::rtds::RTDSMessage;
+use {
+    crate::paper::PaperTrade,
+    polymarket_api::{
+        clob::{DEFAULT_TICK_SIZE, FillEstimate, Orderbook, Side, TimeInForce},
+        rtds::RTDSMessage,
+    },
+    std::time::{Duration, Instant},
+};
+
+/// How long a newly arrived trade stays highlighted in the Trades table
+pub const TRADE_FLASH: Duration = Duration::from_millis(1500);
+
+/// Trade-value filters cycled with `v` (USD, 0 keeps every trade)
+pub const MIN_TRADE_VALUES: [f64; 4] = [0.0, 100.0, 1_000.0, 10_000.0];
 
 #[derive(Debug)]
 pub struct Trade {
@@ -15,12 +28,124 @@ pub struct Trade {
     pub user: String,
     #[allow(dead_code)]
     pub pseudonym: String,
+    /// When the trade was received (drives the arrival flash)
+    pub arrived_at: Instant,
+}
+
+impl Trade {
+    /// Remaining flash strength, from 1.0 on arrival down to 0.0 after [`TRADE_FLASH`]
+    pub fn flash_level(&self, now: Instant) -> f32 {
+        let elapsed = now.saturating_duration_since(self.arrived_at);
+        (1.0 - elapsed.as_secs_f32() / TRADE_FLASH.as_secs_f32()).max(0.0)
+    }
+}
+
+/// Column the Trades panel can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TradeSortColumn {
+    #[default]
+    Time,
+    Price,
+    Shares,
+    Value,
+}
+
+/// Order of the Trades panel (newest first by default)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TradeSort {
+    pub column: TradeSortColumn,
+    pub ascending: bool,
+}
+
+impl TradeSort {
+    /// Newest, then price, shares and value (each descending, then ascending), then back
+    pub fn next(self) -> Self {
+        let (column, ascending) = match (self.column, self.ascending) {
+            (TradeSortColumn::Time, _) => (TradeSortColumn::Price, false),
+            (column, false) => (column, true),
+            (TradeSortColumn::Price, true) => (TradeSortColumn::Shares, false),
+            (TradeSortColumn::Shares, true) => (TradeSortColumn::Value, false),
+            (TradeSortColumn::Value, true) => (TradeSortColumn::Time, false),
+        };
+        Self { column, ascending }
+    }
+
+    pub fn label(self) -> String {
+        let column = match self.column {
+            TradeSortColumn::Time => return "Newest".to_string(),
+            TradeSortColumn::Price => "Price",
+            TradeSortColumn::Shares => "Shares",
+            TradeSortColumn::Value => "Value",
+        };
+        format!("{} {}", column, self.arrow())
+    }
+
+    /// Header arrow for the sorted column ("↑" ascending, "↓" descending)
+    pub fn arrow(self) -> &'static str {
+        if self.ascending {
+            "↑"
+        } else {
+            "↓"
+        }
+    }
+
+    /// `trades` (stored newest first) in this order; ties keep the newest first
+    pub fn apply(self, trades: &[Trade]) -> Vec<&Trade> {
+        let mut sorted: Vec<&Trade> = trades.iter().collect();
+        let key = match self.column {
+            TradeSortColumn::Time => return sorted,
+            TradeSortColumn::Price => |t: &Trade| t.price,
+            TradeSortColumn::Shares => |t: &Trade| t.shares,
+            TradeSortColumn::Value => |t: &Trade| t.total_value,
+        };
+        sorted.sort_by(|a, b| {
+            let order = key(a).total_cmp(&key(b));
+            if self.ascending {
+                order
+            } else {
+                order.reverse()
+            }
+        });
+        sorted
+    }
+}
+
+/// Running totals of the trades seen for an event this session
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TradeStats {
+    /// Dollar value bought
+    pub buy_volume: f64,
+    /// Dollar value sold
+    pub sell_volume: f64,
+    pub trade_count: usize,
+}
+
+impl TradeStats {
+    pub fn record(&mut self, trade: &Trade) {
+        if trade.side == "BUY" {
+            self.buy_volume += trade.total_value;
+        } else {
+            self.sell_volume += trade.total_value;
+        }
+        self.trade_count += 1;
+    }
+
+    /// Buy volume minus sell volume
+    pub fn net_flow(&self) -> f64 {
+        self.buy_volume - self.sell_volume
+    }
 }
 
 #[derive(Debug)]
 pub struct EventTrades {
     pub trades: Vec<Trade>,
     pub is_watching: bool,
+    /// Totals over every trade received, including ones dropped from `trades`
+    pub stats: TradeStats,
+    /// Trades worth less than this (USD) are counted in `stats` but not kept
+    pub min_trade_value: f64,
+    /// Time of the last trade, or of the start of watching if none arrived since
+    pub last_active_at: Option<Instant>,
 }
 
 impl EventTrades {
@@ -28,9 +153,24 @@ impl EventTrades {
         Self {
             trades: Vec::new(),
             is_watching: false,
+            stats: TradeStats::default(),
+            min_trade_value: 0.0,
+            last_active_at: None,
         }
     }
 
+    /// Switch to the next of [`MIN_TRADE_VALUES`] and drop kept trades below it
+    pub fn cycle_min_trade_value(&mut self) -> f64 {
+        let next = MIN_TRADE_VALUES
+            .iter()
+            .position(|&value| value == self.min_trade_value)
+            .map_or(0, |idx| (idx + 1) % MIN_TRADE_VALUES.len());
+        self.min_trade_value = MIN_TRADE_VALUES[next];
+        let min = self.min_trade_value;
+        self.trades.retain(|trade| trade.total_value >= min);
+        min
+    }
+
     pub fn add_trade(&mut self, msg: &RTDSMessage) {
         let rounded_shares = (msg.payload.size * 100.0).round() / 100.0;
         let total_value = msg.payload.price * msg.payload.size;
@@ -46,8 +186,14 @@ impl EventTrades {
             asset_id: msg.payload.asset.clone(),
             user: msg.payload.name.clone(),
             pseudonym: msg.payload.pseudonym.clone(),
+            arrived_at: Instant::now(),
         };
 
+        self.stats.record(&trade);
+        self.last_active_at = Some(trade.arrived_at);
+        if total_value < self.min_trade_value {
+            return;
+        }
         self.trades.insert(0, trade);
         // Keep only the last 500 trades per event
         if self.trades.len() > 500 {
@@ -79,6 +225,14 @@ impl TradeSide {
             TradeSide::Sell => "SELL",
         }
     }
+
+    /// Matching CLOB API side
+    pub fn api_side(&self) -> Side {
+        match self {
+            TradeSide::Buy => Side::Buy,
+            TradeSide::Sell => Side::Sell,
+        }
+    }
 }
 
 /// Order type (Limit or Market)
@@ -106,6 +260,31 @@ impl OrderType {
     }
 }
 
+/// Unit the size input is entered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeUnit {
+    Shares,
+    Dollars,
+}
+
+impl SizeUnit {
+    pub fn toggle(&self) -> Self {
+        match self {
+            SizeUnit::Shares => SizeUnit::Dollars,
+            SizeUnit::Dollars => SizeUnit::Shares,
+        }
+    }
+
+    /// Unit the CLOB expects for an order type (limit orders are sized in shares,
+    /// market orders in dollars)
+    pub fn native(order_type: OrderType) -> Self {
+        match order_type {
+            OrderType::Limit => SizeUnit::Shares,
+            OrderType::Market => SizeUnit::Dollars,
+        }
+    }
+}
+
 /// Trade form field being edited
 /// Note: Side is now controlled via clickable title tabs, not a field
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -113,6 +292,7 @@ pub enum TradeField {
     OrderType,
     LimitPrice,
     Shares,
+    TimeInForce,
     Amount,
 }
 
@@ -123,7 +303,8 @@ impl TradeField {
             OrderType::Limit => match self {
                 TradeField::OrderType => TradeField::LimitPrice,
                 TradeField::LimitPrice => TradeField::Shares,
-                TradeField::Shares => TradeField::OrderType,
+                TradeField::Shares => TradeField::TimeInForce,
+                TradeField::TimeInForce => TradeField::OrderType,
                 TradeField::Amount => TradeField::OrderType, // Shouldn't happen in limit mode
             },
             OrderType::Market => match self {
@@ -131,6 +312,7 @@ impl TradeField {
                 TradeField::Amount => TradeField::OrderType,
                 TradeField::LimitPrice => TradeField::OrderType, // Shouldn't happen in market mode
                 TradeField::Shares => TradeField::OrderType,     // Shouldn't happen in market mode
+                TradeField::TimeInForce => TradeField::OrderType, // Shouldn't happen in market mode
             },
         }
     }
@@ -139,9 +321,10 @@ impl TradeField {
     pub fn prev(&self, order_type: OrderType) -> Self {
         match order_type {
             OrderType::Limit => match self {
-                TradeField::OrderType => TradeField::Shares,
+                TradeField::OrderType => TradeField::TimeInForce,
                 TradeField::LimitPrice => TradeField::OrderType,
                 TradeField::Shares => TradeField::LimitPrice,
+                TradeField::TimeInForce => TradeField::Shares,
                 TradeField::Amount => TradeField::Shares, // Shouldn't happen in limit mode
             },
             OrderType::Market => match self {
@@ -149,11 +332,45 @@ impl TradeField {
                 TradeField::Amount => TradeField::OrderType,
                 TradeField::LimitPrice => TradeField::OrderType, // Shouldn't happen in market mode
                 TradeField::Shares => TradeField::OrderType,     // Shouldn't happen in market mode
+                TradeField::TimeInForce => TradeField::OrderType, // Shouldn't happen in market mode
             },
         }
     }
 }
 
+/// Shares and dollars of the order as entered, summarized under the trade form
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradePreview {
+    pub shares: f64,
+    /// Cost of a buy, or proceeds of a sell
+    pub dollars: f64,
+}
+
+impl TradePreview {
+    /// Dollars the shares pay if the outcome wins (given up, and released, by a sell)
+    pub fn max_payout(&self) -> f64 {
+        self.shares
+    }
+
+    /// Payout minus cost of a buy
+    pub fn max_profit(&self) -> f64 {
+        self.max_payout() - self.dollars
+    }
+}
+
+/// Order ready to be signed and posted to the CLOB
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderRequest {
+    pub token_id: String,
+    pub outcome: String,
+    pub side: TradeSide,
+    pub price: f64,
+    pub size: f64, // Shares
+    pub time_in_force: TimeInForce,
+    /// Limit order priced away from the market price (rests on the book)
+    pub away_from_market: bool,
+}
+
 /// Outcome with its token ID and price
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -172,12 +389,24 @@ pub struct TradeFormState {
     pub selected_outcome_idx: usize, // Index of currently selected outcome
     pub side: TradeSide,
     pub order_type: OrderType,
-    pub limit_price: f64, // Limit price (for limit orders)
+    /// GTC or FOK for limit orders (market orders are always FOK)
+    pub time_in_force: TimeInForce,
+    pub limit_price: f64, // Limit price (for limit orders), last valid value of `price`
+    pub price: String,    // Limit price in cents (input as string for editing)
     pub shares: String,   // Number of shares (input as string for editing, for limit orders)
     pub amount: String,   // Amount in dollars (input as string for editing, for market orders)
+    /// Unit the size input is typed in; when it differs from the order type's native
+    /// unit the native field is computed from it (position-size calculator)
+    pub size_unit: SizeUnit,
     pub active_field: TradeField,
     pub error_message: Option<String>,
     pub is_submitting: bool,
+    /// Description of the live trade this form mirrors (for "copy trade"), if any
+    pub copied_from: Option<String>,
+    /// Orderbook snapshot for the selected outcome (used for fill estimates)
+    pub orderbook: Option<Orderbook>,
+    /// Whether the size has been typed into; until then digits pick quick amounts
+    pub size_edited: bool,
 }
 
 impl TradeFormState {
@@ -189,22 +418,58 @@ impl TradeFormState {
             selected_outcome_idx: selected_idx,
             side: TradeSide::Buy,
             order_type: OrderType::Limit,
+            time_in_force: TimeInForce::Gtc,
             limit_price: initial_price,
+            price: format_cents(initial_price),
             shares: String::new(),
             amount: String::new(),
+            size_unit: SizeUnit::Shares,
             active_field: TradeField::Shares, // Default to shares input for limit orders
             error_message: None,
             is_submitting: false,
+            copied_from: None,
+            orderbook: None,
+            size_edited: false,
         }
     }
 
+    /// Create a form that mirrors a trade from the live feed (same outcome, side and price)
+    ///
+    /// The size is left empty: this is a new independent order, not a copy of the other
+    /// party's position.
+    pub fn mirror_trade(
+        market_question: String,
+        outcomes: Vec<OutcomeInfo>,
+        selected_idx: usize,
+        trade: &Trade,
+    ) -> Self {
+        let mut form = Self::new(market_question, outcomes, selected_idx);
+        form.side = if trade.side.eq_ignore_ascii_case("SELL") {
+            TradeSide::Sell
+        } else {
+            TradeSide::Buy
+        };
+        form.set_limit_price(((trade.price * 1000.0).round() / 1000.0).clamp(0.001, 0.999));
+        form.copied_from = Some(format!(
+            "{} {} @ {:.1}¢ by {}",
+            trade.side.to_uppercase(),
+            trade.outcome,
+            trade.price * 100.0,
+            if trade.user.is_empty() {
+                "anonymous"
+            } else {
+                &trade.user
+            }
+        ));
+        form
+    }
+
     /// Get the currently selected outcome
     pub fn selected_outcome(&self) -> Option<&OutcomeInfo> {
         self.outcomes.get(self.selected_outcome_idx)
     }
 
     /// Get the current token ID
-    #[allow(dead_code)]
     pub fn token_id(&self) -> Option<&str> {
         self.selected_outcome().map(|o| o.token_id.as_str())
     }
@@ -228,40 +493,159 @@ impl TradeFormState {
             // Update limit price to the new outcome's price
             if let Some(outcome) = self.outcomes.get(idx) {
                 self.limit_price = outcome.price;
+                self.price = format_cents(outcome.price);
             }
+            // The cached book belongs to the previous outcome's token
+            self.orderbook = None;
+            self.update_from_input();
             self.error_message = None;
         }
     }
 
-    pub fn add_char(&mut self, c: char) {
-        let target = match self.active_field {
-            TradeField::Shares => Some(&mut self.shares),
-            TradeField::Amount => Some(&mut self.amount),
-            _ => None,
+    /// Select the next (or previous) outcome, wrapping around
+    pub fn cycle_outcome(&mut self, forward: bool) {
+        let len = self.outcomes.len();
+        if len < 2 {
+            return;
+        }
+        let idx = if forward {
+            (self.selected_outcome_idx + 1) % len
+        } else {
+            (self.selected_outcome_idx + len - 1) % len
         };
+        self.select_outcome(idx);
+    }
 
-        if let Some(field) = target {
+    fn size_input_mut(&mut self) -> Option<&mut String> {
+        match self.active_field {
+            TradeField::LimitPrice => Some(&mut self.price),
+            TradeField::Shares | TradeField::Amount => Some(match self.size_unit {
+                SizeUnit::Shares => &mut self.shares,
+                SizeUnit::Dollars => &mut self.amount,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn add_char(&mut self, c: char) {
+        self.mark_size_edited();
+        if let Some(field) = self.size_input_mut() {
             // Only allow numeric input and decimal point
             if c.is_ascii_digit() || (c == '.' && !field.contains('.')) {
                 field.push(c);
             }
         }
+        self.sync_limit_price();
+        self.update_from_input();
         self.error_message = None;
     }
 
     pub fn delete_char(&mut self) {
-        let target = match self.active_field {
-            TradeField::Shares => Some(&mut self.shares),
-            TradeField::Amount => Some(&mut self.amount),
-            _ => None,
-        };
-
-        if let Some(field) = target {
+        self.mark_size_edited();
+        if let Some(field) = self.size_input_mut() {
             field.pop();
         }
+        self.sync_limit_price();
+        self.update_from_input();
         self.error_message = None;
     }
 
+    fn mark_size_edited(&mut self) {
+        if matches!(self.active_field, TradeField::Shares | TradeField::Amount) {
+            self.size_edited = true;
+        }
+    }
+
+    /// Limit price typed in the price field, as a probability in (0, 1)
+    pub fn price_f64(&self) -> Result<f64, String> {
+        let cents: f64 = self
+            .price
+            .parse()
+            .map_err(|_| "Please enter a limit price".to_string())?;
+        let price = cents / 100.0;
+        if price > 0.0 && price < 1.0 {
+            Ok(price)
+        } else {
+            Err("Limit price must be between 0¢ and 100¢".to_string())
+        }
+    }
+
+    /// Apply a typed price (the cost preview follows it while it is valid)
+    fn sync_limit_price(&mut self) {
+        if self.active_field == TradeField::LimitPrice
+            && let Ok(price) = self.price_f64()
+        {
+            self.limit_price = price;
+        }
+    }
+
+    /// Set the limit price and its input text
+    pub fn set_limit_price(&mut self, price: f64) {
+        self.limit_price = price;
+        self.price = format_cents(price);
+    }
+
+    /// Whether the size is typed in the other unit than the order type's native one
+    pub fn is_converting(&self) -> bool {
+        self.size_unit != SizeUnit::native(self.order_type)
+    }
+
+    /// Switch the size input between shares and dollars, keeping the order size
+    pub fn toggle_size_unit(&mut self) {
+        self.size_unit = self.size_unit.toggle();
+        self.update_input();
+        self.error_message = None;
+    }
+
+    /// Recompute the native size field from a converted input
+    ///
+    /// Limit orders get shares = dollars / limit price; market orders get the dollar
+    /// cost of the shares at the best ask.
+    fn update_from_input(&mut self) {
+        if !self.is_converting() {
+            return;
+        }
+        match self.order_type {
+            OrderType::Limit => {
+                self.shares = if self.limit_price > 0.0 && !self.amount.is_empty() {
+                    format_size(self.amount_f64() / self.limit_price)
+                } else {
+                    String::new()
+                };
+            },
+            OrderType::Market => {
+                self.amount = if self.shares.is_empty() {
+                    String::new()
+                } else {
+                    format_size(self.shares_f64() * self.best_ask())
+                };
+            },
+        }
+    }
+
+    /// Refresh a converted input after the native size field changed
+    fn update_input(&mut self) {
+        if !self.is_converting() {
+            return;
+        }
+        match self.order_type {
+            OrderType::Limit => {
+                self.amount = if self.shares.is_empty() {
+                    String::new()
+                } else {
+                    format_size(self.total_cost())
+                };
+            },
+            OrderType::Market => {
+                self.shares = if self.amount.is_empty() {
+                    String::new()
+                } else {
+                    format_size(self.estimated_shares())
+                };
+            },
+        }
+    }
+
     #[allow(dead_code)]
     pub fn toggle_side(&mut self) {
         self.side = self.side.toggle();
@@ -275,25 +659,114 @@ impl TradeFormState {
             OrderType::Limit => TradeField::Shares,
             OrderType::Market => TradeField::Amount,
         };
+        self.size_unit = SizeUnit::native(self.order_type);
         self.error_message = None;
     }
 
-    /// Increment limit price by 0.1 cents (0.001)
-    pub fn increment_limit_price(&mut self) {
-        self.limit_price = (self.limit_price + 0.001).min(1.0);
-        // Round to avoid floating point issues
-        self.limit_price = (self.limit_price * 1000.0).round() / 1000.0;
+    pub fn toggle_time_in_force(&mut self) {
+        self.time_in_force = match self.time_in_force {
+            TimeInForce::Gtc => TimeInForce::Fok,
+            TimeInForce::Fok => TimeInForce::Gtc,
+        };
         self.error_message = None;
     }
 
-    /// Decrement limit price by 0.1 cents (0.001)
+    /// Raise the limit price by one tick of the selected outcome's book
+    pub fn increment_limit_price(&mut self) {
+        self.step_limit_price(1.0);
+    }
+
+    /// Lower the limit price by one tick of the selected outcome's book
     pub fn decrement_limit_price(&mut self) {
-        self.limit_price = (self.limit_price - 0.001).max(0.001);
-        // Round to avoid floating point issues
-        self.limit_price = (self.limit_price * 1000.0).round() / 1000.0;
+        self.step_limit_price(-1.0);
+    }
+
+    /// Move the limit price `steps` ticks, from the nearest tick in that direction
+    /// when the typed price is between ticks
+    fn step_limit_price(&mut self, steps: f64) {
+        let ticks = self.limit_price / self.tick_size();
+        let from = if steps > 0.0 {
+            (ticks + 1e-9).floor()
+        } else {
+            (ticks - 1e-9).ceil()
+        };
+        self.set_limit_price(self.tick_price(from + steps));
+        // A dollar input keeps its value, so the share count follows the price
+        self.update_from_input();
         self.error_message = None;
     }
 
+    /// Fill the size field from a dollar amount
+    ///
+    /// Market orders take the amount directly; limit orders get the number of shares it
+    /// buys at the limit price.
+    pub fn set_amount(&mut self, dollars: f64) {
+        match self.order_type {
+            OrderType::Market => self.amount = format_size(dollars),
+            OrderType::Limit => {
+                self.shares = if self.limit_price > 0.0 {
+                    format_size(dollars / self.limit_price)
+                } else {
+                    String::new()
+                };
+            },
+        }
+        if self.order_type == OrderType::Limit && self.is_converting() {
+            // Show the exact dollar amount rather than the rounded-down share cost
+            self.amount = format_size(dollars);
+        } else {
+            self.update_input();
+        }
+        self.error_message = None;
+    }
+
+    /// Fill the size field with the largest possible order
+    ///
+    /// Buys spend the whole `balance`; sells close the whole `position` (in shares).
+    /// Market sells are sized by what the position fetches walking the bids.
+    pub fn set_max(&mut self, balance: Option<f64>, position: Option<f64>) {
+        match self.side {
+            TradeSide::Buy => match balance {
+                Some(balance) => self.set_amount(balance),
+                None => self.error_message = Some("Balance unknown - login first".to_string()),
+            },
+            TradeSide::Sell => match position {
+                Some(shares) if shares > 0.0 => {
+                    match self.order_type {
+                        OrderType::Limit => self.shares = format_size(shares),
+                        OrderType::Market => {
+                            let Some(proceeds) = self
+                                .orderbook
+                                .as_ref()
+                                .and_then(|book| book.simulate_limit_fill(Side::Sell, 0.0, shares))
+                                .map(|estimate| estimate.amount)
+                            else {
+                                self.error_message =
+                                    Some("Orderbook not loaded yet - try again".to_string());
+                                return;
+                            };
+                            self.amount = format_size(proceeds);
+                        },
+                    }
+                    self.update_input();
+                    self.error_message = None;
+                },
+                _ => self.error_message = Some("No position in this outcome".to_string()),
+            },
+        }
+    }
+
+    /// Whether the digit keys pick quick amounts instead of typing into the field
+    ///
+    /// The limit price always takes digits; the size only once it has been edited.
+    pub fn digits_pick_quick_amounts(&self) -> bool {
+        match self.active_field {
+            TradeField::LimitPrice => false,
+            TradeField::Shares | TradeField::Amount => !self.size_edited,
+            _ => true,
+        }
+    }
+
     pub fn shares_f64(&self) -> f64 {
         self.shares.parse().unwrap_or(0.0)
     }
@@ -307,8 +780,14 @@ impl TradeFormState {
         self.shares_f64() * self.limit_price
     }
 
-    /// Calculate estimated shares for market orders (amount / best_ask)
+    /// Calculate estimated shares for market orders
+    ///
+    /// Walks the orderbook depth once it has loaded, otherwise assumes everything
+    /// fills at the outcome's price (amount / best_ask).
     pub fn estimated_shares(&self) -> f64 {
+        if let Some(estimate) = self.fill_estimate() {
+            return estimate.shares;
+        }
         let amount = self.amount_f64();
         let best_ask = self.best_ask();
         if best_ask > 0.0 {
@@ -318,23 +797,219 @@ impl TradeFormState {
         }
     }
 
-    /// Calculate potential profit (for buy: payout - cost, for sell: proceeds)
-    pub fn potential_profit(&self) -> f64 {
+    /// Simulate the market order against the cached orderbook depth
+    pub fn fill_estimate(&self) -> Option<FillEstimate> {
+        self.orderbook
+            .as_ref()?
+            .simulate_fill(self.side.api_side(), self.amount_f64())
+    }
+
+    /// Minimum price increment of the selected outcome's orderbook
+    pub fn tick_size(&self) -> f64 {
+        self.orderbook
+            .as_ref()
+            .map_or(DEFAULT_TICK_SIZE, Orderbook::tick)
+    }
+
+    /// Fill price exactly at a slippage tolerance (in %) from the best price
+    ///
+    /// Buys may pay up to best ask + tolerance, sells accept down to best bid - tolerance.
+    pub fn slippage_bound(&self, max_slippage_pct: f64) -> Option<f64> {
+        let best = self.fill_estimate()?.best_price;
+        let tolerance = max_slippage_pct / 100.0;
+        Some(match self.side {
+            TradeSide::Buy => best * (1.0 + tolerance),
+            TradeSide::Sell => best * (1.0 - tolerance),
+        })
+    }
+
+    /// Worst acceptable fill price for a market order given a slippage tolerance (in %)
+    ///
+    /// The [`Self::slippage_bound`] rounded outwards to the tick, so rounding never
+    /// tightens the tolerance, and kept one tick inside 0 and 1, the prices the
+    /// exchange accepts.
+    pub fn slippage_limit(&self, max_slippage_pct: f64) -> Option<f64> {
+        let bound = self.slippage_bound(max_slippage_pct)?;
+        let tick = self.tick_size();
+        let ticks = match self.side {
+            TradeSide::Buy => (bound / tick - 1e-9).ceil(),
+            TradeSide::Sell => (bound / tick + 1e-9).floor(),
+        };
+        Some(self.tick_price(ticks))
+    }
+
+    /// Price `ticks` ticks above 0, kept one tick inside 0 and 1
+    fn tick_price(&self, ticks: f64) -> f64 {
+        let tick = self.tick_size();
+        let max_ticks = (1.0 / tick).round() - 1.0;
+        // Round off floating point noise (ticks are far coarser than 1e-6)
+        (ticks.clamp(1.0, max_ticks) * tick * 1e6).round() / 1e6
+    }
+
+    /// Limit price from the price field, checked against the book's tick
+    ///
+    /// The exchange only accepts prices on the tick, so one between ticks is
+    /// rejected here rather than rounded to a price the user didn't confirm.
+    pub fn limit_order_price(&self) -> Result<f64, String> {
+        let price = self.price_f64()?;
+        let tick = self.tick_size();
+        let ticks = price / tick;
+        if (ticks - ticks.round()).abs() > 1e-6 {
+            return Err(format!(
+                "Limit price must be a multiple of the {}¢ tick",
+                tick * 100.0
+            ));
+        }
+        Ok(price)
+    }
+
+    /// Client-side slippage protection for market orders
+    ///
+    /// Returns the fill estimate if the whole order fills within the tolerance,
+    /// otherwise a message explaining why the order should be rejected.
+    pub fn check_slippage(&self, max_slippage_pct: f64) -> Result<FillEstimate, String> {
+        if self.orderbook.is_none() {
+            return Err("Orderbook not loaded yet - try again".to_string());
+        }
+        let estimate = self
+            .fill_estimate()
+            .ok_or_else(|| "No liquidity on this side of the book".to_string())?;
+        if !estimate.complete {
+            return Err(format!(
+                "Not enough liquidity: only ${:.2} fillable",
+                estimate.amount
+            ));
+        }
+        let slippage_pct = estimate.worst_slippage() * 100.0;
+        if slippage_pct > max_slippage_pct + 1e-9 {
+            return Err(format!(
+                "Slippage {:.1}% exceeds max {:.1}% (worst fill {:.1}¢)",
+                slippage_pct,
+                max_slippage_pct,
+                estimate.worst_price * 100.0
+            ));
+        }
+        Ok(estimate)
+    }
+
+    /// Dollars a buy order can spend (limit: shares * price, market: amount)
+    pub fn order_cost(&self) -> f64 {
         match self.order_type {
-            OrderType::Limit => {
-                let shares = self.shares_f64();
-                let cost = self.total_cost();
-                match self.side {
-                    TradeSide::Buy => shares - cost, // Shares pay $1 each if won
-                    TradeSide::Sell => cost,         // Proceeds from selling
-                }
-            },
+            OrderType::Limit => self.total_cost(),
+            OrderType::Market => self.amount_f64(),
+        }
+    }
+
+    /// Reject buys that cost more than the cached USDC balance
+    pub fn check_balance(&self, balance: Option<f64>) -> Result<(), String> {
+        if self.side == TradeSide::Sell {
+            return Ok(());
+        }
+        let balance = balance.ok_or_else(|| "Balance unknown - login first".to_string())?;
+        let cost = self.order_cost();
+        if cost > balance + 1e-9 {
+            return Err(format!(
+                "Insufficient balance: ${:.2} needed, ${:.2} available",
+                cost, balance
+            ));
+        }
+        Ok(())
+    }
+
+    /// Order to post for the current form
+    ///
+    /// Market orders become FOK orders priced at the slippage limit, sized so the
+    /// amount is never exceeded.
+    pub fn order_request(&self, max_slippage_pct: f64) -> Result<OrderRequest, String> {
+        let token_id = self
+            .token_id()
+            .ok_or_else(|| "No token selected".to_string())?
+            .to_string();
+        let (price, size, time_in_force) = match self.order_type {
+            OrderType::Limit => (
+                self.limit_order_price()?,
+                self.shares_f64(),
+                self.time_in_force,
+            ),
             OrderType::Market => {
-                let shares = self.estimated_shares();
-                match self.side {
-                    TradeSide::Buy => shares - self.amount_f64(), // Shares pay $1 each if won
-                    TradeSide::Sell => self.amount_f64(),         // Proceeds from selling
-                }
+                let estimate = self.check_slippage(max_slippage_pct)?;
+                let price = self
+                    .slippage_limit(max_slippage_pct)
+                    .unwrap_or(estimate.worst_price);
+                let size = match self.side {
+                    TradeSide::Buy if price > 0.0 => self.amount_f64() / price,
+                    TradeSide::Buy => 0.0,
+                    TradeSide::Sell => estimate.shares,
+                };
+                (price, size, TimeInForce::Fok)
+            },
+        };
+        Ok(OrderRequest {
+            token_id,
+            outcome: self.outcome_name().to_string(),
+            side: self.side,
+            price,
+            size,
+            time_in_force,
+            away_from_market: self.order_type == OrderType::Limit
+                && (price - self.best_ask()).abs() > 1e-9,
+        })
+    }
+
+    /// Simulated fill of `order` for paper trading
+    ///
+    /// Market orders fill at the orderbook's average price for the amount. Limit
+    /// orders fill only the book levels that cross the limit, at those levels'
+    /// prices; paper orders never rest, so the rest of a GTC order is dropped and
+    /// a FOK order that can't fill in full is rejected.
+    pub fn paper_trade(&self, order: &OrderRequest) -> Result<PaperTrade, String> {
+        let orderbook = self
+            .orderbook
+            .as_ref()
+            .ok_or_else(|| "Orderbook not loaded yet - try again".to_string())?;
+        let side = order.side.api_side();
+        let estimate = match self.order_type {
+            OrderType::Market => orderbook.simulate_fill(side, self.amount_f64()),
+            OrderType::Limit => orderbook.simulate_limit_fill(side, order.price, order.size),
+        }
+        .ok_or_else(|| "No liquidity on this side of the book".to_string())?;
+        if estimate.shares <= 1e-9 {
+            return Err(format!(
+                "Limit {}¢ doesn't cross the book - paper orders don't rest",
+                format_cents(order.price)
+            ));
+        }
+        if !estimate.complete && order.time_in_force == TimeInForce::Fok {
+            return Err(format!(
+                "FOK rejected: only {:.2} of {:.2} shares fillable at {}¢",
+                estimate.shares,
+                order.size,
+                format_cents(order.price)
+            ));
+        }
+        Ok(PaperTrade {
+            timestamp: chrono::Utc::now().timestamp(),
+            token_id: order.token_id.clone(),
+            market: self.market_question.clone(),
+            outcome: order.outcome.clone(),
+            side,
+            price: estimate.avg_price,
+            size: estimate.shares,
+        })
+    }
+
+    /// Shares and cost (or proceeds) of the order, updated as the size is typed
+    ///
+    /// Limit orders use the limit price, market orders the depth-based share estimate.
+    pub fn preview(&self) -> TradePreview {
+        match self.order_type {
+            OrderType::Limit => TradePreview {
+                shares: self.shares_f64(),
+                dollars: self.total_cost(),
+            },
+            OrderType::Market => TradePreview {
+                shares: self.estimated_shares(),
+                dollars: self.amount_f64(),
             },
         }
     }
@@ -351,11 +1026,370 @@ impl TradeFormState {
     pub fn clear(&mut self) {
         self.shares.clear();
         self.amount.clear();
-        self.limit_price = self.best_ask();
+        self.set_limit_price(self.best_ask());
         self.side = TradeSide::Buy;
         self.order_type = OrderType::Limit;
+        self.time_in_force = TimeInForce::Gtc;
+        self.size_unit = SizeUnit::Shares;
         self.active_field = TradeField::Shares;
         self.error_message = None;
         self.is_submitting = false;
     }
 }
+
+/// Format a price as cents for the price input field (at most 1 decimal)
+fn format_cents(price: f64) -> String {
+    let formatted = format!("{:.1}", price * 100.0);
+    formatted
+        .strip_suffix(".0")
+        .map(str::to_string)
+        .unwrap_or(formatted)
+}
+
+/// Format a size for an input field (2 decimals, rounded down, trailing zeros trimmed)
+fn format_size(value: f64) -> String {
+    let rounded = (value.max(0.0) * 100.0).floor() / 100.0;
+    let formatted = format!("{:.2}", rounded);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::trending_tui::test_fixtures::{fixtures, rtds_trade},
+    };
+
+    #[test]
+    fn test_market_order_slippage_check() {
+        let books: Vec<polymarket_api::clob::Orderbook> = fixtures()
+            .load_or_default("clob/books")
+            .expect("Should load orderbook fixtures");
+        let mut form = TradeFormState::new(
+            "Will Candidate A win?".to_string(),
+            vec![OutcomeInfo {
+                name: "Yes".to_string(),
+                token_id: "1002".to_string(),
+                price: 0.63,
+            }],
+            0,
+        );
+        form.toggle_order_type();
+        form.amount = "500".to_string();
+        assert!(
+            form.check_slippage(2.0).is_err(),
+            "Should reject until the book is loaded"
+        );
+
+        form.orderbook = books
+            .into_iter()
+            .find(|b| b.asset_id.as_deref() == Some("1002"));
+
+        // $500 walks from 63¢ into the 64¢ level (~1.6% slippage)
+        let estimate = form.check_slippage(2.0).expect("Should fill within 2%");
+        assert_eq!(estimate.worst_price, 0.64);
+        // 300 shares @ 63¢ ($189), then $311 @ 64¢
+        assert!((form.estimated_shares() - (300.0 + 311.0 / 0.64)).abs() < 1e-9);
+        assert!(form.check_slippage(1.0).is_err(), "Should exceed 1%");
+
+        // More than the whole ask side
+        form.amount = "1000".to_string();
+        assert!(form.check_slippage(100.0).is_err());
+    }
+
+    #[test]
+    fn test_slippage_limit_rounds_to_tick_inside_price_range() {
+        let mut form = TradeFormState::new(
+            "Will Candidate A win?".to_string(),
+            vec![OutcomeInfo {
+                name: "Yes".to_string(),
+                token_id: "1002".to_string(),
+                price: 0.5,
+            }],
+            0,
+        );
+        form.toggle_order_type();
+        form.amount = "10".to_string();
+        let set_book = |form: &mut TradeFormState, tick: &str, bid: &str, ask: &str| {
+            form.orderbook = Some(
+                serde_json::from_value(serde_json::json!({
+                    "bids": [{ "price": bid, "size": "1000" }],
+                    "asks": [{ "price": ask, "size": "1000" }],
+                    "tick_size": tick,
+                }))
+                .expect("Should parse orderbook"),
+            );
+        };
+
+        // Buys round up and sells down, so the tick never tightens the tolerance
+        set_book(&mut form, "0.01", "0.61", "0.62");
+        let limit = |form: &TradeFormState, pct| form.slippage_limit(pct).expect("Should price");
+        assert!((limit(&form, 2.0) - 0.64).abs() < 1e-9, "62¢ + 2% = 63.24¢");
+        assert!((limit(&form, 0.0) - 0.62).abs() < 1e-9);
+        form.side = TradeSide::Sell;
+        assert!((limit(&form, 2.0) - 0.59).abs() < 1e-9, "61¢ - 2% = 59.78¢");
+
+        // Near the edges the limit stays one tick inside (0, 1)
+        set_book(&mut form, "0.001", "0.002", "0.995");
+        assert!((limit(&form, 60.0) - 0.001).abs() < 1e-9);
+        form.side = TradeSide::Buy;
+        assert!((limit(&form, 2.0) - 0.999).abs() < 1e-9);
+        let order = form.order_request(2.0).expect("Should build market order");
+        assert!((order.price - 0.999).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_trade_size_calculator() {
+        let mut form = TradeFormState::new(
+            "Will Candidate A win?".to_string(),
+            vec![OutcomeInfo {
+                name: "Yes".to_string(),
+                token_id: "1002".to_string(),
+                price: 0.25,
+            }],
+            0,
+        );
+
+        // Limit order sized in dollars: $10 at 25¢ = 40 shares
+        form.toggle_size_unit();
+        for c in "10".chars() {
+            form.add_char(c);
+        }
+        assert_eq!(form.shares, "40");
+        assert_eq!(form.total_cost(), 10.0);
+
+        // Raising the limit price keeps the dollars and buys fewer shares
+        form.limit_price = 0.5;
+        form.delete_char();
+        form.add_char('0');
+        assert_eq!(form.shares, "20");
+
+        // Back to shares keeps the order size
+        form.toggle_size_unit();
+        assert_eq!(form.size_unit, SizeUnit::Shares);
+        assert_eq!(form.shares, "20");
+
+        // Market order switched to shares converts the $10 amount at the 25¢ best ask
+        form.toggle_order_type();
+        assert_eq!(form.size_unit, SizeUnit::Dollars);
+        form.amount = "10".to_string();
+        form.toggle_size_unit();
+        assert_eq!(form.shares, "40");
+
+        // 8 shares at the 25¢ best ask = $2
+        form.delete_char();
+        form.delete_char();
+        form.add_char('8');
+        assert_eq!(form.amount, "2");
+    }
+
+    #[test]
+    fn test_order_request() {
+        let mut form = TradeFormState::new(
+            "Will Candidate A win?".to_string(),
+            vec![OutcomeInfo {
+                name: "Yes".to_string(),
+                token_id: "1002".to_string(),
+                price: 0.5,
+            }],
+            0,
+        );
+        form.shares = "100".to_string();
+
+        // $50 limit buy: balance must cover it
+        assert!(form.check_balance(None).is_err());
+        assert!(form.check_balance(Some(49.99)).is_err());
+        assert!(form.check_balance(Some(50.0)).is_ok());
+
+        let order = form.order_request(2.0).expect("Should build limit order");
+        assert_eq!(order.token_id, "1002");
+        assert_eq!(order.price, 0.5);
+        assert_eq!(order.size, 100.0);
+        assert_eq!(order.time_in_force, TimeInForce::Gtc);
+
+        // Limit orders can be fill-or-kill
+        form.toggle_time_in_force();
+        assert_eq!(
+            form.order_request(2.0).map(|o| o.time_in_force),
+            Ok(TimeInForce::Fok)
+        );
+
+        // Sells don't spend the balance
+        form.side = TradeSide::Sell;
+        assert!(form.check_balance(None).is_ok());
+
+        // Market buys are always FOK at the slippage limit, never spending more than the amount
+        form.side = TradeSide::Buy;
+        form.outcomes[0].price = 0.63;
+        form.toggle_order_type();
+        form.amount = "500".to_string();
+        form.orderbook = fixtures()
+            .load_or_default::<Vec<polymarket_api::clob::Orderbook>>("clob/books")
+            .expect("Should load orderbook fixtures")
+            .into_iter()
+            .find(|b| b.asset_id.as_deref() == Some("1002"));
+        let order = form.order_request(2.0).expect("Should build market order");
+        assert_eq!(order.time_in_force, TimeInForce::Fok);
+        // 63¢ + 2% = 64.26¢, rounded up to the 1¢ tick
+        assert!((order.price - 0.65).abs() < 1e-9);
+        assert!((order.size * order.price - 500.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_limit_price_input() {
+        let mut form = TradeFormState::new(
+            "Will Candidate A win?".to_string(),
+            vec![OutcomeInfo {
+                name: "Yes".to_string(),
+                token_id: "1002".to_string(),
+                price: 0.5,
+            }],
+            0,
+        );
+        form.shares = "100".to_string();
+        assert_eq!(form.price, "50");
+        assert_eq!(
+            form.order_request(2.0).map(|o| o.away_from_market),
+            Ok(false)
+        );
+
+        // Type a price away from the market: the cost preview follows it
+        form.active_field = TradeField::LimitPrice;
+        form.delete_char();
+        form.delete_char();
+        for c in "42.5".chars() {
+            form.add_char(c);
+        }
+        assert_eq!(form.price_f64(), Ok(0.425));
+        assert!((form.total_cost() - 42.5).abs() < 1e-9);
+
+        // Between the default 1¢ ticks it can't be placed, on a 0.1¢ book it can
+        assert!(form.order_request(2.0).is_err());
+        form.orderbook = Some(
+            serde_json::from_value(serde_json::json!({
+                "bids": [],
+                "asks": [],
+                "tick_size": "0.001",
+            }))
+            .expect("Should parse orderbook"),
+        );
+        let order = form.order_request(2.0).expect("Should build limit order");
+        assert_eq!(order.price, 0.425);
+        assert!(order.away_from_market);
+
+        // Out-of-range prices are rejected, the preview keeps the last valid one
+        form.price = "100".to_string();
+        assert!(form.price_f64().is_err());
+        assert!(form.order_request(2.0).is_err());
+        form.price.clear();
+        assert!(form.price_f64().is_err());
+
+        // -/+ step by the book's tick and reset the text to the adjusted price
+        form.increment_limit_price();
+        assert_eq!(form.price, "42.6");
+
+        // From between 1¢ ticks they move to the next tick in that direction
+        form.orderbook = None;
+        form.set_limit_price(0.425);
+        form.increment_limit_price();
+        assert_eq!(form.limit_price, 0.43);
+        form.set_limit_price(0.425);
+        form.decrement_limit_price();
+        assert_eq!(form.limit_price, 0.42);
+
+        // They stop one tick inside 0 and 1
+        form.set_limit_price(0.99);
+        form.increment_limit_price();
+        assert_eq!(form.limit_price, 0.99);
+        form.set_limit_price(0.01);
+        form.decrement_limit_price();
+        assert_eq!(form.limit_price, 0.01);
+    }
+
+    #[test]
+    fn test_trade_stats_update_incrementally() {
+        let mut event_trades = EventTrades::new();
+        event_trades.add_trade(&rtds_trade("1001", 0.5));
+        let mut sell = rtds_trade("1001", 0.25);
+        sell.payload.side = "SELL".to_string();
+        event_trades.add_trade(&sell);
+
+        let stats = event_trades.stats;
+        assert_eq!(stats.trade_count, 2);
+        assert_eq!(stats.buy_volume, 5.0);
+        assert_eq!(stats.sell_volume, 2.5);
+        assert_eq!(stats.net_flow(), 2.5);
+
+        // Totals cover the whole session, not just the trades still kept
+        for _ in 0..600 {
+            event_trades.add_trade(&rtds_trade("1001", 0.5));
+        }
+        assert_eq!(event_trades.trades.len(), 500);
+        assert_eq!(event_trades.stats.trade_count, 602);
+    }
+
+    #[test]
+    fn test_new_trades_flash_then_fade() {
+        use std::time::Duration;
+
+        let mut event_trades = EventTrades::new();
+        event_trades.add_trade(&rtds_trade("1001", 0.5));
+        let trade = &event_trades.trades[0];
+
+        let arrived = trade.arrived_at;
+        assert_eq!(trade.flash_level(arrived), 1.0);
+        let halfway = trade.flash_level(arrived + Duration::from_millis(750));
+        assert!((halfway - 0.5).abs() < 0.01);
+        assert_eq!(
+            trade.flash_level(arrived + Duration::from_millis(1500)),
+            0.0
+        );
+        assert_eq!(trade.flash_level(arrived + Duration::from_secs(5)), 0.0);
+    }
+
+    #[test]
+    fn test_trade_form_cycles_outcomes() {
+        let outcome = |name: &str, token_id: &str, price: f64| OutcomeInfo {
+            name: name.to_string(),
+            token_id: token_id.to_string(),
+            price,
+        };
+        let mut form = TradeFormState::new(
+            "Who will win?".to_string(),
+            vec![
+                outcome("A", "1", 0.5),
+                outcome("B", "2", 0.3),
+                outcome("C", "3", 0.2),
+            ],
+            0,
+        );
+        form.cycle_outcome(true);
+        assert_eq!(form.token_id(), Some("2"));
+        assert_eq!(form.price, "30");
+        form.cycle_outcome(true);
+        form.cycle_outcome(true);
+        assert_eq!(
+            form.outcome_name(),
+            "A",
+            "Should wrap past the last outcome"
+        );
+        form.cycle_outcome(false);
+        assert_eq!(
+            form.outcome_name(),
+            "C",
+            "Should wrap before the first outcome"
+        );
+        assert_eq!(
+            form.order_request(2.0).map(|order| order.outcome),
+            Ok("C".to_string())
+        );
+
+        // A single outcome stays selected
+        let mut form = TradeFormState::new("Q?".to_string(), vec![outcome("Yes", "1", 0.5)], 0);
+        form.cycle_outcome(true);
+        assert_eq!(form.outcome_name(), "Yes");
+    }
+}
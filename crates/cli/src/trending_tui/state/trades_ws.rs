@@ -1,6 +1,40 @@
 //! Trades and WebSocket management state
 
-use {super::trade::EventTrades, std::collections::HashMap, tokio::task::JoinHandle};
+use {
+    super::trade::EventTrades,
+    std::{
+        collections::HashMap,
+        time::{Duration, Instant},
+    },
+    tokio::task::JoinHandle,
+};
+
+/// Default for the most events watched for live trades at once
+pub const DEFAULT_MAX_WATCHED_EVENTS: usize = 10;
+
+/// A live feed with no RTDS message (trade or keep-alive) for this long is stale
+pub const RTDS_STALE_AFTER: Duration = Duration::from_secs(30);
+
+/// Connection status of a watched event's live trades
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtdsStatus {
+    Connecting,
+    Live,
+    /// Connected, but nothing arrived for `RTDS_STALE_AFTER`
+    Stale,
+    Disconnected,
+}
+
+impl RtdsStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RtdsStatus::Connecting => "Connecting",
+            RtdsStatus::Live => "Live",
+            RtdsStatus::Stale => "Stale",
+            RtdsStatus::Disconnected => "Disconnected",
+        }
+    }
+}
 
 /// Trades and WebSocket management state
 #[derive(Debug)]
@@ -9,6 +43,12 @@ pub struct TradesState {
     pub event_trades: HashMap<String, EventTrades>,
     // Map from event slug to websocket task handle
     pub ws_handles: HashMap<String, JoinHandle<()>>,
+    // Map from event slug to RTDS connection status (kept only while watching)
+    pub rtds_status: HashMap<String, RtdsStatus>,
+    // Map from event slug to the time of the last RTDS message
+    pub last_rtds_message_at: HashMap<String, Instant>,
+    // Most events watched at once; the least recently active watch is evicted
+    pub max_watched_events: usize,
 }
 
 impl TradesState {
@@ -16,6 +56,45 @@ impl TradesState {
         Self {
             event_trades: HashMap::new(),
             ws_handles: HashMap::new(),
+            rtds_status: HashMap::new(),
+            last_rtds_message_at: HashMap::new(),
+            max_watched_events: DEFAULT_MAX_WATCHED_EVENTS,
+        }
+    }
+
+    /// Update the connection status of a watched event (ignored once unwatched)
+    pub fn set_rtds_status(&mut self, event_slug: &str, status: RtdsStatus) {
+        if let Some(current) = self.rtds_status.get_mut(event_slug) {
+            *current = status;
+        }
+    }
+
+    /// Note that the event's socket delivered something, marking it live
+    pub fn record_rtds_message(&mut self, event_slug: &str, now: Instant) {
+        if let Some(status) = self.rtds_status.get_mut(event_slug) {
+            *status = RtdsStatus::Live;
+            self.last_rtds_message_at
+                .insert(event_slug.to_string(), now);
         }
     }
+
+    /// Connection status as of `now`, or None if the event isn't watched
+    pub fn rtds_status(&self, event_slug: &str, now: Instant) -> Option<RtdsStatus> {
+        let status = *self.rtds_status.get(event_slug)?;
+        let stale = self
+            .last_rtds_message_age(event_slug, now)
+            .is_some_and(|age| age >= RTDS_STALE_AFTER);
+        Some(if status == RtdsStatus::Live && stale {
+            RtdsStatus::Stale
+        } else {
+            status
+        })
+    }
+
+    /// Time since the event's last RTDS message
+    pub fn last_rtds_message_age(&self, event_slug: &str, now: Instant) -> Option<Duration> {
+        self.last_rtds_message_at
+            .get(event_slug)
+            .map(|at| now.saturating_duration_since(*at))
+    }
 }
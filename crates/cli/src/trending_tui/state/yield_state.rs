@@ -1,23 +1,18 @@
 //! Yield tab state types
 
-use chrono::{DateTime, Utc};
+// Full event details for an opportunity are looked up from the global event_cache using event_slug
+pub use polymarket_api::yields::YieldOpportunity;
+use {
+    crate::settings::{Settings, YieldSettings},
+    chrono::Utc,
+    polymarket_api::yields::DEFAULT_MIN_PROB,
+    serde::{Deserialize, Serialize},
+    std::{cmp::Ordering, path::Path},
+};
 
-/// A single yield opportunity (high probability market)
-/// Full event details are looked up from the global event_cache using event_slug
-/// Some event data is cached here for filtering and sorting purposes
-#[derive(Debug, Clone)]
-pub struct YieldOpportunity {
-    pub market_name: String,
-    pub market_status: &'static str,
-    pub outcome: String,
-    pub price: f64,
-    pub est_return: f64,
-    pub volume: f64,
-    pub event_slug: String,
-    // Cached event data for filtering/sorting (full details from event_cache)
-    pub event_title: String,
-    pub end_date: Option<DateTime<Utc>>,
-}
+/// Histogram buckets by estimated return: label and lower bound in percent
+pub const RETURN_BUCKETS: [(&str, f64); 4] =
+    [("0-2%", 0.0), ("2-5%", 2.0), ("5-10%", 5.0), ("10%+", 10.0)];
 
 /// A search result in the Yield tab - an event with its best yield opportunity (if any)
 /// Event details are looked up from the global event_cache using event_slug
@@ -28,6 +23,18 @@ pub struct YieldSearchResult {
     pub best_yield: Option<YieldOpportunity>,
 }
 
+/// Totals across the filtered opportunities, shown under the Yield list
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct YieldSummary {
+    pub count: usize,
+    /// Mean estimated return in percent
+    pub avg_return: f64,
+    /// Highest estimated return in percent
+    pub best_return: f64,
+    /// Sum of the markets' 24h volume in dollars
+    pub total_volume: f64,
+}
+
 /// Yield tab state
 #[derive(Debug)]
 pub struct YieldState {
@@ -37,6 +44,10 @@ pub struct YieldState {
     pub is_loading: bool,
     pub min_prob: f64,
     pub min_volume: f64,
+    /// Skip markets with less liquidity than this, too thin to fill at the quoted price
+    pub min_liquidity: f64,
+    /// Skip markets resolving further out than this many days (None = no limit)
+    pub max_days_to_resolution: Option<u32>,
     pub sort_by: YieldSortBy,
     pub filter_query: String, // Current filter query
     pub is_filtering: bool,   // Whether filter input is active
@@ -48,17 +59,20 @@ pub struct YieldState {
     pub last_searched_query: String,            // Last query that was searched
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum YieldSortBy {
-    Return,  // Sort by estimated return (default)
-    Volume,  // Sort by 24h volume
-    EndDate, // Sort by end date (soonest first)
+    Return,     // Sort by estimated return (default)
+    Annualized, // Sort by return per year until resolution
+    Volume,     // Sort by 24h volume
+    EndDate,    // Sort by end date (soonest first)
 }
 
 impl YieldSortBy {
     pub fn label(&self) -> &'static str {
         match self {
             YieldSortBy::Return => "Return",
+            YieldSortBy::Annualized => "Annualized",
             YieldSortBy::Volume => "Volume",
             YieldSortBy::EndDate => "End Date",
         }
@@ -66,7 +80,8 @@ impl YieldSortBy {
 
     pub fn next(&self) -> Self {
         match self {
-            YieldSortBy::Return => YieldSortBy::Volume,
+            YieldSortBy::Return => YieldSortBy::Annualized,
+            YieldSortBy::Annualized => YieldSortBy::Volume,
             YieldSortBy::Volume => YieldSortBy::EndDate,
             YieldSortBy::EndDate => YieldSortBy::Return,
         }
@@ -80,8 +95,10 @@ impl YieldState {
             selected_index: 0,
             scroll: 0,
             is_loading: false,
-            min_prob: 0.95,
+            min_prob: DEFAULT_MIN_PROB,
             min_volume: 0.0,
+            min_liquidity: 0.0,
+            max_days_to_resolution: None,
             sort_by: YieldSortBy::Return,
             filter_query: String::new(),
             is_filtering: false,
@@ -93,28 +110,63 @@ impl YieldState {
         }
     }
 
-    pub fn sort_opportunities(&mut self) {
-        match self.sort_by {
-            YieldSortBy::Return => {
-                self.opportunities
-                    .sort_by(|a, b| b.est_return.partial_cmp(&a.est_return).unwrap());
-            },
-            YieldSortBy::Volume => {
-                self.opportunities
-                    .sort_by(|a, b| b.volume.partial_cmp(&a.volume).unwrap());
-            },
-            YieldSortBy::EndDate => {
-                self.opportunities
-                    .sort_by(|a, b| match (&a.end_date, &b.end_date) {
-                        (Some(a_date), Some(b_date)) => a_date.cmp(b_date),
-                        (Some(_), None) => std::cmp::Ordering::Less,
-                        (None, Some(_)) => std::cmp::Ordering::Greater,
-                        (None, None) => std::cmp::Ordering::Equal,
-                    });
-            },
+    /// Filters and sort order to restore on the next launch
+    pub fn settings(&self) -> YieldSettings {
+        YieldSettings {
+            min_prob: self.min_prob,
+            min_volume: self.min_volume,
+            min_liquidity: self.min_liquidity,
+            max_days_to_resolution: self.max_days_to_resolution,
+            sort_by: self.sort_by,
         }
     }
 
+    /// Apply saved filters and sort order
+    pub fn load_settings(&mut self, settings: &YieldSettings) {
+        self.min_prob = settings.min_prob;
+        self.min_volume = settings.min_volume;
+        self.min_liquidity = settings.min_liquidity;
+        self.max_days_to_resolution = settings.max_days_to_resolution;
+        self.sort_by = settings.sort_by;
+        self.sort_opportunities();
+    }
+
+    /// Write the filters and sort order to the settings file at `path`
+    pub fn save_settings(&self, path: &Path) -> Result<(), String> {
+        let mut settings = Settings::read_from(path)?;
+        settings.yield_tab = self.settings();
+        settings.save_to(path)
+    }
+
+    /// Sort by the current key; opportunities without an end date always go last
+    pub fn sort_opportunities(&mut self) {
+        let now = Utc::now();
+        let by_return = |a: &YieldOpportunity, b: &YieldOpportunity| {
+            b.est_return
+                .partial_cmp(&a.est_return)
+                .unwrap_or(Ordering::Equal)
+        };
+        let sort_by = self.sort_by;
+        self.opportunities.sort_by(|a, b| {
+            let undated = a.end_date.is_none().cmp(&b.end_date.is_none());
+            undated.then_with(|| match sort_by {
+                YieldSortBy::Return => by_return(a, b),
+                YieldSortBy::Annualized => {
+                    match (a.est_return_annualized(now), b.est_return_annualized(now)) {
+                        (Some(a_ann), Some(b_ann)) => {
+                            b_ann.partial_cmp(&a_ann).unwrap_or(Ordering::Equal)
+                        },
+                        (Some(_), None) => Ordering::Less,
+                        (None, Some(_)) => Ordering::Greater,
+                        (None, None) => by_return(a, b),
+                    }
+                },
+                YieldSortBy::Volume => b.volume.partial_cmp(&a.volume).unwrap_or(Ordering::Equal),
+                YieldSortBy::EndDate => a.end_date.cmp(&b.end_date),
+            })
+        });
+    }
+
     pub fn move_up(&mut self) {
         let filtered_len = self.filtered_opportunities().len();
         if filtered_len == 0 {
@@ -141,6 +193,18 @@ impl YieldState {
         }
     }
 
+    /// Select the filtered opportunity at `index` (clamped to the list), scrolling it
+    /// into view
+    pub fn select_index(&mut self, index: usize, visible_height: usize) {
+        let filtered_len = self.filtered_opportunities().len();
+        self.selected_index = index.min(filtered_len.saturating_sub(1));
+        if self.selected_index < self.scroll {
+            self.scroll = self.selected_index;
+        } else if self.selected_index >= self.scroll + visible_height {
+            self.scroll = self.selected_index + 1 - visible_height;
+        }
+    }
+
     pub fn selected_opportunity(&self) -> Option<&YieldOpportunity> {
         self.filtered_opportunities()
             .get(self.selected_index)
@@ -164,6 +228,37 @@ impl YieldState {
             .collect()
     }
 
+    /// Count, average and best return, and total volume of the filtered opportunities
+    pub fn summary(&self) -> YieldSummary {
+        let filtered = self.filtered_opportunities();
+        if filtered.is_empty() {
+            return YieldSummary::default();
+        }
+        let total_return: f64 = filtered.iter().map(|opp| opp.est_return).sum();
+        YieldSummary {
+            count: filtered.len(),
+            avg_return: total_return / filtered.len() as f64,
+            best_return: filtered
+                .iter()
+                .map(|opp| opp.est_return)
+                .fold(f64::MIN, f64::max),
+            total_volume: filtered.iter().map(|opp| opp.volume).sum(),
+        }
+    }
+
+    /// Count filtered opportunities per [`RETURN_BUCKETS`] entry
+    pub fn return_histogram(&self) -> [u64; RETURN_BUCKETS.len()] {
+        let mut counts = [0; RETURN_BUCKETS.len()];
+        for opp in self.filtered_opportunities() {
+            let bucket = RETURN_BUCKETS
+                .iter()
+                .rposition(|(_, lower)| opp.est_return >= *lower)
+                .unwrap_or(0);
+            counts[bucket] += 1;
+        }
+        counts
+    }
+
     pub fn enter_filter_mode(&mut self) {
         self.is_filtering = true;
         self.filter_query.clear();
@@ -261,3 +356,133 @@ impl YieldState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, crate::trending_tui::test_fixtures::fixture_markets,
+        polymarket_api::yields::find_yield_opportunities,
+    };
+
+    /// First opportunity found in the fixture markets, to copy into test cases
+    fn template() -> YieldOpportunity {
+        find_yield_opportunities(&fixture_markets(), DEFAULT_MIN_PROB, 0.0)
+            .into_iter()
+            .next()
+            .expect("Should find a yield opportunity")
+    }
+
+    #[test]
+    fn test_yield_sort_annualized_puts_undated_last() {
+        let template = template();
+        let opportunity = |name: &str, est_return: f64, days: Option<i64>| YieldOpportunity {
+            market_name: name.to_string(),
+            est_return,
+            end_date: days.map(|days| Utc::now() + chrono::Duration::days(days)),
+            ..template.clone()
+        };
+        let mut state = YieldState::new();
+        state.opportunities = vec![
+            opportunity("undated", 9.0, None),
+            opportunity("far", 5.0, Some(365)),
+            opportunity("soon", 1.0, Some(7)),
+        ];
+
+        state.sort_opportunities();
+        let names = |state: &YieldState| -> Vec<String> {
+            state
+                .opportunities
+                .iter()
+                .map(|opp| opp.market_name.clone())
+                .collect()
+        };
+        assert_eq!(names(&state), vec!["far", "soon", "undated"]);
+
+        state.sort_by = state.sort_by.next();
+        assert_eq!(state.sort_by, YieldSortBy::Annualized);
+        state.sort_opportunities();
+        // 1% in a week beats 5% in a year
+        assert_eq!(names(&state), vec!["soon", "far", "undated"]);
+    }
+
+    #[test]
+    fn test_yield_settings_round_trip() {
+        let mut state = YieldState::new();
+        state.min_prob = 0.9;
+        state.min_volume = 1000.0;
+        state.min_liquidity = 5000.0;
+        state.max_days_to_resolution = Some(30);
+        state.sort_by = YieldSortBy::Annualized;
+
+        let settings = Settings {
+            yield_tab: state.settings(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&settings).expect("Should serialize settings");
+        assert!(json.contains("\"yield\"") && json.contains("\"annualized\""));
+        let loaded: Settings = serde_json::from_str(&json).expect("Should parse settings");
+
+        let mut restored = YieldState::new();
+        restored.load_settings(&loaded.yield_tab);
+        assert_eq!(restored.settings(), state.settings());
+
+        // Files written before the Yield filters were saved keep the defaults
+        let old: Settings = serde_json::from_str(r#"{"favorites": []}"#).expect("Should parse");
+        assert_eq!(old.yield_tab.min_prob, DEFAULT_MIN_PROB);
+        assert_eq!(old.yield_tab.sort_by, YieldSortBy::Return);
+    }
+
+    #[test]
+    fn test_yield_return_histogram_follows_filter() {
+        let template = template();
+        let opportunity = |name: &str, est_return: f64| YieldOpportunity {
+            market_name: name.to_string(),
+            est_return,
+            ..template.clone()
+        };
+        let mut state = YieldState::new();
+        state.opportunities = vec![
+            opportunity("alpha", 0.5),
+            opportunity("beta", 2.0),
+            opportunity("gamma", 4.9),
+            opportunity("delta", 7.5),
+            opportunity("epsilon", 25.0),
+        ];
+        assert_eq!(state.return_histogram(), [1, 2, 1, 1]);
+
+        state.filter_query = "ta".to_string();
+        assert_eq!(state.return_histogram(), [0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn test_yield_summary_follows_filter() {
+        let mut state = YieldState::new();
+        assert_eq!(state.summary(), Default::default());
+
+        let template = template();
+        let opportunity = |name: &str, est_return: f64, volume: f64| YieldOpportunity {
+            market_name: name.to_string(),
+            est_return,
+            volume,
+            ..template.clone()
+        };
+        state.opportunities = vec![
+            opportunity("alpha", 2.0, 1_000.0),
+            opportunity("beta", 6.0, 3_000.0),
+            opportunity("alpha two", 4.0, 500.0),
+        ];
+
+        let summary = state.summary();
+        assert_eq!(summary.count, 3);
+        assert!((summary.avg_return - 4.0).abs() < 1e-9);
+        assert_eq!(summary.best_return, 6.0);
+        assert_eq!(summary.total_volume, 4_500.0);
+
+        state.filter_query = "alpha".to_string();
+        let summary = state.summary();
+        assert_eq!(summary.count, 2);
+        assert!((summary.avg_return - 3.0).abs() < 1e-9);
+        assert_eq!(summary.best_return, 4.0);
+        assert_eq!(summary.total_volume, 1_500.0);
+    }
+}
@@ -0,0 +1,86 @@
+//! Shared API fixtures for the trending TUI tests
+//!
+//! Loads the `polymarket-api` test fixtures and builds `TrendingAppState`s from them,
+//! for the snapshot tests and the unit tests next to the state and input code.
+
+use {
+    super::state::TrendingAppState,
+    polymarket_api::{
+        Fixtures,
+        gamma::{Event, Market},
+    },
+};
+
+pub(super) fn fixtures() -> Fixtures {
+    Fixtures::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../polymarket-api/tests/fixtures"
+    ))
+}
+
+/// Fixture events with end dates removed so countdowns don't depend on the current time
+pub(super) fn fixture_events() -> Vec<Event> {
+    let mut events: Vec<Event> = fixtures()
+        .load_or_default("gamma/events")
+        .expect("Should load event fixtures");
+    for event in &mut events {
+        event.end_date = None;
+        for market in &mut event.markets {
+            for event_ref in &mut market.events {
+                event_ref.end_date = None;
+            }
+        }
+    }
+    events
+}
+
+pub(super) fn fixture_markets() -> Vec<Market> {
+    let mut markets: Vec<Market> = fixtures()
+        .load_or_default("gamma/markets")
+        .expect("Should load market fixtures");
+    for market in &mut markets {
+        for event_ref in &mut market.events {
+            event_ref.end_date = None;
+        }
+    }
+    markets
+}
+
+pub(super) fn app_with_events(events: Vec<Event>) -> TrendingAppState {
+    TrendingAppState::new(events, "volume24hr".to_string(), false, false)
+}
+
+pub(super) fn loaded_app() -> TrendingAppState {
+    app_with_events(fixture_events())
+}
+
+/// Live trade message for `asset` at `price`
+pub(super) fn rtds_trade(asset: &str, price: f64) -> polymarket_api::rtds::RTDSMessage {
+    serde_json::from_value(serde_json::json!({
+        "connection_id": null,
+        "payload": {
+            "asset": asset,
+            "side": "BUY",
+            "price": price,
+            "size": 10.0,
+            "timestamp": 1_700_000_000,
+            "title": "Will Candidate A win?",
+            "slug": "example-election-winner-candidate-a",
+            "eventSlug": "example-election-winner",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "name": "trader",
+            "pseudonym": "Trader",
+            "proxyWallet": "0x0000000000000000000000000000000000000000",
+            "transactionHash": "0x00",
+            "conditionId": null,
+            "bio": null,
+            "icon": null,
+            "profile_image": null
+        },
+        "timestamp": 1_700_000_000,
+        "topic": "activity",
+        "type": "trades"
+    }))
+    .expect("Should build RTDS trade")
+}
@@ -0,0 +1,136 @@
+//! Portable working set module
+//!
+//! Exports the watchlist, favorites and settings to a single JSON file that can be
+//! imported on another machine. Credentials (auth.json) are never part of it.
+
+use {
+    crate::settings::Settings,
+    serde::{Deserialize, Serialize},
+    std::path::Path,
+};
+
+/// Format version written to exported files
+pub const WORKING_SET_VERSION: u32 = 1;
+
+/// Shareable snapshot of the user's setup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkingSet {
+    pub version: u32,
+    /// Settings, including the watchlist and local favorites (no credentials)
+    #[serde(flatten)]
+    pub settings: Settings,
+}
+
+impl WorkingSet {
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            version: WORKING_SET_VERSION,
+            settings,
+        }
+    }
+
+    /// Write the working set to a JSON file
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize working set: {}", e))?;
+        std::fs::write(path, content)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Read a working set exported by `save`
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let working_set: Self = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+        if working_set.version > WORKING_SET_VERSION {
+            return Err(format!(
+                "Unsupported working set version {} (expected {} or older)",
+                working_set.version, WORKING_SET_VERSION
+            ));
+        }
+        Ok(working_set)
+    }
+
+    /// Apply the imported set on top of existing settings
+    ///
+    /// Trade settings, Yield tab filters and the events sort are replaced; watchlist
+    /// and favorites are merged, keeping existing entries first. The price refresh
+    /// interval and watch limit are replaced only when the imported set has them.
+    pub fn merge_into(self, settings: &mut Settings) {
+        settings.trade = self.settings.trade;
+        settings.yield_tab = self.settings.yield_tab;
+        settings.event_sort = self.settings.event_sort;
+        settings.price_refresh_secs = self
+            .settings
+            .price_refresh_secs
+            .or(settings.price_refresh_secs);
+        settings.max_watched_events = self
+            .settings
+            .max_watched_events
+            .or(settings.max_watched_events);
+        merge_slugs(&mut settings.watchlist, self.settings.watchlist);
+        merge_slugs(&mut settings.favorites, self.settings.favorites);
+    }
+}
+
+fn merge_slugs(existing: &mut Vec<String>, imported: Vec<String>) {
+    for slug in imported {
+        if !existing.contains(&slug) {
+            existing.push(slug);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::trending_tui::state::EventSortBy};
+
+    #[test]
+    fn test_working_set_export_import() {
+        let mut exported = Settings {
+            watchlist: vec!["example-election-winner".to_string()],
+            favorites: vec!["example-championship-final".to_string()],
+            ..Default::default()
+        };
+        exported.trade.max_slippage_pct = 5.0;
+        exported.yield_tab.min_liquidity = 1_000.0;
+        exported.yield_tab.max_days_to_resolution = Some(30);
+        let exported_yield = exported.yield_tab.clone();
+        exported.event_sort = EventSortBy::Liquidity;
+        exported.price_refresh_secs = Some(30);
+        exported.max_watched_events = Some(20);
+
+        let path =
+            std::env::temp_dir().join(format!("polymarket-state-{}.json", std::process::id()));
+        WorkingSet::new(exported)
+            .save(&path)
+            .expect("Should export");
+        let content = std::fs::read_to_string(&path).expect("Should read export");
+        assert!(!content.contains("api_key") && !content.contains("secret"));
+        let imported = WorkingSet::load(&path).expect("Should import");
+        let _ = std::fs::remove_file(&path);
+
+        // Lists are merged into the existing settings, preferences replaced
+        let mut settings = Settings {
+            watchlist: vec!["example-championship-final".to_string()],
+            ..Default::default()
+        };
+        imported.merge_into(&mut settings);
+        assert_eq!(settings.watchlist, vec![
+            "example-championship-final",
+            "example-election-winner"
+        ]);
+        assert_eq!(settings.favorites, vec!["example-championship-final"]);
+        assert_eq!(settings.trade.max_slippage_pct, 5.0);
+        assert_eq!(settings.yield_tab, exported_yield);
+        assert_eq!(settings.event_sort, EventSortBy::Liquidity);
+        assert_eq!(settings.price_refresh_secs, Some(30));
+        assert_eq!(settings.max_watched_events, Some(20));
+
+        // Unset optional values in the import keep the current ones
+        WorkingSet::new(Settings::default()).merge_into(&mut settings);
+        assert_eq!(settings.price_refresh_secs, Some(30));
+        assert_eq!(settings.max_watched_events, Some(20));
+    }
+}
@@ -35,3 +35,282 @@ fn cli_starts_and_connects() {
         },
     }
 }
+
+#[test]
+fn json_output_is_clean_on_stdout() {
+    let bin_path = env!("CARGO_BIN_EXE_polymarket-tui");
+    let fixtures = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../polymarket-api/tests/fixtures"
+    );
+
+    let output = Command::new(bin_path)
+        .args([
+            "--fixtures",
+            fixtures,
+            "--json",
+            "orderbook",
+            "1002",
+            "--asset",
+        ])
+        .env("RUST_LOG", "info")
+        .output()
+        .expect("failed to run polymarket-tui");
+    assert!(output.status.success());
+
+    // Only the JSON document is on stdout; logs went to stderr
+    let value: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a single JSON value");
+    assert_eq!(value["orderbook"]["asset_id"], "1002");
+    assert_eq!(value["summary"]["best_bid"], 0.61);
+    assert_eq!(value["summary"]["best_ask"], 0.63);
+}
+
+#[test]
+fn price_command_prints_the_requested_side() {
+    let bin_path = env!("CARGO_BIN_EXE_polymarket-tui");
+    let fixtures = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../polymarket-api/tests/fixtures"
+    );
+    let price = |args: &[&str]| {
+        let output = Command::new(bin_path)
+            .args(["--fixtures", fixtures, "price", "1002"])
+            .args(args)
+            // Text mode logs to stdout, so keep them out of the printed price
+            .env("RUST_LOG", "off")
+            .output()
+            .expect("failed to run polymarket-tui");
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).expect("stdout should be UTF-8")
+    };
+
+    assert_eq!(price(&[]).trim(), "0.61");
+    assert_eq!(price(&["--side", "buy"]).trim(), "0.63");
+
+    let value: serde_json::Value =
+        serde_json::from_str(&price(&["--side", "BUY", "--json"])).expect("Should be JSON");
+    assert_eq!(value["side"], "BUY");
+    assert_eq!(value["price"], "0.63");
+}
+
+#[test]
+fn markets_command_lists_token_ids() {
+    let bin_path = env!("CARGO_BIN_EXE_polymarket-tui");
+    let fixtures = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../polymarket-api/tests/fixtures"
+    );
+
+    let output = Command::new(bin_path)
+        .args([
+            "--fixtures",
+            fixtures,
+            "--json",
+            "markets",
+            "https://polymarket.com/event/example-election-winner",
+        ])
+        .output()
+        .expect("failed to run polymarket-tui");
+    assert!(output.status.success());
+
+    let markets: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a single JSON value");
+    assert_eq!(markets.as_array().map(Vec::len), Some(3));
+    assert_eq!(markets[0]["question"], "Will Candidate A win?");
+    assert_eq!(markets[0]["clob_token_ids"][0], "1002");
+    assert_eq!(markets[0]["outcome_prices"][1], "0.38");
+}
+
+#[test]
+fn ids_only_flags_print_bare_ids() {
+    let bin_path = env!("CARGO_BIN_EXE_polymarket-tui");
+    let fixtures = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../polymarket-api/tests/fixtures"
+    );
+    let stdout = |args: &[&str]| {
+        let output = Command::new(bin_path)
+            .args(["--fixtures", fixtures])
+            .args(args)
+            .output()
+            .expect("failed to run polymarket-tui");
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).expect("stdout should be UTF-8")
+    };
+
+    assert_eq!(
+        stdout(&["market", "501", "--id", "--token-ids-only"]),
+        "1002\n1003\n"
+    );
+    assert_eq!(
+        stdout(&[
+            "market",
+            "example-election-winner-candidate-b",
+            "--condition-ids-only"
+        ]),
+        "0x0000000000000000000000000000000000000000000000000000000000000002\n"
+    );
+    // The flags win over --json
+    assert_eq!(
+        stdout(&[
+            "--json",
+            "event",
+            "example-championship-final",
+            "--token-ids-only"
+        ]),
+        "1008\n1009\n1010\n1011\n"
+    );
+}
+
+#[test]
+fn trades_since_filters_old_trades_and_rejects_bad_durations() {
+    let bin_path = env!("CARGO_BIN_EXE_polymarket-tui");
+    let fixtures = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../polymarket-api/tests/fixtures"
+    );
+
+    // Fixture CLOB trades are from 2023, so none are within the last hour
+    let output = Command::new(bin_path)
+        .args([
+            "--fixtures",
+            fixtures,
+            "--json",
+            "trades",
+            "1002",
+            "--asset",
+            "--since",
+            "1h",
+        ])
+        .output()
+        .expect("failed to run polymarket-tui");
+    assert!(output.status.success());
+    let trades: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a single JSON value");
+    assert_eq!(trades.as_array().map(Vec::len), Some(0));
+
+    let output = Command::new(bin_path)
+        .args([
+            "--fixtures",
+            fixtures,
+            "trades",
+            "1002",
+            "--asset",
+            "--since",
+            "soon",
+        ])
+        .output()
+        .expect("failed to run polymarket-tui");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid --since 'soon'"));
+}
+
+#[test]
+fn token_command_resolves_outcome() {
+    let bin_path = env!("CARGO_BIN_EXE_polymarket-tui");
+    let fixtures = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../polymarket-api/tests/fixtures"
+    );
+
+    let output = Command::new(bin_path)
+        .args(["--fixtures", fixtures, "--json", "token", "1003"])
+        .output()
+        .expect("failed to run polymarket-tui");
+    assert!(output.status.success());
+
+    let token: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a single JSON value");
+    assert_eq!(token["market_question"], "Will Candidate A win?");
+    assert_eq!(token["outcome"], "No");
+    assert_eq!(token["event_slug"], "example-election-winner");
+    // Midpoint of the 0.39 / 0.37 price fixtures
+    assert_eq!(token["price"], 0.38);
+
+    let output = Command::new(bin_path)
+        .args(["--fixtures", fixtures, "token", "9999"])
+        .output()
+        .expect("failed to run polymarket-tui");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn status_command_reports_every_api() {
+    let bin_path = env!("CARGO_BIN_EXE_polymarket-tui");
+    let fixtures = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../polymarket-api/tests/fixtures"
+    );
+
+    let output = Command::new(bin_path)
+        .args(["--fixtures", fixtures, "--json", "status"])
+        .output()
+        .expect("failed to run polymarket-tui");
+    assert!(output.status.success());
+
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a single JSON value");
+    let apis: Vec<_> = report
+        .as_array()
+        .expect("Should be an array")
+        .iter()
+        .map(|check| check["api"].as_str().unwrap_or_default())
+        .collect();
+    assert_eq!(apis, ["gamma", "data", "clob", "rtds"]);
+    assert!(
+        report
+            .as_array()
+            .into_iter()
+            .flatten()
+            .all(|check| check["up"] == true)
+    );
+    assert_eq!(report[3]["critical"], false);
+}
+
+#[test]
+fn favorites_export_and_import_round_trip() {
+    let bin_path = env!("CARGO_BIN_EXE_polymarket-tui");
+    let fixtures = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../polymarket-api/tests/fixtures"
+    );
+    // Placeholder credentials in a throwaway config directory
+    let config_home =
+        std::env::temp_dir().join(format!("polymarket-favorites-{}", std::process::id()));
+    let config_dir = config_home.join("polymarket-tui");
+    std::fs::create_dir_all(&config_dir).expect("should create config dir");
+    std::fs::write(
+        config_dir.join("auth.json"),
+        r#"{"api_key": "test", "secret": "test", "passphrase": "test",
+            "address": "0x0000000000000000000000000000000000000001",
+            "session_cookie": "test", "session_nonce": "test"}"#,
+    )
+    .expect("should write auth.json");
+    let run = |args: &[&str]| {
+        let output = Command::new(bin_path)
+            .args(["--fixtures", fixtures, "favorites"])
+            .args(args)
+            .env("XDG_CONFIG_HOME", &config_home)
+            .env("RUST_LOG", "info")
+            .output()
+            .expect("failed to run polymarket-tui");
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).expect("stdout should be UTF-8")
+    };
+
+    // The second favorite has no embedded event and is looked up by ID
+    let exported: serde_json::Value =
+        serde_json::from_str(&run(&["--export"])).expect("stdout should be JSON only");
+    assert_eq!(exported[0]["slug"], "example-election-winner");
+    assert_eq!(exported[1]["slug"], "example-championship-final");
+    assert_eq!(exported[1]["title"], "Example championship final winner");
+
+    // Everything in the file is already favorited
+    let file = config_home.join("favorites.json");
+    run(&["--export", file.to_str().expect("path should be UTF-8")]);
+    let report = run(&["--import", file.to_str().expect("path should be UTF-8")]);
+    assert!(report.contains("Added 0, skipped 2 already favorited, 0 not found"));
+
+    let _ = std::fs::remove_dir_all(&config_home);
+}
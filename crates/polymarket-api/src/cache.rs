@@ -15,6 +15,20 @@ pub struct FileCache {
     default_ttl_seconds: Option<u64>,
 }
 
+/// Number of cache entries and the bytes they take on disk
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheUsage {
+    pub entries: usize,
+    pub bytes: u64,
+}
+
+impl CacheUsage {
+    fn add(&mut self, bytes: u64) {
+        self.entries += 1;
+        self.bytes += bytes;
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct CacheEntry<T> {
     data: T,
@@ -79,6 +93,21 @@ impl FileCache {
 
     /// Store data in cache with the given key
     pub fn set<T>(&self, key: &str, data: T) -> Result<()>
+    where
+        T: Serialize,
+    {
+        self.write_entry(key, data, self.default_ttl_seconds)
+    }
+
+    /// Store data with its own TTL instead of the cache's default
+    pub fn set_with_ttl<T>(&self, key: &str, data: T, ttl_seconds: u64) -> Result<()>
+    where
+        T: Serialize,
+    {
+        self.write_entry(key, data, Some(ttl_seconds))
+    }
+
+    fn write_entry<T>(&self, key: &str, data: T, ttl_seconds: Option<u64>) -> Result<()>
     where
         T: Serialize,
     {
@@ -92,7 +121,7 @@ impl FileCache {
         let entry = CacheEntry {
             data,
             cached_at,
-            ttl_seconds: self.default_ttl_seconds,
+            ttl_seconds,
         };
 
         let json = serde_json::to_string_pretty(&entry).map_err(PolymarketError::Serialization)?;
@@ -121,24 +150,73 @@ impl FileCache {
         Ok(())
     }
 
-    /// Clear all cached entries
-    pub fn clear(&self) -> Result<()> {
-        if self.cache_dir.exists() {
-            for entry in fs::read_dir(&self.cache_dir).map_err(|e| {
-                PolymarketError::InvalidData(format!("Failed to read cache directory: {}", e))
-            })? {
-                let entry = entry.map_err(|e| {
-                    PolymarketError::InvalidData(format!("Failed to read directory entry: {}", e))
-                })?;
-                let path = entry.path();
-                if path.is_file() && path.extension().map(|e| e == "json").unwrap_or(false) {
-                    fs::remove_file(&path).map_err(|e| {
-                        PolymarketError::InvalidData(format!("Failed to remove cache file: {}", e))
-                    })?;
-                }
+    /// Remove all cached entries, returning what was removed
+    pub fn clear(&self) -> Result<CacheUsage> {
+        let mut removed = CacheUsage::default();
+        for (path, bytes) in self.entry_files()? {
+            remove_cache_file(&path)?;
+            removed.add(bytes);
+        }
+        Ok(removed)
+    }
+
+    /// Remove only entries past their TTL, returning what was removed
+    ///
+    /// Entries that no longer parse are removed too, since `get` can't use them.
+    pub fn clear_expired(&self) -> Result<CacheUsage> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| PolymarketError::InvalidData(format!("System time error: {}", e)))?
+            .as_secs();
+
+        let mut removed = CacheUsage::default();
+        for (path, bytes) in self.entry_files()? {
+            let expired = fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| {
+                    serde_json::from_str::<CacheEntry<serde_json::Value>>(&content).ok()
+                })
+                .is_none_or(|entry| {
+                    entry
+                        .ttl_seconds
+                        .is_some_and(|ttl| now.saturating_sub(entry.cached_at) > ttl)
+                });
+            if expired {
+                remove_cache_file(&path)?;
+                removed.add(bytes);
             }
         }
-        Ok(())
+        Ok(removed)
+    }
+
+    /// Number of cached entries and their total size
+    pub fn size_on_disk(&self) -> Result<CacheUsage> {
+        let mut usage = CacheUsage::default();
+        for (_, bytes) in self.entry_files()? {
+            usage.add(bytes);
+        }
+        Ok(usage)
+    }
+
+    /// Cache files with their sizes (a missing directory has none)
+    fn entry_files(&self) -> Result<Vec<(PathBuf, u64)>> {
+        let mut files = Vec::new();
+        if !self.cache_dir.exists() {
+            return Ok(files);
+        }
+        for entry in fs::read_dir(&self.cache_dir).map_err(|e| {
+            PolymarketError::InvalidData(format!("Failed to read cache directory: {}", e))
+        })? {
+            let entry = entry.map_err(|e| {
+                PolymarketError::InvalidData(format!("Failed to read directory entry: {}", e))
+            })?;
+            let path = entry.path();
+            if path.is_file() && path.extension().map(|e| e == "json").unwrap_or(false) {
+                let bytes = entry.metadata().map(|m| m.len()).unwrap_or(0);
+                files.push((path, bytes));
+            }
+        }
+        Ok(files)
     }
 
     /// Get the cache directory path
@@ -162,6 +240,11 @@ impl FileCache {
     }
 }
 
+fn remove_cache_file(path: &Path) -> Result<()> {
+    fs::remove_file(path)
+        .map_err(|e| PolymarketError::InvalidData(format!("Failed to remove cache file: {}", e)))
+}
+
 /// Helper function to get default cache directory
 pub fn default_cache_dir() -> PathBuf {
     dirs::cache_dir()
@@ -4,13 +4,22 @@
 //! which allows fetching orderbooks, trades, and managing orders.
 
 use {
-    crate::error::Result,
+    crate::{
+        error::{PolymarketError, Result, check_status, lock_mutex},
+        fixtures::{Fixtures, offline_write_error},
+        signing::{OrderSigner, SignatureType, SignedOrder},
+        websocket::WebSocketMessage,
+    },
     base64::{Engine, engine::general_purpose::STANDARD},
     hmac::{Hmac, Mac},
     reqwest::header::{HeaderMap, HeaderValue},
     serde::{Deserialize, Serialize},
     sha2::Sha256,
-    std::time::{SystemTime, UNIX_EPOCH},
+    std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    },
 };
 
 /// Macro for conditional info logging based on tracing feature
@@ -37,14 +46,33 @@ macro_rules! log_debug {
 
 const CLOB_API_BASE: &str = "https://clob.polymarket.com";
 
+/// Requests per second allowed by a client that didn't call `with_rate_limit`
+pub const DEFAULT_REQUESTS_PER_SEC: u32 = 10;
+
+/// Price increment assumed for orderbooks that don't report a tick size
+pub const DEFAULT_TICK_SIZE: f64 = 0.01;
+
 /// Order side (buy or sell)
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Side {
     Buy,
     Sell,
 }
 
+impl std::str::FromStr for Side {
+    type Err = String;
+
+    /// Parse "buy" or "sell" (any case)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "buy" => Ok(Self::Buy),
+            "sell" => Ok(Self::Sell),
+            _ => Err(format!("invalid side '{}' (expected buy or sell)", s)),
+        }
+    }
+}
+
 /// Order type
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -53,6 +81,27 @@ pub enum OrderType {
     Market,
 }
 
+/// How long an order stays on the book
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimeInForce {
+    /// Good-til-cancelled: rests on the book until filled or cancelled
+    #[default]
+    #[serde(rename = "GTC")]
+    Gtc,
+    /// Fill-or-kill: fills completely right away or is cancelled
+    #[serde(rename = "FOK")]
+    Fok,
+}
+
+impl TimeInForce {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeInForce::Gtc => "GTC",
+            TimeInForce::Fok => "FOK",
+        }
+    }
+}
+
 /// Order status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -98,6 +147,307 @@ pub struct Orderbook {
     pub neg_risk: Option<bool>,
 }
 
+/// Replace the size resting at `price` and re-sort the side, best price first for
+/// orders taking from it (asks for `Side::Buy`, bids for `Side::Sell`)
+fn set_level(levels: &mut Vec<PriceLevel>, side: Side, price: &str, size: &str) {
+    let Ok(target) = price.parse::<f64>() else {
+        return;
+    };
+    levels.retain(|level| {
+        level
+            .price
+            .parse::<f64>()
+            .is_ok_and(|p| (p - target).abs() > 1e-9)
+    });
+    if size.parse::<f64>().is_ok_and(|size| size > 0.0) {
+        levels.push(PriceLevel {
+            price: price.to_string(),
+            size: size.to_string(),
+        });
+    }
+    sort_levels(levels, side);
+}
+
+/// Replace every level of one side with a snapshot's, dropping emptied levels
+fn replace_levels(
+    levels: &mut Vec<PriceLevel>,
+    side: Side,
+    snapshot: &[crate::websocket::PriceLevel],
+) {
+    *levels = snapshot
+        .iter()
+        .filter(|level| level.size.parse::<f64>().is_ok_and(|size| size > 0.0))
+        .map(|level| PriceLevel {
+            price: level.price.clone(),
+            size: level.size.clone(),
+        })
+        .collect();
+    sort_levels(levels, side);
+}
+
+/// Sort levels best-first: ascending prices for asks (`Buy`), descending for bids
+fn sort_levels(levels: &mut [PriceLevel], side: Side) {
+    levels.sort_by(|a, b| {
+        let a = a.price.parse::<f64>().unwrap_or(0.0);
+        let b = b.price.parse::<f64>().unwrap_or(0.0);
+        let ord = a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal);
+        match side {
+            Side::Buy => ord,
+            Side::Sell => ord.reverse(),
+        }
+    });
+}
+
+/// Estimated result of filling a market order against an orderbook snapshot
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillEstimate {
+    /// Shares filled
+    pub shares: f64,
+    /// Dollars spent (buys) or received (sells)
+    pub amount: f64,
+    /// Volume-weighted average fill price
+    pub avg_price: f64,
+    /// Best price in the book before the fill
+    pub best_price: f64,
+    /// Price of the last (worst) level touched
+    pub worst_price: f64,
+    /// Whether the book had enough depth to fill the whole order
+    pub complete: bool,
+}
+
+/// Top of book and depth around the mid price, parsed from an orderbook snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+pub struct OrderbookSummary {
+    pub best_bid: Option<f64>,
+    pub best_ask: Option<f64>,
+    /// Midpoint of best bid and ask (None if either side is empty)
+    pub mid_price: Option<f64>,
+    /// Best ask minus best bid
+    pub spread: Option<f64>,
+    /// Spread relative to the mid price, in basis points
+    pub spread_bps: Option<f64>,
+    /// Dollar value of bids within the price band (price * size)
+    pub bid_notional: f64,
+    /// Dollar value of asks within the price band (price * size)
+    pub ask_notional: f64,
+}
+
+impl FillEstimate {
+    /// Price impact of the whole order: distance of the average fill from the best
+    /// price (always >= 0, e.g. 0.006 = 0.6¢ worse than top of book)
+    pub fn price_impact(&self) -> f64 {
+        (self.avg_price - self.best_price).abs()
+    }
+
+    /// Price slippage of the worst fill relative to the best price, as a fraction
+    /// (always >= 0, e.g. 0.02 = 2% worse than top of book)
+    pub fn worst_slippage(&self) -> f64 {
+        if self.best_price <= 0.0 {
+            return 0.0;
+        }
+        (self.worst_price - self.best_price).abs() / self.best_price
+    }
+}
+
+impl Orderbook {
+    /// Minimum price increment, or [`DEFAULT_TICK_SIZE`] if missing or invalid
+    pub fn tick(&self) -> f64 {
+        self.tick_size
+            .as_deref()
+            .and_then(|tick| tick.parse::<f64>().ok())
+            .filter(|tick| *tick > 0.0 && *tick < 1.0)
+            .unwrap_or(DEFAULT_TICK_SIZE)
+    }
+
+    /// Price levels parsed to `(price, size)`, best first for the side taking liquidity
+    ///
+    /// Buys take from asks (lowest first), sells take from bids (highest first).
+    /// Unparseable, non-finite and zero-size levels are skipped.
+    pub fn levels_for(&self, side: Side) -> Vec<(f64, f64)> {
+        let raw = match side {
+            Side::Buy => &self.asks,
+            Side::Sell => &self.bids,
+        };
+        let mut levels: Vec<(f64, f64)> = raw
+            .iter()
+            .filter_map(|level| {
+                let price = level.price.parse::<f64>().ok()?;
+                let size = level.size.parse::<f64>().ok()?;
+                (price.is_finite() && size.is_finite() && price > 0.0 && size > 0.0)
+                    .then_some((price, size))
+            })
+            .collect();
+        levels.sort_by(|a, b| {
+            let ord = a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal);
+            match side {
+                Side::Buy => ord,
+                Side::Sell => ord.reverse(),
+            }
+        });
+        levels
+    }
+
+    /// Spread and depth summary
+    ///
+    /// Notional totals include levels within `band` (in price, e.g. 0.05 = 5¢) of the
+    /// mid price, or of the best price when one side is empty. `depth` limits how many
+    /// levels per side are counted, best first.
+    pub fn summary(&self, band: f64, depth: Option<usize>) -> OrderbookSummary {
+        let bids = self.levels_for(Side::Sell);
+        let asks = self.levels_for(Side::Buy);
+        let best_bid = bids.first().map(|&(price, _)| price);
+        let best_ask = asks.first().map(|&(price, _)| price);
+        let (mid_price, spread) = match (best_bid, best_ask) {
+            (Some(bid), Some(ask)) => (Some((bid + ask) / 2.0), Some(ask - bid)),
+            _ => (None, None),
+        };
+        let spread_bps = match (spread, mid_price) {
+            (Some(spread), Some(mid)) if mid > 0.0 => Some(spread / mid * 10_000.0),
+            _ => None,
+        };
+
+        let notional = |levels: &[(f64, f64)], best: Option<f64>| -> f64 {
+            let Some(reference) = mid_price.or(best) else {
+                return 0.0;
+            };
+            levels
+                .iter()
+                .take(depth.unwrap_or(usize::MAX))
+                .filter(|(price, _)| (price - reference).abs() <= band + 1e-9)
+                .map(|(price, size)| price * size)
+                .sum()
+        };
+
+        OrderbookSummary {
+            best_bid,
+            best_ask,
+            mid_price,
+            spread,
+            spread_bps,
+            bid_notional: notional(&bids, best_bid),
+            ask_notional: notional(&asks, best_ask),
+        }
+    }
+
+    /// Apply a WebSocket book message, keeping bids highest-first and asks lowest-first
+    ///
+    /// `Orderbook` messages are full snapshots and replace both sides. `Price` messages
+    /// with a side and size set that one level; a size of zero removes the level.
+    /// Messages for another asset, or without book data, are ignored. Returns whether
+    /// the book was touched.
+    pub fn apply_update(&mut self, msg: &WebSocketMessage) -> bool {
+        if let Some(asset_id) = &self.asset_id
+            && msg.asset_id() != Some(asset_id.as_str())
+        {
+            return false;
+        }
+        match msg {
+            WebSocketMessage::Orderbook(snapshot) => {
+                replace_levels(&mut self.bids, Side::Sell, &snapshot.bids);
+                replace_levels(&mut self.asks, Side::Buy, &snapshot.asks);
+                true
+            },
+            WebSocketMessage::Price(update) => {
+                let (Some(side), Some(size)) = (&update.side, &update.size) else {
+                    return false;
+                };
+                match side.parse::<Side>() {
+                    Ok(Side::Buy) => set_level(&mut self.bids, Side::Sell, &update.price, size),
+                    Ok(Side::Sell) => set_level(&mut self.asks, Side::Buy, &update.price, size),
+                    Err(_) => return false,
+                }
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Simulate a market order worth `amount` dollars by walking the book levels
+    ///
+    /// Buys spend `amount` against the asks; sells take bids until `amount` is received.
+    /// Returns None if the amount is not positive or that side of the book is empty.
+    pub fn simulate_fill(&self, side: Side, amount: f64) -> Option<FillEstimate> {
+        if amount <= 0.0 {
+            return None;
+        }
+        let levels = self.levels_for(side);
+        let best_price = levels.first()?.0;
+
+        let mut remaining = amount;
+        let mut shares = 0.0;
+        let mut worst_price = best_price;
+        for (price, size) in levels {
+            if remaining <= f64::EPSILON {
+                break;
+            }
+            let level_value = price * size;
+            let take = level_value.min(remaining);
+            shares += take / price;
+            remaining -= take;
+            worst_price = price;
+        }
+
+        let filled = amount - remaining.max(0.0);
+        Some(FillEstimate {
+            shares,
+            amount: filled,
+            avg_price: if shares > 0.0 {
+                filled / shares
+            } else {
+                0.0
+            },
+            best_price,
+            worst_price,
+            complete: remaining <= 1e-9,
+        })
+    }
+
+    /// Simulate a limit order for `shares` shares at `limit` by taking only the
+    /// levels that cross it
+    ///
+    /// Buys take asks priced at or below the limit; sells take bids at or above it.
+    /// The part that doesn't cross is left unfilled (`complete` is false).
+    /// Returns None if `shares` is not positive or that side of the book is empty.
+    pub fn simulate_limit_fill(&self, side: Side, limit: f64, shares: f64) -> Option<FillEstimate> {
+        if shares <= 0.0 {
+            return None;
+        }
+        let levels = self.levels_for(side);
+        let best_price = levels.first()?.0;
+        let crosses = |price: f64| match side {
+            Side::Buy => price <= limit + 1e-9,
+            Side::Sell => price >= limit - 1e-9,
+        };
+
+        let mut remaining = shares;
+        let mut amount = 0.0;
+        let mut worst_price = best_price;
+        for (price, size) in levels {
+            if remaining <= 1e-9 || !crosses(price) {
+                break;
+            }
+            let take = size.min(remaining);
+            amount += take * price;
+            remaining -= take;
+            worst_price = price;
+        }
+
+        let filled = shares - remaining.max(0.0);
+        Some(FillEstimate {
+            shares: filled,
+            amount,
+            avg_price: if filled > 0.0 {
+                amount / filled
+            } else {
+                0.0
+            },
+            best_price,
+            worst_price,
+            complete: remaining <= 1e-9,
+        })
+    }
+}
+
 /// Price response from GET /price endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceResponse {
@@ -125,6 +475,23 @@ pub struct PriceHistoryResponse {
     pub history: Vec<PriceHistoryPoint>,
 }
 
+/// High and low price (0-1) over a stretch of price history
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceStats {
+    pub high: f64,
+    pub low: f64,
+}
+
+impl PriceStats {
+    /// Range of the history's prices, or None if it has no points
+    pub fn from_history(history: &PriceHistoryResponse) -> Option<Self> {
+        let prices = history.history.iter().map(|point| point.p);
+        let high = prices.clone().reduce(f64::max)?;
+        let low = prices.reduce(f64::min)?;
+        Some(Self { high, low })
+    }
+}
+
 /// Time interval for price history queries
 #[derive(Debug, Clone, Copy)]
 pub enum PriceInterval {
@@ -177,6 +544,12 @@ pub struct TokenPrices {
 /// Trade information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trade {
+    /// Market (condition ID) the trade belongs to
+    #[serde(default)]
+    pub market: Option<String>,
+    /// Token traded
+    #[serde(default)]
+    pub asset_id: Option<String>,
     pub price: String,
     pub size: String,
     pub timestamp: i64,
@@ -292,6 +665,122 @@ pub struct MarketOrderRequest {
     pub fee_rate_bps: Option<u32>,
 }
 
+/// Short-lived in-memory orderbook cache, keyed by token ID
+///
+/// Clones share the same entries, so one cache can be handed to every client that
+/// refreshes prices.
+#[derive(Debug, Clone)]
+pub struct OrderbookCache {
+    ttl: Duration,
+    entries: Arc<Mutex<HashMap<String, (Instant, Orderbook)>>>,
+}
+
+impl OrderbookCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Cached orderbooks in request order, or None if any token is missing or stale
+    pub fn get_all<'a>(
+        &self,
+        token_ids: impl IntoIterator<Item = &'a str>,
+    ) -> Option<Vec<Orderbook>> {
+        let entries = lock_mutex(&self.entries).ok()?;
+        token_ids
+            .into_iter()
+            .map(|token_id| {
+                entries
+                    .get(token_id)
+                    .filter(|(fetched_at, _)| fetched_at.elapsed() < self.ttl)
+                    .map(|(_, orderbook)| orderbook.clone())
+            })
+            .collect()
+    }
+
+    /// Store orderbooks under their asset IDs (books without one are skipped)
+    pub fn insert(&self, orderbooks: &[Orderbook]) {
+        let Ok(mut entries) = lock_mutex(&self.entries) else {
+            return;
+        };
+        let now = Instant::now();
+        for orderbook in orderbooks {
+            if let Some(asset_id) = &orderbook.asset_id {
+                entries.insert(asset_id.clone(), (now, orderbook.clone()));
+            }
+        }
+    }
+
+    /// Drop every entry (e.g. on a forced refresh)
+    pub fn clear(&self) {
+        if let Ok(mut entries) = lock_mutex(&self.entries) {
+            entries.clear();
+        }
+    }
+}
+
+/// Token-bucket limiter shared by every request a `ClobClient` makes
+///
+/// The bucket holds up to one second of requests, so short bursts go out at once
+/// and longer runs (e.g. one orderbook per market) are spread out. Clones share
+/// the same bucket.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    requests_per_sec: f64,
+    /// Available tokens (negative while callers are queued) and when they were counted
+    ///
+    /// Measured on tokio's clock, so tests can run it paused.
+    bucket: Arc<Mutex<(f64, tokio::time::Instant)>>,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_sec: u32) -> Self {
+        let requests_per_sec = f64::from(requests_per_sec.max(1));
+        Self {
+            requests_per_sec,
+            bucket: Arc::new(Mutex::new((requests_per_sec, tokio::time::Instant::now()))),
+        }
+    }
+
+    pub fn requests_per_sec(&self) -> u32 {
+        self.requests_per_sec as u32
+    }
+
+    /// Take a token, sleeping until one is available
+    pub async fn acquire(&self) {
+        let wait = {
+            let Ok(mut bucket) = lock_mutex(&self.bucket) else {
+                return;
+            };
+            let (tokens, counted_at) = &mut *bucket;
+            let now = tokio::time::Instant::now();
+            *tokens = (*tokens
+                + now.duration_since(*counted_at).as_secs_f64() * self.requests_per_sec)
+                .min(self.requests_per_sec);
+            *counted_at = now;
+            // Reserve the token up front so concurrent callers queue in order
+            *tokens -= 1.0;
+            (*tokens < 0.0).then(|| Duration::from_secs_f64(-*tokens / self.requests_per_sec))
+        };
+        if let Some(wait) = wait {
+            log_debug!("CLOB rate limit reached, waiting {:?}", wait);
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_REQUESTS_PER_SEC)
+    }
+}
+
 /// CLOB REST API client
 pub struct ClobClient {
     client: reqwest::Client,
@@ -300,6 +789,25 @@ pub struct ClobClient {
     passphrase: Option<String>,
     /// Polygon wallet address (required for L2 authentication)
     address: Option<String>,
+    /// Canned responses used instead of the network (offline mode)
+    fixtures: Option<Fixtures>,
+    /// Private key used to sign orders (required for `submit_order`)
+    signer: Option<OrderSigner>,
+    /// Wallet kind of `address`, inferred from the signer when not set
+    signature_type: Option<SignatureType>,
+    /// Recently fetched orderbooks reused by `get_orderbooks`
+    price_cache: Option<OrderbookCache>,
+    /// Awaited before every network request
+    rate_limiter: RateLimiter,
+}
+
+/// Body of a `POST /order` request
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostOrderRequest<'a> {
+    order: &'a SignedOrder,
+    owner: &'a str,
+    order_type: TimeInForce,
 }
 
 impl ClobClient {
@@ -311,6 +819,11 @@ impl ClobClient {
             api_secret: None,
             passphrase: None,
             address: None,
+            fixtures: Fixtures::from_env(),
+            signer: None,
+            signature_type: None,
+            price_cache: None,
+            rate_limiter: RateLimiter::default(),
         }
     }
 
@@ -327,11 +840,17 @@ impl ClobClient {
             api_secret: Some(api_secret),
             passphrase: Some(passphrase),
             address: Some(address),
+            fixtures: Fixtures::from_env(),
+            signer: None,
+            signature_type: None,
+            price_cache: None,
+            rate_limiter: RateLimiter::default(),
         }
     }
 
     /// Create a new CLOB client from environment variables
     /// Requires: api_key, secret, passphrase, address (or poly_address)
+    /// Optional: private_key (or POLY_PRIVATE_KEY) to sign orders
     pub fn from_env() -> Self {
         let address = std::env::var("address")
             .or_else(|_| std::env::var("poly_address"))
@@ -344,12 +863,117 @@ impl ClobClient {
             std::env::var("passphrase"),
             address,
         ) {
-            Self::with_auth(api_key, api_secret, passphrase, addr)
+            let mut client = Self::with_auth(api_key, api_secret, passphrase, addr);
+            if let Ok(private_key) =
+                std::env::var("private_key").or_else(|_| std::env::var("POLY_PRIVATE_KEY"))
+                && let Err(_e) = client.set_private_key(&private_key)
+            {
+                log_debug!("Ignoring private key from environment: {}", _e);
+            }
+            client
         } else {
             Self::new()
         }
     }
 
+    /// Reuse batch orderbooks fetched less than `ttl` ago
+    pub fn with_price_cache(mut self, ttl: Duration) -> Self {
+        self.price_cache = Some(OrderbookCache::new(ttl));
+        self
+    }
+
+    /// Allow at most `requests_per_sec` requests per second (default 10)
+    pub fn with_rate_limit(mut self, requests_per_sec: u32) -> Self {
+        self.rate_limiter = RateLimiter::new(requests_per_sec);
+        self
+    }
+
+    /// Share an existing rate limiter, so several clients stay under one budget
+    pub fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        self.rate_limiter = rate_limiter;
+    }
+
+    /// Share an existing orderbook cache with this client
+    pub fn set_price_cache(&mut self, cache: OrderbookCache) {
+        self.price_cache = Some(cache);
+    }
+
+    /// Drop cached orderbooks so the next request hits the network
+    pub fn clear_price_cache(&self) {
+        if let Some(ref cache) = self.price_cache {
+            cache.clear();
+        }
+    }
+
+    /// Set the private key used to sign orders (hex, with or without `0x`)
+    pub fn set_private_key(&mut self, private_key: &str) -> Result<()> {
+        self.signer = Some(OrderSigner::from_private_key(private_key)?);
+        Ok(())
+    }
+
+    /// Set how the CLOB verifies order signatures for `address`
+    ///
+    /// Without it, orders are signed as [`SignatureType::Eoa`] when `address` is the
+    /// signing key's own address and as [`SignatureType::PolyProxy`] otherwise. Browser
+    /// wallet accounts need [`SignatureType::PolyGnosisSafe`].
+    pub fn set_signature_type(&mut self, signature_type: SignatureType) {
+        self.signature_type = Some(signature_type);
+    }
+
+    /// Signature type used for orders signed by `signer` on behalf of `maker`
+    fn order_signature_type(&self, signer: &OrderSigner, maker: &str) -> Result<SignatureType> {
+        let is_own_wallet = maker.eq_ignore_ascii_case(signer.address());
+        match self.signature_type {
+            Some(SignatureType::Eoa) if !is_own_wallet => {
+                Err(PolymarketError::InvalidData(format!(
+                    "signature_type eoa needs address {} to match the private key",
+                    maker
+                )))
+            },
+            Some(signature_type) => Ok(signature_type),
+            None if is_own_wallet => Ok(SignatureType::Eoa),
+            None => Ok(SignatureType::PolyProxy),
+        }
+    }
+
+    /// Check if the client can sign and submit orders
+    pub fn can_trade(&self) -> bool {
+        self.has_auth() && self.signer.is_some()
+    }
+
+    /// Serve responses from a fixtures directory instead of the network
+    pub fn set_fixtures(&mut self, fixtures: Fixtures) {
+        self.fixtures = Some(fixtures);
+    }
+
+    /// Orderbook for a token from the fixtures directory (empty if not present)
+    fn fixture_orderbook(fixtures: &Fixtures, token_id: &str) -> Result<Orderbook> {
+        let books: Vec<Orderbook> = fixtures.load_or_default("clob/books")?;
+        Ok(books
+            .into_iter()
+            .find(|book| book.asset_id.as_deref() == Some(token_id))
+            .unwrap_or_else(|| Orderbook {
+                bids: Vec::new(),
+                asks: Vec::new(),
+                market: None,
+                asset_id: Some(token_id.to_string()),
+                timestamp: None,
+                hash: None,
+                min_order_size: None,
+                tick_size: None,
+                neg_risk: None,
+            }))
+    }
+
+    /// Buy/sell prices for a token from the fixtures directory
+    fn fixture_prices(fixtures: &Fixtures, token_id: &str) -> Result<TokenPrices> {
+        let mut prices: std::collections::HashMap<String, TokenPrices> =
+            fixtures.load_or_default("clob/prices")?;
+        prices.remove(token_id).ok_or_else(|| {
+            PolymarketError::InvalidData(format!("No price fixture for token {}", token_id))
+        })
+    }
+
     /// Check if the client has authentication credentials
     pub fn has_auth(&self) -> bool {
         self.api_key.is_some()
@@ -393,8 +1017,21 @@ impl ClobClient {
 
     /// Get orderbook for a specific market (condition ID)
     pub async fn get_orderbook(&self, condition_id: &str) -> Result<Orderbook> {
+        if let Some(ref fixtures) = self.fixtures {
+            let books: Vec<Orderbook> = fixtures.load_or_default("clob/books")?;
+            return books
+                .into_iter()
+                .find(|book| book.market.as_deref() == Some(condition_id))
+                .ok_or_else(|| {
+                    PolymarketError::InvalidData(format!(
+                        "No orderbook fixture for market {}",
+                        condition_id
+                    ))
+                });
+        }
         let url = format!("{}/book", CLOB_API_BASE);
         let params = [("market", condition_id)];
+        self.rate_limiter.acquire().await;
         let orderbook: Orderbook = self
             .client
             .get(&url)
@@ -407,29 +1044,30 @@ impl ClobClient {
     }
 
     /// Get recent trades for a specific market (condition ID)
-    pub async fn get_trades(&self, condition_id: &str, limit: Option<usize>) -> Result<Vec<Trade>> {
-        let url = format!("{}/trades", CLOB_API_BASE);
-        let mut params = vec![("market", condition_id.to_string())];
-        if let Some(limit) = limit {
-            params.push(("limit", limit.to_string()));
-        }
-        let trades: Vec<Trade> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(trades)
+    ///
+    /// The CLOB `/trades` endpoint has no offset, so `offset` trades are fetched on
+    /// top of `limit` and skipped client-side.
+    pub async fn get_trades(
+        &self,
+        condition_id: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<Trade>> {
+        self.get_trades_page(("market", condition_id), limit, offset.unwrap_or(0))
+            .await
     }
 
     /// Get orderbook for a specific token ID (clob_token_id from Gamma API)
     pub async fn get_orderbook_by_asset(&self, token_id: &str) -> Result<Orderbook> {
+        if let Some(ref fixtures) = self.fixtures {
+            return Self::fixture_orderbook(fixtures, token_id);
+        }
+
         let _url = format!("{}/book?token_id={}", CLOB_API_BASE, token_id);
         log_info!("GET {}", _url);
 
         let params = [("token_id", token_id)];
+        self.rate_limiter.acquire().await;
         let response = self
             .client
             .get(format!("{}/book", CLOB_API_BASE))
@@ -459,16 +1097,7 @@ impl ClobClient {
             });
         }
 
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
-        }
+        let response = check_status(response).await?;
 
         let response_text = response.text().await?;
         log_info!(
@@ -516,25 +1145,71 @@ impl ClobClient {
         }
     }
 
+    /// Get the spread and depth summary for a token ID
+    ///
+    /// See [`Orderbook::summary`] for `band` and `depth`.
+    pub async fn get_orderbook_summary(
+        &self,
+        token_id: &str,
+        band: f64,
+        depth: Option<usize>,
+    ) -> Result<OrderbookSummary> {
+        Ok(self
+            .get_orderbook_by_asset(token_id)
+            .await?
+            .summary(band, depth))
+    }
+
     /// Get recent trades for a specific asset ID
+    ///
+    /// `offset` is applied client-side, as in [`Self::get_trades`].
     pub async fn get_trades_by_asset(
         &self,
         asset_id: &str,
         limit: Option<usize>,
+        offset: Option<usize>,
     ) -> Result<Vec<Trade>> {
-        let url = format!("{}/trades", CLOB_API_BASE);
-        let mut params = vec![("asset_id", asset_id.to_string())];
-        if let Some(limit) = limit {
-            params.push(("limit", limit.to_string()));
-        }
-        let trades: Vec<Trade> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        self.get_trades_page(("asset_id", asset_id), limit, offset.unwrap_or(0))
+            .await
+    }
+
+    /// Fetch `offset + limit` trades matching `filter` and drop the first `offset`
+    async fn get_trades_page(
+        &self,
+        filter: (&str, &str),
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<Vec<Trade>> {
+        let mut trades: Vec<Trade> = if let Some(ref fixtures) = self.fixtures {
+            let trades: Vec<Trade> = fixtures.load_or_default("clob/trades")?;
+            trades
+                .into_iter()
+                .filter(|trade| {
+                    let key = if filter.0 == "market" {
+                        &trade.market
+                    } else {
+                        &trade.asset_id
+                    };
+                    key.as_deref() == Some(filter.1)
+                })
+                .collect()
+        } else {
+            let url = format!("{}/trades", CLOB_API_BASE);
+            let mut params = vec![(filter.0, filter.1.to_string())];
+            if let Some(limit) = limit {
+                params.push(("limit", (limit + offset).to_string()));
+            }
+            self.rate_limiter.acquire().await;
+            self.client
+                .get(&url)
+                .query(&params)
+                .send()
+                .await?
+                .json()
+                .await?
+        };
+        trades.drain(..offset.min(trades.len()));
+        trades.truncate(limit.unwrap_or(usize::MAX));
         Ok(trades)
     }
 
@@ -565,20 +1240,10 @@ impl ClobClient {
             })?;
 
         let url = format!("{}{}", CLOB_API_BASE, request_path);
+        self.rate_limiter.acquire().await;
         let response = self.client.get(&url).headers(headers).send().await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            log_info!("GET {} -> error: {} - {}", request_path, status, error_text);
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
-        }
+        let response = check_status(response).await?;
 
         let trades: Vec<Trade> = response.json().await?;
         log_info!("GET {} -> {} trades", request_path, trades.len());
@@ -616,19 +1281,10 @@ impl ClobClient {
             })?;
 
         let url = format!("{}{}", CLOB_API_BASE, request_path);
+        self.rate_limiter.acquire().await;
         let response = self.client.get(&url).headers(headers).send().await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
-        }
+        let response = check_status(response).await?;
 
         let orders: Vec<OpenOrder> = response.json().await?;
         Ok(orders)
@@ -647,19 +1303,10 @@ impl ClobClient {
             })?;
 
         let url = format!("{}{}", CLOB_API_BASE, request_path);
+        self.rate_limiter.acquire().await;
         let response = self.client.get(&url).headers(headers).send().await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
-        }
+        let response = check_status(response).await?;
 
         let order: OpenOrder = response.json().await?;
         Ok(order)
@@ -681,20 +1328,15 @@ impl ClobClient {
                 )
             })?;
 
+        if let Some(ref fixtures) = self.fixtures {
+            return fixtures.load_required("clob/balance-allowance");
+        }
+
         let url = format!("{}{}", CLOB_API_BASE, request_path);
+        self.rate_limiter.acquire().await;
         let response = self.client.get(&url).headers(headers).send().await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
-        }
+        let response = check_status(response).await?;
 
         let balance: BalanceAllowance = response.json().await?;
         Ok(balance)
@@ -702,6 +1344,9 @@ impl ClobClient {
 
     /// Cancel a single order (requires authentication)
     pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        if self.fixtures.is_some() {
+            return Err(offline_write_error("cancel orders"));
+        }
         let request_path = format!("/orders/{}", order_id);
 
         let headers = self
@@ -713,19 +1358,10 @@ impl ClobClient {
             })?;
 
         let url = format!("{}{}", CLOB_API_BASE, request_path);
+        self.rate_limiter.acquire().await;
         let response = self.client.delete(&url).headers(headers).send().await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
-        }
+        let response = check_status(response).await?;
 
         let result: CancelOrdersResponse = response.json().await?;
         Ok(result)
@@ -733,6 +1369,9 @@ impl ClobClient {
 
     /// Cancel all open orders (requires authentication)
     pub async fn cancel_all_orders(&self) -> Result<CancelOrdersResponse> {
+        if self.fixtures.is_some() {
+            return Err(offline_write_error("cancel orders"));
+        }
         let request_path = "/orders";
 
         let headers = self
@@ -744,24 +1383,91 @@ impl ClobClient {
             })?;
 
         let url = format!("{}{}", CLOB_API_BASE, request_path);
+        self.rate_limiter.acquire().await;
         let response = self.client.delete(&url).headers(headers).send().await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
-        }
+        let response = check_status(response).await?;
 
         let result: CancelOrdersResponse = response.json().await?;
         Ok(result)
     }
 
+    /// Sign and post an order for `size` shares of `token_id` at `price` (requires
+    /// authentication and a private key)
+    ///
+    /// The token's orderbook is fetched first for its tick size and exchange contract.
+    /// Market orders are FOK orders priced at the worst acceptable fill.
+    pub async fn submit_order(
+        &self,
+        token_id: &str,
+        side: Side,
+        price: f64,
+        size: f64,
+        time_in_force: TimeInForce,
+    ) -> Result<OrderResponse> {
+        if self.fixtures.is_some() {
+            return Err(offline_write_error("place orders"));
+        }
+        let (Some(signer), Some(api_key), Some(maker)) =
+            (&self.signer, &self.api_key, &self.address)
+        else {
+            return Err(PolymarketError::InvalidData(
+                "Missing private key or authentication credentials".to_string(),
+            ));
+        };
+
+        let signature_type = self.order_signature_type(signer, maker)?;
+        let book = self.get_orderbook_by_asset(token_id).await?;
+        let tick_size = book.tick();
+        let order = signer.sign_order(
+            maker,
+            signature_type,
+            token_id,
+            side,
+            price,
+            size,
+            tick_size,
+            book.neg_risk.unwrap_or(false),
+        )?;
+
+        let body = serde_json::to_string(&PostOrderRequest {
+            order: &order,
+            owner: api_key,
+            order_type: time_in_force,
+        })?;
+        let request_path = "/order";
+        let headers = self
+            .create_l2_headers("POST", request_path, Some(&body))
+            .ok_or_else(|| {
+                PolymarketError::InvalidData("Missing authentication credentials".to_string())
+            })?;
+
+        let url = format!("{}{}", CLOB_API_BASE, request_path);
+        log_info!("POST {}", url);
+        self.rate_limiter.acquire().await;
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers)
+            .header("Content-Type", "application/json")
+            .body(body)
+            .send()
+            .await?;
+
+        let response = check_status(response).await?;
+
+        let result: OrderResponse = response.json().await?;
+        if !result.success {
+            return Err(PolymarketError::InvalidData(
+                result
+                    .error_msg
+                    .filter(|msg| !msg.is_empty())
+                    .unwrap_or_else(|| "Order rejected".to_string()),
+            ));
+        }
+        Ok(result)
+    }
+
     /// Get the wallet address used for authentication
     pub fn get_address(&self) -> Option<&str> {
         self.address.as_deref()
@@ -772,12 +1478,38 @@ impl ClobClient {
         self.api_key.as_deref()
     }
 
+    /// Check API health (`GET /` answers "OK")
+    pub async fn get_status(&self) -> Result<String> {
+        if self.fixtures.is_some() {
+            return Ok("OK".to_string());
+        }
+        let url = format!("{}/", CLOB_API_BASE);
+
+        self.rate_limiter.acquire().await;
+        let response = self.client.get(&url).send().await?;
+
+        let response = check_status(response).await?;
+
+        let status = response.text().await?;
+        Ok(status.trim().trim_matches('"').to_string())
+    }
+
     /// Get market price for a specific token and side
     ///
     /// # Arguments
     /// * `token_id` - The unique identifier for the token
     /// * `side` - The side of the market (BUY or SELL)
     pub async fn get_price(&self, token_id: &str, side: Side) -> Result<PriceResponse> {
+        if let Some(ref fixtures) = self.fixtures {
+            let prices = Self::fixture_prices(fixtures, token_id)?;
+            let price = match side {
+                Side::Buy => prices.buy,
+                Side::Sell => prices.sell,
+            };
+            return price.map(|price| PriceResponse { price }).ok_or_else(|| {
+                PolymarketError::InvalidData(format!("No price fixture for token {}", token_id))
+            });
+        }
         let url = format!("{}/price", CLOB_API_BASE);
         let side_str = match side {
             Side::Buy => "BUY",
@@ -785,15 +1517,10 @@ impl ClobClient {
         };
         let params = [("token_id", token_id), ("side", side_str)];
 
+        self.rate_limiter.acquire().await;
         let response = self.client.get(&url).query(&params).send().await?;
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
-        }
+        let response = check_status(response).await?;
 
         let price: PriceResponse = response.json().await?;
         Ok(price)
@@ -806,18 +1533,26 @@ impl ClobClient {
     /// # Arguments
     /// * `token_id` - The unique identifier for the token
     pub async fn get_midpoint(&self, token_id: &str) -> Result<MidpointResponse> {
+        if let Some(ref fixtures) = self.fixtures {
+            let prices = Self::fixture_prices(fixtures, token_id)?;
+            let parse = |p: Option<String>| p.and_then(|p| p.parse::<f64>().ok());
+            return match (parse(prices.buy), parse(prices.sell)) {
+                (Some(buy), Some(sell)) => Ok(MidpointResponse {
+                    mid: format!("{}", (buy + sell) / 2.0),
+                }),
+                _ => Err(PolymarketError::InvalidData(format!(
+                    "No price fixture for token {}",
+                    token_id
+                ))),
+            };
+        }
         let url = format!("{}/midpoint", CLOB_API_BASE);
         let params = [("token_id", token_id)];
 
+        self.rate_limiter.acquire().await;
         let response = self.client.get(&url).query(&params).send().await?;
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
-        }
+        let response = check_status(response).await?;
 
         let midpoint: MidpointResponse = response.json().await?;
         Ok(midpoint)
@@ -839,6 +1574,13 @@ impl ClobClient {
         interval: Option<PriceInterval>,
         fidelity: Option<u32>,
     ) -> Result<PriceHistoryResponse> {
+        if let Some(ref fixtures) = self.fixtures {
+            return Ok(fixtures
+                .load("clob/prices-history")?
+                .unwrap_or(PriceHistoryResponse {
+                    history: Vec::new(),
+                }));
+        }
         let url = format!("{}/prices-history", CLOB_API_BASE);
         let mut params = vec![("market", token_id.to_string())];
 
@@ -855,20 +1597,25 @@ impl ClobClient {
             params.push(("fidelity", fidelity.to_string()));
         }
 
+        self.rate_limiter.acquire().await;
         let response = self.client.get(&url).query(&params).send().await?;
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
-        }
+        let response = check_status(response).await?;
 
         let history: PriceHistoryResponse = response.json().await?;
         Ok(history)
     }
 
+    /// 24h high and low of a token, from hourly price history
+    ///
+    /// Returns None when the token has no history (e.g. a brand new market).
+    pub async fn get_price_stats_24h(&self, token_id: &str) -> Result<Option<PriceStats>> {
+        let history = self
+            .get_prices_history(token_id, None, None, Some(PriceInterval::OneDay), Some(60))
+            .await?;
+        Ok(PriceStats::from_history(&history))
+    }
+
     /// Get bid-ask spreads for multiple tokens
     ///
     /// # Arguments
@@ -879,15 +1626,10 @@ impl ClobClient {
     ) -> Result<std::collections::HashMap<String, String>> {
         let url = format!("{}/spreads", CLOB_API_BASE);
 
+        self.rate_limiter.acquire().await;
         let response = self.client.post(&url).json(&requests).send().await?;
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
-        }
+        let response = check_status(response).await?;
 
         let spreads: std::collections::HashMap<String, String> = response.json().await?;
         Ok(spreads)
@@ -895,22 +1637,35 @@ impl ClobClient {
 
     /// Get multiple orderbooks at once
     ///
+    /// With a price cache, the network is skipped when every requested book is fresh.
+    ///
     /// # Arguments
     /// * `requests` - Array of batch token requests (max 500)
     pub async fn get_orderbooks(&self, requests: Vec<BatchTokenRequest>) -> Result<Vec<Orderbook>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return requests
+                .iter()
+                .map(|request| Self::fixture_orderbook(fixtures, &request.token_id))
+                .collect();
+        }
+        if let Some(ref cache) = self.price_cache
+            && let Some(orderbooks) =
+                cache.get_all(requests.iter().map(|request| request.token_id.as_str()))
+        {
+            log_debug!("Serving {} orderbooks from cache", orderbooks.len());
+            return Ok(orderbooks);
+        }
         let url = format!("{}/books", CLOB_API_BASE);
 
+        self.rate_limiter.acquire().await;
         let response = self.client.post(&url).json(&requests).send().await?;
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
-        }
+        let response = check_status(response).await?;
 
         let orderbooks: Vec<Orderbook> = response.json().await?;
+        if let Some(ref cache) = self.price_cache {
+            cache.insert(&orderbooks);
+        }
         Ok(orderbooks)
     }
 
@@ -925,17 +1680,18 @@ impl ClobClient {
         &self,
         requests: Vec<BatchTokenRequest>,
     ) -> Result<std::collections::HashMap<String, TokenPrices>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let mut prices: std::collections::HashMap<String, TokenPrices> =
+                fixtures.load_or_default("clob/prices")?;
+            prices.retain(|token_id, _| requests.iter().any(|r| &r.token_id == token_id));
+            return Ok(prices);
+        }
         let url = format!("{}/prices", CLOB_API_BASE);
 
+        self.rate_limiter.acquire().await;
         let response = self.client.post(&url).json(&requests).send().await?;
 
-        if !response.status().is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(crate::error::PolymarketError::InvalidData(error_text));
-        }
+        let response = check_status(response).await?;
 
         let prices: std::collections::HashMap<String, TokenPrices> = response.json().await?;
         Ok(prices)
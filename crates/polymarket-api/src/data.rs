@@ -4,7 +4,10 @@
 //! which allows querying user positions, trade history, and portfolio data.
 
 use {
-    crate::error::Result,
+    crate::{
+        error::{Result, check_status},
+        fixtures::Fixtures,
+    },
     serde::{Deserialize, Serialize},
 };
 
@@ -97,6 +100,67 @@ pub struct Portfolio {
     pub positions: Vec<Position>,
 }
 
+/// A wallet holding one outcome token of a market
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Holder {
+    #[serde(rename = "proxyWallet")]
+    pub address: String,
+    /// Token ID of the outcome held
+    pub asset: String,
+    #[serde(rename = "outcomeIndex", default)]
+    pub outcome_index: i32,
+    /// Shares held
+    #[serde(rename = "amount", default)]
+    pub size: f64,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub pseudonym: Option<String>,
+}
+
+impl Holder {
+    /// Profile name, then pseudonym, then the shortened wallet address
+    pub fn display_name(&self) -> String {
+        [&self.name, &self.pseudonym]
+            .into_iter()
+            .flatten()
+            .find(|name| !name.is_empty())
+            .cloned()
+            .unwrap_or_else(|| {
+                if self.address.len() >= 10 {
+                    format!(
+                        "{}...{}",
+                        &self.address[..6],
+                        &self.address[self.address.len() - 4..]
+                    )
+                } else {
+                    self.address.clone()
+                }
+            })
+    }
+
+    /// Outcome name from the market's outcome list (e.g. "Yes")
+    pub fn outcome<'a>(&self, outcomes: &'a [String]) -> Option<&'a str> {
+        usize::try_from(self.outcome_index)
+            .ok()
+            .and_then(|idx| outcomes.get(idx))
+            .map(String::as_str)
+    }
+
+    /// Dollar value of the position at `price` per share
+    pub fn value(&self, price: f64) -> f64 {
+        self.size * price
+    }
+}
+
+/// Top holders of one outcome token, as returned by `/holders`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenHolders {
+    pub token: String,
+    #[serde(default)]
+    pub holders: Vec<Holder>,
+}
+
 /// Activity type enum
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
@@ -221,6 +285,8 @@ impl TradeSide {
 /// Data API client
 pub struct DataClient {
     client: reqwest::Client,
+    /// Canned responses used instead of the network (offline mode)
+    fixtures: Option<Fixtures>,
 }
 
 /// Data API status response
@@ -234,13 +300,48 @@ impl DataClient {
     pub fn new() -> Self {
         Self {
             client: reqwest::Client::new(),
+            fixtures: Fixtures::from_env(),
         }
     }
 
+    /// Serve responses from a fixtures directory instead of the network
+    pub fn set_fixtures(&mut self, fixtures: Fixtures) {
+        self.fixtures = Some(fixtures);
+    }
+
+    /// Send a GET and decode its JSON body, turning failed statuses into errors
+    async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T> {
+        Ok(check_status(request.send().await?).await?.json().await?)
+    }
+
+    /// Trades from the fixtures directory matching `filter`, paginated like the API
+    fn fixture_trades(
+        fixtures: &Fixtures,
+        filter: impl Fn(&DataTrade) -> bool,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<DataTrade>> {
+        let trades: Vec<DataTrade> = fixtures.load_or_default("data/trades")?;
+        Ok(trades
+            .into_iter()
+            .filter(|trade| filter(trade))
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
     /// Check Data API health status
     pub async fn get_status(&self) -> Result<DataStatusResponse> {
+        if self.fixtures.is_some() {
+            return Ok(DataStatusResponse {
+                data: "OK".to_string(),
+            });
+        }
         let url = DATA_API_BASE;
-        let status: DataStatusResponse = self.client.get(url).send().await?.json().await?;
+        let status: DataStatusResponse = self.get_json(self.client.get(url)).await?;
         Ok(status)
     }
 
@@ -253,6 +354,14 @@ impl DataClient {
         filter_type: Option<&str>,
         filter_amount: Option<f64>,
     ) -> Result<Vec<DataTrade>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return Self::fixture_trades(
+                fixtures,
+                |_| true,
+                limit.unwrap_or(10),
+                offset.unwrap_or(0),
+            );
+        }
         let url = format!("{}/trades", DATA_API_BASE);
         let mut params = vec![
             ("eventId", event_id.to_string()),
@@ -267,14 +376,7 @@ impl DataClient {
             params.push(("filterAmount", filter_amount.to_string()));
         }
 
-        let trades: Vec<DataTrade> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let trades: Vec<DataTrade> = self.get_json(self.client.get(&url).query(&params)).await?;
         Ok(trades)
     }
 
@@ -285,6 +387,14 @@ impl DataClient {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<DataTrade>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return Self::fixture_trades(
+                fixtures,
+                |trade| trade.event_slug == event_slug,
+                limit.unwrap_or(10),
+                offset.unwrap_or(0),
+            );
+        }
         let url = format!("{}/trades", DATA_API_BASE);
         let params = vec![
             ("eventSlug", event_slug.to_string()),
@@ -292,14 +402,7 @@ impl DataClient {
             ("offset", offset.unwrap_or(0).to_string()),
         ];
 
-        let trades: Vec<DataTrade> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let trades: Vec<DataTrade> = self.get_json(self.client.get(&url).query(&params)).await?;
         Ok(trades)
     }
 
@@ -310,6 +413,14 @@ impl DataClient {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<DataTrade>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return Self::fixture_trades(
+                fixtures,
+                |trade| trade.condition_id == condition_id,
+                limit.unwrap_or(10),
+                offset.unwrap_or(0),
+            );
+        }
         let url = format!("{}/trades", DATA_API_BASE);
         let params = vec![
             ("conditionId", condition_id.to_string()),
@@ -317,44 +428,89 @@ impl DataClient {
             ("offset", offset.unwrap_or(0).to_string()),
         ];
 
-        let trades: Vec<DataTrade> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let trades: Vec<DataTrade> = self.get_json(self.client.get(&url).query(&params)).await?;
+        Ok(trades)
+    }
+
+    /// Get trades made by a wallet address, newest first
+    pub async fn get_trades_by_user(
+        &self,
+        user_address: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<Vec<DataTrade>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return Self::fixture_trades(
+                fixtures,
+                |trade| trade.proxy_wallet.eq_ignore_ascii_case(user_address),
+                limit.unwrap_or(10),
+                offset.unwrap_or(0),
+            );
+        }
+        let url = format!("{}/trades", DATA_API_BASE);
+        let params = vec![
+            ("user", user_address.to_string()),
+            ("limit", limit.unwrap_or(10).to_string()),
+            ("offset", offset.unwrap_or(0).to_string()),
+        ];
+
+        let trades: Vec<DataTrade> = self.get_json(self.client.get(&url).query(&params)).await?;
         Ok(trades)
     }
 
     /// Get user positions (requires authentication)
     pub async fn get_positions(&self, user_address: &str) -> Result<Vec<Position>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return fixtures.load_or_default("data/positions");
+        }
         let url = format!("{}/positions", DATA_API_BASE);
         let params = [("user", user_address)];
-        let positions: Vec<Position> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let positions: Vec<Position> = self.get_json(self.client.get(&url).query(&params)).await?;
+        Ok(positions)
+    }
+
+    /// Get positions the user has fully exited (sold or redeemed)
+    pub async fn get_closed_positions(&self, user_address: &str) -> Result<Vec<Position>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return fixtures.load_or_default("data/closed-positions");
+        }
+        let url = format!("{}/closed-positions", DATA_API_BASE);
+        let params = [("user", user_address)];
+        let positions: Vec<Position> = self.get_json(self.client.get(&url).query(&params)).await?;
         Ok(positions)
     }
 
+    /// Get the biggest holders of a market (condition ID), largest position first
+    ///
+    /// `limit` applies per outcome token, so a Yes/No market returns up to twice
+    /// as many holders. Markets without holder data return an empty list.
+    pub async fn get_holders(&self, condition_id: &str, limit: usize) -> Result<Vec<Holder>> {
+        let groups: Vec<TokenHolders> = if let Some(ref fixtures) = self.fixtures {
+            fixtures.load_or_default("data/holders")?
+        } else {
+            let url = format!("{}/holders", DATA_API_BASE);
+            let params = [
+                ("market", condition_id.to_string()),
+                ("limit", limit.to_string()),
+            ];
+            self.get_json(self.client.get(&url).query(&params)).await?
+        };
+        let mut holders: Vec<Holder> = groups
+            .into_iter()
+            .flat_map(|group| group.holders.into_iter().take(limit))
+            .collect();
+        holders.sort_by(|a, b| b.size.total_cmp(&a.size));
+        Ok(holders)
+    }
+
     /// Get portfolio for a user (requires authentication)
     pub async fn get_portfolio(&self, user_address: &str) -> Result<Portfolio> {
+        if let Some(ref fixtures) = self.fixtures {
+            return fixtures.load_required("data/portfolio");
+        }
         let url = format!("{}/portfolio", DATA_API_BASE);
         let params = [("user", user_address)];
-        let portfolio: Portfolio = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let portfolio: Portfolio = self.get_json(self.client.get(&url).query(&params)).await?;
         Ok(portfolio)
     }
 
@@ -387,6 +543,14 @@ impl DataClient {
         sort_direction: Option<SortDirection>,
         side: Option<TradeSide>,
     ) -> Result<Vec<Activity>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let activities: Vec<Activity> = fixtures.load_or_default("data/activity")?;
+            return Ok(activities
+                .into_iter()
+                .skip(offset.unwrap_or(0))
+                .take(limit.unwrap_or(100))
+                .collect());
+        }
         let url = format!("{}/activity", DATA_API_BASE);
         let mut params = vec![
             ("user", user_address.to_string()),
@@ -424,14 +588,7 @@ impl DataClient {
             params.push(("side", side.as_str().to_string()));
         }
 
-        let activities: Vec<Activity> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let activities: Vec<Activity> = self.get_json(self.client.get(&url).query(&params)).await?;
         Ok(activities)
     }
 
@@ -460,6 +617,18 @@ impl DataClient {
         filter_amount: Option<f64>,
         side: Option<TradeSide>,
     ) -> Result<Vec<DataTrade>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return Self::fixture_trades(
+                fixtures,
+                |trade| {
+                    user_address.is_none_or(|user| trade.proxy_wallet.eq_ignore_ascii_case(user))
+                        && market.is_none_or(|m| m.split(',').any(|id| id == trade.condition_id))
+                        && side.is_none_or(|side| trade.side == side.as_str())
+                },
+                limit.unwrap_or(100),
+                offset.unwrap_or(0),
+            );
+        }
         let url = format!("{}/trades", DATA_API_BASE);
         let mut params = vec![
             ("limit", limit.unwrap_or(100).to_string()),
@@ -488,14 +657,7 @@ impl DataClient {
             params.push(("side", side.as_str().to_string()));
         }
 
-        let trades: Vec<DataTrade> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let trades: Vec<DataTrade> = self.get_json(self.client.get(&url).query(&params)).await?;
         Ok(trades)
     }
 
@@ -522,6 +684,15 @@ impl DataClient {
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<Position>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let positions: Vec<Position> = fixtures.load_or_default("data/positions")?;
+            return Ok(positions
+                .into_iter()
+                .filter(|p| market.is_none_or(|m| m.split(',').any(|id| id == p.condition_id)))
+                .skip(offset.unwrap_or(0))
+                .take(limit.unwrap_or(100))
+                .collect());
+        }
         let url = format!("{}/positions", DATA_API_BASE);
         let mut params = vec![
             ("user", user_address.to_string()),
@@ -545,14 +716,7 @@ impl DataClient {
             params.push(("mergeable", mergeable.to_string()));
         }
 
-        let positions: Vec<Position> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let positions: Vec<Position> = self.get_json(self.client.get(&url).query(&params)).await?;
         Ok(positions)
     }
 }
@@ -10,7 +10,14 @@ use {
 
 pub struct MarketUpdateFormatter;
 
-pub struct RTDSFormatter;
+/// Formats RTDS trade activity, labelling traders by name and pseudonym
+///
+/// `with_address` adds a line with the trader's proxy wallet, shortened and in full,
+/// to correlate activity with on-chain addresses.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RTDSFormatter {
+    pub show_address: bool,
+}
 
 impl MarketUpdateFormatter {
     pub fn format_message(msg: &WebSocketMessage, market_info: Option<&MarketInfo>) -> String {
@@ -164,7 +171,18 @@ impl MarketUpdateFormatter {
 }
 
 impl RTDSFormatter {
+    /// Also show the trader's wallet address
+    pub fn with_address(mut self, show_address: bool) -> Self {
+        self.show_address = show_address;
+        self
+    }
+
+    /// Format with the default options (pseudonym only)
     pub fn format_message(msg: &RTDSMessage) -> String {
+        Self::default().format(msg)
+    }
+
+    pub fn format(&self, msg: &RTDSMessage) -> String {
         let timestamp = DateTime::from_timestamp(msg.payload.timestamp, 0)
             .map(|dt| dt.format("%H:%M:%S").to_string())
             .unwrap_or_else(|| "now".to_string());
@@ -187,7 +205,7 @@ impl RTDSFormatter {
         // Calculate total value in dollars
         let total_value = msg.payload.price * msg.payload.size;
 
-        format!(
+        let mut formatted = format!(
             "\n{} {} {} {} @ ${:.4} ({} shares, ${:.2}) - {} - {}\n  User: {} ({})\n",
             "💸 TRADE".bright_yellow().bold(),
             timestamp.dimmed(),
@@ -200,6 +218,23 @@ impl RTDSFormatter {
             msg.payload.event_slug.dimmed(),
             msg.payload.name.bright_white(),
             msg.payload.pseudonym.dimmed()
-        )
+        );
+        if self.show_address && !msg.payload.proxy_wallet.is_empty() {
+            formatted.push_str(&format!(
+                "  Wallet: {} ({})\n",
+                short_address(&msg.payload.proxy_wallet).bright_white(),
+                msg.payload.proxy_wallet.dimmed()
+            ));
+        }
+        formatted
+    }
+}
+
+/// Shorten a wallet address to its first 6 and last 4 characters (0x1234...abcd)
+pub fn short_address(address: &str) -> String {
+    if address.len() >= 10 {
+        format!("{}...{}", &address[..6], &address[address.len() - 4..])
+    } else {
+        address.to_string()
     }
 }
@@ -1,4 +1,7 @@
-use thiserror::Error;
+use {std::time::Duration, thiserror::Error};
+
+/// Longest response body kept in `PolymarketError::Http`
+const MAX_ERROR_BODY_LEN: usize = 200;
 
 #[derive(Debug, Error)]
 pub enum PolymarketError {
@@ -8,8 +11,24 @@ pub enum PolymarketError {
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
+    /// The request could not be sent or the connection failed
     #[error("HTTP request error: {0}")]
-    Http(#[from] reqwest::Error),
+    Network(reqwest::Error),
+
+    /// The server answered with a non-2xx status (other than 429)
+    #[error("{}", http_message(*.status, .body))]
+    Http { status: u16, body: String },
+
+    #[error("Request timed out")]
+    Timeout,
+
+    /// 429, with the server's `Retry-After` if it sent one
+    #[error("{}", rate_limited_message(*.retry_after))]
+    RateLimited { retry_after: Option<Duration> },
+
+    /// The response body did not match the expected type
+    #[error("Unexpected response: {0}")]
+    Deserialize(String),
 
     #[error("WebSocket error: {0}")]
     WebSocket(String),
@@ -18,6 +37,99 @@ pub enum PolymarketError {
     InvalidData(String),
 }
 
+impl PolymarketError {
+    /// HTTP status of the response that caused the error, if there was one
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Http { status, .. } => Some(*status),
+            Self::RateLimited { .. } => Some(429),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+    }
+
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::RateLimited { .. })
+    }
+
+    /// Whether the same request may succeed later (timeouts, 429s and 5xx)
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Timeout | Self::RateLimited { .. } => true,
+            Self::Network(e) => e.is_connect(),
+            Self::Http { status, .. } => (500..600).contains(status),
+            _ => false,
+        }
+    }
+}
+
+impl From<reqwest::Error> for PolymarketError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            Self::Timeout
+        } else if e.is_decode() {
+            Self::Deserialize(e.to_string())
+        } else {
+            Self::Network(e)
+        }
+    }
+}
+
+fn http_message(status: u16, body: &str) -> String {
+    let label = match status {
+        401 | 403 => "Unauthorized",
+        404 => "Not found",
+        500..=599 => "Server error",
+        _ => "Request failed",
+    };
+    if body.is_empty() {
+        format!("{} (HTTP {})", label, status)
+    } else {
+        format!("{} (HTTP {}): {}", label, status, body)
+    }
+}
+
+fn rate_limited_message(retry_after: Option<Duration>) -> String {
+    match retry_after {
+        Some(delay) => format!("Rate limited (retry after {}s)", delay.as_secs()),
+        None => "Rate limited".to_string(),
+    }
+}
+
+/// `Retry-After` of a response, when given in seconds
+pub(crate) fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok()?.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Pass 2xx responses through and turn anything else into `RateLimited` or `Http`
+pub(crate) async fn check_status(response: reqwest::Response) -> Result<reqwest::Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(PolymarketError::RateLimited {
+            retry_after: retry_after(&response),
+        });
+    }
+    let mut body = response.text().await.unwrap_or_default().trim().to_string();
+    if let Some((idx, _)) = body.char_indices().nth(MAX_ERROR_BODY_LEN) {
+        body.truncate(idx);
+        body.push('…');
+    }
+    Err(PolymarketError::Http {
+        status: status.as_u16(),
+        body,
+    })
+}
+
 pub type Result<T> = std::result::Result<T, PolymarketError>;
 
 /// Helper function to lock a Mutex and convert poison errors
@@ -0,0 +1,116 @@
+//! Offline fixture mode
+//!
+//! When a fixtures directory is configured, the Gamma, CLOB and Data clients read
+//! canned JSON responses from it instead of hitting the network. This enables offline
+//! development, deterministic demos and screenshot generation.
+//!
+//! Fixture mode is enabled with [`set_fixtures_dir`] or the `POLYMARKET_FIXTURES`
+//! environment variable. Files are laid out per API:
+//!
+//! ```text
+//! <dir>/gamma/events.json      Vec<Event> (trending, breaking, search, lookups by id/slug)
+//! <dir>/gamma/markets.json     Vec<Market>
+//! <dir>/gamma/public-profiles.json Vec<PublicProfile> (matched by address)
+//! <dir>/clob/books.json        Vec<Orderbook> (matched by asset_id)
+//! <dir>/clob/prices.json       HashMap<token_id, TokenPrices>
+//! <dir>/clob/prices-history.json PriceHistoryResponse (same history for every token)
+//! <dir>/clob/trades.json       Vec<Trade> (matched by market or asset_id)
+//! <dir>/data/trades.json       Vec<DataTrade>
+//! <dir>/data/positions.json    Vec<Position>
+//! <dir>/data/holders.json      Vec<TokenHolders> (same holders for every market)
+//! ```
+//!
+//! Missing list fixtures are treated as empty responses. Requests that change
+//! account state (posting or cancelling orders, editing favorites) fail instead of
+//! reaching the live API. Streaming clients (RTDS and the market WebSocket) are not
+//! affected.
+
+use {
+    crate::error::{PolymarketError, Result},
+    serde::de::DeserializeOwned,
+    std::{
+        path::{Path, PathBuf},
+        sync::OnceLock,
+    },
+};
+
+/// Error returned by requests that would change account state in fixture mode
+pub(crate) fn offline_write_error(action: &str) -> PolymarketError {
+    PolymarketError::InvalidData(format!("Cannot {} in fixtures mode", action))
+}
+
+/// Environment variable that enables fixture mode
+pub const FIXTURES_ENV: &str = "POLYMARKET_FIXTURES";
+
+static FIXTURES_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Enable fixture mode for every client created afterwards in this process
+///
+/// Returns false if a fixtures directory was already set.
+pub fn set_fixtures_dir<P: Into<PathBuf>>(dir: P) -> bool {
+    FIXTURES_DIR.set(dir.into()).is_ok()
+}
+
+/// Directory of canned JSON responses used instead of the network
+#[derive(Debug, Clone)]
+pub struct Fixtures {
+    dir: PathBuf,
+}
+
+impl Fixtures {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Fixtures configured for this process (via `set_fixtures_dir` or `POLYMARKET_FIXTURES`)
+    pub fn from_env() -> Option<Self> {
+        FIXTURES_DIR
+            .get()
+            .cloned()
+            .or_else(|| std::env::var_os(FIXTURES_ENV).map(PathBuf::from))
+            .map(Self::new)
+    }
+
+    /// Get the fixtures directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of a fixture file (e.g., "gamma/events" -> "<dir>/gamma/events.json")
+    fn fixture_path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", name))
+    }
+
+    /// Load a fixture, returning None if the file does not exist
+    pub fn load<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>> {
+        let path = self.fixture_path(name);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path).map_err(|e| {
+            PolymarketError::InvalidData(format!(
+                "Failed to read fixture {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let data = serde_json::from_str(&content)?;
+        Ok(Some(data))
+    }
+
+    /// Load a fixture, falling back to the default value (e.g., an empty list) if missing
+    pub fn load_or_default<T: DeserializeOwned + Default>(&self, name: &str) -> Result<T> {
+        Ok(self.load(name)?.unwrap_or_default())
+    }
+
+    /// Load a fixture that has no sensible default, failing if it is missing
+    pub fn load_required<T: DeserializeOwned>(&self, name: &str) -> Result<T> {
+        self.load(name)?.ok_or_else(|| {
+            PolymarketError::InvalidData(format!(
+                "Missing fixture: {}",
+                self.fixture_path(name).display()
+            ))
+        })
+    }
+}
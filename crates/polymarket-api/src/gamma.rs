@@ -1,6 +1,11 @@
 use {
-    crate::{cache::FileCache, error::Result},
+    crate::{
+        cache::FileCache,
+        error::{PolymarketError, Result, check_status},
+        fixtures::{Fixtures, offline_write_error},
+    },
     serde::{Deserialize, Deserializer, Serialize},
+    std::time::Duration,
 };
 
 /// Macro for conditional info logging based on tracing feature
@@ -49,6 +54,84 @@ macro_rules! log_error {
 
 const GAMMA_API_BASE: &str = "https://gamma-api.polymarket.com";
 
+/// How long cached public profiles are reused (6 hours; names rarely change)
+pub const PUBLIC_PROFILE_CACHE_TTL: u64 = 6 * 60 * 60;
+
+/// How long each kind of cached Gamma data is reused, in seconds
+///
+/// Market metadata rarely changes, while event listings carry prices and volume
+/// that go stale within minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// Market info looked up by asset ID (`get_market_info_by_asset_id`)
+    pub market_info_ttl: u64,
+    /// Active event listings scanned for market info
+    pub event_ttl: u64,
+    /// Public profiles by wallet address
+    pub profile_ttl: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            market_info_ttl: 24 * 60 * 60,
+            event_ttl: 5 * 60,
+            profile_ttl: PUBLIC_PROFILE_CACHE_TTL,
+        }
+    }
+}
+
+/// Most event IDs requested at once by `GammaClient::get_events_batch`
+const EVENTS_BATCH_SIZE: usize = 50;
+
+/// Delay before the first retry (doubled for each further retry)
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Longest wait between retries, including a server's `Retry-After`
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+/// Request settings for `GammaClient::with_config`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GammaClientConfig {
+    /// Per-request timeout (connect + response)
+    pub timeout: Duration,
+    /// Retries after the first attempt for a failed GET
+    pub max_retries: u32,
+    /// Also retry 5xx responses (429 and timeouts are always retried)
+    pub retry_on_5xx: bool,
+}
+
+impl Default for GammaClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            max_retries: 2,
+            retry_on_5xx: true,
+        }
+    }
+}
+
+impl GammaClientConfig {
+    /// Whether a failed request is worth retrying
+    ///
+    /// Follows [`PolymarketError::is_transient`], with 5xx responses only retried
+    /// when `retry_on_5xx` is set.
+    pub fn should_retry(&self, error: &PolymarketError) -> bool {
+        match error.status() {
+            Some(status) if (500..600).contains(&status) => self.retry_on_5xx,
+            _ => error.is_transient(),
+        }
+    }
+
+    /// Exponential backoff for a 1-based retry number
+    pub fn retry_delay(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(16);
+        RETRY_BASE_DELAY
+            .saturating_mul(1 << exponent)
+            .min(MAX_RETRY_DELAY)
+    }
+}
+
 // Helper function to deserialize clobTokenIds which can be either a JSON string or an array
 fn deserialize_clob_token_ids<'de, D>(
     deserializer: D,
@@ -145,6 +228,12 @@ pub struct Event {
     /// Total volume
     #[serde(default)]
     pub volume: Option<f64>,
+    /// Volume in the last 7 days
+    #[serde(rename = "volume1wk", default)]
+    pub volume_1wk: Option<f64>,
+    /// Volume in the last 30 days
+    #[serde(rename = "volume1mo", default)]
+    pub volume_1mo: Option<f64>,
     /// Total liquidity
     #[serde(default)]
     pub liquidity: Option<f64>,
@@ -154,12 +243,38 @@ pub struct Event {
     /// When the event was created
     #[serde(rename = "createdAt", default)]
     pub created_at: Option<String>,
+    /// When trading on the event started
+    #[serde(rename = "startDate", default)]
+    pub start_date: Option<String>,
     /// Max price change in the last 24 hours across all markets (for Breaking tab)
     /// This is populated when fetching breaking events, not from the API directly
     #[serde(skip)]
     pub max_price_change_24hr: Option<f64>,
 }
 
+impl Event {
+    /// Get human-readable status string
+    pub fn status(&self) -> &'static str {
+        if self.closed {
+            "closed"
+        } else if self.active {
+            "active"
+        } else {
+            "inactive"
+        }
+    }
+
+    /// Whether any market has outcome tokens to fetch prices and orderbooks for
+    ///
+    /// Gamma sometimes returns events whose `markets` is empty (or lacks token IDs),
+    /// e.g. when embedded in another response.
+    pub fn has_tradeable_markets(&self) -> bool {
+        self.markets
+            .iter()
+            .any(|m| m.clob_token_ids.as_ref().is_some_and(|ids| !ids.is_empty()))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tag {
     pub id: String,
@@ -171,6 +286,9 @@ pub struct Tag {
 pub struct Market {
     #[serde(default)]
     pub id: Option<String>,
+    /// CTF condition ID shared by the market's outcome tokens
+    #[serde(rename = "conditionId", default)]
+    pub condition_id: Option<String>,
     pub question: String,
     /// Short display name for grouped markets (e.g., "400-419" for tweet count ranges)
     #[serde(rename = "groupItemTitle", default)]
@@ -193,6 +311,9 @@ pub struct Market {
     pub volume_24hr: Option<f64>,
     #[serde(rename = "volumeTotal", default)]
     pub volume_total: Option<f64>,
+    /// Liquidity resting in the market's orderbooks, in dollars
+    #[serde(rename = "liquidityNum", default)]
+    pub liquidity: Option<f64>,
     /// Whether the market is active (accepting new trades)
     #[serde(default)]
     pub active: bool,
@@ -243,6 +364,163 @@ impl Market {
             "paused"
         }
     }
+
+    /// Index of the winning outcome once the market has resolved
+    ///
+    /// Resolved markets are closed with the winning outcome priced at (nearly) 1.
+    pub fn resolved_outcome(&self) -> Option<usize> {
+        if !self.closed {
+            return None;
+        }
+        self.outcome_prices
+            .iter()
+            .position(|price| price.parse::<f64>().is_ok_and(|p| p >= 0.99))
+    }
+
+    /// Name of the winning outcome once the market has resolved
+    pub fn winning_outcome(&self) -> Option<&str> {
+        self.outcomes
+            .get(self.resolved_outcome()?)
+            .map(String::as_str)
+    }
+
+    /// Pair each outcome with its parsed price
+    ///
+    /// Entries without a matching outcome name or with an unparseable price are
+    /// skipped; a warning is logged when the API returns mismatched arrays.
+    pub fn outcomes_with_prices(&self) -> Vec<(&str, f64)> {
+        if self.outcomes.len() != self.outcome_prices.len() {
+            log_warn!(
+                "Market '{}' has {} outcomes but {} prices, skipping unpaired entries",
+                self.question,
+                self.outcomes.len(),
+                self.outcome_prices.len()
+            );
+        }
+        self.outcomes
+            .iter()
+            .zip(&self.outcome_prices)
+            .filter_map(|(outcome, price)| Some((outcome.as_str(), price.parse::<f64>().ok()?)))
+            .collect()
+    }
+
+    /// Short display name: `group_item_title` when set, otherwise the full question
+    pub fn display_name(&self) -> &str {
+        self.group_item_title
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .unwrap_or(&self.question)
+    }
+
+    /// Format an outcome of this market for display using the given abbreviation style
+    pub fn display_outcome(&self, outcome: &str, style: OutcomeNameStyle) -> String {
+        style.label(outcome, self.group_item_title.as_deref())
+    }
+}
+
+/// How outcome names are abbreviated when displayed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutcomeNameStyle {
+    /// Show outcome names unchanged
+    #[default]
+    Full,
+    /// Truncate names to at most this many characters (ending with "..." when it fits)
+    Truncate(usize),
+    /// Use the initials of multi-word names (e.g., "Donald Trump" -> "DT")
+    Acronym,
+    /// Show the market's `group_item_title` in place of "Yes" (e.g., the candidate name)
+    GroupTitle,
+}
+
+impl OutcomeNameStyle {
+    /// Format an outcome of a market with the given `group_item_title`
+    ///
+    /// `GroupTitle` shows the group title in place of "Yes"; every other case is
+    /// [`shorten`](Self::shorten).
+    pub fn label(&self, outcome: &str, group_item_title: Option<&str>) -> String {
+        if *self == Self::GroupTitle
+            && outcome.eq_ignore_ascii_case("yes")
+            && let Some(title) = group_item_title.filter(|s| !s.is_empty())
+        {
+            return title.to_string();
+        }
+        self.shorten(outcome)
+    }
+
+    /// Apply the abbreviation rule to a name, without any market context
+    pub fn shorten(&self, name: &str) -> String {
+        match self {
+            Self::Full | Self::GroupTitle => name.to_string(),
+            Self::Truncate(max_chars) => {
+                if name.chars().count() <= *max_chars {
+                    name.to_string()
+                } else if *max_chars < 3 {
+                    // No room for the ellipsis
+                    name.chars().take(*max_chars).collect()
+                } else {
+                    let truncated: String = name.chars().take(max_chars - 3).collect();
+                    format!("{}...", truncated)
+                }
+            },
+            Self::Acronym => {
+                let words: Vec<&str> = name.split_whitespace().collect();
+                if words.len() < 2 {
+                    name.to_string()
+                } else {
+                    words
+                        .iter()
+                        .filter_map(|w| w.chars().next())
+                        .flat_map(char::to_uppercase)
+                        .collect()
+                }
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for OutcomeNameStyle {
+    type Err = String;
+
+    /// Parse "full", "acronym", "group", "truncate" (20 chars) or "truncate:N"
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "full" => Ok(Self::Full),
+            "acronym" => Ok(Self::Acronym),
+            "group" | "group-title" => Ok(Self::GroupTitle),
+            "truncate" => Ok(Self::Truncate(20)),
+            other => other
+                .strip_prefix("truncate:")
+                .and_then(|n| n.parse::<usize>().ok())
+                .map(Self::Truncate)
+                .ok_or_else(|| {
+                    format!(
+                        "invalid outcome name style '{}' (expected full, acronym, group, truncate or truncate:N)",
+                        s
+                    )
+                }),
+        }
+    }
+}
+
+/// A market matched by [`GammaClient::search_markets`], with its parent event
+#[derive(Debug, Clone)]
+pub struct MarketSearchResult {
+    pub event: Event,
+    pub market: Market,
+}
+
+/// Whether every word of `query` appears in the market's question or group title
+fn market_matches_query(market: &Market, query: &str) -> bool {
+    let text = format!(
+        "{} {}",
+        market.question,
+        market.group_item_title.as_deref().unwrap_or("")
+    )
+    .to_lowercase();
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .all(|word| text.contains(word))
 }
 
 /// Lightweight event reference embedded in market responses
@@ -274,9 +552,14 @@ impl MarketEventRef {
 
 pub struct GammaClient {
     client: reqwest::Client,
+    config: GammaClientConfig,
     cache: Option<FileCache>,
+    /// Per-endpoint TTLs for entries written to `cache`
+    cache_config: CacheConfig,
     /// Authentication credentials (for favorite events, etc.)
     auth: Option<GammaAuth>,
+    /// Canned responses used instead of the network (offline mode)
+    fixtures: Option<Fixtures>,
 }
 
 /// Authentication credentials for Gamma API
@@ -300,19 +583,71 @@ pub struct GammaAuth {
 
 impl GammaClient {
     pub fn new() -> Self {
+        Self::with_config(GammaClientConfig::default())
+    }
+
+    /// Create a new GammaClient with custom timeout and retry settings
+    pub fn with_config(config: GammaClientConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .unwrap_or_default();
         Self {
-            client: reqwest::Client::new(),
+            client,
+            config,
             cache: None,
+            cache_config: CacheConfig::default(),
             auth: None,
+            fixtures: Fixtures::from_env(),
         }
     }
 
     /// Create a new GammaClient with authentication
     pub fn with_auth(auth: GammaAuth) -> Self {
         Self {
-            client: reqwest::Client::new(),
-            cache: None,
             auth: Some(auth),
+            ..Self::new()
+        }
+    }
+
+    /// Send an idempotent GET, retrying timeouts, 429s and (optionally) 5xx responses
+    ///
+    /// 404s are returned for the caller to handle; other failed statuses become
+    /// `PolymarketError::Http`, or `RateLimited` once the retries run out on a 429.
+    async fn get_with_retry(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let mut retry = 0;
+        loop {
+            let Some(attempt) = request.try_clone() else {
+                return check_status(request.send().await?).await;
+            };
+            retry += 1;
+            let result = match attempt.send().await {
+                Ok(response) if response.status() == reqwest::StatusCode::NOT_FOUND => {
+                    return Ok(response);
+                },
+                Ok(response) => check_status(response).await,
+                Err(e) => Err(e.into()),
+            };
+            let error = match result {
+                Ok(response) => return Ok(response),
+                Err(e) if retry <= self.config.max_retries && self.config.should_retry(&e) => e,
+                Err(e) => return Err(e),
+            };
+            // Honor the server's Retry-After (in seconds) for rate limits
+            let delay = match error {
+                PolymarketError::RateLimited {
+                    retry_after: Some(delay),
+                } => delay.min(MAX_RETRY_DELAY),
+                _ => self.config.retry_delay(retry),
+            };
+            log_warn!(
+                "Gamma request failed ({}), retrying in {:.1}s ({}/{})",
+                error,
+                delay.as_secs_f64(),
+                retry,
+                self.config.max_retries
+            );
+            tokio::time::sleep(delay).await;
         }
     }
 
@@ -338,18 +673,19 @@ impl GammaClient {
     pub fn with_cache<P: AsRef<std::path::Path>>(cache_dir: P) -> Result<Self> {
         let cache = FileCache::new(cache_dir)?;
         Ok(Self {
-            client: reqwest::Client::new(),
             cache: Some(cache),
-            auth: None,
+            ..Self::new()
         })
     }
 
-    /// Set cache TTL (time to live) in seconds
-    pub fn set_cache_ttl(&mut self, ttl_seconds: u64) -> Result<()> {
-        if let Some(ref mut cache) = self.cache {
-            *cache = cache.clone().with_default_ttl(ttl_seconds);
-        }
-        Ok(())
+    /// Set how long each kind of cached data is kept
+    pub fn set_cache_config(&mut self, config: CacheConfig) {
+        self.cache_config = config;
+    }
+
+    /// Per-endpoint cache TTLs in use
+    pub fn cache_config(&self) -> CacheConfig {
+        self.cache_config
     }
 
     /// Set cache for this client
@@ -357,13 +693,37 @@ impl GammaClient {
         self.cache = Some(cache);
     }
 
+    /// Serve responses from a fixtures directory instead of the network
+    pub fn set_fixtures(&mut self, fixtures: Fixtures) {
+        self.fixtures = Some(fixtures);
+    }
+
+    /// Events from the fixtures directory, truncated to `limit`
+    fn fixture_events(fixtures: &Fixtures, limit: usize) -> Result<Vec<Event>> {
+        let mut events: Vec<Event> = fixtures.load_or_default("gamma/events")?;
+        events.truncate(limit);
+        Ok(events)
+    }
+
+    /// Markets from the fixtures directory
+    fn fixture_markets(fixtures: &Fixtures) -> Result<Vec<Market>> {
+        fixtures.load_or_default("gamma/markets")
+    }
+
     pub async fn get_active_events(&self, limit: Option<usize>) -> Result<Vec<Event>> {
         let limit = limit.unwrap_or(100);
+        if let Some(ref fixtures) = self.fixtures {
+            return Self::fixture_events(fixtures, limit);
+        }
         let url = format!(
             "{}/events?active=true&closed=false&limit={}",
             GAMMA_API_BASE, limit
         );
-        let events: Vec<Event> = self.client.get(&url).send().await?.json().await?;
+        let events: Vec<Event> = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .json()
+            .await?;
         Ok(events)
     }
 
@@ -383,6 +743,10 @@ impl GammaClient {
         let order_by = order_by.unwrap_or("volume24hr");
         let ascending = ascending.unwrap_or(false);
 
+        if let Some(ref fixtures) = self.fixtures {
+            return Self::fixture_events(fixtures, limit);
+        }
+
         let url = format!(
             "{}/events?active=true&closed=false&order={}&ascending={}&limit={}",
             GAMMA_API_BASE, order_by, ascending, limit
@@ -390,7 +754,7 @@ impl GammaClient {
 
         log_info!("GET {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get_with_retry(self.client.get(&url)).await?;
         let _status = response.status();
 
         log_info!("GET {} -> status: {}", url, _status);
@@ -409,6 +773,9 @@ impl GammaClient {
     /// * `limit` - Maximum number of events to return (default 50)
     pub async fn get_breaking_events(&self, limit: Option<usize>) -> Result<Vec<Event>> {
         let limit = limit.unwrap_or(50);
+        if let Some(ref fixtures) = self.fixtures {
+            return Self::fixture_events(fixtures, limit);
+        }
         // Fetch more markets than needed since we dedupe by event
         let market_limit = limit * 3;
 
@@ -419,7 +786,7 @@ impl GammaClient {
 
         log_info!("GET {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.get_with_retry(self.client.get(&url)).await?;
         let _status = response.status();
 
         log_info!("GET {} -> status: {}", url, _status);
@@ -472,8 +839,17 @@ impl GammaClient {
     }
 
     pub async fn get_market_by_slug(&self, slug: &str) -> Result<Vec<Market>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let mut markets = Self::fixture_markets(fixtures)?;
+            markets.retain(|m| m.slug.as_deref() == Some(slug));
+            return Ok(markets);
+        }
         let url = format!("{}/markets?slug={}", GAMMA_API_BASE, slug);
-        let response: serde_json::Value = self.client.get(&url).send().await?.json().await?;
+        let response: serde_json::Value = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .json()
+            .await?;
 
         // The API might return a single market or an array
         let markets = if response.is_array() {
@@ -502,8 +878,12 @@ impl GammaClient {
 
     /// Get event by ID
     pub async fn get_event_by_id(&self, event_id: &str) -> Result<Option<Event>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let events = Self::fixture_events(fixtures, usize::MAX)?;
+            return Ok(events.into_iter().find(|e| e.id == event_id));
+        }
         let url = format!("{}/events/{}", GAMMA_API_BASE, event_id);
-        let response = self.client.get(&url).send().await?;
+        let response = self.get_with_retry(self.client.get(&url)).await?;
 
         if response.status() == 404 {
             return Ok(None);
@@ -513,17 +893,65 @@ impl GammaClient {
         Ok(Some(event))
     }
 
+    /// Get several events by ID in as few requests as possible
+    ///
+    /// IDs are sent in chunks of `EVENTS_BATCH_SIZE` using repeated `id` filters.
+    /// Events come back in the order of `ids`; unknown IDs are left out.
+    pub async fn get_events_batch(&self, ids: &[String]) -> Result<Vec<Event>> {
+        let mut found: std::collections::HashMap<String, Event> = std::collections::HashMap::new();
+        if let Some(ref fixtures) = self.fixtures {
+            found.extend(
+                Self::fixture_events(fixtures, usize::MAX)?
+                    .into_iter()
+                    .filter(|e| ids.contains(&e.id))
+                    .map(|e| (e.id.clone(), e)),
+            );
+        } else {
+            for chunk in ids.chunks(EVENTS_BATCH_SIZE) {
+                let query: Vec<(&str, &str)> = chunk.iter().map(|id| ("id", id.as_str())).collect();
+                let url = format!("{}/events", GAMMA_API_BASE);
+                log_info!("GET {} ({} ids)", url, chunk.len());
+                let events: Vec<Event> = self
+                    .get_with_retry(
+                        self.client
+                            .get(&url)
+                            .query(&query)
+                            .query(&[("limit", chunk.len())]),
+                    )
+                    .await?
+                    .json()
+                    .await?;
+                found.extend(events.into_iter().map(|e| (e.id.clone(), e)));
+            }
+        }
+        Ok(ids.iter().filter_map(|id| found.remove(id)).collect())
+    }
+
     /// Get event by slug
     pub async fn get_event_by_slug(&self, slug: &str) -> Result<Option<Event>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let events = Self::fixture_events(fixtures, usize::MAX)?;
+            return Ok(events.into_iter().find(|e| e.slug == slug));
+        }
         let url = format!("{}/events?slug={}", GAMMA_API_BASE, slug);
-        let events: Vec<Event> = self.client.get(&url).send().await?.json().await?;
+        let events: Vec<Event> = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .json()
+            .await?;
         Ok(events.into_iter().next())
     }
 
     /// Get market by ID
     pub async fn get_market_by_id(&self, market_id: &str) -> Result<Option<Market>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let markets = Self::fixture_markets(fixtures)?;
+            return Ok(markets
+                .into_iter()
+                .find(|m| m.id.as_deref() == Some(market_id)));
+        }
         let url = format!("{}/markets/{}", GAMMA_API_BASE, market_id);
-        let response = self.client.get(&url).send().await?;
+        let response = self.get_with_retry(self.client.get(&url)).await?;
 
         if response.status() == 404 {
             return Ok(None);
@@ -533,6 +961,37 @@ impl GammaClient {
         Ok(Some(market))
     }
 
+    /// Get market by CTF condition ID
+    ///
+    /// Several markets can share a condition ID (e.g. a relisted market), so an active,
+    /// open one is preferred over the first match.
+    pub async fn get_market_by_condition_id(&self, condition_id: &str) -> Result<Option<Market>> {
+        let markets = if let Some(ref fixtures) = self.fixtures {
+            let mut markets = Self::fixture_markets(fixtures)?;
+            markets.retain(|m| m.condition_id.as_deref() == Some(condition_id));
+            markets
+        } else {
+            let url = format!("{}/markets", GAMMA_API_BASE);
+            let request = self
+                .client
+                .get(&url)
+                .query(&[("condition_ids", condition_id)]);
+            let markets: Vec<Market> = self.get_with_retry(request).await?.json().await?;
+            markets
+        };
+        log_debug!(
+            "Found {} markets for condition {}",
+            markets.len(),
+            condition_id
+        );
+
+        let active = markets.iter().position(|m| m.active && !m.closed);
+        Ok(match active {
+            Some(index) => markets.into_iter().nth(index),
+            None => markets.into_iter().next(),
+        })
+    }
+
     /// Get all markets (with optional filters)
     pub async fn get_markets(
         &self,
@@ -540,6 +999,14 @@ impl GammaClient {
         closed: Option<bool>,
         limit: Option<usize>,
     ) -> Result<Vec<Market>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let mut markets = Self::fixture_markets(fixtures)?;
+            markets.retain(|m| {
+                active.is_none_or(|a| m.active == a) && closed.is_none_or(|c| m.closed == c)
+            });
+            markets.truncate(limit.unwrap_or(usize::MAX));
+            return Ok(markets);
+        }
         let url = format!("{}/markets", GAMMA_API_BASE);
         let mut params = Vec::new();
 
@@ -553,21 +1020,22 @@ impl GammaClient {
             params.push(("limit", limit.to_string()));
         }
 
-        let markets: Vec<Market> = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let request = self.client.get(&url).query(&params);
+        let markets: Vec<Market> = self.get_with_retry(request).await?.json().await?;
         Ok(markets)
     }
 
     /// Get categories/tags
     pub async fn get_categories(&self) -> Result<Vec<Tag>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return fixtures.load_or_default("gamma/categories");
+        }
         let url = format!("{}/categories", GAMMA_API_BASE);
-        let categories: Vec<Tag> = self.client.get(&url).send().await?.json().await?;
+        let categories: Vec<Tag> = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .json()
+            .await?;
         Ok(categories)
     }
 
@@ -578,17 +1046,121 @@ impl GammaClient {
         limit: Option<usize>,
     ) -> Result<Vec<Event>> {
         let limit = limit.unwrap_or(100);
+        if let Some(ref fixtures) = self.fixtures {
+            let mut events = Self::fixture_events(fixtures, usize::MAX)?;
+            events.retain(|e| e.tags.iter().any(|t| t.slug == category_slug));
+            events.truncate(limit);
+            return Ok(events);
+        }
         let url = format!(
             "{}/events?category={}&limit={}",
             GAMMA_API_BASE, category_slug, limit
         );
-        let events: Vec<Event> = self.client.get(&url).send().await?.json().await?;
+        let events: Vec<Event> = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .json()
+            .await?;
         Ok(events)
     }
 
+    /// Get open events sharing a tag with `event` (e.g. other months of a series)
+    ///
+    /// Fetches the top events by 24h volume for each of the event's tags and ranks
+    /// them by the number of tags shared with it, keeping the volume order for ties.
+    /// The event itself is left out.
+    pub async fn get_related_events(
+        &self,
+        event: &Event,
+        limit: Option<usize>,
+    ) -> Result<Vec<Event>> {
+        let limit = limit.unwrap_or(50);
+        let candidates = if let Some(ref fixtures) = self.fixtures {
+            Self::fixture_events(fixtures, usize::MAX)?
+        } else {
+            let mut candidates = Vec::new();
+            for tag in &event.tags {
+                let url = format!(
+                    "{}/events?tag_id={}&active=true&closed=false&order=volume24hr&ascending=false&limit={}",
+                    GAMMA_API_BASE, tag.id, limit
+                );
+                log_info!("GET {}", url);
+                let events: Vec<Event> = self
+                    .get_with_retry(self.client.get(&url))
+                    .await?
+                    .json()
+                    .await?;
+                candidates.extend(events);
+            }
+            candidates
+        };
+
+        let shared_tags = |other: &Event| {
+            other
+                .tags
+                .iter()
+                .filter(|tag| event.tags.iter().any(|t| t.id == tag.id))
+                .count()
+        };
+        let mut seen = std::collections::HashSet::new();
+        let mut related: Vec<Event> = candidates
+            .into_iter()
+            .filter(|other| other.id != event.id && shared_tags(other) > 0)
+            .filter(|other| seen.insert(other.id.clone()))
+            .collect();
+        // Stable sort keeps the volume order for events sharing as many tags
+        related.sort_by_key(|other| std::cmp::Reverse(shared_tags(other)));
+        related.truncate(limit);
+        log_info!("Found {} events related to {}", related.len(), event.slug);
+        Ok(related)
+    }
+
+    /// Search markets by question, returning each match with its parent event
+    ///
+    /// Runs an event search and keeps the markets whose question (or group title)
+    /// contains every word of the query.
+    pub async fn search_markets(
+        &self,
+        query: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<MarketSearchResult>> {
+        let limit = limit.unwrap_or(50);
+        let events = if let Some(ref fixtures) = self.fixtures {
+            Self::fixture_events(fixtures, usize::MAX)?
+        } else {
+            self.search_events(query, Some(limit)).await?
+        };
+
+        let mut results = Vec::new();
+        for event in events {
+            for market in event
+                .markets
+                .iter()
+                .filter(|m| market_matches_query(m, query))
+            {
+                results.push(MarketSearchResult {
+                    event: event.clone(),
+                    market: market.clone(),
+                });
+            }
+        }
+        results.truncate(limit);
+        log_info!("Market search returned {} markets", results.len());
+        Ok(results)
+    }
+
     /// Search events by query string using the public-search endpoint
     pub async fn search_events(&self, query: &str, limit: Option<usize>) -> Result<Vec<Event>> {
         let limit_per_type = limit.unwrap_or(50);
+        if let Some(ref fixtures) = self.fixtures {
+            let query = query.to_lowercase();
+            let mut events = Self::fixture_events(fixtures, usize::MAX)?;
+            events.retain(|e| {
+                e.title.to_lowercase().contains(&query) || e.slug.to_lowercase().contains(&query)
+            });
+            events.truncate(limit_per_type);
+            return Ok(events);
+        }
         let url = format!(
             "{}/public-search?q={}&optimized=true&limit_per_type={}&type=events&search_tags=true&search_profiles=true&cache=true",
             GAMMA_API_BASE,
@@ -599,41 +1171,19 @@ impl GammaClient {
         // Log the API call
         log_info!("GET {}", url);
 
-        let response = self.client.get(&url).send().await.inspect_err(|_e| {
-            log_error!("Failed to send search request: {}", _e);
-        })?;
+        let response = self
+            .get_with_retry(self.client.get(&url))
+            .await
+            .inspect_err(|_e| {
+                log_error!("Failed to send search request: {}", _e);
+            })?;
 
-        let status = response.status();
-        log_info!("GET {} -> status: {}", url, status);
+        log_info!("GET {} -> status: {}", url, response.status());
 
         let response_text = response.text().await.inspect_err(|_e| {
             log_error!("Failed to read search response body: {}", _e);
         })?;
 
-        // Only log response body on error or in debug mode
-        if !status.is_success() {
-            log_debug!(
-                "Search API response body (first 500 chars): {}",
-                if response_text.len() > 500 {
-                    &response_text[..500]
-                } else {
-                    &response_text
-                }
-            );
-        }
-
-        if !status.is_success() {
-            log_warn!(
-                "Search API error: status={}, body={}",
-                status,
-                response_text
-            );
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "Search API returned status {}: {}",
-                status, response_text
-            )));
-        }
-
         #[derive(Deserialize)]
         struct SearchResponse {
             events: Vec<Event>,
@@ -692,17 +1242,20 @@ impl GammaClient {
         // Check cache first
         if let Some(ref cache) = self.cache {
             let cache_key = format!("market_info_{}", asset_id);
-            if let Some(cached_info) = cache.get::<MarketInfo>(&cache_key)? {
+            // Entries cached before `outcome_index` existed are looked up again
+            if let Some(cached_info) = cache.get::<MarketInfo>(&cache_key)?
+                && cached_info.outcome_index.is_some()
+            {
                 return Ok(Some(cached_info));
             }
         }
 
-        let events = self.get_active_events(Some(1000)).await?;
+        let events = self.get_cached_active_events(1000).await?;
 
         for event in events {
             for market in event.markets {
                 if let Some(ref token_ids) = market.clob_token_ids
-                    && token_ids.contains(&asset_id.to_string())
+                    && let Some(outcome_index) = token_ids.iter().position(|id| id == asset_id)
                 {
                     let outcomes = market.outcomes.clone();
                     let prices = market.outcome_prices.clone();
@@ -713,6 +1266,7 @@ impl GammaClient {
                         market_question: market.question,
                         market_id: market.id.clone().unwrap_or_default(),
                         asset_id: asset_id.to_string(),
+                        outcome_index: Some(outcome_index),
                         outcomes,
                         prices,
                     };
@@ -720,7 +1274,11 @@ impl GammaClient {
                     // Cache the result
                     if let Some(ref cache) = self.cache {
                         let cache_key = format!("market_info_{}", asset_id);
-                        let _ = cache.set(&cache_key, &market_info);
+                        let _ = cache.set_with_ttl(
+                            &cache_key,
+                            &market_info,
+                            self.cache_config.market_info_ttl,
+                        );
                     }
 
                     return Ok(Some(market_info));
@@ -731,17 +1289,44 @@ impl GammaClient {
         Ok(None)
     }
 
+    /// Active events, reused from the cache for `CacheConfig::event_ttl` seconds
+    async fn get_cached_active_events(&self, limit: usize) -> Result<Vec<Event>> {
+        let cache_key = format!("active_events_{}", limit);
+        if let Some(ref cache) = self.cache
+            && let Ok(Some(events)) = cache.get::<Vec<Event>>(&cache_key)
+        {
+            return Ok(events);
+        }
+
+        let events = self.get_active_events(Some(limit)).await?;
+        if let Some(ref cache) = self.cache {
+            let _ = cache.set_with_ttl(&cache_key, &events, self.cache_config.event_ttl);
+        }
+        Ok(events)
+    }
+
     /// Check API health status
     pub async fn get_status(&self) -> Result<String> {
+        if self.fixtures.is_some() {
+            return Ok("OK".to_string());
+        }
         let url = format!("{}/status", GAMMA_API_BASE);
-        let status = self.client.get(&url).send().await?.text().await?;
+        let status = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .text()
+            .await?;
         Ok(status)
     }
 
     /// Get tag by ID
     pub async fn get_tag_by_id(&self, tag_id: &str) -> Result<Option<Tag>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let tags: Vec<Tag> = fixtures.load_or_default("gamma/tags")?;
+            return Ok(tags.into_iter().find(|t| t.id == tag_id));
+        }
         let url = format!("{}/tags/{}", GAMMA_API_BASE, tag_id);
-        let response = self.client.get(&url).send().await?;
+        let response = self.get_with_retry(self.client.get(&url)).await?;
 
         if response.status() == 404 {
             return Ok(None);
@@ -753,8 +1338,12 @@ impl GammaClient {
 
     /// Get tag by slug
     pub async fn get_tag_by_slug(&self, slug: &str) -> Result<Option<Tag>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let tags: Vec<Tag> = fixtures.load_or_default("gamma/tags")?;
+            return Ok(tags.into_iter().find(|t| t.slug == slug));
+        }
         let url = format!("{}/tags/slug/{}", GAMMA_API_BASE, slug);
-        let response = self.client.get(&url).send().await?;
+        let response = self.get_with_retry(self.client.get(&url)).await?;
 
         if response.status() == 404 {
             return Ok(None);
@@ -766,23 +1355,43 @@ impl GammaClient {
 
     /// Get related tags for a tag ID
     pub async fn get_related_tags(&self, tag_id: &str) -> Result<Vec<Tag>> {
+        if self.fixtures.is_some() {
+            return Ok(Vec::new());
+        }
         let url = format!("{}/tags/{}/related-tags", GAMMA_API_BASE, tag_id);
-        let tags: Vec<Tag> = self.client.get(&url).send().await?.json().await?;
+        let tags: Vec<Tag> = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .json()
+            .await?;
         Ok(tags)
     }
 
     /// Get all series
     pub async fn get_series(&self, limit: Option<usize>) -> Result<Vec<Series>> {
         let limit = limit.unwrap_or(100);
+        if let Some(ref fixtures) = self.fixtures {
+            let mut series: Vec<Series> = fixtures.load_or_default("gamma/series")?;
+            series.truncate(limit);
+            return Ok(series);
+        }
         let url = format!("{}/series?limit={}", GAMMA_API_BASE, limit);
-        let series: Vec<Series> = self.client.get(&url).send().await?.json().await?;
+        let series: Vec<Series> = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .json()
+            .await?;
         Ok(series)
     }
 
     /// Get series by ID
     pub async fn get_series_by_id(&self, series_id: &str) -> Result<Option<Series>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let series: Vec<Series> = fixtures.load_or_default("gamma/series")?;
+            return Ok(series.into_iter().find(|s| s.id == series_id));
+        }
         let url = format!("{}/series/{}", GAMMA_API_BASE, series_id);
-        let response = self.client.get(&url).send().await?;
+        let response = self.get_with_retry(self.client.get(&url)).await?;
 
         if response.status() == 404 {
             return Ok(None);
@@ -793,30 +1402,69 @@ impl GammaClient {
     }
 
     /// Get public profile by wallet address
+    ///
+    /// With a cache, found profiles are kept for `CacheConfig::profile_ttl` seconds
+    /// ([`PUBLIC_PROFILE_CACHE_TTL`] by default).
     pub async fn get_public_profile(&self, address: &str) -> Result<Option<PublicProfile>> {
+        if let Some(ref fixtures) = self.fixtures {
+            let profiles: Vec<PublicProfile> = fixtures.load_or_default("gamma/public-profiles")?;
+            return Ok(profiles.into_iter().find(|profile| {
+                profile
+                    .address
+                    .as_deref()
+                    .is_some_and(|a| a.eq_ignore_ascii_case(address))
+            }));
+        }
+        let cache_key = format!("public_profile_{}", address.to_lowercase());
+        if let Some(ref cache) = self.cache
+            && let Ok(Some(profile)) = cache.get::<PublicProfile>(&cache_key)
+        {
+            return Ok(Some(profile));
+        }
+
         let url = format!("{}/public-profile", GAMMA_API_BASE);
         let params = [("address", address)];
-        let response = self.client.get(&url).query(&params).send().await?;
+        let response = self
+            .get_with_retry(self.client.get(&url).query(&params))
+            .await?;
 
         if response.status() == 404 {
             return Ok(None);
         }
 
         let profile: PublicProfile = response.json().await?;
+        if let Some(ref cache) = self.cache {
+            let _ = cache.set_with_ttl(&cache_key, &profile, self.cache_config.profile_ttl);
+        }
         Ok(Some(profile))
     }
 
     /// Get tags for a specific event
     pub async fn get_event_tags(&self, event_id: &str) -> Result<Vec<Tag>> {
+        if self.fixtures.is_some() {
+            let event = self.get_event_by_id(event_id).await?;
+            return Ok(event.map(|e| e.tags).unwrap_or_default());
+        }
         let url = format!("{}/events/{}/tags", GAMMA_API_BASE, event_id);
-        let tags: Vec<Tag> = self.client.get(&url).send().await?.json().await?;
+        let tags: Vec<Tag> = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .json()
+            .await?;
         Ok(tags)
     }
 
     /// Get tags for a specific market
     pub async fn get_market_tags(&self, market_id: &str) -> Result<Vec<Tag>> {
+        if self.fixtures.is_some() {
+            return Ok(Vec::new());
+        }
         let url = format!("{}/markets/{}/tags", GAMMA_API_BASE, market_id);
-        let tags: Vec<Tag> = self.client.get(&url).send().await?.json().await?;
+        let tags: Vec<Tag> = self
+            .get_with_retry(self.client.get(&url))
+            .await?
+            .json()
+            .await?;
         Ok(tags)
     }
 
@@ -894,6 +1542,9 @@ impl GammaClient {
     /// Get all favorite events for the authenticated user
     /// Requires a valid session cookie (browser-based authentication)
     pub async fn get_favorite_events(&self) -> Result<Vec<FavoriteEvent>> {
+        if let Some(ref fixtures) = self.fixtures {
+            return fixtures.load_or_default("gamma/favorite_events");
+        }
         if !self.has_session_cookie() {
             return Err(crate::error::PolymarketError::InvalidData(
                 "Session cookie required for favorite events. \
@@ -910,19 +1561,10 @@ impl GammaClient {
 
         let headers = self.create_cookie_headers()?;
 
-        let response = self.client.get(&url).headers(headers).send().await?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let body = response.text().await.unwrap_or_default();
-            log_warn!("GET {} -> error: {} - {}", request_path, status, body);
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "Failed to get favorite events: {} - {}",
-                status, body
-            )));
-        }
-
-        log_info!("GET {} -> {}", request_path, status);
+        let response = self
+            .get_with_retry(self.client.get(&url).headers(headers))
+            .await?;
+        log_info!("GET {} -> {}", request_path, response.status());
         let favorites: Vec<FavoriteEvent> = response.json().await?;
         log_info!("Fetched {} favorite events", favorites.len());
         Ok(favorites)
@@ -931,6 +1573,9 @@ impl GammaClient {
     /// Add an event to favorites
     /// Requires a valid session cookie (browser-based authentication)
     pub async fn add_favorite_event(&self, event_id: &str) -> Result<FavoriteEvent> {
+        if self.fixtures.is_some() {
+            return Err(offline_write_error("add favorites"));
+        }
         if !self.has_session_cookie() {
             return Err(crate::error::PolymarketError::InvalidData(
                 "Session cookie required for favorite events".to_string(),
@@ -957,15 +1602,9 @@ impl GammaClient {
             .send()
             .await?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log_warn!("Failed to add favorite event: {} - {}", status, body);
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "Failed to add favorite event: {}",
-                status
-            )));
-        }
+        let response = check_status(response).await.inspect_err(|_e| {
+            log_warn!("Failed to add favorite event: {}", _e);
+        })?;
 
         let favorite: FavoriteEvent = response.json().await?;
         log_info!("Added favorite event: {}", event_id);
@@ -975,6 +1614,9 @@ impl GammaClient {
     /// Remove an event from favorites
     /// Requires a valid session cookie (browser-based authentication)
     pub async fn remove_favorite_event(&self, favorite_id: i64) -> Result<()> {
+        if self.fixtures.is_some() {
+            return Err(offline_write_error("remove favorites"));
+        }
         if !self.has_session_cookie() {
             return Err(crate::error::PolymarketError::InvalidData(
                 "Session cookie required for favorite events".to_string(),
@@ -990,15 +1632,9 @@ impl GammaClient {
 
         let response = self.client.delete(&url).headers(headers).send().await?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            log_warn!("Failed to remove favorite event: {} - {}", status, body);
-            return Err(crate::error::PolymarketError::InvalidData(format!(
-                "Failed to remove favorite event: {}",
-                status
-            )));
-        }
+        check_status(response).await.inspect_err(|_e| {
+            log_warn!("Failed to remove favorite event: {}", _e);
+        })?;
 
         log_info!("Removed favorite event: {}", favorite_id);
         Ok(())
@@ -1030,10 +1666,25 @@ pub struct MarketInfo {
     pub market_question: String,
     pub market_id: String,
     pub asset_id: String,
+    /// Position of `asset_id` in the market's `clobTokenIds` (and so in `outcomes`)
+    #[serde(default)]
+    pub outcome_index: Option<usize>,
     pub outcomes: Vec<String>,
     pub prices: Vec<String>,
 }
 
+impl MarketInfo {
+    /// Outcome name of the looked-up token, e.g. "Yes"
+    pub fn outcome(&self) -> Option<&str> {
+        self.outcomes.get(self.outcome_index?).map(String::as_str)
+    }
+
+    /// Gamma's last price for the looked-up token
+    pub fn price(&self) -> Option<f64> {
+        self.prices.get(self.outcome_index?)?.parse().ok()
+    }
+}
+
 /// API status response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StatusResponse {
@@ -3,17 +3,22 @@ pub mod clob;
 pub mod data;
 pub mod display;
 pub mod error;
+pub mod fixtures;
 pub mod gamma;
 pub mod rtds;
+pub mod signing;
 pub mod websocket;
+pub mod yields;
 
 pub use {
-    cache::{FileCache, default_cache_dir},
-    clob::ClobClient,
+    cache::{CacheUsage, FileCache, default_cache_dir},
+    clob::{ClobClient, OrderbookCache, RateLimiter},
     data::DataClient,
     display::{MarketUpdateFormatter, RTDSFormatter},
     error::{PolymarketError, Result, lock_mutex},
-    gamma::{FavoriteEvent, GammaAuth, GammaClient},
-    rtds::{ActivityPayload, RTDSClient, RTDSMessage},
+    fixtures::Fixtures,
+    gamma::{CacheConfig, FavoriteEvent, GammaAuth, GammaClient, GammaClientConfig},
+    rtds::{ActivityPayload, RTDSClient, RTDSEvent, RTDSMessage, RTDSStream},
     websocket::PolymarketWebSocket,
+    yields::YieldOpportunity,
 };
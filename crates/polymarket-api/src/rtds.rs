@@ -1,9 +1,20 @@
 use {
-    crate::error::{PolymarketError, Result},
-    futures_util::{SinkExt, StreamExt},
+    crate::{
+        error::{PolymarketError, Result},
+        fixtures::Fixtures,
+    },
+    futures_util::{SinkExt, Stream, StreamExt},
     serde::{Deserialize, Serialize},
-    std::sync::Arc,
-    tokio::sync::Mutex,
+    std::{
+        pin::Pin,
+        sync::Arc,
+        task::{Context, Poll},
+        time::Duration,
+    },
+    tokio::{
+        sync::{Mutex, mpsc},
+        task::JoinHandle,
+    },
     tokio_tungstenite::{connect_async, tungstenite::Message},
 };
 
@@ -80,11 +91,98 @@ pub struct ActivityPayload {
     pub profile_image: Option<String>,
 }
 
+/// Longest wait between reconnect attempts
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+
+/// Reconnect settings for `RTDSClient::with_reconnect`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Consecutive failed attempts before giving up
+    pub max_retries: u32,
+    /// Delay before the first attempt (doubled for each further attempt)
+    pub base_delay: Duration,
+}
+
+impl ReconnectPolicy {
+    /// Exponential backoff with up to 50% jitter for a 1-based attempt number
+    ///
+    /// `jitter` is a fraction in `0.0..=1.0`.
+    pub fn delay(&self, attempt: u32, jitter: f64) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(MAX_RECONNECT_DELAY);
+        backoff.mul_f64(1.0 + jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
+
+/// Item delivered by `RTDSClient::connect_and_listen_events`
+#[derive(Debug, Clone)]
+pub enum RTDSEvent {
+    Message(Box<RTDSMessage>),
+    /// The socket connected and the subscription was sent
+    Connected,
+    /// The server answered a keep-alive PING (the socket is still up)
+    Heartbeat,
+    /// The socket dropped and attempt `attempt` will be made after `delay`
+    Reconnecting {
+        attempt: u32,
+        delay: Duration,
+    },
+}
+
+/// Why a connection ended
+enum Disconnect {
+    /// The socket closed or errored (worth reconnecting)
+    Dropped { received_messages: bool },
+    /// The server rejected the subscription (reconnecting won't help)
+    Rejected,
+}
+
+/// Live messages from [`RTDSClient::into_stream`]
+///
+/// Ends when the socket closes; dropping the stream disconnects.
+pub struct RTDSStream {
+    messages: mpsc::UnboundedReceiver<RTDSMessage>,
+    listener: Option<JoinHandle<Result<()>>>,
+}
+
+impl RTDSStream {
+    /// Wait for the connection to end and return the error that closed it, if any
+    pub async fn finish(mut self) -> Result<()> {
+        match self.listener.take() {
+            Some(listener) => listener.await.map_err(|e| {
+                PolymarketError::WebSocket(format!("RTDS listener task failed: {}", e))
+            })?,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Stream for RTDSStream {
+    type Item = RTDSMessage;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.poll_recv(cx)
+    }
+}
+
+impl Drop for RTDSStream {
+    fn drop(&mut self) {
+        if let Some(ref listener) = self.listener {
+            listener.abort();
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct RTDSClient {
     event_slug: Option<String>,
     event_id: Option<u64>,
     clob_auth: Option<ClobAuth>,
     gamma_auth: Option<GammaAuth>,
+    reconnect: Option<ReconnectPolicy>,
 }
 
 impl RTDSClient {
@@ -129,6 +227,7 @@ impl RTDSClient {
             event_id: None,
             clob_auth,
             gamma_auth,
+            reconnect: None,
         }
     }
 
@@ -156,23 +255,109 @@ impl RTDSClient {
         self
     }
 
+    /// Reconnect with exponential backoff (plus jitter) when the socket drops
+    ///
+    /// Gives up after `max_retries` consecutive failed attempts; the count resets once
+    /// a reconnected socket delivers messages again.
+    pub fn with_reconnect(mut self, max_retries: u32, base_delay: Duration) -> Self {
+        self.reconnect = Some(ReconnectPolicy {
+            max_retries,
+            base_delay,
+        });
+        self
+    }
+
+    /// Open a connection and close it again, to check RTDS is reachable
+    ///
+    /// Always succeeds in fixture mode, which never touches the network.
+    pub async fn check_connection() -> Result<()> {
+        if Fixtures::from_env().is_some() {
+            return Ok(());
+        }
+        let (mut ws_stream, _) = connect_async(RTDS_WS_URL).await.map_err(|e| {
+            PolymarketError::WebSocket(format!("Failed to connect to RTDS WebSocket: {}", e))
+        })?;
+        let _ = ws_stream.close(None).await;
+        Ok(())
+    }
+
+    /// Call `on_update` for every message until the socket closes
     pub async fn connect_and_listen<F>(&self, mut on_update: F) -> Result<()>
     where
         F: FnMut(RTDSMessage) + Send,
     {
-        #[cfg(feature = "tracing")]
-        debug!("Connecting to RTDS WebSocket: {}", RTDS_WS_URL);
+        let mut stream = self.clone().into_stream();
+        while let Some(msg) = stream.next().await {
+            on_update(msg);
+        }
+        stream.finish().await
+    }
 
-        let (ws_stream, _) = connect_async(RTDS_WS_URL).await.map_err(|e| {
-            PolymarketError::WebSocket(format!("Failed to connect to RTDS WebSocket: {}", e))
-        })?;
+    /// Connect in a background task and yield the messages as a stream
+    ///
+    /// The stream ends when the socket closes (after the retries run out if
+    /// reconnecting is enabled); [`RTDSStream::finish`] then returns why.
+    pub fn into_stream(self) -> RTDSStream {
+        let (sender, messages) = mpsc::unbounded_channel();
+        let listener = tokio::spawn(async move {
+            self.connect_and_listen_events(move |event| {
+                if let RTDSEvent::Message(msg) = event {
+                    let _ = sender.send(*msg);
+                }
+            })
+            .await
+        });
+        RTDSStream {
+            messages,
+            listener: Some(listener),
+        }
+    }
 
-        #[cfg(feature = "tracing")]
-        debug!("Connected to RTDS WebSocket");
+    /// Like `connect_and_listen`, but also reports reconnect attempts
+    ///
+    /// Returns when the socket closes (after the retries run out if reconnecting is
+    /// enabled). Aborting the task drops the connection without reconnecting.
+    pub async fn connect_and_listen_events<F>(&self, mut on_event: F) -> Result<()>
+    where
+        F: FnMut(RTDSEvent) + Send,
+    {
+        // The subscription is re-sent on every reconnect
+        let subscribe_json = self.subscription_message()?;
+        let mut attempt = 0;
+        loop {
+            let result = self.listen_once(&subscribe_json, &mut on_event).await;
+            let Some(policy) = self.reconnect else {
+                return result.map(|_| ());
+            };
+
+            let error = match result {
+                Ok(Disconnect::Rejected) => return Ok(()),
+                Ok(Disconnect::Dropped { received_messages }) => {
+                    if received_messages {
+                        attempt = 0;
+                    }
+                    None
+                },
+                Err(e) => Some(e),
+            };
+            if attempt >= policy.max_retries {
+                return error.map_or(Ok(()), Err);
+            }
 
-        let (write, mut read) = ws_stream.split();
-        let write = Arc::new(Mutex::new(write));
+            attempt += 1;
+            let delay = policy.delay(attempt, jitter());
+            #[cfg(feature = "tracing")]
+            warn!(
+                "RTDS connection lost, reconnecting in {:?} (attempt {}/{})",
+                delay, attempt, policy.max_retries
+            );
+            on_event(RTDSEvent::Reconnecting { attempt, delay });
+            tokio::time::sleep(delay).await;
+        }
+    }
 
+    /// Subscription message for the configured event slug and/or event ID
+    fn subscription_message(&self) -> Result<String> {
         // Build subscription message
         let mut subscriptions = Vec::new();
 
@@ -218,15 +403,33 @@ impl RTDSClient {
             subscriptions,
         };
 
-        let subscribe_json =
-            serde_json::to_string(&subscribe_msg).map_err(PolymarketError::Serialization)?;
+        serde_json::to_string(&subscribe_msg).map_err(PolymarketError::Serialization)
+    }
+
+    /// Connect, subscribe and forward messages until the socket closes
+    async fn listen_once<F>(&self, subscribe_json: &str, on_event: &mut F) -> Result<Disconnect>
+    where
+        F: FnMut(RTDSEvent) + Send,
+    {
+        #[cfg(feature = "tracing")]
+        debug!("Connecting to RTDS WebSocket: {}", RTDS_WS_URL);
+
+        let (ws_stream, _) = connect_async(RTDS_WS_URL).await.map_err(|e| {
+            PolymarketError::WebSocket(format!("Failed to connect to RTDS WebSocket: {}", e))
+        })?;
+
+        #[cfg(feature = "tracing")]
+        debug!("Connected to RTDS WebSocket");
+
+        let (write, mut read) = ws_stream.split();
+        let write = Arc::new(Mutex::new(write));
 
         #[cfg(feature = "tracing")]
         debug!("Sending RTDS subscription: {}", subscribe_json);
 
         {
             let mut w = write.lock().await;
-            w.send(Message::Text(subscribe_json.clone()))
+            w.send(Message::Text(subscribe_json.to_string()))
                 .await
                 .map_err(|e| {
                     PolymarketError::WebSocket(format!(
@@ -238,6 +441,7 @@ impl RTDSClient {
 
         #[cfg(feature = "tracing")]
         debug!("RTDS subscription sent successfully");
+        on_event(RTDSEvent::Connected);
 
         // Start PING task (send PING every 5 seconds as per RTDS docs)
         let write_ping = Arc::clone(&write);
@@ -257,6 +461,9 @@ impl RTDSClient {
         });
 
         // Listen for messages
+        let mut disconnect = Disconnect::Dropped {
+            received_messages: false,
+        };
         while let Some(msg) = read.next().await {
             match msg {
                 Ok(Message::Text(text)) => {
@@ -272,7 +479,10 @@ impl RTDSClient {
                             "Received RTDS message: topic={}, type={}",
                             rtds_msg.topic, rtds_msg.message_type
                         );
-                        on_update(rtds_msg);
+                        on_event(RTDSEvent::Message(Box::new(rtds_msg)));
+                        disconnect = Disconnect::Dropped {
+                            received_messages: true,
+                        };
                         continue; // Successfully handled, move to next message
                     }
 
@@ -288,7 +498,8 @@ impl RTDSClient {
                             break;
                         }
                     } else if text == "PONG" {
-                        // Server responded to our PING, just continue silently
+                        // Server responded to our PING
+                        on_event(RTDSEvent::Heartbeat);
                         continue;
                     } else {
                         // Try to parse as error message
@@ -306,6 +517,7 @@ impl RTDSClient {
                             }
                             // If it's an authentication error, break the connection
                             if message.contains("validation") || message.contains("auth") {
+                                disconnect = Disconnect::Rejected;
                                 break;
                             }
                             continue;
@@ -346,6 +558,7 @@ impl RTDSClient {
                         eprintln!("Failed to send pong: {}", e);
                         break;
                     }
+                    on_event(RTDSEvent::Heartbeat);
                 },
                 Ok(Message::Close(_)) => {
                     break;
@@ -364,10 +577,19 @@ impl RTDSClient {
         // Cancel PING task
         ping_handle.abort();
 
-        Ok(())
+        Ok(disconnect)
     }
 }
 
+/// Pseudo-random fraction in `0.0..1.0` for backoff jitter
+fn jitter() -> f64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    f64::from(nanos % 1000) / 1000.0
+}
+
 impl Default for RTDSClient {
     fn default() -> Self {
         Self::new()
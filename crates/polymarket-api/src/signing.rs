@@ -0,0 +1,305 @@
+//! EIP-712 order signing for the CLOB
+//!
+//! Orders posted to the CLOB must be signed by the wallet that owns the funds. This
+//! module builds the exchange's `Order` struct, hashes it per EIP-712 and signs it
+//! with a secp256k1 private key.
+
+use {
+    crate::{
+        clob::Side,
+        error::{PolymarketError, Result},
+    },
+    k256::ecdsa::SigningKey,
+    serde::{Deserialize, Serialize},
+    sha3::{Digest, Keccak256},
+};
+
+/// Polygon mainnet chain ID
+pub const POLYGON_CHAIN_ID: u64 = 137;
+
+/// CTF exchange contract (regular markets)
+pub const EXCHANGE_ADDRESS: &str = "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E";
+
+/// Neg-risk CTF exchange contract (multi-outcome markets)
+pub const NEG_RISK_EXCHANGE_ADDRESS: &str = "0xC5d563A36AE78145C45a50134d48A1215220f80a";
+
+/// Taker address for public orders (anyone can fill)
+const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+/// USDC and conditional tokens both use 6 decimals
+const TOKEN_DECIMALS: f64 = 1_000_000.0;
+
+const DOMAIN_TYPE: &str =
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+const ORDER_TYPE: &str = "Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,uint256 feeRateBps,uint8 side,uint8 signatureType)";
+
+/// How the CLOB verifies the order signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureType {
+    /// The signing key holds the funds
+    #[default]
+    Eoa = 0,
+    /// Polymarket proxy wallet (email/Magic accounts)
+    PolyProxy = 1,
+    /// Gnosis Safe wallet (browser wallet accounts)
+    PolyGnosisSafe = 2,
+}
+
+/// Order struct signed for the exchange contract, in its JSON wire format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedOrder {
+    pub salt: u64,
+    pub maker: String,
+    pub signer: String,
+    pub taker: String,
+    pub token_id: String,
+    pub maker_amount: String,
+    pub taker_amount: String,
+    pub expiration: String,
+    pub nonce: String,
+    pub fee_rate_bps: String,
+    pub side: Side,
+    pub signature_type: u8,
+    pub signature: String,
+}
+
+/// Private key used to sign orders
+#[derive(Clone)]
+pub struct OrderSigner {
+    key: SigningKey,
+    address: String,
+}
+
+impl std::fmt::Debug for OrderSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the key itself
+        f.debug_struct("OrderSigner")
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+impl OrderSigner {
+    /// Create a signer from a hex private key (with or without `0x`)
+    pub fn from_private_key(private_key: &str) -> Result<Self> {
+        let bytes = hex::decode(private_key.trim().trim_start_matches("0x"))
+            .map_err(|e| PolymarketError::InvalidData(format!("Invalid private key: {}", e)))?;
+        let key = SigningKey::from_slice(&bytes)
+            .map_err(|e| PolymarketError::InvalidData(format!("Invalid private key: {}", e)))?;
+        let public = key.verifying_key().to_encoded_point(false);
+        let hash = keccak256(&public.as_bytes()[1..]);
+        let address = format!("0x{}", hex::encode(&hash[12..]));
+        Ok(Self { key, address })
+    }
+
+    /// Address of the signing key (lowercase hex)
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Build and sign an order for `size` shares of `token_id` at `price`
+    ///
+    /// `maker` is the wallet holding the funds; it differs from the signer for proxy
+    /// and Safe wallets. Sizes are rounded down to 2 decimals; prices that aren't a
+    /// multiple of `tick_size` are rejected rather than rounded to a price the
+    /// caller didn't ask for.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign_order(
+        &self,
+        maker: &str,
+        signature_type: SignatureType,
+        token_id: &str,
+        side: Side,
+        price: f64,
+        size: f64,
+        tick_size: f64,
+        neg_risk: bool,
+    ) -> Result<SignedOrder> {
+        let salt = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64 & ((1 << 53) - 1))
+            .unwrap_or_default();
+        self.sign_order_with_salt(
+            salt,
+            maker,
+            signature_type,
+            token_id,
+            side,
+            price,
+            size,
+            tick_size,
+            neg_risk,
+        )
+    }
+
+    /// [`Self::sign_order`] with a fixed salt, for reproducible signatures
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign_order_with_salt(
+        &self,
+        salt: u64,
+        maker: &str,
+        signature_type: SignatureType,
+        token_id: &str,
+        side: Side,
+        price: f64,
+        size: f64,
+        tick_size: f64,
+        neg_risk: bool,
+    ) -> Result<SignedOrder> {
+        if !(price > 0.0 && price < 1.0) {
+            return Err(PolymarketError::InvalidData(format!(
+                "Price must be between 0 and 1 (got {})",
+                price
+            )));
+        }
+        let tick = tick_or_default(tick_size);
+        if (price / tick - (price / tick).round()).abs() > 1e-6 {
+            return Err(PolymarketError::InvalidData(format!(
+                "Price {} is not a multiple of the {} tick size",
+                price, tick
+            )));
+        }
+        let (maker_amount, taker_amount) = order_amounts(side, price, size, tick_size);
+        if maker_amount == 0 || taker_amount == 0 {
+            return Err(PolymarketError::InvalidData(
+                "Order size is too small".to_string(),
+            ));
+        }
+
+        let mut order = SignedOrder {
+            salt,
+            maker: maker.to_string(),
+            signer: self.address.clone(),
+            taker: ZERO_ADDRESS.to_string(),
+            token_id: token_id.to_string(),
+            maker_amount: maker_amount.to_string(),
+            taker_amount: taker_amount.to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "0".to_string(),
+            side,
+            signature_type: signature_type as u8,
+            signature: String::new(),
+        };
+
+        let digest = order_digest(&order, exchange_address(neg_risk))?;
+        let (signature, recovery_id) = self
+            .key
+            .sign_prehash_recoverable(&digest)
+            .map_err(|e| PolymarketError::InvalidData(format!("Failed to sign order: {}", e)))?;
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(27 + recovery_id.to_byte());
+        order.signature = format!("0x{}", hex::encode(bytes));
+        Ok(order)
+    }
+}
+
+/// Maker and taker amounts (in 6-decimal token units) for an order
+///
+/// Buyers give USDC and receive shares; sellers give shares and receive USDC.
+/// The price is snapped to the tick, which only removes floating point noise for
+/// prices [`OrderSigner::sign_order`] accepts.
+pub fn order_amounts(side: Side, price: f64, size: f64, tick_size: f64) -> (u64, u64) {
+    let tick = tick_or_default(tick_size);
+    let price = (price / tick).round() * tick;
+    let shares = (size * 100.0).floor() / 100.0;
+    let dollars = (shares * price * 10_000.0).round() / 10_000.0;
+    let to_units = |value: f64| (value * TOKEN_DECIMALS).round() as u64;
+    match side {
+        Side::Buy => (to_units(dollars), to_units(shares)),
+        Side::Sell => (to_units(shares), to_units(dollars)),
+    }
+}
+
+/// `tick_size`, or the CLOB's default tick if it isn't positive
+fn tick_or_default(tick_size: f64) -> f64 {
+    if tick_size > 0.0 {
+        tick_size
+    } else {
+        crate::clob::DEFAULT_TICK_SIZE
+    }
+}
+
+/// Exchange contract that verifies orders for regular or neg-risk markets
+pub fn exchange_address(neg_risk: bool) -> &'static str {
+    if neg_risk {
+        NEG_RISK_EXCHANGE_ADDRESS
+    } else {
+        EXCHANGE_ADDRESS
+    }
+}
+
+/// EIP-712 digest of an order for the given exchange contract
+pub fn order_digest(order: &SignedOrder, exchange: &str) -> Result<[u8; 32]> {
+    let mut domain = keccak256(DOMAIN_TYPE.as_bytes()).to_vec();
+    domain.extend(keccak256(b"Polymarket CTF Exchange"));
+    domain.extend(keccak256(b"1"));
+    domain.extend(uint_word(&POLYGON_CHAIN_ID.to_string())?);
+    domain.extend(address_word(exchange)?);
+    let domain_separator = keccak256(&domain);
+
+    let side: u8 = match order.side {
+        Side::Buy => 0,
+        Side::Sell => 1,
+    };
+    let mut encoded = keccak256(ORDER_TYPE.as_bytes()).to_vec();
+    encoded.extend(uint_word(&order.salt.to_string())?);
+    encoded.extend(address_word(&order.maker)?);
+    encoded.extend(address_word(&order.signer)?);
+    encoded.extend(address_word(&order.taker)?);
+    encoded.extend(uint_word(&order.token_id)?);
+    encoded.extend(uint_word(&order.maker_amount)?);
+    encoded.extend(uint_word(&order.taker_amount)?);
+    encoded.extend(uint_word(&order.expiration)?);
+    encoded.extend(uint_word(&order.nonce)?);
+    encoded.extend(uint_word(&order.fee_rate_bps)?);
+    encoded.extend(uint_word(&side.to_string())?);
+    encoded.extend(uint_word(&order.signature_type.to_string())?);
+    let struct_hash = keccak256(&encoded);
+
+    let mut message = vec![0x19, 0x01];
+    message.extend(domain_separator);
+    message.extend(struct_hash);
+    Ok(keccak256(&message))
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// ABI-encode a decimal uint256 (token IDs don't fit in a u128)
+fn uint_word(decimal: &str) -> Result<[u8; 32]> {
+    let mut word = [0u8; 32];
+    for c in decimal.chars() {
+        let digit = c
+            .to_digit(10)
+            .ok_or_else(|| PolymarketError::InvalidData(format!("Invalid uint256: {}", decimal)))?;
+        // word = word * 10 + digit, big-endian
+        let mut carry = digit;
+        for byte in word.iter_mut().rev() {
+            let value = u32::from(*byte) * 10 + carry;
+            *byte = (value & 0xff) as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            return Err(PolymarketError::InvalidData(format!(
+                "uint256 overflow: {}",
+                decimal
+            )));
+        }
+    }
+    Ok(word)
+}
+
+/// ABI-encode an address (left-padded to 32 bytes)
+fn address_word(address: &str) -> Result<[u8; 32]> {
+    let bytes = hex::decode(address.trim_start_matches("0x"))
+        .ok()
+        .filter(|b| b.len() == 20)
+        .ok_or_else(|| PolymarketError::InvalidData(format!("Invalid address: {}", address)))?;
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(&bytes);
+    Ok(word)
+}
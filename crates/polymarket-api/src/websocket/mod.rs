@@ -11,13 +11,25 @@ use {
     anyhow::{Context, Result},
     futures_util::{SinkExt, StreamExt},
     serde::{Deserialize, Serialize},
-    std::collections::HashMap,
+    std::{collections::HashMap, future::Future, time::Duration},
+    tokio::sync::mpsc::UnboundedSender,
     tokio_tungstenite::{connect_async, tungstenite::Message},
 };
 
 #[cfg(feature = "tracing")]
 use tracing::{error, warn};
 
+/// Macro for conditional warn logging based on tracing feature
+#[cfg(feature = "tracing")]
+macro_rules! log_warn {
+    ($($arg:tt)*) => { tracing::warn!($($arg)*) };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! log_warn {
+    ($($arg:tt)*) => {};
+}
+
 pub use {
     messages::{Auth, SubscribedMessage, SubscriptionMessage, UpdateSubscriptionMessage},
     types::{ErrorMessage, OrderUpdate, OrderbookUpdate, PriceLevel, PriceUpdate, TradeUpdate},
@@ -45,9 +57,77 @@ pub enum WebSocketMessage {
     Unknown,
 }
 
+impl WebSocketMessage {
+    pub fn kind(&self) -> MessageKind {
+        match self {
+            Self::Orderbook(_) => MessageKind::Orderbook,
+            Self::Trade(_) => MessageKind::Trade,
+            Self::Order(_) => MessageKind::Order,
+            Self::Price(_) => MessageKind::Price,
+            Self::Error(_) => MessageKind::Error,
+            Self::Subscribed(_) => MessageKind::Subscribed,
+            Self::Unknown => MessageKind::Unknown,
+        }
+    }
+
+    /// Asset the message is about (orderbook, trade, order and price updates)
+    pub fn asset_id(&self) -> Option<&str> {
+        match self {
+            Self::Orderbook(update) => Some(&update.asset_id),
+            Self::Trade(update) => Some(&update.asset_id),
+            Self::Order(update) => Some(&update.asset_id),
+            Self::Price(update) => Some(&update.asset_id),
+            _ => None,
+        }
+    }
+}
+
+/// Type of a [`WebSocketMessage`] without its payload, for filtering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageKind {
+    Orderbook,
+    Trade,
+    Order,
+    Price,
+    Error,
+    Subscribed,
+    Unknown,
+}
+
+impl std::str::FromStr for MessageKind {
+    type Err = String;
+
+    /// Parse the message `type` tag, e.g. "trade" or "price"
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "orderbook" | "book" => Ok(Self::Orderbook),
+            "trade" => Ok(Self::Trade),
+            "order" => Ok(Self::Order),
+            "price" => Ok(Self::Price),
+            "error" => Ok(Self::Error),
+            "subscribed" => Ok(Self::Subscribed),
+            "unknown" => Ok(Self::Unknown),
+            _ => Err(format!(
+                "invalid message type '{}' (expected orderbook, trade, order, price, error, subscribed or unknown)",
+                s
+            )),
+        }
+    }
+}
+
+/// How long a dropped chunk connection waits before reconnecting
+pub const CHUNK_RESTART_DELAY: Duration = Duration::from_secs(5);
+
+/// Consecutive drops without a message after which a chunk gives up
+pub const MAX_CHUNK_RESTARTS: usize = 5;
+
+/// Most asset IDs sent in one subscribe message (and so one connection)
+pub const DEFAULT_SUBSCRIPTION_CHUNK_SIZE: usize = 500;
+
 /// WebSocket client for connecting to Polymarket's market data stream
 pub struct PolymarketWebSocket {
     pub(crate) asset_ids: Vec<String>,
+    chunk_size: usize,
     market_info_cache: HashMap<String, crate::gamma::MarketInfo>,
 }
 
@@ -56,126 +136,328 @@ impl PolymarketWebSocket {
     pub fn new(asset_ids: Vec<String>) -> Self {
         Self {
             asset_ids,
+            chunk_size: DEFAULT_SUBSCRIPTION_CHUNK_SIZE,
             market_info_cache: HashMap::new(),
         }
     }
 
+    /// Subscribe at most `chunk_size` asset IDs per connection (at least 1)
+    ///
+    /// Large asset lists are split across several connections whose messages are
+    /// merged into the one callback.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Subscribe messages sent on connect, one per connection
+    pub fn subscription_messages(&self) -> Vec<SubscriptionMessage> {
+        self.asset_ids
+            .chunks(self.chunk_size)
+            .map(|chunk| market_subscription(chunk.to_vec()))
+            .collect()
+    }
+
     /// Connect to the WebSocket and listen for updates
     ///
-    /// The callback function will be called for each message received.
-    pub async fn connect_and_listen<F>(&mut self, mut on_update: F) -> Result<()>
+    /// The callback function will be called for each message received. With more
+    /// asset IDs than the chunk size, one connection is opened per chunk; a chunk
+    /// whose connection closes or fails is reopened after
+    /// [`CHUNK_RESTART_DELAY`], and this only returns an error (naming the chunk)
+    /// once one has dropped [`MAX_CHUNK_RESTARTS`] times in a row without
+    /// delivering a message. A single connection (one chunk) isn't reopened: it
+    /// returns as soon as that connection ends.
+    pub async fn connect_and_listen<F>(&mut self, on_update: F) -> Result<()>
     where
         F: FnMut(WebSocketMessage) + Send,
     {
-        let (ws_stream, _) = connect_async(WS_URL)
-            .await
-            .context("Failed to connect to WebSocket")?;
+        let mut subscriptions = self.subscription_messages();
+        if subscriptions.len() <= 1 {
+            // A single connection isn't restarted here: it returns when the
+            // connection ends, and reconnecting is left to the caller
+            let subscription = subscriptions
+                .pop()
+                .unwrap_or_else(|| market_subscription(Vec::new()));
+            return listen_connection(subscription, on_update).await;
+        }
+        supervise_chunks(subscriptions, on_update, listen_chunk).await
+    }
 
-        let (mut write, mut read) = ws_stream.split();
+    /// Update cached market info for an asset
+    pub fn update_market_info(&mut self, asset_id: String, info: crate::gamma::MarketInfo) {
+        self.market_info_cache.insert(asset_id, info);
+    }
 
-        // Subscribe to market channel
-        let subscribe_msg = SubscriptionMessage {
-            auth: None, // No auth needed for public market data
-            markets: None,
-            assets_ids: Some(self.asset_ids.clone()),
-            channel_type: "market".to_string(), // Use lowercase as per Polymarket docs
-            custom_feature_enabled: None,
-        };
+    /// Get cached market info for an asset
+    pub fn get_market_info(&self, asset_id: &str) -> Option<&crate::gamma::MarketInfo> {
+        self.market_info_cache.get(asset_id)
+    }
+}
 
-        let subscribe_json = serde_json::to_string(&subscribe_msg)?;
-        write
-            .send(Message::Text(subscribe_json))
-            .await
-            .context("Failed to send subscription message")?;
-
-        // Listen for messages
-        while let Some(msg) = read.next().await {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    // Try to parse as WebSocketMessage first
-                    if let Ok(ws_msg) = serde_json::from_str::<WebSocketMessage>(&text) {
-                        on_update(ws_msg);
-                    } else if let Ok(subscribed) = serde_json::from_str::<SubscribedMessage>(&text)
+fn market_subscription(asset_ids: Vec<String>) -> SubscriptionMessage {
+    SubscriptionMessage {
+        auth: None, // No auth needed for public market data
+        markets: None,
+        assets_ids: Some(asset_ids),
+        channel_type: "market".to_string(), // Use lowercase as per Polymarket docs
+        custom_feature_enabled: None,
+    }
+}
+
+/// Keep one connection per subscription open, reopening the ones that drop
+///
+/// `connect` runs a chunk's connection, forwarding its messages to the sender,
+/// and reports whether any message arrived and how the connection ended.
+async fn supervise_chunks<F, C, Fut>(
+    subscriptions: Vec<SubscriptionMessage>,
+    mut on_update: F,
+    connect: C,
+) -> Result<()>
+where
+    F: FnMut(WebSocketMessage) + Send,
+    C: Fn(SubscriptionMessage, UnboundedSender<WebSocketMessage>) -> Fut,
+    Fut: Future<Output = (bool, Result<()>)> + Send + 'static,
+{
+    let chunk_count = subscriptions.len();
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut connections = tokio::task::JoinSet::new();
+    for (index, subscription) in subscriptions.iter().enumerate() {
+        spawn_chunk(
+            &mut connections,
+            index,
+            connect(subscription.clone(), tx.clone()),
+            Duration::ZERO,
+        );
+    }
+    let mut failures = vec![0usize; chunk_count];
+
+    // Dropping the set on return aborts the remaining connections
+    loop {
+        tokio::select! {
+            Some(msg) = rx.recv() => on_update(msg),
+            Some(finished) = connections.join_next() => {
+                let (index, received, result) =
+                    finished.context("WebSocket connection task failed")?;
+                failures[index] = if received { 1 } else { failures[index] + 1 };
+                let assets = subscriptions[index]
+                    .assets_ids
+                    .as_ref()
+                    .map_or(0, Vec::len);
+                let dropped = format!(
+                    "WebSocket connection for chunk {}/{} ({} assets)",
+                    index + 1,
+                    chunk_count,
+                    assets
+                );
+                if failures[index] > MAX_CHUNK_RESTARTS {
+                    // Deliver what the other connections already received
+                    while let Ok(msg) = rx.try_recv() {
+                        on_update(msg);
+                    }
+                    return match result {
+                        Ok(()) => Err(anyhow::anyhow!("{} kept closing", dropped)),
+                        Err(e) => Err(e.context(format!("{} kept failing", dropped))),
+                    };
+                }
+                match &result {
+                    Ok(()) => {
+                        log_warn!("{} closed, reconnecting", dropped);
+                    },
+                    Err(_e) => {
+                        log_warn!("{} failed, reconnecting: {:#}", dropped, _e);
+                    },
+                }
+                spawn_chunk(
+                    &mut connections,
+                    index,
+                    connect(subscriptions[index].clone(), tx.clone()),
+                    CHUNK_RESTART_DELAY,
+                );
+            },
+        }
+    }
+}
+
+/// Run `connection` on `connections` after `delay`
+///
+/// The task yields the chunk index, whether any message arrived, and how the
+/// connection ended.
+fn spawn_chunk(
+    connections: &mut tokio::task::JoinSet<(usize, bool, Result<()>)>,
+    index: usize,
+    connection: impl Future<Output = (bool, Result<()>)> + Send + 'static,
+    delay: Duration,
+) {
+    connections.spawn(async move {
+        tokio::time::sleep(delay).await;
+        let (received, result) = connection.await;
+        (index, received, result)
+    });
+}
+
+/// Run one chunk's connection, forwarding its messages to `tx`
+async fn listen_chunk(
+    subscription: SubscriptionMessage,
+    tx: UnboundedSender<WebSocketMessage>,
+) -> (bool, Result<()>) {
+    let mut received = false;
+    let result = listen_connection(subscription, |msg| {
+        received = true;
+        let _ = tx.send(msg);
+    })
+    .await;
+    (received, result)
+}
+
+/// Run one connection: send `subscription`, then pass every message to `on_update`
+async fn listen_connection<F>(subscription: SubscriptionMessage, mut on_update: F) -> Result<()>
+where
+    F: FnMut(WebSocketMessage) + Send,
+{
+    let (ws_stream, _) = connect_async(WS_URL)
+        .await
+        .context("Failed to connect to WebSocket")?;
+
+    let (mut write, mut read) = ws_stream.split();
+
+    // Subscribe to market channel
+    let subscribe_json = serde_json::to_string(&subscription)?;
+    write
+        .send(Message::Text(subscribe_json))
+        .await
+        .context("Failed to send subscription message")?;
+
+    // Listen for messages
+    while let Some(msg) = read.next().await {
+        match msg {
+            Ok(Message::Text(text)) => {
+                // Try to parse as WebSocketMessage first
+                if let Ok(ws_msg) = serde_json::from_str::<WebSocketMessage>(&text) {
+                    on_update(ws_msg);
+                } else if let Ok(subscribed) = serde_json::from_str::<SubscribedMessage>(&text) {
+                    on_update(WebSocketMessage::Subscribed(subscribed));
+                } else if let Ok(err) = serde_json::from_str::<ErrorMessage>(&text) {
+                    on_update(WebSocketMessage::Error(err));
+                } else {
+                    // Try to parse by checking for type field
+                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&text)
+                        && let Some(msg_type) = json.get("type").and_then(|v| v.as_str())
                     {
-                        on_update(WebSocketMessage::Subscribed(subscribed));
-                    } else if let Ok(err) = serde_json::from_str::<ErrorMessage>(&text) {
-                        on_update(WebSocketMessage::Error(err));
-                    } else {
-                        // Try to parse by checking for type field
-                        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&text)
-                            && let Some(msg_type) = json.get("type").and_then(|v| v.as_str())
-                        {
-                            match msg_type {
-                                "orderbook" => {
-                                    if let Ok(update) =
-                                        serde_json::from_value::<OrderbookUpdate>(json)
-                                    {
-                                        on_update(WebSocketMessage::Orderbook(update));
-                                    }
-                                },
-                                "trade" => {
-                                    if let Ok(update) = serde_json::from_value::<TradeUpdate>(json)
-                                    {
-                                        on_update(WebSocketMessage::Trade(update));
-                                    }
-                                },
-                                "order" => {
-                                    if let Ok(update) = serde_json::from_value::<OrderUpdate>(json)
-                                    {
-                                        on_update(WebSocketMessage::Order(update));
-                                    }
-                                },
-                                "price" => {
-                                    if let Ok(update) = serde_json::from_value::<PriceUpdate>(json)
-                                    {
-                                        on_update(WebSocketMessage::Price(update));
-                                    }
-                                },
-                                _ => {
-                                    // Unknown message type, log for debugging
-                                    #[cfg(feature = "tracing")]
-                                    warn!("Unknown message type: {}", text);
-                                    #[cfg(not(feature = "tracing"))]
-                                    eprintln!("Unknown message type: {}", text);
-                                },
-                            }
+                        match msg_type {
+                            "orderbook" => {
+                                if let Ok(update) = serde_json::from_value::<OrderbookUpdate>(json)
+                                {
+                                    on_update(WebSocketMessage::Orderbook(update));
+                                }
+                            },
+                            "trade" => {
+                                if let Ok(update) = serde_json::from_value::<TradeUpdate>(json) {
+                                    on_update(WebSocketMessage::Trade(update));
+                                }
+                            },
+                            "order" => {
+                                if let Ok(update) = serde_json::from_value::<OrderUpdate>(json) {
+                                    on_update(WebSocketMessage::Order(update));
+                                }
+                            },
+                            "price" => {
+                                if let Ok(update) = serde_json::from_value::<PriceUpdate>(json) {
+                                    on_update(WebSocketMessage::Price(update));
+                                }
+                            },
+                            _ => {
+                                // Unknown message type, log for debugging
+                                #[cfg(feature = "tracing")]
+                                warn!("Unknown message type: {}", text);
+                                #[cfg(not(feature = "tracing"))]
+                                eprintln!("Unknown message type: {}", text);
+                            },
                         }
                     }
-                },
-                Ok(Message::Ping(data)) => {
-                    // Respond to ping with pong
-                    if let Err(e) = write.send(Message::Pong(data)).await {
-                        #[cfg(feature = "tracing")]
-                        error!("Failed to send pong: {}", e);
-                        #[cfg(not(feature = "tracing"))]
-                        eprintln!("Failed to send pong: {}", e);
-                        break;
-                    }
-                },
-                Ok(Message::Close(_)) => {
-                    break;
-                },
-                Err(e) => {
+                }
+            },
+            Ok(Message::Ping(data)) => {
+                // Respond to ping with pong
+                if let Err(e) = write.send(Message::Pong(data)).await {
                     #[cfg(feature = "tracing")]
-                    error!("WebSocket error: {}", e);
+                    error!("Failed to send pong: {}", e);
                     #[cfg(not(feature = "tracing"))]
-                    eprintln!("WebSocket error: {}", e);
+                    eprintln!("Failed to send pong: {}", e);
                     break;
-                },
-                _ => {},
-            }
+                }
+            },
+            Ok(Message::Close(_)) => {
+                break;
+            },
+            Err(e) => {
+                #[cfg(feature = "tracing")]
+                error!("WebSocket error: {}", e);
+                #[cfg(not(feature = "tracing"))]
+                eprintln!("WebSocket error: {}", e);
+                break;
+            },
+            _ => {},
         }
-
-        Ok(())
     }
 
-    /// Update cached market info for an asset
-    pub fn update_market_info(&mut self, asset_id: String, info: crate::gamma::MarketInfo) {
-        self.market_info_cache.insert(asset_id, info);
-    }
+    Ok(())
+}
 
-    /// Get cached market info for an asset
-    pub fn get_market_info(&self, asset_id: &str) -> Option<&crate::gamma::MarketInfo> {
-        self.market_info_cache.get(asset_id)
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::sync::{Arc, Mutex},
+        tokio::time::Instant,
+    };
+
+    #[tokio::test(start_paused = true)]
+    async fn test_chunk_restarts_reset_after_messages() {
+        let subscriptions = vec![
+            market_subscription(vec!["1".to_string()]),
+            market_subscription(vec!["2".to_string()]),
+        ];
+        // Chunk 1 only delivers a message on its MAX_CHUNK_RESTARTS-th connection;
+        // chunk 2 stays connected
+        let attempts = Arc::new(Mutex::new(0usize));
+        let connect = {
+            let attempts = Arc::clone(&attempts);
+            move |subscription: SubscriptionMessage, tx: UnboundedSender<WebSocketMessage>| {
+                let attempts = Arc::clone(&attempts);
+                async move {
+                    if subscription.assets_ids != Some(vec!["1".to_string()]) {
+                        std::future::pending::<()>().await;
+                    }
+                    let mut attempts = attempts.lock().expect("Should lock attempts");
+                    *attempts += 1;
+                    let received = *attempts == MAX_CHUNK_RESTARTS;
+                    if received {
+                        let _ = tx.send(WebSocketMessage::Unknown);
+                    }
+                    (received, Ok(()))
+                }
+            }
+        };
+
+        let start = Instant::now();
+        let mut messages = 0;
+        let err = supervise_chunks(subscriptions, |_| messages += 1, connect)
+            .await
+            .expect_err("Should give up on chunk 1");
+
+        // The message resets the count, so it takes MAX_CHUNK_RESTARTS more empty drops
+        assert_eq!(
+            *attempts.lock().expect("Should lock attempts"),
+            2 * MAX_CHUNK_RESTARTS
+        );
+        assert_eq!(messages, 1);
+        assert_eq!(
+            start.elapsed(),
+            CHUNK_RESTART_DELAY * (2 * MAX_CHUNK_RESTARTS as u32 - 1)
+        );
+        assert_eq!(
+            err.to_string(),
+            "WebSocket connection for chunk 1/2 (1 assets) kept closing"
+        );
     }
 }
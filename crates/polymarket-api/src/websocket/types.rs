@@ -58,6 +58,12 @@ pub struct PriceUpdate {
     #[serde(rename = "asset_id")]
     pub asset_id: String,
     pub price: String,
+    /// Book side of a price-change delta ("buy" for bids, "sell" for asks)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<String>,
+    /// New size resting at `price` on `side` ("0" when the level was emptied)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<i64>,
 }
@@ -0,0 +1,161 @@
+//! Yield calculation for high-probability outcomes
+//!
+//! A yield opportunity is an outcome priced just below $1: buying it returns
+//! `1 - price` per share if the market resolves the way it is expected to.
+
+use {
+    crate::gamma::{Event, Market, OutcomeNameStyle},
+    chrono::{DateTime, Utc},
+};
+
+/// Default probability threshold (95% probability = ~5% potential return)
+pub const DEFAULT_MIN_PROB: f64 = 0.95;
+
+/// A single yield opportunity (high probability outcome)
+#[derive(Debug, Clone)]
+pub struct YieldOpportunity {
+    pub market_name: String,
+    pub market_status: &'static str,
+    pub outcome: String,
+    /// The market's `group_item_title`, shown in place of "Yes" by
+    /// [`OutcomeNameStyle::GroupTitle`]
+    pub group_item_title: Option<String>,
+    pub price: f64,
+    /// Estimated return in percent (`(1 - price) * 100`)
+    pub est_return: f64,
+    pub volume: f64,
+    /// Market liquidity in dollars, how much resting size backs the quoted price
+    /// (0 if the API didn't report it)
+    pub liquidity: f64,
+    pub event_slug: String,
+    pub event_title: String,
+    pub event_status: &'static str,
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+impl YieldOpportunity {
+    /// Format the outcome for display, like [`Market::display_outcome`]
+    pub fn display_outcome(&self, style: OutcomeNameStyle) -> String {
+        style.label(&self.outcome, self.group_item_title.as_deref())
+    }
+
+    /// Days until the event ends, negative once it has passed
+    pub fn days_remaining(&self, now: DateTime<Utc>) -> Option<f64> {
+        self.end_date
+            .map(|end| (end - now).num_seconds() as f64 / 86_400.0)
+    }
+
+    /// Estimated return scaled to a year (`est_return * 365 / days_remaining`)
+    ///
+    /// None without an end date or once the end date has passed. Anything ending
+    /// within a day counts as one day so same-day markets don't dwarf the rest.
+    pub fn est_return_annualized(&self, now: DateTime<Utc>) -> Option<f64> {
+        let days = self.days_remaining(now).filter(|days| *days > 0.0)?;
+        Some(self.est_return * 365.0 / days.max(1.0))
+    }
+}
+
+/// Event fields copied into every opportunity found for one of its markets
+struct EventContext<'a> {
+    slug: &'a str,
+    title: &'a str,
+    status: &'static str,
+    end_date: Option<DateTime<Utc>>,
+}
+
+/// Parse an ISO 8601 end date from the Gamma API
+fn parse_end_date(end_date: Option<&String>) -> Option<DateTime<Utc>> {
+    end_date
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Collect the outcomes of a market priced in `[min_prob, 1.0)`
+///
+/// Outcomes already at 1.0 offer no return; unparseable prices and outcomes
+/// without a matching name are skipped.
+fn market_opportunities(
+    market: &Market,
+    event: &EventContext<'_>,
+    min_prob: f64,
+) -> Vec<YieldOpportunity> {
+    let volume = market.volume_24hr.unwrap_or(0.0);
+
+    market
+        .outcomes_with_prices()
+        .into_iter()
+        .filter_map(|(outcome, price)| {
+            if price < min_prob || price >= 1.0 {
+                return None;
+            }
+
+            Some(YieldOpportunity {
+                market_name: market.display_name().to_string(),
+                market_status: market.status(),
+                outcome: outcome.to_string(),
+                group_item_title: market.group_item_title.clone(),
+                price,
+                est_return: (1.0 - price) * 100.0,
+                volume,
+                liquidity: market.liquidity.unwrap_or(0.0),
+                event_slug: event.slug.to_string(),
+                event_title: event.title.to_string(),
+                event_status: event.status,
+                end_date: event.end_date,
+            })
+        })
+        .collect()
+}
+
+/// Find yield opportunities across a list of markets (e.g., from `GammaClient::get_markets`)
+///
+/// Closed markets, markets without an event and markets whose 24h volume is below
+/// `min_volume` are skipped. Results are sorted by estimated return, highest first.
+pub fn find_yield_opportunities(
+    markets: &[Market],
+    min_prob: f64,
+    min_volume: f64,
+) -> Vec<YieldOpportunity> {
+    let mut opportunities: Vec<YieldOpportunity> = markets
+        .iter()
+        .filter(|market| !market.closed && market.volume_24hr.unwrap_or(0.0) >= min_volume)
+        .filter_map(|market| {
+            let event = market.event()?;
+            let context = EventContext {
+                slug: &event.slug,
+                title: &event.title,
+                status: event.status(),
+                end_date: parse_end_date(event.end_date.as_ref()),
+            };
+            Some(market_opportunities(market, &context, min_prob))
+        })
+        .flatten()
+        .collect();
+
+    opportunities.sort_by(|a, b| {
+        b.est_return
+            .partial_cmp(&a.est_return)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    opportunities
+}
+
+/// Find the best (highest return) yield opportunity across an event's open markets
+pub fn best_event_yield(event: &Event, min_prob: f64) -> Option<YieldOpportunity> {
+    let context = EventContext {
+        slug: &event.slug,
+        title: &event.title,
+        status: event.status(),
+        end_date: parse_end_date(event.end_date.as_ref()),
+    };
+
+    event
+        .markets
+        .iter()
+        .filter(|market| !market.closed)
+        .flat_map(|market| market_opportunities(market, &context, min_prob))
+        .fold(None, |best: Option<YieldOpportunity>, opp| match best {
+            Some(b) if b.est_return >= opp.est_return => Some(b),
+            _ => Some(opp),
+        })
+}
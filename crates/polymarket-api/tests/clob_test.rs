@@ -1,6 +1,15 @@
-use polymarket_api::clob::{
-    BatchTokenRequest, ClobClient, MidpointResponse, Orderbook, PriceHistoryResponse,
-    PriceInterval, PriceLevel, PriceResponse, Side, SpreadRequest, TokenPrices,
+use {
+    polymarket_api::{
+        clob::{
+            BatchTokenRequest, ClobClient, MidpointResponse, Orderbook, OrderbookCache,
+            PriceHistoryPoint, PriceHistoryResponse, PriceInterval, PriceLevel, PriceResponse,
+            PriceStats, RateLimiter, Side, SpreadRequest, TimeInForce, TokenPrices,
+        },
+        signing::SignatureType,
+        websocket::{self, OrderbookUpdate, PriceUpdate, WebSocketMessage},
+    },
+    std::time::Duration,
+    tokio::time::Instant,
 };
 
 // ============================================================================
@@ -124,6 +133,14 @@ fn test_side_serialization() {
     assert_eq!(sell, "\"SELL\"");
 }
 
+#[test]
+fn test_time_in_force_serialization() {
+    let gtc = serde_json::to_string(&TimeInForce::Gtc).expect("Should serialize");
+    let fok = serde_json::to_string(&TimeInForce::Fok).expect("Should serialize");
+    assert_eq!(gtc, "\"GTC\"");
+    assert_eq!(fok, "\"FOK\"");
+}
+
 #[test]
 fn test_batch_token_request_serialization() {
     let request = BatchTokenRequest {
@@ -170,6 +187,321 @@ fn test_token_prices_deserialization_empty() {
     assert!(prices.sell.is_none());
 }
 
+fn book(bids: &[(&str, &str)], asks: &[(&str, &str)]) -> Orderbook {
+    let levels = |levels: &[(&str, &str)]| {
+        levels
+            .iter()
+            .map(|(price, size)| PriceLevel {
+                price: price.to_string(),
+                size: size.to_string(),
+            })
+            .collect()
+    };
+    Orderbook {
+        bids: levels(bids),
+        asks: levels(asks),
+        market: None,
+        asset_id: None,
+        timestamp: None,
+        hash: None,
+        tick_size: None,
+        min_order_size: None,
+        neg_risk: None,
+    }
+}
+
+#[test]
+fn test_simulate_fill_buy_walks_asks() {
+    // Unsorted on purpose: the best ask (0.50) must be taken first
+    let orderbook = book(&[], &[("0.60", "100"), ("0.50", "100")]);
+    let estimate = orderbook
+        .simulate_fill(Side::Buy, 80.0)
+        .expect("Should estimate fill");
+
+    // $50 buys 100 @ 0.50, the remaining $30 buys 50 @ 0.60
+    assert!((estimate.shares - 150.0).abs() < 1e-9);
+    assert!((estimate.avg_price - 80.0 / 150.0).abs() < 1e-9);
+    assert_eq!(estimate.best_price, 0.50);
+    assert_eq!(estimate.worst_price, 0.60);
+    assert!(estimate.complete);
+    assert!((estimate.worst_slippage() - 0.2).abs() < 1e-9);
+    assert!((estimate.price_impact() - (80.0 / 150.0 - 0.50)).abs() < 1e-9);
+}
+
+#[test]
+fn test_simulate_fill_sell_walks_bids() {
+    let orderbook = book(&[("0.40", "100"), ("0.45", "100")], &[]);
+    let estimate = orderbook
+        .simulate_fill(Side::Sell, 20.0)
+        .expect("Should estimate fill");
+
+    // Fully filled at the best bid
+    assert_eq!(estimate.best_price, 0.45);
+    assert_eq!(estimate.worst_price, 0.45);
+    assert!(estimate.complete);
+    assert_eq!(estimate.worst_slippage(), 0.0);
+    assert_eq!(estimate.price_impact(), 0.0);
+}
+
+#[test]
+fn test_simulate_fill_insufficient_depth() {
+    let orderbook = book(&[], &[("0.50", "10"), ("bad", "10"), ("0.55", "0")]);
+    let estimate = orderbook
+        .simulate_fill(Side::Buy, 100.0)
+        .expect("Should estimate fill");
+    assert!(!estimate.complete);
+    assert!((estimate.amount - 5.0).abs() < 1e-9);
+
+    assert!(orderbook.simulate_fill(Side::Sell, 10.0).is_none());
+    assert!(orderbook.simulate_fill(Side::Buy, 0.0).is_none());
+}
+
+#[test]
+fn test_simulate_limit_fill_takes_only_crossing_levels() {
+    let orderbook = book(&[("0.40", "100"), ("0.45", "50")], &[
+        ("0.60", "100"),
+        ("0.50", "100"),
+    ]);
+
+    // Buy 150 @ 0.55: only the 0.50 level crosses
+    let estimate = orderbook
+        .simulate_limit_fill(Side::Buy, 0.55, 150.0)
+        .expect("Should estimate fill");
+    assert!((estimate.shares - 100.0).abs() < 1e-9);
+    assert!((estimate.avg_price - 0.50).abs() < 1e-9);
+    assert!(!estimate.complete);
+
+    // Sell 120 @ 0.40 crosses both bids
+    let estimate = orderbook
+        .simulate_limit_fill(Side::Sell, 0.40, 120.0)
+        .expect("Should estimate fill");
+    assert!((estimate.shares - 120.0).abs() < 1e-9);
+    assert!((estimate.amount - (50.0 * 0.45 + 70.0 * 0.40)).abs() < 1e-9);
+    assert_eq!(estimate.worst_price, 0.40);
+    assert!(estimate.complete);
+
+    // Nothing crosses a limit below the best ask
+    let estimate = orderbook
+        .simulate_limit_fill(Side::Buy, 0.45, 10.0)
+        .expect("Should estimate fill");
+    assert_eq!(estimate.shares, 0.0);
+    assert!(
+        orderbook
+            .simulate_limit_fill(Side::Buy, 0.55, 0.0)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_orderbook_summary() {
+    let orderbook = book(&[("0.40", "100"), ("0.48", "50"), ("0.30", "1000")], &[
+        ("0.52", "20"),
+        ("0.60", "10"),
+        ("0.55", "0"),
+    ]);
+    let summary = orderbook.summary(0.05, None);
+
+    assert_eq!(summary.best_bid, Some(0.48));
+    assert_eq!(summary.best_ask, Some(0.52));
+    assert!((summary.mid_price.expect("Should have mid") - 0.50).abs() < 1e-9);
+    assert!((summary.spread.expect("Should have spread") - 0.04).abs() < 1e-9);
+    assert!((summary.spread_bps.expect("Should have bps") - 800.0).abs() < 1e-6);
+    // Only levels within 5¢ of the 0.50 mid count
+    assert!((summary.bid_notional - 24.0).abs() < 1e-9);
+    assert!((summary.ask_notional - 10.4).abs() < 1e-9);
+
+    // Depth limits the levels counted, best first
+    let wide = orderbook.summary(1.0, Some(2));
+    assert!((wide.bid_notional - (24.0 + 40.0)).abs() < 1e-9);
+    assert!((wide.ask_notional - (10.4 + 6.0)).abs() < 1e-9);
+}
+
+#[test]
+fn test_orderbook_skips_malformed_levels() {
+    let orderbook = book(&[("0.45", "100"), ("NaN", "5"), ("0.50", "0")], &[
+        ("0.01", "0"),
+        ("0", "10"),
+        ("inf", "3"),
+        ("", "30"),
+        ("0.55", "abc"),
+        ("0.60", "10"),
+    ]);
+    assert_eq!(orderbook.levels_for(Side::Sell), vec![(0.45, 100.0)]);
+    assert_eq!(orderbook.levels_for(Side::Buy), vec![(0.60, 10.0)]);
+    let summary = orderbook.summary(0.0, None);
+    assert_eq!(summary.best_bid, Some(0.45));
+    assert_eq!(summary.best_ask, Some(0.60));
+}
+
+#[test]
+fn test_orderbook_summary_one_sided() {
+    let summary = book(&[("0.40", "100")], &[]).summary(0.05, None);
+    assert_eq!(summary.best_bid, Some(0.40));
+    assert!(summary.best_ask.is_none());
+    assert!(summary.mid_price.is_none());
+    assert!(summary.spread.is_none());
+    assert!(summary.spread_bps.is_none());
+    // Falls back to the best price as the band reference
+    assert!((summary.bid_notional - 40.0).abs() < 1e-9);
+    assert_eq!(summary.ask_notional, 0.0);
+}
+
+#[test]
+fn test_orderbook_apply_update() {
+    let mut orderbook = book(&[("0.40", "100"), ("0.45", "50")], &[("0.55", "20")]);
+    orderbook.asset_id = Some("1002".to_string());
+    let level = |price: &str, size: &str| websocket::PriceLevel {
+        price: price.to_string(),
+        size: size.to_string(),
+    };
+
+    // A book message is a snapshot: levels it leaves out are gone, empty ones dropped
+    let snapshot = WebSocketMessage::Orderbook(OrderbookUpdate {
+        market: "0xabc".to_string(),
+        asset_id: "1002".to_string(),
+        bids: vec![level("0.47", "10"), level("0.45", "0")],
+        asks: vec![level("0.55", "5")],
+        timestamp: None,
+    });
+    assert!(orderbook.apply_update(&snapshot));
+    let prices = |levels: &[PriceLevel]| -> Vec<(String, String)> {
+        levels
+            .iter()
+            .map(|l| (l.price.clone(), l.size.clone()))
+            .collect()
+    };
+    assert_eq!(prices(&orderbook.bids), vec![(
+        "0.47".to_string(),
+        "10".to_string()
+    )]);
+    assert_eq!(prices(&orderbook.asks), vec![(
+        "0.55".to_string(),
+        "5".to_string()
+    )]);
+
+    // A price change with side and size inserts an ask in order
+    let change = |asset_id: &str, side: Option<&str>| {
+        WebSocketMessage::Price(PriceUpdate {
+            market: "0xabc".to_string(),
+            asset_id: asset_id.to_string(),
+            price: "0.52".to_string(),
+            side: side.map(str::to_string),
+            size: Some("30".to_string()),
+            timestamp: None,
+        })
+    };
+    assert!(orderbook.apply_update(&change("1002", Some("SELL"))));
+    assert_eq!(orderbook.asks[0].price, "0.52");
+    assert_eq!(orderbook.summary(0.0, None).best_ask, Some(0.52));
+
+    // Other assets, plain price ticks and other messages leave the book alone
+    assert!(!orderbook.apply_update(&change("1003", Some("buy"))));
+    assert!(!orderbook.apply_update(&change("1002", None)));
+    assert!(!orderbook.apply_update(&WebSocketMessage::Unknown));
+    assert_eq!(orderbook.bids.len(), 1);
+    assert_eq!(orderbook.asks.len(), 2);
+}
+
+#[test]
+fn test_orderbook_cache() {
+    let with_asset = |asset_id: &str| Orderbook {
+        asset_id: Some(asset_id.to_string()),
+        ..book(&[("0.40", "100")], &[("0.60", "100")])
+    };
+    let cache = OrderbookCache::new(Duration::from_secs(60));
+    cache.insert(&[with_asset("1"), with_asset("2")]);
+
+    // Served in request order once every token is cached
+    let cached = cache.get_all(["2", "1"]).expect("Should hit cache");
+    let ids: Vec<_> = cached.iter().map(|b| b.asset_id.as_deref()).collect();
+    assert_eq!(ids, vec![Some("2"), Some("1")]);
+    // One missing token means a full fetch
+    assert!(cache.get_all(["1", "3"]).is_none());
+
+    // Clones share entries
+    cache.clone().clear();
+    assert!(cache.get_all(["1"]).is_none());
+
+    let expired = OrderbookCache::new(Duration::ZERO);
+    expired.insert(&[with_asset("1")]);
+    assert!(expired.get_all(["1"]).is_none());
+}
+
+#[test]
+fn test_side_from_str() {
+    assert_eq!("buy".parse::<Side>(), Ok(Side::Buy));
+    assert_eq!(" SELL ".parse::<Side>(), Ok(Side::Sell));
+    assert!("bid".parse::<Side>().is_err());
+}
+
+#[test]
+fn test_price_stats_from_history() {
+    let history = |prices: &[f64]| PriceHistoryResponse {
+        history: prices
+            .iter()
+            .enumerate()
+            .map(|(t, &p)| PriceHistoryPoint { t: t as i64, p })
+            .collect(),
+    };
+    assert_eq!(
+        PriceStats::from_history(&history(&[0.42, 0.55, 0.38, 0.5])),
+        Some(PriceStats {
+            high: 0.55,
+            low: 0.38
+        })
+    );
+    assert!(PriceStats::from_history(&history(&[])).is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_spreads_requests() {
+    let limiter = RateLimiter::new(20);
+    assert_eq!(limiter.requests_per_sec(), 20);
+
+    // A full bucket lets one second of requests through at once
+    let start = Instant::now();
+    for _ in 0..20 {
+        limiter.acquire().await;
+    }
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    // Then each request waits for a new token (50ms at 20/s)
+    for _ in 0..2 {
+        limiter.clone().acquire().await;
+    }
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed >= Duration::from_millis(100) && elapsed < Duration::from_millis(110),
+        "{:?}",
+        elapsed
+    );
+
+    // Zero is treated as one request per second instead of never refilling
+    assert_eq!(RateLimiter::new(0).requests_per_sec(), 1);
+}
+
+#[tokio::test]
+async fn test_submit_order_rejects_eoa_for_other_wallet() {
+    // Placeholder credentials and key (private key 1), not a real account
+    let mut client = ClobClient::with_auth(
+        "key".to_string(),
+        "c2VjcmV0".to_string(),
+        "pass".to_string(),
+        "0x0000000000000000000000000000000000000002".to_string(),
+    );
+    client
+        .set_private_key("0x0000000000000000000000000000000000000000000000000000000000000001")
+        .expect("Should parse key");
+    client.set_signature_type(SignatureType::Eoa);
+
+    // Fails before any request is sent
+    let err = client
+        .submit_order("1", Side::Buy, 0.5, 10.0, TimeInForce::Gtc)
+        .await
+        .expect_err("An EOA signature can't authorize another wallet");
+    assert!(err.to_string().contains("signature_type"));
+}
+
 // ============================================================================
 // Integration Tests (require network)
 // ============================================================================
@@ -243,6 +575,7 @@ async fn test_get_trades() {
         .get_trades(
             "will-mike-wheeler-die-in-stranger-things-season-5",
             Some(10),
+            None,
         )
         .await;
 
@@ -260,6 +593,7 @@ async fn test_get_trades_by_asset() {
         .get_trades_by_asset(
             "50229529616777085027502492682800195748509080624860515924115435116786910229377",
             Some(10),
+            None,
         )
         .await;
 
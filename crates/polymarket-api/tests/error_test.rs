@@ -0,0 +1,34 @@
+use {polymarket_api::PolymarketError, std::time::Duration};
+
+#[test]
+fn test_http_errors_are_distinguishable() {
+    let not_found = PolymarketError::Http {
+        status: 404,
+        body: String::new(),
+    };
+    assert!(not_found.is_not_found());
+    assert!(!not_found.is_transient());
+    assert_eq!(not_found.to_string(), "Not found (HTTP 404)");
+
+    let rate_limited = PolymarketError::RateLimited {
+        retry_after: Some(Duration::from_secs(5)),
+    };
+    assert!(rate_limited.is_rate_limited());
+    assert!(rate_limited.is_transient());
+    assert_eq!(rate_limited.status(), Some(429));
+    assert_eq!(rate_limited.to_string(), "Rate limited (retry after 5s)");
+
+    let server_error = PolymarketError::Http {
+        status: 503,
+        body: "upstream unavailable".to_string(),
+    };
+    assert!(server_error.is_transient());
+    assert_eq!(
+        server_error.to_string(),
+        "Server error (HTTP 503): upstream unavailable"
+    );
+
+    assert!(PolymarketError::Timeout.is_transient());
+    assert_eq!(PolymarketError::Timeout.status(), None);
+    assert!(!PolymarketError::Deserialize("missing field".to_string()).is_transient());
+}
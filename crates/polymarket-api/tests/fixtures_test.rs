@@ -0,0 +1,472 @@
+use polymarket_api::{
+    CacheConfig, ClobClient, DataClient, Fixtures, GammaClient,
+    clob::{AssetType, BatchTokenRequest, Side, TimeInForce},
+    yields::{DEFAULT_MIN_PROB, find_yield_opportunities},
+};
+
+const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Condition ID of the fixture election markets
+const MARKET: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+fn gamma_client() -> GammaClient {
+    let mut client = GammaClient::new();
+    client.set_fixtures(Fixtures::new(FIXTURES_DIR));
+    client
+}
+
+fn clob_client() -> ClobClient {
+    let mut client = ClobClient::new();
+    client.set_fixtures(Fixtures::new(FIXTURES_DIR));
+    client
+}
+
+fn data_client() -> DataClient {
+    let mut client = DataClient::new();
+    client.set_fixtures(Fixtures::new(FIXTURES_DIR));
+    client
+}
+
+// ============================================================================
+// Fixtures loader
+// ============================================================================
+
+#[test]
+fn test_fixtures_missing_file_is_none() {
+    let fixtures = Fixtures::new(FIXTURES_DIR);
+    let missing: Option<Vec<String>> = fixtures.load("gamma/does-not-exist").expect("Should load");
+    assert!(missing.is_none());
+
+    let empty: Vec<String> = fixtures
+        .load_or_default("gamma/does-not-exist")
+        .expect("Should default");
+    assert!(empty.is_empty());
+
+    assert!(
+        fixtures
+            .load_required::<Vec<String>>("gamma/does-not-exist")
+            .is_err()
+    );
+}
+
+#[test]
+fn test_fixtures_invalid_json_is_error() {
+    let dir = std::env::temp_dir().join(format!("polymarket-fixtures-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("gamma")).expect("Should create dir");
+    std::fs::write(dir.join("gamma/events.json"), "not json").expect("Should write");
+
+    let fixtures = Fixtures::new(&dir);
+    let result: polymarket_api::Result<Option<Vec<String>>> = fixtures.load("gamma/events");
+    assert!(result.is_err());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+// ============================================================================
+// Clients in fixture mode (no network required)
+// ============================================================================
+
+#[tokio::test]
+async fn test_gamma_fixtures() {
+    let client = gamma_client();
+
+    let events = client
+        .get_trending_events(None, None, Some(1))
+        .await
+        .expect("Should load events");
+    assert_eq!(events.len(), 1, "Should respect limit");
+
+    let event = client
+        .get_event_by_slug("example-championship-final")
+        .await
+        .expect("Should load event")
+        .expect("Should find event");
+    assert_eq!(event.markets.len(), 2);
+
+    let results = client
+        .search_events("ELECTION", None)
+        .await
+        .expect("Should search events");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].slug, "example-election-winner");
+
+    // Market search matches questions and returns the parent event
+    let markets = client
+        .search_markets("team blue", None)
+        .await
+        .expect("Should search markets");
+    assert_eq!(markets.len(), 1);
+    assert_eq!(markets[0].market.question, "Will Team Blue win?");
+    assert_eq!(markets[0].event.slug, "example-championship-final");
+    let markets = client
+        .search_markets("win", Some(2))
+        .await
+        .expect("Should search markets");
+    assert_eq!(markets.len(), 2);
+
+    assert_eq!(client.get_status().await.expect("Should get status"), "OK");
+}
+
+#[tokio::test]
+async fn test_gamma_fixture_events_batch() {
+    let client = gamma_client();
+    let ids = vec!["101".to_string(), "404".to_string(), "100".to_string()];
+    let events = client
+        .get_events_batch(&ids)
+        .await
+        .expect("Should load events");
+    // Requested order is kept and unknown IDs are dropped
+    let found: Vec<&str> = events.iter().map(|e| e.id.as_str()).collect();
+    assert_eq!(found, vec!["101", "100"]);
+    assert!(events.iter().all(|e| !e.markets.is_empty()));
+
+    assert!(
+        client
+            .get_events_batch(&[])
+            .await
+            .expect("Should handle no IDs")
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn test_gamma_fixture_related_events() {
+    let client = gamma_client();
+    let events = client
+        .get_active_events(None)
+        .await
+        .expect("Should load events");
+    let final_event = events
+        .iter()
+        .find(|e| e.slug == "example-championship-final")
+        .expect("Should have the championship event");
+
+    // The only other sports event is the event itself
+    let related = client
+        .get_related_events(final_event, None)
+        .await
+        .expect("Should find related events");
+    assert!(related.is_empty());
+
+    let mut other = final_event.clone();
+    other.id = "999".to_string();
+    other.slug = "example-championship-rematch".to_string();
+    let related = client
+        .get_related_events(&other, None)
+        .await
+        .expect("Should find related events");
+    let slugs: Vec<&str> = related.iter().map(|e| e.slug.as_str()).collect();
+    assert_eq!(slugs, vec!["example-championship-final"]);
+}
+
+#[tokio::test]
+async fn test_gamma_fixture_markets_yield() {
+    let client = gamma_client();
+    let markets = client
+        .get_markets(Some(true), Some(false), None)
+        .await
+        .expect("Should load markets");
+    assert_eq!(markets.len(), 5);
+
+    let opportunities = find_yield_opportunities(&markets, DEFAULT_MIN_PROB, 0.0);
+    assert_eq!(opportunities.len(), 1);
+    assert_eq!(opportunities[0].market_name, "Candidate C");
+}
+
+#[tokio::test]
+async fn test_gamma_fixture_market_info_by_asset_id() {
+    let client = gamma_client();
+    let info = client
+        .get_market_info_by_asset_id("1003")
+        .await
+        .expect("Should look up token")
+        .expect("Should find token");
+    assert_eq!(info.event_slug, "example-election-winner");
+    assert_eq!(info.market_question, "Will Candidate A win?");
+    assert_eq!(info.outcome_index, Some(1));
+    assert_eq!(info.outcome(), Some("No"));
+    assert_eq!(info.price(), Some(0.38));
+
+    let missing = client
+        .get_market_info_by_asset_id("9999")
+        .await
+        .expect("Should look up token");
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn test_gamma_cache_config_ttl_per_endpoint() {
+    let dir = std::env::temp_dir().join(format!("polymarket-cache-config-{}", std::process::id()));
+    let mut client = GammaClient::with_cache(&dir).expect("Should create cache");
+    client.set_fixtures(Fixtures::new(FIXTURES_DIR));
+    client.set_cache_config(CacheConfig {
+        market_info_ttl: 86_400,
+        event_ttl: 300,
+        profile_ttl: 3_600,
+    });
+
+    client
+        .get_market_info_by_asset_id("1003")
+        .await
+        .expect("Should look up token")
+        .expect("Should find token");
+
+    let ttl = |key: &str| {
+        let content = std::fs::read_to_string(dir.join(format!("{}.json", key)))
+            .expect("Should write cache entry");
+        let entry: serde_json::Value = serde_json::from_str(&content).expect("Should parse entry");
+        entry["ttl_seconds"].as_u64()
+    };
+    assert_eq!(ttl("market_info_1003"), Some(86_400));
+    assert_eq!(ttl("active_events_1000"), Some(300));
+
+    // A second token is found in the cached event listing
+    let other = client
+        .get_market_info_by_asset_id("1004")
+        .await
+        .expect("Should look up token")
+        .expect("Should find token");
+    assert_eq!(other.market_question, "Will Candidate B win?");
+    assert_eq!(ttl("market_info_1004"), Some(86_400));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_gamma_fixture_market_by_condition_id() {
+    let client = gamma_client();
+    let market = client
+        .get_market_by_condition_id(
+            "0x0000000000000000000000000000000000000000000000000000000000000002",
+        )
+        .await
+        .expect("Should look up market")
+        .expect("Should find market");
+    assert_eq!(market.id.as_deref(), Some("502"));
+
+    let missing = client
+        .get_market_by_condition_id("0xunknown")
+        .await
+        .expect("Should look up market");
+    assert!(missing.is_none());
+}
+
+#[tokio::test]
+async fn test_clob_fixtures() {
+    let client = clob_client();
+
+    let book = client
+        .get_orderbook_by_asset("1002")
+        .await
+        .expect("Should load orderbook");
+    assert!(!book.bids.is_empty());
+    assert!(!book.asks.is_empty());
+
+    let empty = client
+        .get_orderbook_by_asset("unknown-token")
+        .await
+        .expect("Unknown tokens should get an empty orderbook");
+    assert!(empty.bids.is_empty());
+    assert_eq!(empty.asset_id.as_deref(), Some("unknown-token"));
+
+    let prices = client
+        .get_prices_batch(vec![BatchTokenRequest {
+            token_id: "1002".to_string(),
+            side: Side::Buy,
+        }])
+        .await
+        .expect("Should load prices");
+    assert_eq!(prices.len(), 1);
+
+    let price = client
+        .get_price("1002", Side::Buy)
+        .await
+        .expect("Should load price");
+    assert_eq!(price.price, "0.63");
+
+    let midpoint = client
+        .get_midpoint("1002")
+        .await
+        .expect("Should compute midpoint");
+    assert_eq!(midpoint.mid.parse::<f64>().ok(), Some(0.62));
+}
+
+#[tokio::test]
+async fn test_fixture_mode_refuses_account_writes() {
+    // Placeholder credentials and key (private key 1), not a real account
+    let mut clob = ClobClient::with_auth(
+        "key".to_string(),
+        "c2VjcmV0".to_string(),
+        "pass".to_string(),
+        "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf".to_string(),
+    );
+    clob.set_fixtures(Fixtures::new(FIXTURES_DIR));
+    clob.set_private_key("0x0000000000000000000000000000000000000000000000000000000000000001")
+        .expect("Should parse key");
+    assert!(clob.can_trade());
+
+    let balance = clob
+        .get_balance_allowance(AssetType::Collateral)
+        .await
+        .expect("Should load balance");
+    assert_eq!(balance.balance, "250000000");
+
+    let err = clob
+        .submit_order("1002", Side::Buy, 0.5, 10.0, TimeInForce::Gtc)
+        .await
+        .expect_err("Orders must not be posted in fixtures mode");
+    assert!(err.to_string().contains("fixtures mode"));
+    assert!(clob.cancel_order("0x1").await.is_err());
+    assert!(clob.cancel_all_orders().await.is_err());
+
+    let gamma = gamma_client();
+    assert!(gamma.add_favorite_event("1").await.is_err());
+    assert!(gamma.remove_favorite_event(1).await.is_err());
+}
+
+#[tokio::test]
+async fn test_clob_fixture_trades_offset() {
+    let client = clob_client();
+
+    let trades = client
+        .get_trades_by_asset("1002", Some(2), None)
+        .await
+        .expect("Should load trades");
+    assert_eq!(trades.len(), 2);
+    assert_eq!(trades[0].timestamp, 1_700_000_300);
+
+    // Only the requested asset's (or market's) trades are returned
+    let other = client
+        .get_trades_by_asset("1003", None, None)
+        .await
+        .expect("Should load trades");
+    assert_eq!(other.len(), 1);
+    assert_eq!(other[0].asset_id.as_deref(), Some("1003"));
+    assert!(
+        client
+            .get_trades("0x02", None, None)
+            .await
+            .expect("Should load trades")
+            .is_empty()
+    );
+
+    // The offset is skipped client-side, past the end gives an empty page
+    let page = client
+        .get_trades(MARKET, Some(2), Some(2))
+        .await
+        .expect("Should load trades");
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].timestamp, 1_700_000_200);
+    let past_end = client
+        .get_trades(MARKET, Some(2), Some(5))
+        .await
+        .expect("Should load trades");
+    assert!(past_end.is_empty());
+}
+
+#[tokio::test]
+async fn test_data_fixtures() {
+    let client = data_client();
+
+    let trades = client
+        .get_trades_by_event_slug("example-election-winner", Some(2), None)
+        .await
+        .expect("Should load trades");
+    assert_eq!(trades.len(), 2);
+    assert!(
+        trades
+            .iter()
+            .all(|t| t.event_slug == "example-election-winner")
+    );
+
+    let offset = client
+        .get_trades_by_event_slug("example-election-winner", Some(10), Some(2))
+        .await
+        .expect("Should load trades");
+    assert_eq!(offset.len(), 1);
+
+    let by_user = client
+        .get_trades_by_user(
+            "0x0000000000000000000000000000000000000000",
+            Some(10),
+            Some(1),
+        )
+        .await
+        .expect("Should load trades");
+    assert_eq!(by_user.len(), 3);
+    let other_user = client
+        .get_trades_by_user("0x1111111111111111111111111111111111111111", None, None)
+        .await
+        .expect("Should load trades");
+    assert!(other_user.is_empty());
+
+    let positions = client
+        .get_positions("0x0000000000000000000000000000000000000000")
+        .await
+        .expect("Should load positions");
+    assert_eq!(positions.len(), 1);
+
+    let closed = client
+        .get_closed_positions("0x0000000000000000000000000000000000000000")
+        .await
+        .expect("Should load closed positions");
+    assert_eq!(closed.len(), 1);
+    assert!(closed[0].size.is_none());
+    assert_eq!(closed[0].realized_pnl, Some(12.5));
+
+    // No portfolio fixture is shipped
+    assert!(
+        client
+            .get_portfolio("0x0000000000000000000000000000000000000000")
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_data_fixture_holders() {
+    let client = data_client();
+
+    let holders = client
+        .get_holders(
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            10,
+        )
+        .await
+        .expect("Should load holders");
+    let sizes: Vec<f64> = holders.iter().map(|h| h.size).collect();
+    assert_eq!(sizes, vec![12500.0, 8000.0, 3200.5]);
+
+    let outcomes = vec!["Yes".to_string(), "No".to_string()];
+    assert_eq!(holders[0].display_name(), "Example-Whale");
+    assert_eq!(holders[1].display_name(), "example-trader");
+    assert_eq!(holders[1].outcome(&outcomes), Some("No"));
+    assert_eq!(holders[2].display_name(), "0x0000...0002");
+    assert_eq!(holders[0].value(0.62), 7750.0);
+
+    // The limit applies per outcome token
+    let top = client
+        .get_holders("0x01", 1)
+        .await
+        .expect("Should load holders");
+    assert_eq!(top.len(), 2);
+}
+
+#[tokio::test]
+async fn test_gamma_fixture_profile_by_address() {
+    let client = gamma_client();
+
+    let profile = client
+        .get_public_profile("0x0000000000000000000000000000000000000001")
+        .await
+        .expect("Should load profile")
+        .expect("Should find profile");
+    assert_eq!(profile.pseudonym.as_deref(), Some("Example-Trader"));
+    assert!(
+        client
+            .get_public_profile("0x0000000000000000000000000000000000000002")
+            .await
+            .expect("Should load profile")
+            .is_none()
+    );
+}
@@ -1,4 +1,13 @@
-use polymarket_api::gamma::{GammaClient, Market, PublicProfile, Series, StatusResponse, Tag};
+use {
+    polymarket_api::{
+        FileCache, PolymarketError,
+        gamma::{
+            Event, GammaClient, GammaClientConfig, Market, OutcomeNameStyle,
+            PUBLIC_PROFILE_CACHE_TTL, PublicProfile, Series, StatusResponse, Tag,
+        },
+    },
+    std::time::Duration,
+};
 
 // ============================================================================
 // Unit Tests (no network required)
@@ -11,6 +20,36 @@ fn test_status_response_deserialization() {
     assert_eq!(response.status, "ok");
 }
 
+#[test]
+fn test_client_config_retry_policy() {
+    let config = GammaClientConfig::default();
+    assert_eq!(config.timeout, Duration::from_secs(10));
+    assert_eq!(config.max_retries, 2);
+
+    let http = |status: u16| PolymarketError::Http {
+        status,
+        body: String::new(),
+    };
+    let rate_limited = PolymarketError::RateLimited { retry_after: None };
+    assert!(config.should_retry(&rate_limited));
+    assert!(config.should_retry(&PolymarketError::Timeout));
+    assert!(config.should_retry(&http(503)));
+    assert!(!config.should_retry(&http(404)));
+    assert!(!config.should_retry(&http(400)));
+    assert!(!config.should_retry(&PolymarketError::Deserialize("bad".to_string())));
+
+    let no_5xx = GammaClientConfig {
+        retry_on_5xx: false,
+        ..config
+    };
+    assert!(no_5xx.should_retry(&rate_limited));
+    assert!(!no_5xx.should_retry(&http(502)));
+
+    assert_eq!(config.retry_delay(1), Duration::from_millis(500));
+    assert_eq!(config.retry_delay(2), Duration::from_secs(1));
+    assert_eq!(config.retry_delay(10), Duration::from_secs(10));
+}
+
 #[test]
 fn test_tag_deserialization() {
     let json = r#"{"id": "123", "label": "Politics", "slug": "politics"}"#;
@@ -71,6 +110,78 @@ fn test_public_profile_deserialization_minimal() {
     assert!(profile.name.is_none());
 }
 
+#[test]
+fn test_public_profile_cache_ttl() {
+    let dir = std::env::temp_dir().join(format!("polymarket-profile-cache-{}", std::process::id()));
+    let cache = FileCache::new(&dir).expect("Should create cache");
+    let profile = PublicProfile {
+        address: Some("0x0000000000000000000000000000000000000000".to_string()),
+        name: Some("TestUser".to_string()),
+        pseudonym: None,
+        bio: None,
+        profile_image: None,
+        profile_image_optimized: None,
+    };
+
+    cache
+        .set_with_ttl("public_profile_0x0", &profile, PUBLIC_PROFILE_CACHE_TTL)
+        .expect("Should write cache entry");
+    let cached: Option<PublicProfile> = cache.get("public_profile_0x0").expect("Should read");
+    assert_eq!(cached.and_then(|p| p.name), Some("TestUser".to_string()));
+
+    // Backdate the entry past its TTL
+    let path = dir.join("public_profile_0x0.json");
+    let mut entry: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).expect("Should read file"))
+            .expect("Should parse entry");
+    assert_eq!(entry["ttl_seconds"], PUBLIC_PROFILE_CACHE_TTL);
+    entry["cached_at"] = 0.into();
+    std::fs::write(&path, entry.to_string()).expect("Should write file");
+    let expired: Option<PublicProfile> = cache.get("public_profile_0x0").expect("Should read");
+    assert!(expired.is_none());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_cache_clear_expired_and_size() {
+    let dir = std::env::temp_dir().join(format!("polymarket-cache-clear-{}", std::process::id()));
+    let cache = FileCache::new(&dir).expect("Should create cache");
+    cache.set("fresh", "a").expect("Should write cache entry");
+    cache
+        .set_with_ttl("stale", "b", 60)
+        .expect("Should write cache entry");
+    cache
+        .set_with_ttl("unexpired", "c", 3600)
+        .expect("Should write cache entry");
+
+    let usage = cache.size_on_disk().expect("Should measure cache");
+    assert_eq!(usage.entries, 3);
+    assert!(usage.bytes > 0);
+
+    // Backdate the short-lived entry past its TTL
+    let path = dir.join("stale.json");
+    let mut entry: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).expect("Should read file"))
+            .expect("Should parse entry");
+    entry["cached_at"] = 0.into();
+    std::fs::write(&path, entry.to_string()).expect("Should write file");
+
+    let removed = cache.clear_expired().expect("Should clear expired entries");
+    assert_eq!(removed.entries, 1);
+    assert!(!path.exists());
+    assert_eq!(cache.size_on_disk().expect("Should measure").entries, 2);
+
+    let removed = cache.clear().expect("Should clear cache");
+    assert_eq!(removed.entries, 2);
+    assert_eq!(
+        cache.size_on_disk().expect("Should measure"),
+        Default::default()
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
 #[test]
 fn test_market_deserialization_with_json_string() {
     // Test that we can deserialize a market where clobTokenIds is a JSON string
@@ -157,6 +268,130 @@ fn test_market_deserialization_with_null_clob_token_ids() {
     assert_eq!(market.clob_token_ids, None);
 }
 
+#[test]
+fn test_event_has_tradeable_markets() {
+    let event = |markets: &str| -> Event {
+        let json = format!(
+            r#"{{"id": "1", "slug": "e", "title": "E", "active": true, "closed": false, "markets": {}}}"#,
+            markets
+        );
+        serde_json::from_str(&json).expect("Should deserialize")
+    };
+    assert!(!event("[]").has_tradeable_markets());
+    assert!(!event(r#"[{"question": "Q?"}]"#).has_tradeable_markets());
+    assert!(!event(r#"[{"question": "Q?", "clobTokenIds": []}]"#).has_tradeable_markets());
+    assert!(event(r#"[{"question": "Q?", "clobTokenIds": ["1", "2"]}]"#).has_tradeable_markets());
+}
+
+#[test]
+fn test_market_display_name() {
+    let json = r#"
+    {
+        "question": "Will Candidate A win the election?",
+        "groupItemTitle": "Candidate A",
+        "outcomes": ["Yes", "No"]
+    }
+    "#;
+    let market: Market = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(market.display_name(), "Candidate A");
+
+    let json = r#"{"question": "Will it rain?", "groupItemTitle": ""}"#;
+    let market: Market = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(market.display_name(), "Will it rain?");
+}
+
+#[test]
+fn test_market_resolved_outcome() {
+    let json = r#"{"question": "Will it rain?", "outcomes": ["Yes", "No"], "outcomePrices": ["0", "1"], "closed": true}"#;
+    let market: Market = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(market.resolved_outcome(), Some(1));
+    assert_eq!(market.winning_outcome(), Some("No"));
+
+    // Open markets have not resolved, whatever their prices
+    let json = r#"{"question": "Will it rain?", "outcomePrices": ["0.995", "0.005"]}"#;
+    let market: Market = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(market.resolved_outcome(), None);
+
+    // Closed without a winner yet (e.g. awaiting resolution)
+    let json = r#"{"question": "Will it rain?", "outcomePrices": ["0.5", "0.5"], "closed": true}"#;
+    let market: Market = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(market.resolved_outcome(), None);
+    assert_eq!(market.winning_outcome(), None);
+}
+
+#[test]
+fn test_market_display_outcome_styles() {
+    let json = r#"
+    {
+        "question": "Will Candidate A win the election?",
+        "groupItemTitle": "Candidate A",
+        "outcomes": ["Yes", "No"]
+    }
+    "#;
+    let market: Market = serde_json::from_str(json).expect("Should deserialize");
+
+    assert_eq!(
+        market.display_outcome("Yes", OutcomeNameStyle::GroupTitle),
+        "Candidate A"
+    );
+    assert_eq!(
+        market.display_outcome("No", OutcomeNameStyle::GroupTitle),
+        "No"
+    );
+    assert_eq!(market.display_outcome("Yes", OutcomeNameStyle::Full), "Yes");
+    assert_eq!(
+        market.display_outcome("Real Madrid CF", OutcomeNameStyle::Acronym),
+        "RMC"
+    );
+    assert_eq!(
+        market.display_outcome("Arsenal", OutcomeNameStyle::Acronym),
+        "Arsenal"
+    );
+    assert_eq!(
+        market.display_outcome("Manchester United", OutcomeNameStyle::Truncate(10)),
+        "Manches..."
+    );
+    assert_eq!(
+        market.display_outcome("Chelsea", OutcomeNameStyle::Truncate(10)),
+        "Chelsea"
+    );
+    // Too short for an ellipsis: never longer than the limit
+    assert_eq!(OutcomeNameStyle::Truncate(2).shorten("Chelsea"), "Ch");
+    assert_eq!(OutcomeNameStyle::Truncate(0).shorten("Chelsea"), "");
+    assert_eq!(OutcomeNameStyle::Truncate(3).shorten("Chelsea"), "...");
+}
+
+#[test]
+fn test_market_outcomes_with_prices() {
+    let json = r#"{"question": "Q?", "outcomes": ["Yes", "No"], "outcomePrices": ["0.7", "0.3"]}"#;
+    let market: Market = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(market.outcomes_with_prices(), vec![
+        ("Yes", 0.7),
+        ("No", 0.3)
+    ]);
+
+    // Mismatched lengths only pair the entries present in both arrays
+    let json = r#"{"question": "Q?", "outcomes": ["Yes"], "outcomePrices": ["0.7", "0.3"]}"#;
+    let market: Market = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(market.outcomes_with_prices(), vec![("Yes", 0.7)]);
+
+    // Unparseable prices are skipped
+    let json = r#"{"question": "Q?", "outcomes": ["Yes", "No"], "outcomePrices": ["?", "0.3"]}"#;
+    let market: Market = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(market.outcomes_with_prices(), vec![("No", 0.3)]);
+}
+
+#[test]
+fn test_outcome_name_style_from_str() {
+    assert_eq!("full".parse(), Ok(OutcomeNameStyle::Full));
+    assert_eq!("Acronym".parse(), Ok(OutcomeNameStyle::Acronym));
+    assert_eq!("group".parse(), Ok(OutcomeNameStyle::GroupTitle));
+    assert_eq!("truncate".parse(), Ok(OutcomeNameStyle::Truncate(20)));
+    assert_eq!("truncate:8".parse(), Ok(OutcomeNameStyle::Truncate(8)));
+    assert!("truncate:abc".parse::<OutcomeNameStyle>().is_err());
+    assert!("short".parse::<OutcomeNameStyle>().is_err());
+}
+
 #[tokio::test]
 async fn test_gamma_client_creation() {
     let _client = GammaClient::new();
@@ -0,0 +1,112 @@
+use {
+    futures_util::StreamExt,
+    polymarket_api::{
+        RTDSFormatter,
+        display::short_address,
+        rtds::{RTDSClient, RTDSMessage, ReconnectPolicy},
+    },
+    std::time::Duration,
+};
+
+#[test]
+fn test_reconnect_backoff() {
+    let policy = ReconnectPolicy {
+        max_retries: 5,
+        base_delay: Duration::from_secs(1),
+    };
+    assert_eq!(policy.delay(1, 0.0), Duration::from_secs(1));
+    assert_eq!(policy.delay(2, 0.0), Duration::from_secs(2));
+    assert_eq!(policy.delay(4, 0.0), Duration::from_secs(8));
+    // Jitter adds up to half the backoff
+    assert_eq!(policy.delay(2, 1.0), Duration::from_secs(3));
+    assert_eq!(policy.delay(2, 5.0), Duration::from_secs(3));
+    // Capped at a minute before jitter
+    assert_eq!(policy.delay(30, 0.0), Duration::from_secs(60));
+}
+
+/// An RTDS trade as received from the WebSocket
+fn sample_message() -> serde_json::Value {
+    serde_json::json!({
+        "connection_id": "conn-1",
+        "payload": {
+            "asset": "123",
+            "side": "BUY",
+            "price": 0.42,
+            "size": 10.0,
+            "timestamp": 1700000000,
+            "title": "Will it rain?",
+            "slug": "will-it-rain",
+            "eventSlug": "weather",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "name": "trader",
+            "pseudonym": "Example-Trader",
+            "proxyWallet": "0x0000000000000000000000000000000000000000",
+            "transactionHash": "0x00",
+            "conditionId": null,
+            "bio": null,
+            "icon": null,
+            "profile_image": null
+        },
+        "timestamp": 1700000000123_i64,
+        "topic": "activity",
+        "type": "trades"
+    })
+}
+
+#[test]
+fn test_rtds_message_serializes_as_received() {
+    let raw = sample_message();
+    let msg: RTDSMessage = serde_json::from_value(raw.clone()).expect("Should deserialize");
+
+    // One line per message, with the wire field names
+    let line = serde_json::to_string(&msg).expect("Should serialize");
+    assert!(!line.contains('\n'));
+    let reparsed: serde_json::Value = serde_json::from_str(&line).expect("Should parse");
+    assert_eq!(reparsed, raw);
+}
+
+#[test]
+fn test_rtds_formatter_wallet_address() {
+    colored::control::set_override(false);
+    let mut raw = sample_message();
+    raw["payload"]["proxyWallet"] = "0x00000000000000000000000000000000000000ab".into();
+    let msg: RTDSMessage = serde_json::from_value(raw).expect("Should deserialize");
+
+    // Pseudonym only by default
+    let formatted = RTDSFormatter::format_message(&msg);
+    assert!(formatted.contains("User: trader (Example-Trader)"));
+    assert!(!formatted.contains("Wallet"));
+
+    let formatted = RTDSFormatter::default().with_address(true).format(&msg);
+    assert!(
+        formatted.contains("Wallet: 0x0000...00ab (0x00000000000000000000000000000000000000ab)")
+    );
+    assert_eq!(short_address("0x12"), "0x12");
+}
+
+#[tokio::test]
+async fn test_reconnect_requires_subscription() {
+    // Configuration errors fail right away instead of being retried
+    let client = RTDSClient::new().with_reconnect(3, Duration::from_secs(60));
+    let mut events = 0;
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.connect_and_listen_events(|_| events += 1),
+    )
+    .await
+    .expect("Should not wait for a reconnect");
+    assert!(result.is_err());
+    assert_eq!(events, 0);
+}
+
+#[tokio::test]
+async fn test_stream_ends_when_listener_fails() {
+    // Without a subscription the listener fails before connecting and the stream ends
+    let mut stream = RTDSClient::new().into_stream();
+    let next = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("Should end without waiting");
+    assert!(next.is_none());
+    assert!(stream.finish().await.is_err());
+}
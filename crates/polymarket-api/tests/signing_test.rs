@@ -0,0 +1,165 @@
+use polymarket_api::{
+    clob::Side,
+    signing::{OrderSigner, SignatureType, exchange_address, order_amounts, order_digest},
+};
+
+// Placeholder key (private key 1), not a real wallet
+const TEST_KEY: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+const TEST_ADDRESS: &str = "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf";
+const TEST_TOKEN: &str =
+    "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+
+#[test]
+fn test_signer_address() {
+    let signer = OrderSigner::from_private_key(TEST_KEY).expect("Should parse key");
+    assert_eq!(signer.address(), TEST_ADDRESS);
+
+    let unprefixed = OrderSigner::from_private_key(TEST_KEY.trim_start_matches("0x"))
+        .expect("Should parse key without 0x");
+    assert_eq!(unprefixed.address(), TEST_ADDRESS);
+
+    assert!(OrderSigner::from_private_key("not-a-key").is_err());
+}
+
+#[test]
+fn test_order_amounts() {
+    // Buy 100 shares at 55¢: give $55, receive 100 shares
+    assert_eq!(
+        order_amounts(Side::Buy, 0.55, 100.0, 0.01),
+        (55_000_000, 100_000_000)
+    );
+    // Sell gives shares, receives dollars
+    assert_eq!(
+        order_amounts(Side::Sell, 0.55, 100.0, 0.01),
+        (100_000_000, 55_000_000)
+    );
+    // Size is rounded down to 2 decimals, price to the tick
+    assert_eq!(
+        order_amounts(Side::Buy, 0.5549, 10.129, 0.01),
+        (5_566_000, 10_120_000)
+    );
+}
+
+#[test]
+fn test_sign_order() {
+    let signer = OrderSigner::from_private_key(TEST_KEY).expect("Should parse key");
+    let order = signer
+        .sign_order(
+            TEST_ADDRESS,
+            SignatureType::Eoa,
+            TEST_TOKEN,
+            Side::Buy,
+            0.55,
+            100.0,
+            0.01,
+            false,
+        )
+        .expect("Should sign order");
+
+    assert_eq!(order.signer, TEST_ADDRESS);
+    assert_eq!(order.maker_amount, "55000000");
+    assert_eq!(order.taker_amount, "100000000");
+    assert_eq!(order.signature_type, 0);
+    // 65-byte signature: r, s and v (27 or 28)
+    assert_eq!(order.signature.len(), 2 + 130);
+    assert!(order.signature.ends_with("1b") || order.signature.ends_with("1c"));
+
+    let json = serde_json::to_value(&order).expect("Should serialize");
+    assert_eq!(json["side"], "BUY");
+    assert_eq!(json["makerAmount"], "55000000");
+    assert!(json["tokenId"].is_string());
+}
+
+// Expected digests and signatures were computed with a standalone reference (EIP-712
+// encoding, Keccak-256 and RFC 6979 secp256k1 signing) for the same inputs.
+#[test]
+fn test_sign_order_known_vectors() {
+    let signer = OrderSigner::from_private_key(TEST_KEY).expect("Should parse key");
+    let cases = [
+        (
+            TEST_ADDRESS,
+            SignatureType::Eoa,
+            false,
+            "6dce340962ed6aa367e5e02a71e771996de25928a7afae26409541369d15eaad",
+            "0xac64a378f2db8aabf2b51188b4cbe89a96a5743fe7bd7037e04d9c25bf3a8a1b2760b030e0e681cd13c6a80ac3d262dc3c3bcc0c483270b5fb683be21c0a66671c",
+        ),
+        (
+            // Placeholder Safe address, not a real wallet
+            "0x0000000000000000000000000000000000000002",
+            SignatureType::PolyGnosisSafe,
+            true,
+            "5e6ce353559e2c9f4c8a234e1b75ef9872109f8c874d34b96f0b1e20955bc37b",
+            "0x8de59dc912acbb8ad929c3b963715ca235c170dff8b9bf4bf3876005428ab7547643f453ef6c6cf953f56e2a58e96181ac011a9c265bb11a5f8d98a3e418e89e1b",
+        ),
+    ];
+    for (maker, signature_type, neg_risk, digest, signature) in cases {
+        let order = signer
+            .sign_order_with_salt(
+                123_456_789,
+                maker,
+                signature_type,
+                TEST_TOKEN,
+                Side::Buy,
+                0.55,
+                100.0,
+                0.01,
+                neg_risk,
+            )
+            .expect("Should sign order");
+        assert_eq!(order.signature_type, signature_type as u8);
+        assert_eq!(
+            hex::encode(order_digest(&order, exchange_address(neg_risk)).expect("Should hash")),
+            digest
+        );
+        assert_eq!(order.signature, signature);
+    }
+}
+
+#[test]
+fn test_signature_type_names() {
+    let parsed: SignatureType =
+        serde_json::from_str("\"poly_gnosis_safe\"").expect("Should parse signature type");
+    assert_eq!(parsed, SignatureType::PolyGnosisSafe);
+    assert_eq!(
+        serde_json::to_string(&SignatureType::PolyProxy).expect("Should serialize"),
+        "\"poly_proxy\""
+    );
+}
+
+#[test]
+fn test_sign_order_rejects_invalid_price() {
+    let signer = OrderSigner::from_private_key(TEST_KEY).expect("Should parse key");
+    for price in [0.0, 1.0, 1.5] {
+        assert!(
+            signer
+                .sign_order(
+                    TEST_ADDRESS,
+                    SignatureType::Eoa,
+                    "1",
+                    Side::Buy,
+                    price,
+                    10.0,
+                    0.01,
+                    false
+                )
+                .is_err()
+        );
+    }
+
+    // Prices between ticks are rejected rather than rounded
+    let sign = |price, tick_size| {
+        signer.sign_order(
+            TEST_ADDRESS,
+            SignatureType::Eoa,
+            "1",
+            Side::Buy,
+            price,
+            10.0,
+            tick_size,
+            false,
+        )
+    };
+    assert!(sign(0.425, 0.01).is_err());
+    assert!(sign(0.425, 0.001).is_ok());
+    assert!(sign(0.43, 0.01).is_ok());
+}
@@ -222,6 +222,8 @@ fn test_price_update_with_timestamp() {
         market: "0x123".to_string(),
         asset_id: "0x456".to_string(),
         price: "0.55".to_string(),
+        side: None,
+        size: None,
         timestamp: Some(1234567890),
     };
 
@@ -236,6 +238,8 @@ fn test_price_update_without_timestamp() {
         market: "0x123".to_string(),
         asset_id: "0x456".to_string(),
         price: "0.55".to_string(),
+        side: None,
+        size: None,
         timestamp: None,
     };
 
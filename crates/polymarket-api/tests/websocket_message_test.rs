@@ -1,7 +1,7 @@
 //! Tests for WebSocketMessage enum
 
 use polymarket_api::websocket::{
-    WebSocketMessage,
+    MessageKind, WebSocketMessage,
     messages::SubscribedMessage,
     types::{ErrorMessage, OrderUpdate, OrderbookUpdate, PriceLevel, PriceUpdate, TradeUpdate},
 };
@@ -74,6 +74,8 @@ fn test_websocket_message_price() {
         market: "0x123".to_string(),
         asset_id: "0x456".to_string(),
         price: "0.55".to_string(),
+        side: None,
+        size: None,
         timestamp: Some(1234567890),
     };
 
@@ -293,3 +295,36 @@ fn test_round_trip_websocket_message_orderbook() {
         _ => panic!("Round trip failed"),
     }
 }
+
+#[test]
+fn test_websocket_message_kind() {
+    let json = r#"{"type": "price", "market": "0x123", "asset_id": "0x456", "price": "0.55"}"#;
+    let msg: WebSocketMessage = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(msg.kind(), MessageKind::Price);
+    assert_eq!(WebSocketMessage::Unknown.kind(), MessageKind::Unknown);
+
+    assert_eq!("trade".parse::<MessageKind>(), Ok(MessageKind::Trade));
+    assert_eq!(" Price ".parse::<MessageKind>(), Ok(MessageKind::Price));
+    assert_eq!("book".parse::<MessageKind>(), Ok(MessageKind::Orderbook));
+    assert!("trades".parse::<MessageKind>().is_err());
+}
+
+#[test]
+fn test_websocket_message_asset_id() {
+    let json = r#"{
+        "type": "price",
+        "market": "0x123",
+        "asset_id": "0x456",
+        "price": "0.55",
+        "timestamp": 1234567890
+    }"#;
+    let msg: WebSocketMessage = serde_json::from_str(json).expect("Should deserialize");
+    assert_eq!(msg.asset_id(), Some("0x456"));
+
+    let msg = WebSocketMessage::Subscribed(SubscribedMessage {
+        message: "Subscribed successfully".to_string(),
+        assets_ids: None,
+        markets: None,
+    });
+    assert_eq!(msg.asset_id(), None);
+}
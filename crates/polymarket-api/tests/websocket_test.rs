@@ -73,3 +73,24 @@ async fn test_websocket_connection_with_subscription() {
 
     // Test passes if we got this far without panicking
 }
+
+#[test]
+fn test_subscription_messages_are_chunked() {
+    let asset_ids: Vec<String> = (1..=5).map(|i| format!("token{}", i)).collect();
+
+    let client = PolymarketWebSocket::new(asset_ids.clone()).with_chunk_size(2);
+    let chunks: Vec<Vec<String>> = client
+        .subscription_messages()
+        .into_iter()
+        .map(|msg| msg.assets_ids.expect("Should subscribe to asset IDs"))
+        .collect();
+    assert_eq!(chunks, vec![
+        vec!["token1".to_string(), "token2".to_string()],
+        vec!["token3".to_string(), "token4".to_string()],
+        vec!["token5".to_string()],
+    ]);
+
+    // The default chunk size keeps small lists on one connection
+    let client = PolymarketWebSocket::new(asset_ids);
+    assert_eq!(client.subscription_messages().len(), 1);
+}
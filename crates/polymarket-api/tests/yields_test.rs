@@ -0,0 +1,199 @@
+use polymarket_api::{
+    gamma::{Event, Market, OutcomeNameStyle},
+    yields::{best_event_yield, find_yield_opportunities},
+};
+
+fn market(json: &str) -> Market {
+    serde_json::from_str(json).expect("Should deserialize market")
+}
+
+fn market_with_event(question: &str, outcomes: &str, prices: &str) -> Market {
+    market(&format!(
+        r#"{{
+            "question": "{question}",
+            "outcomes": {outcomes},
+            "outcomePrices": {prices},
+            "volume24hr": 1000.0,
+            "active": true,
+            "events": [{{
+                "id": "1",
+                "slug": "test-event",
+                "title": "Test Event",
+                "endDate": "2030-01-01T00:00:00Z",
+                "active": true
+            }}]
+        }}"#
+    ))
+}
+
+fn event(markets: Vec<Market>) -> Event {
+    let mut event: Event = serde_json::from_str(
+        r#"{"id": "1", "slug": "test-event", "title": "Test Event", "active": true, "closed": false}"#,
+    )
+    .expect("Should deserialize event");
+    event.markets = markets;
+    event
+}
+
+#[test]
+fn test_price_at_one_is_excluded() {
+    let markets = vec![market_with_event(
+        "Resolved already?",
+        r#"["Yes", "No"]"#,
+        r#"["1.0", "0.0"]"#,
+    )];
+    let opportunities = find_yield_opportunities(&markets, 0.95, 0.0);
+    assert!(opportunities.is_empty());
+}
+
+#[test]
+fn test_price_at_min_prob_is_included() {
+    let markets = vec![market_with_event(
+        "Boundary?",
+        r#"["Yes", "No"]"#,
+        r#"["0.95", "0.05"]"#,
+    )];
+    let opportunities = find_yield_opportunities(&markets, 0.95, 0.0);
+    assert_eq!(opportunities.len(), 1);
+    let opp = &opportunities[0];
+    assert_eq!(opp.outcome, "Yes");
+    assert_eq!(opp.price, 0.95);
+    assert!((opp.est_return - 5.0).abs() < 1e-9);
+    assert_eq!(opp.event_slug, "test-event");
+    assert_eq!(opp.event_status, "active");
+    assert!(opp.end_date.is_some());
+}
+
+#[test]
+fn test_price_below_min_prob_is_excluded() {
+    let markets = vec![market_with_event(
+        "Too uncertain?",
+        r#"["Yes", "No"]"#,
+        r#"["0.9499", "0.0501"]"#,
+    )];
+    assert!(find_yield_opportunities(&markets, 0.95, 0.0).is_empty());
+}
+
+#[test]
+fn test_unparseable_prices_are_skipped() {
+    let markets = vec![market_with_event(
+        "Garbage prices?",
+        r#"["Yes", "No", "Maybe"]"#,
+        r#"["n/a", "", "0.97"]"#,
+    )];
+    let opportunities = find_yield_opportunities(&markets, 0.95, 0.0);
+    assert_eq!(opportunities.len(), 1);
+    assert_eq!(opportunities[0].outcome, "Maybe");
+}
+
+#[test]
+fn test_mismatched_outcomes_are_skipped() {
+    // Extra prices without an outcome name must not produce invented labels
+    let markets = vec![market_with_event(
+        "Missing outcomes?",
+        r#"["Yes"]"#,
+        r#"["0.02", "0.98"]"#,
+    )];
+    assert!(find_yield_opportunities(&markets, 0.95, 0.0).is_empty());
+
+    // Extra outcome names without a price are ignored too
+    let markets = vec![market_with_event(
+        "Missing prices?",
+        r#"["Yes", "No", "Maybe"]"#,
+        r#"["0.97", "0.03"]"#,
+    )];
+    let opportunities = find_yield_opportunities(&markets, 0.95, 0.0);
+    assert_eq!(opportunities.len(), 1);
+    assert_eq!(opportunities[0].outcome, "Yes");
+}
+
+#[test]
+fn test_low_volume_and_closed_markets_are_skipped() {
+    let mut closed = market_with_event("Closed?", r#"["Yes", "No"]"#, r#"["0.97", "0.03"]"#);
+    closed.closed = true;
+    let mut low_volume = market_with_event("Quiet?", r#"["Yes", "No"]"#, r#"["0.97", "0.03"]"#);
+    low_volume.volume_24hr = Some(10.0);
+    let no_event = market(r#"{"question": "Orphan?", "outcomePrices": ["0.97", "0.03"]}"#);
+
+    let opportunities = find_yield_opportunities(&[closed, low_volume, no_event], 0.95, 100.0);
+    assert!(opportunities.is_empty());
+}
+
+#[test]
+fn test_opportunities_sorted_by_return() {
+    let markets = vec![
+        market_with_event("Safe?", r#"["Yes", "No"]"#, r#"["0.99", "0.01"]"#),
+        market_with_event("Riskier?", r#"["Yes", "No"]"#, r#"["0.96", "0.04"]"#),
+    ];
+    let opportunities = find_yield_opportunities(&markets, 0.95, 0.0);
+    assert_eq!(opportunities.len(), 2);
+    assert_eq!(opportunities[0].market_name, "Riskier?");
+    assert_eq!(opportunities[1].market_name, "Safe?");
+}
+
+#[test]
+fn test_best_event_yield_selects_highest_return() {
+    let mut closed = market_with_event("Closed?", r#"["Yes", "No"]"#, r#"["0.95", "0.05"]"#);
+    closed.closed = true;
+    let event = event(vec![
+        market_with_event("First?", r#"["Yes", "No"]"#, r#"["0.99", "0.01"]"#),
+        market_with_event("Second?", r#"["Yes", "No"]"#, r#"["0.03", "0.97"]"#),
+        market_with_event("Third?", r#"["Yes", "No"]"#, r#"["0.5", "0.5"]"#),
+        closed,
+    ]);
+
+    let best = best_event_yield(&event, 0.95).expect("Should find a yield");
+    assert_eq!(best.market_name, "Second?");
+    assert_eq!(best.outcome, "No");
+    assert_eq!(best.price, 0.97);
+}
+
+#[test]
+fn test_best_event_yield_none_without_candidates() {
+    let event = event(vec![market_with_event(
+        "Close race?",
+        r#"["Yes", "No"]"#,
+        r#"["0.5", "0.5"]"#,
+    )]);
+    assert!(best_event_yield(&event, 0.95).is_none());
+}
+
+#[test]
+fn test_est_return_annualized() {
+    let markets = vec![market_with_event(
+        "Boundary?",
+        r#"["Yes", "No"]"#,
+        r#"["0.95", "0.05"]"#,
+    )];
+    let mut opp = find_yield_opportunities(&markets, 0.95, 0.0).remove(0);
+    let end = opp.end_date.expect("Should have an end date");
+
+    // 5% over 73 days is 25% a year
+    let now = end - chrono::Duration::days(73);
+    assert!((opp.days_remaining(now).expect("Should have days") - 73.0).abs() < 1e-9);
+    let annualized = opp.est_return_annualized(now).expect("Should annualize");
+    assert!((annualized - 25.0).abs() < 1e-9);
+
+    // Less than a day left counts as one day, past the end date there is nothing to earn
+    let annualized = opp
+        .est_return_annualized(end - chrono::Duration::hours(1))
+        .expect("Should annualize");
+    assert!((annualized - 5.0 * 365.0).abs() < 1e-9);
+    assert!(opp.est_return_annualized(end).is_none());
+
+    opp.end_date = None;
+    assert!(opp.days_remaining(now).is_none());
+    assert!(opp.est_return_annualized(now).is_none());
+}
+
+#[test]
+fn test_opportunity_outcome_uses_group_title() {
+    let mut candidate =
+        market_with_event("Will Alice win?", r#"["Yes", "No"]"#, r#"["0.97", "0.03"]"#);
+    candidate.group_item_title = Some("Alice".to_string());
+    let opportunities = find_yield_opportunities(&[candidate], 0.95, 0.0);
+    let opp = opportunities.first().expect("Should find opportunity");
+
+    assert_eq!(opp.display_outcome(OutcomeNameStyle::GroupTitle), "Alice");
+    assert_eq!(opp.display_outcome(OutcomeNameStyle::Full), "Yes");
+}